@@ -0,0 +1,142 @@
+use anyhow::{Context, Result};
+use ramekin_client::apis::auth_api;
+use ramekin_client::apis::configuration::Configuration;
+use ramekin_client::models::LoginRequest;
+use serde::Deserialize;
+use std::path::Path;
+
+/// Restore response (matches server's RestoreBackupResponse)
+#[derive(Debug, Deserialize)]
+struct RestoreBackupResponse {
+    recipes: usize,
+    recipe_versions: usize,
+    tags: usize,
+    photos: usize,
+    meal_plans: usize,
+    shopping_list_items: usize,
+}
+
+async fn login(server: &str, username: &str, password: &str) -> Result<Configuration> {
+    let mut config = Configuration::new();
+    config.base_path = server.to_string();
+
+    let login_response = auth_api::login(
+        &config,
+        LoginRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+        },
+    )
+    .await
+    .context("Failed to login")?;
+
+    config.bearer_access_token = Some(login_response.token);
+    Ok(config)
+}
+
+/// Download a full account backup archive
+pub async fn backup(
+    server: &str,
+    username: &str,
+    password: &str,
+    output_path: &Path,
+) -> Result<()> {
+    let config = login(server, username, password).await?;
+
+    println!("Backing up account... (this may take a while)");
+
+    let mut request = config
+        .client
+        .get(format!("{}/api/account/backup", config.base_path));
+
+    if let Some(ref token) = config.bearer_access_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Failed to send backup request")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Backup failed with status {}: {}", status, body);
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .context("Failed to read backup response body")?;
+
+    std::fs::write(output_path, &bytes)
+        .with_context(|| format!("Failed to write to file: {}", output_path.display()))?;
+
+    println!(
+        "Backed up account to: {} ({} bytes)",
+        output_path.display(),
+        bytes.len()
+    );
+
+    Ok(())
+}
+
+/// Upload a backup archive and restore it into the account
+pub async fn restore(server: &str, username: &str, password: &str, file_path: &Path) -> Result<()> {
+    let config = login(server, username, password).await?;
+
+    let data = std::fs::read(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+
+    let part = reqwest::multipart::Part::bytes(data)
+        .file_name("backup.zip")
+        .mime_str("application/zip")?;
+    let form = reqwest::multipart::Form::new().part("file", part);
+
+    // The generated client has no multipart support for this endpoint, so post directly
+    let mut request = reqwest::Client::new()
+        .post(format!("{}/api/account/restore", config.base_path))
+        .multipart(form);
+
+    if let Some(ref token) = config.bearer_access_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Failed to send restore request")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Restore failed with status {}: {}", status, body);
+    }
+
+    let response_text = response
+        .text()
+        .await
+        .context("Failed to read restore response body")?;
+
+    let restored: RestoreBackupResponse =
+        serde_json::from_str(&response_text).with_context(|| {
+            format!(
+                "Failed to parse restore response as JSON: {}",
+                response_text
+            )
+        })?;
+
+    println!();
+    println!("{}", "=".repeat(50));
+    println!("RESTORE COMPLETE");
+    println!("{}", "=".repeat(50));
+    println!("Recipes: {}", restored.recipes);
+    println!("Recipe versions: {}", restored.recipe_versions);
+    println!("Tags: {}", restored.tags);
+    println!("Photos: {}", restored.photos);
+    println!("Meal plans: {}", restored.meal_plans);
+    println!("Shopping list items: {}", restored.shopping_list_items);
+    println!("{}", "=".repeat(50));
+
+    Ok(())
+}
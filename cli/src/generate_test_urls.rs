@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use chrono::Utc;
 use quick_xml::de::from_str;
-use ramekin_core::http::{CachingClient, HttpClient};
+use ramekin_core::http::{CachingClient, HttpClient, RobotsPolicy};
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -93,8 +93,12 @@ pub async fn generate_test_urls(
     min_year: u32,
     no_limit: bool,
 ) -> Result<()> {
-    // Use CachingClient with rate limiting to avoid hammering servers
-    let client = CachingClient::new().context("Failed to create HTTP client")?;
+    // Use CachingClient with rate limiting to avoid hammering servers; this is a
+    // crawl, so robots.txt rules and Crawl-delay apply
+    let client = CachingClient::builder()
+        .robots_policy(RobotsPolicy::Crawl)
+        .build()
+        .context("Failed to create HTTP client")?;
 
     // When filtering to a single site, always merge to preserve other sites
     let merge = merge || site_filter.is_some();
@@ -5,6 +5,7 @@ use ramekin_client::apis::auth_api;
 use ramekin_client::apis::configuration::Configuration;
 use ramekin_client::models::LoginRequest;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
@@ -13,6 +14,10 @@ use zip::ZipArchive;
 /// Paprika recipe format
 #[derive(Debug, Deserialize)]
 struct PaprikaRecipe {
+    /// Stable recipe identifier (missing from some very old exports)
+    uid: Option<String>,
+    /// Content hash, changes whenever the recipe is edited in Paprika
+    hash: Option<String>,
     name: String,
     ingredients: Option<String>,
     directions: Option<String>,
@@ -89,6 +94,36 @@ struct ImportRecipeResponse {
     status: String,
 }
 
+/// Paprika import request body (matches server's ImportPaprikaRecipeRequest)
+#[derive(Debug, Serialize)]
+struct ImportPaprikaRecipeRequest {
+    raw_recipe: ImportRawRecipe,
+    photo_ids: Vec<uuid::Uuid>,
+    uid: String,
+    hash: String,
+}
+
+/// Paprika import response (matches server's ImportPaprikaRecipeResponse)
+#[derive(Debug, Deserialize)]
+struct ImportPaprikaRecipeResponse {
+    outcome: String,
+    job_id: Option<uuid::Uuid>,
+}
+
+/// Previously imported Paprika recipes (matches server's PaprikaSyncStateResponse)
+#[derive(Debug, Deserialize)]
+struct PaprikaSyncStateResponse {
+    recipes: Vec<PaprikaSyncEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaprikaSyncEntry {
+    uid: String,
+    hash: String,
+    /// None while the previous import's job is still running
+    recipe_id: Option<uuid::Uuid>,
+}
+
 /// Upload a photo via multipart form and return its UUID
 pub async fn upload_photo(config: &Configuration, image_data: &[u8]) -> Result<uuid::Uuid> {
     upload_photo_with_client(config, image_data, &reqwest::Client::new()).await
@@ -227,6 +262,101 @@ async fn import_recipe(
     Ok(import_response)
 }
 
+/// Fetch the Paprika recipes already imported into the account, by uid
+async fn fetch_sync_state(config: &Configuration) -> Result<HashMap<String, PaprikaSyncEntry>> {
+    let mut request =
+        reqwest::Client::new().get(format!("{}/api/import/paprika", config.base_path));
+
+    if let Some(ref token) = config.bearer_access_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Failed to send Paprika sync state request")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!(
+            "Fetching Paprika sync state failed with status {} ({}): {}",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or("Unknown"),
+            body
+        );
+    }
+
+    let response_text = response
+        .text()
+        .await
+        .context("Failed to read Paprika sync state response body")?;
+
+    let state: PaprikaSyncStateResponse =
+        serde_json::from_str(&response_text).with_context(|| {
+            format!(
+                "Failed to parse Paprika sync state response as JSON: {}",
+                response_text
+            )
+        })?;
+
+    Ok(state
+        .recipes
+        .into_iter()
+        .map(|entry| (entry.uid.clone(), entry))
+        .collect())
+}
+
+/// Call the Paprika import endpoint, which adds, versions or skips by uid and hash
+async fn import_paprika_recipe(
+    config: &Configuration,
+    raw_recipe: ImportRawRecipe,
+    photo_ids: Vec<uuid::Uuid>,
+    uid: &str,
+    hash: &str,
+) -> Result<ImportPaprikaRecipeResponse> {
+    let request_body = ImportPaprikaRecipeRequest {
+        raw_recipe,
+        photo_ids,
+        uid: uid.to_string(),
+        hash: hash.to_string(),
+    };
+
+    let mut request = reqwest::Client::new()
+        .post(format!("{}/api/import/paprika", config.base_path))
+        .json(&request_body);
+
+    if let Some(ref token) = config.bearer_access_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Failed to send import request")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!(
+            "Import failed with status {} ({}): {}",
+            status.as_u16(),
+            status.canonical_reason().unwrap_or("Unknown"),
+            body
+        );
+    }
+
+    let response_text = response
+        .text()
+        .await
+        .context("Failed to read import response body")?;
+
+    let import_response: ImportPaprikaRecipeResponse = serde_json::from_str(&response_text)
+        .with_context(|| format!("Failed to parse import response as JSON: {}", response_text))?;
+
+    Ok(import_response)
+}
+
 pub async fn import(
     server: &str,
     username: &str,
@@ -259,7 +389,15 @@ pub async fn import(
 
     println!("Found {} recipes in archive", archive.len());
 
-    let mut success_count = 0;
+    // Recipes imported by a previous run are skipped unless they changed in Paprika
+    let synced = fetch_sync_state(&config).await?;
+    if !synced.is_empty() {
+        println!("{} recipes already imported", synced.len());
+    }
+
+    let mut added_count = 0;
+    let mut updated_count = 0;
+    let mut unchanged_count = 0;
     let mut error_count = 0;
 
     for i in 0..archive.len() {
@@ -288,6 +426,25 @@ pub async fn import(
 
         let recipe_name = recipe.name.clone();
 
+        // Decide before uploading photos, which would be orphaned if the server
+        // then skipped the recipe
+        let sync_key = recipe.uid.as_deref().zip(recipe.hash.as_deref());
+        if let Some(entry) = sync_key.and_then(|(uid, _)| synced.get(uid)) {
+            if Some(entry.hash.as_str()) == recipe.hash.as_deref() {
+                tracing::debug!(recipe = %recipe_name, "Skipping unchanged recipe");
+                unchanged_count += 1;
+                continue;
+            }
+            if entry.recipe_id.is_none() {
+                println!(
+                    "  Skipped '{}': a previous import is still running",
+                    recipe_name
+                );
+                error_count += 1;
+                continue;
+            }
+        }
+
         // Upload all photos from the photos array (these are full resolution)
         // Fall back to photo_data if photos array is empty
         let mut photo_ids = Vec::new();
@@ -347,13 +504,41 @@ pub async fn import(
         // Convert to RawRecipe format and call the import endpoint
         let raw_recipe = convert_to_raw_recipe(&recipe, preserve_tags);
 
-        match import_recipe(&config, raw_recipe, photo_ids).await {
+        let Some((uid, hash)) = sync_key else {
+            // Without a uid the recipe can't be matched on a later run
+            match import_recipe(&config, raw_recipe, photo_ids).await {
+                Ok(response) => {
+                    println!(
+                        "  Imported: {} (job_id: {}, status: {})",
+                        recipe_name, response.job_id, response.status
+                    );
+                    added_count += 1;
+                }
+                Err(e) => {
+                    println!("  Error importing '{}': {}", recipe_name, e);
+                    error_count += 1;
+                }
+            }
+            continue;
+        };
+
+        match import_paprika_recipe(&config, raw_recipe, photo_ids, uid, hash).await {
             Ok(response) => {
-                println!(
-                    "  Imported: {} (job_id: {}, status: {})",
-                    recipe_name, response.job_id, response.status
-                );
-                success_count += 1;
+                let job = response
+                    .job_id
+                    .map(|id| format!(" (job_id: {})", id))
+                    .unwrap_or_default();
+                match response.outcome.as_str() {
+                    "added" => {
+                        println!("  Imported: {}{}", recipe_name, job);
+                        added_count += 1;
+                    }
+                    "updated" => {
+                        println!("  Updated: {}{}", recipe_name, job);
+                        updated_count += 1;
+                    }
+                    _ => unchanged_count += 1,
+                }
             }
             Err(e) => {
                 println!("  Error importing '{}': {}", recipe_name, e);
@@ -366,7 +551,9 @@ pub async fn import(
     println!("{}", "=".repeat(50));
     println!("IMPORT COMPLETE");
     println!("{}", "=".repeat(50));
-    println!("Successful: {}", success_count);
+    println!("Added: {}", added_count);
+    println!("Updated: {}", updated_count);
+    println!("Unchanged: {}", unchanged_count);
     println!("Errors: {}", error_count);
     println!("{}", "=".repeat(50));
 
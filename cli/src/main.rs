@@ -1,3 +1,4 @@
+mod backup;
 mod export;
 mod generate_test_urls;
 mod import;
@@ -161,6 +162,36 @@ enum Commands {
         #[arg(short, long, value_name = "FILE")]
         output: PathBuf,
     },
+    /// Back up the whole account (recipes, history, tags, photos, meal plans, shopping list)
+    Backup {
+        /// Server URL
+        #[arg(long, env = "API_BASE_URL")]
+        server_url: String,
+        /// Username to authenticate as
+        #[arg(long)]
+        username: String,
+        /// Password for authentication
+        #[arg(long)]
+        password: String,
+        /// Output file path
+        #[arg(short, long, value_name = "FILE")]
+        output: PathBuf,
+    },
+    /// Restore an account backup created by the backup command
+    Restore {
+        /// Server URL
+        #[arg(long, env = "API_BASE_URL")]
+        server_url: String,
+        /// Username to authenticate as
+        #[arg(long)]
+        username: String,
+        /// Password for authentication
+        #[arg(long)]
+        password: String,
+        /// Path to the backup archive
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
     /// Generate a list of test URLs from top recipe sites
     GenerateTestUrls {
         /// Output file path
@@ -233,6 +264,18 @@ enum Commands {
         #[arg(long)]
         cache_dir: Option<PathBuf>,
     },
+    /// Prune HTML cache: drop expired entries, then least recently used ones over the size limit
+    PipelineCachePrune {
+        /// Cache directory (defaults to ~/.ramekin/pipeline-cache/html)
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+        /// Size to shrink the cache to, e.g. "20G" (defaults to RAMEKIN_HTTP_CACHE_MAX_SIZE)
+        #[arg(long)]
+        max_size: Option<String>,
+        /// Remove entries fetched more than this many days ago
+        #[arg(long)]
+        max_age_days: Option<u64>,
+    },
     /// Generate ingredient parsing test fixtures from pipeline run
     IngredientTestsGenerate {
         /// Directory containing pipeline run results
@@ -348,6 +391,22 @@ async fn main() -> Result<()> {
         } => {
             export::export_all(&server_url, &username, &password, &output).await?;
         }
+        Commands::Backup {
+            server_url,
+            username,
+            password,
+            output,
+        } => {
+            backup::backup(&server_url, &username, &password, &output).await?;
+        }
+        Commands::Restore {
+            server_url,
+            username,
+            password,
+            file,
+        } => {
+            backup::restore(&server_url, &username, &password, &file).await?;
+        }
         Commands::GenerateTestUrls {
             output,
             num_sites,
@@ -447,6 +506,14 @@ async fn main() -> Result<()> {
             let cache_dir = cache_dir.unwrap_or_else(ramekin_core::http::DiskCache::default_dir);
             pipeline_orchestrator::clear_cache(&cache_dir)?;
         }
+        Commands::PipelineCachePrune {
+            cache_dir,
+            max_size,
+            max_age_days,
+        } => {
+            let cache_dir = cache_dir.unwrap_or_else(ramekin_core::http::DiskCache::default_dir);
+            pipeline_orchestrator::prune_cache(&cache_dir, max_size.as_deref(), max_age_days)?;
+        }
         Commands::IngredientTestsGenerate {
             runs_dir,
             fixtures_dir,
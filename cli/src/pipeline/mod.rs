@@ -29,7 +29,8 @@ use steps::{ApplyAutoTagsStep, FetchImagesStep, SaveRecipeStep};
 
 /// Build a step registry with all CLI pipeline steps.
 ///
-/// The HTTP client is injected for fetch_html and fetch_images steps.
+/// The HTTP client is injected for fetch_html, extract_recipe (alternate pages)
+/// and fetch_images steps.
 /// The AI client is created from environment variables.
 /// User tags are used for auto-tagging evaluation.
 /// When `offline` is true, the AI client uses cache only (no API calls, no cost).
@@ -41,7 +42,7 @@ pub fn build_registry<C: HttpClient + Clone + Send + Sync + 'static>(
     let mut registry = StepRegistry::new();
 
     registry.register(Box::new(FetchHtmlStep::new(client.clone())));
-    registry.register(Box::new(ExtractRecipeStep));
+    registry.register(Box::new(ExtractRecipeStep::new(Arc::new(client.clone()))));
     registry.register(Box::new(FetchImagesStep::new(client)));
     registry.register(Box::new(ParseIngredientsStep));
     registry.register(Box::new(SaveRecipeStep));
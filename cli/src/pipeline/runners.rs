@@ -6,7 +6,7 @@ use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
 
-use ramekin_core::http::{CachingClient, HttpClient};
+use ramekin_core::http::{BrowserClient, CachingClient, HttpClient};
 use ramekin_core::pipeline::{run_pipeline, StepRegistry};
 pub use ramekin_core::PipelineStep;
 
@@ -120,6 +120,22 @@ pub async fn run_fetch_html(url: &str, client: &CachingClient, force: bool) -> S
     }
 }
 
+/// Load a URL's HTML from the cache, rendered in the browser if it needs to be.
+async fn load_cached_html(
+    url: &str,
+    client: &CachingClient,
+    browser: Option<&BrowserClient>,
+) -> Option<String> {
+    let html = {
+        let _span = tracing::info_span!("load_cached_html").entered();
+        client.get_cached_html(url)?
+    };
+    match browser {
+        Some(browser) => Some(browser.resolve_html(url, html).await),
+        None => Some(html),
+    }
+}
+
 // ============================================================================
 // Run all steps
 // ============================================================================
@@ -127,10 +143,13 @@ pub async fn run_fetch_html(url: &str, client: &CachingClient, force: bool) -> S
 /// Run all pipeline steps for a URL using the generic pipeline infrastructure.
 ///
 /// Takes an `Arc<CachingClient>` for shared ownership across pipeline steps,
-/// plus a shared `StepRegistry` (built once per pipeline run).
+/// plus a shared `StepRegistry` (built once per pipeline run). When a
+/// `BrowserClient` is given, HTML loaded straight from the cache goes through
+/// it too, so script-rendered pages are rendered on the fast path as well.
 pub async fn run_all_steps(
     url: &str,
     client: Arc<CachingClient>,
+    browser: Option<Arc<BrowserClient>>,
     run_dir: &Path,
     force_fetch: bool,
     registry: Arc<StepRegistry>,
@@ -161,11 +180,8 @@ pub async fn run_all_steps(
         }
         // After force fetch, pre-populate store and start from extract_recipe
         // Only cache in memory - skip disk write since HTML is already in disk cache
-        {
-            let _span = tracing::info_span!("load_cached_html").entered();
-            if let Some(html) = client.get_cached_html(url) {
-                store.cache_only("fetch_html", serde_json::json!({ "html": html }));
-            }
+        if let Some(html) = load_cached_html(url, &client, browser.as_deref()).await {
+            store.cache_only("fetch_html", serde_json::json!({ "html": html }));
         }
         "extract_recipe"
     } else if already_cached {
@@ -179,11 +195,8 @@ pub async fn run_all_steps(
         });
         // Pre-populate store with cached HTML so extract_recipe can find it
         // Only cache in memory - skip disk write since HTML is already in disk cache
-        {
-            let _span = tracing::info_span!("load_cached_html").entered();
-            if let Some(html) = client.get_cached_html(url) {
-                store.cache_only("fetch_html", serde_json::json!({ "html": html }));
-            }
+        if let Some(html) = load_cached_html(url, &client, browser.as_deref()).await {
+            store.cache_only("fetch_html", serde_json::json!({ "html": html }));
         }
         "extract_recipe"
     } else {
@@ -243,12 +256,8 @@ fn extract_stats_from_output(output: &serde_json::Value) -> Option<ExtractionSta
     let method_used = output.get("method_used")?.as_str()?;
     let all_attempts = output.get("all_attempts")?.as_array()?;
 
-    let method = match method_used {
-        "json_ld" => ramekin_core::ExtractionMethod::JsonLd,
-        "microdata" => ramekin_core::ExtractionMethod::Microdata,
-        "html_fallback" => ramekin_core::ExtractionMethod::HtmlFallback,
-        _ => return None,
-    };
+    let method: ramekin_core::ExtractionMethod =
+        serde_json::from_value(serde_json::Value::String(method_used.to_string())).ok()?;
 
     let jsonld_success = all_attempts.iter().any(|a| {
         a.get("method").and_then(|m| m.as_str()) == Some("json_ld")
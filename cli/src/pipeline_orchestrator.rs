@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
@@ -20,7 +20,10 @@ use crate::pipeline::{
     staging_dir, AllStepsResult, ExtractionStats, IngredientStats, PipelineStep, StepResult,
 };
 use crate::OnFetchFail;
-use ramekin_core::http::{CachingClient, DiskCache};
+use ramekin_core::http::{
+    parse_size, BrowserClient, BrowserClientBuilder, CachingClient, ChromeRenderer, DiskCache,
+    RobotsPolicy,
+};
 use ramekin_core::pipeline::StepRegistry;
 
 // ============================================================================
@@ -160,6 +163,10 @@ pub struct ExtractionMethodStats {
     pub both_success: usize,
     /// URLs where neither method succeeded
     pub neither_success: usize,
+    /// Extraction method that produced the recipe ("json_ld", "wp_recipe_maker", ...)
+    /// -> number of URLs
+    #[serde(default)]
+    pub method_used: BTreeMap<String, usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -269,17 +276,28 @@ pub async fn run_pipeline_test(config: OrchestratorConfig) -> Result<PipelineRes
         CachingClient::builder()
             .rate_limit_ms(0) // We handle delay ourselves between URLs
             .never_network(config.offline)
+            .robots_policy(RobotsPolicy::Crawl) // Still honors robots.txt and Crawl-delay
             .build()
             .context("Failed to create HTTP client")?,
     );
 
+    // Optionally render script-built pages in headless Chromium (RAMEKIN_BROWSER).
+    // Offline runs only use renders that are already cached.
+    let browser_builder = BrowserClientBuilder::new().never_render(config.offline);
+    let browser: Option<Arc<BrowserClient>> = if browser_builder.is_enabled() {
+        Some(Arc::new(
+            browser_builder.build(client.clone(), Arc::new(ChromeRenderer::new())),
+        ))
+    } else {
+        None
+    };
+
     let total_urls = urls_to_process.len();
     let start_time = Instant::now();
-    let registry = Arc::new(build_registry(
-        Arc::clone(&client),
-        user_tags,
-        config.offline,
-    ));
+    let registry = Arc::new(match &browser {
+        Some(browser) => build_registry(Arc::clone(browser), user_tags, config.offline),
+        None => build_registry(Arc::clone(&client), user_tags, config.offline),
+    });
 
     println!("Pipeline Test Starting");
     println!("======================");
@@ -288,6 +306,9 @@ pub async fn run_pipeline_test(config: OrchestratorConfig) -> Result<PipelineRes
     if let Some(ref filter) = config.site_filter {
         println!("Site filter: {}", filter);
     }
+    if browser.is_some() {
+        println!("Browser rendering: enabled");
+    }
     println!();
 
     // In prompt mode, ensure staging directory exists and is empty
@@ -321,9 +342,10 @@ pub async fn run_pipeline_test(config: OrchestratorConfig) -> Result<PipelineRes
     let run_dir = Arc::new(run_dir);
 
     // Process URLs concurrently
-    let url_results: Vec<Option<UrlResult>> = stream::iter(urls_to_process.into_iter())
+    let url_results: Vec<Option<UrlResult>> = stream::iter(urls_to_process)
         .map(|(url, domain)| {
             let client = Arc::clone(&client);
+            let browser = browser.clone();
             let registry = Arc::clone(&registry);
             let run_dir = Arc::clone(&run_dir);
             let results = Arc::clone(&results);
@@ -357,6 +379,7 @@ pub async fn run_pipeline_test(config: OrchestratorConfig) -> Result<PipelineRes
                 let mut all_results = run_all_steps(
                     &url,
                     Arc::clone(&client),
+                    browser,
                     &run_dir,
                     force_refetch,
                     Arc::clone(&registry),
@@ -584,6 +607,15 @@ pub async fn run_pipeline_test(config: OrchestratorConfig) -> Result<PipelineRes
             ems.urls_with_html,
             ems.neither_success as f64 / ems.urls_with_html as f64 * 100.0
         );
+        println!("  Recipe extracted by:");
+        for (method, count) in &ems.method_used {
+            println!(
+                "    {}: {} ({:.1}%)",
+                method,
+                count,
+                *count as f64 / ems.urls_with_html as f64 * 100.0
+            );
+        }
     } else {
         println!("  (no HTML fetched)");
     }
@@ -717,6 +749,13 @@ fn update_results(
             if !stats.jsonld_success && !stats.microdata_success {
                 results.extraction_method_stats.neither_success += 1;
             }
+            if let Ok(serde_json::Value::String(method)) = serde_json::to_value(stats.method_used) {
+                *results
+                    .extraction_method_stats
+                    .method_used
+                    .entry(method)
+                    .or_default() += 1;
+            }
         } else {
             // Fetch succeeded but no extraction stats - means extraction failed
             // This counts as "neither method succeeded"
@@ -929,9 +968,11 @@ async fn prompt_for_manual_cache(
                     println!("  Cached successfully, retrying pipeline...");
                     println!();
 
-                    // Re-run all steps (should hit cache now)
+                    // Re-run all steps (should hit cache now). The saved page
+                    // came from a real browser, so it doesn't need rendering.
                     let new_results =
-                        run_all_steps(url, Arc::clone(&client), run_dir, false, registry).await;
+                        run_all_steps(url, Arc::clone(&client), None, run_dir, false, registry)
+                            .await;
                     return Ok(Some(new_results));
                 }
                 Err(e) => {
@@ -985,6 +1026,44 @@ pub fn print_cache_stats(cache_dir: &Path) {
         "Total entries: {}",
         stats.cached_success + stats.cached_errors
     );
+    println!("Total size: {}", format_bytes(stats.total_bytes));
+
+    if stats.by_host.is_empty() {
+        return;
+    }
+
+    let mut hosts: Vec<_> = stats.by_host.iter().collect();
+    hosts.sort_by(|a, b| b.1.total_bytes.cmp(&a.1.total_bytes).then(a.0.cmp(b.0)));
+
+    println!();
+    println!(
+        "{:<40} {:>8} {:>8} {:>10}",
+        "Host", "Success", "Errors", "Size"
+    );
+    for (host, host_stats) in hosts {
+        println!(
+            "{:<40} {:>8} {:>8} {:>10}",
+            host,
+            host_stats.cached_success,
+            host_stats.cached_errors,
+            format_bytes(host_stats.total_bytes)
+        );
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
 }
 
 pub fn clear_cache(cache_dir: &Path) -> Result<()> {
@@ -994,6 +1073,38 @@ pub fn clear_cache(cache_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+pub fn prune_cache(
+    cache_dir: &Path,
+    max_size: Option<&str>,
+    max_age_days: Option<u64>,
+) -> Result<()> {
+    let max_size = match max_size {
+        Some(size) => {
+            Some(parse_size(size).with_context(|| format!("Invalid --max-size {:?}", size))?)
+        }
+        None => std::env::var("RAMEKIN_HTTP_CACHE_MAX_SIZE")
+            .ok()
+            .and_then(|v| parse_size(&v)),
+    };
+    if max_size.is_none() && max_age_days.is_none() {
+        anyhow::bail!(
+            "Nothing to prune by: pass --max-size and/or --max-age-days \
+             (or set RAMEKIN_HTTP_CACHE_MAX_SIZE)"
+        );
+    }
+
+    let cache = DiskCache::new(cache_dir.to_path_buf())
+        .with_max_size(max_size)
+        .with_ttl(max_age_days.map(|days| Duration::from_secs(days * 24 * 60 * 60)));
+    let pruned = cache.prune()?;
+
+    println!("Cache pruned: {}", cache_dir.display());
+    println!("Expired entries removed: {}", pruned.expired);
+    println!("Least recently used entries evicted: {}", pruned.evicted);
+    println!("Space freed: {}", format_bytes(pruned.freed_bytes));
+    Ok(())
+}
+
 // ============================================================================
 // Summary report generation
 // ============================================================================
@@ -1192,6 +1303,18 @@ pub fn generate_summary_report(results: &PipelineResults) -> String {
             ems.urls_with_html,
             pct(ems.neither_success, ems.urls_with_html)
         ));
+        if !ems.method_used.is_empty() {
+            report.push_str("\n| Extracted by | URLs |\n");
+            report.push_str("|--------------|------|\n");
+            for (method, count) in &ems.method_used {
+                report.push_str(&format!(
+                    "| {} | {} ({:.1}%) |\n",
+                    method,
+                    count,
+                    pct(*count, ems.urls_with_html)
+                ));
+            }
+        }
     }
 
     // Ingredient parsing stats
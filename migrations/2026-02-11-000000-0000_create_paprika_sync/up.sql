@@ -0,0 +1,19 @@
+-- Paprika sync state: remembers which Ramekin recipe each Paprika recipe was
+-- imported into, and the Paprika content hash at the time, so re-importing the
+-- same export can skip unchanged recipes and version changed ones.
+CREATE TABLE paprika_recipes (
+    user_id UUID NOT NULL REFERENCES users(id),
+    -- Paprika's stable recipe uid
+    uid VARCHAR NOT NULL,
+    -- Paprika's content hash from the most recent import
+    hash VARCHAR NOT NULL,
+    -- Set once the import job has saved the recipe (SET NULL if recipe deleted)
+    recipe_id UUID REFERENCES recipes(id) ON DELETE SET NULL,
+    -- Most recent import job for this recipe
+    scrape_job_id UUID REFERENCES scrape_jobs(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, uid)
+);
+
+CREATE INDEX idx_paprika_recipes_recipe ON paprika_recipes(recipe_id);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_scrape_jobs_queue;
+ALTER TABLE scrape_jobs
+    DROP COLUMN IF EXISTS heartbeat_at,
+    DROP COLUMN IF EXISTS claimed_by,
+    DROP COLUMN IF EXISTS queued_at;
@@ -0,0 +1,18 @@
+-- Durable job queue: scrape_jobs rows are claimed by background workers
+-- (SELECT ... FOR UPDATE SKIP LOCKED) instead of running in a detached task,
+-- so jobs interrupted by a restart are picked up again from current_step.
+ALTER TABLE scrape_jobs
+    -- Set while the job is waiting to run or running; cleared once it finishes
+    ADD COLUMN queued_at TIMESTAMPTZ,
+    -- Worker currently running the job
+    ADD COLUMN claimed_by VARCHAR,
+    -- Refreshed by the claiming worker; a stale heartbeat means the worker died
+    ADD COLUMN heartbeat_at TIMESTAMPTZ;
+
+-- Jobs left unfinished by the old in-process tasks are resumed
+UPDATE scrape_jobs
+SET queued_at = updated_at
+WHERE status NOT IN ('completed', 'failed');
+
+CREATE INDEX idx_scrape_jobs_queue ON scrape_jobs(queued_at)
+WHERE queued_at IS NOT NULL;
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS scrape_batch_items;
+DROP INDEX IF EXISTS idx_scrape_jobs_batch;
+ALTER TABLE scrape_jobs DROP COLUMN IF EXISTS batch_id;
+DROP TABLE IF EXISTS scrape_batches;
@@ -0,0 +1,32 @@
+-- Batch URL imports: one batch per submitted list, one item per URL. Items for
+-- URLs that were queued point at their child scrape job; duplicates and invalid
+-- URLs are recorded without a job so the batch can report them.
+CREATE TABLE scrape_batches (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_scrape_batches_user ON scrape_batches(user_id);
+
+ALTER TABLE scrape_jobs
+    -- Batch the job was created for; batch jobs are rate limited per host
+    ADD COLUMN batch_id UUID REFERENCES scrape_batches(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_scrape_jobs_batch ON scrape_jobs(batch_id)
+WHERE batch_id IS NOT NULL;
+
+CREATE TABLE scrape_batch_items (
+    batch_id UUID NOT NULL REFERENCES scrape_batches(id) ON DELETE CASCADE,
+    -- Order of the URL in the submitted list
+    position INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    -- 'queued' (progress is on the scrape job), 'duplicate' or 'invalid'
+    status VARCHAR NOT NULL,
+    scrape_job_id UUID REFERENCES scrape_jobs(id) ON DELETE SET NULL,
+    -- For duplicates of a recipe already in the library
+    recipe_id UUID REFERENCES recipes(id) ON DELETE SET NULL,
+    -- Why the URL was skipped
+    error TEXT,
+    PRIMARY KEY (batch_id, position)
+);
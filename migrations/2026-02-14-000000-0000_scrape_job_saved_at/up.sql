@@ -0,0 +1,6 @@
+-- Set by save_recipe in the same transaction that saves the recipe. Jobs can be
+-- cancelled until then; the save refuses to run for a cancelled job.
+ALTER TABLE scrape_jobs ADD COLUMN saved_at TIMESTAMPTZ;
+
+-- Finished jobs already saved their recipe
+UPDATE scrape_jobs SET saved_at = updated_at WHERE status = 'completed';
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS recipe_upstream_updates;
+DROP TABLE IF EXISTS recipe_upstream_checks;
@@ -0,0 +1,45 @@
+-- Opt-in checks of a recipe's source_url for upstream edits. A row means checks
+-- are enabled for the recipe; etag/last_modified are the validators from the
+-- last fetch, sent back so unchanged pages can answer 304 Not Modified.
+CREATE TABLE recipe_upstream_checks (
+    recipe_id UUID PRIMARY KEY REFERENCES recipes(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id),
+    etag TEXT,
+    last_modified TEXT,
+    -- NULL until the first check; due again once older than the check interval
+    checked_at TIMESTAMPTZ,
+    -- Why the last check failed, cleared on success
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_recipe_upstream_checks_checked_at
+    ON recipe_upstream_checks(checked_at NULLS FIRST);
+
+-- Upstream changes found by a check, waiting for the user to accept (which saves
+-- a new version through a rescrape job) or dismiss them.
+CREATE TABLE recipe_upstream_updates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recipe_id UUID NOT NULL REFERENCES recipes(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id),
+    -- The version the upstream page was compared against
+    base_version_id UUID NOT NULL REFERENCES recipe_versions(id) ON DELETE CASCADE,
+    -- extract_recipe output for the upstream page
+    extract_output JSONB NOT NULL,
+    -- Recipe fields that differ from the base version
+    changed_fields TEXT[] NOT NULL,
+    -- 'pending', 'accepted' or 'dismissed'
+    status VARCHAR NOT NULL DEFAULT 'pending',
+    -- Rescrape job created on accept
+    scrape_job_id UUID REFERENCES scrape_jobs(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+-- At most one pending update per recipe; a newer upstream change replaces it
+CREATE UNIQUE INDEX idx_recipe_upstream_updates_pending
+    ON recipe_upstream_updates(recipe_id)
+    WHERE status = 'pending';
+
+CREATE INDEX idx_recipe_upstream_updates_user
+    ON recipe_upstream_updates(user_id, created_at DESC);
@@ -0,0 +1,20 @@
+-- Shared HTTP response cache, so every server instance reuses pages and images
+-- fetched by any of them. A row holds the last good response, the last error
+-- (negative caching), or both; a successful fetch clears the error.
+CREATE TABLE url_cache (
+    url TEXT PRIMARY KEY,
+    -- NULL when only an error is cached
+    content BYTEA,
+    content_type TEXT,
+    -- Validators sent back as If-None-Match / If-Modified-Since
+    etag TEXT,
+    last_modified TEXT,
+    -- Length of content, summed to enforce the total size cap
+    size_bytes BIGINT NOT NULL DEFAULT 0,
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    error TEXT,
+    error_at TIMESTAMPTZ
+);
+
+-- Expiry and size-cap pruning drop the oldest entries first
+CREATE INDEX idx_url_cache_fetched_at ON url_cache(fetched_at);
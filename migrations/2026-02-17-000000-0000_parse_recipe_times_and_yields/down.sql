@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS idx_recipe_versions_parsed_fields_version;
+
+ALTER TABLE recipe_versions
+    DROP COLUMN IF EXISTS prep_time_minutes,
+    DROP COLUMN IF EXISTS cook_time_minutes,
+    DROP COLUMN IF EXISTS total_time_minutes,
+    DROP COLUMN IF EXISTS yield_quantity,
+    DROP COLUMN IF EXISTS yield_quantity_max,
+    DROP COLUMN IF EXISTS yield_unit,
+    DROP COLUMN IF EXISTS parsed_fields_version;
@@ -0,0 +1,17 @@
+-- Typed copies of the free-text servings and time fields, parsed by
+-- ramekin_core::{duration_parser, yield_parser}. The text stays the source of
+-- truth; these are NULL when it's missing or couldn't be parsed.
+ALTER TABLE recipe_versions
+    ADD COLUMN prep_time_minutes INTEGER,
+    ADD COLUMN cook_time_minutes INTEGER,
+    ADD COLUMN total_time_minutes INTEGER,
+    ADD COLUMN yield_quantity DOUBLE PRECISION,
+    ADD COLUMN yield_quantity_max DOUBLE PRECISION,
+    ADD COLUMN yield_unit TEXT,
+    -- Version of the parsers that filled the columns above. Rows behind the
+    -- current version (all existing rows, to start) are re-parsed by the
+    -- server's backfill task.
+    ADD COLUMN parsed_fields_version INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_recipe_versions_parsed_fields_version
+    ON recipe_versions(parsed_fields_version);
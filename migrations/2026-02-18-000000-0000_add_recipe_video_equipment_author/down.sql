@@ -0,0 +1,5 @@
+ALTER TABLE recipe_versions
+    DROP COLUMN IF EXISTS video_url,
+    DROP COLUMN IF EXISTS equipment,
+    DROP COLUMN IF EXISTS author,
+    DROP COLUMN IF EXISTS published_date;
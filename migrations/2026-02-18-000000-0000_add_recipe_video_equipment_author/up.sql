@@ -0,0 +1,6 @@
+-- Recipe metadata carried over from the source page's structured data
+ALTER TABLE recipe_versions
+    ADD COLUMN video_url TEXT,
+    ADD COLUMN equipment TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN author TEXT,
+    ADD COLUMN published_date DATE;
@@ -0,0 +1,3 @@
+ALTER TABLE scrape_jobs
+    DROP COLUMN IF EXISTS extraction_confidence,
+    DROP COLUMN IF EXISTS quality_warnings;
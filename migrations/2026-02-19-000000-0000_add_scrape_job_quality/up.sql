@@ -0,0 +1,4 @@
+-- How far the extracted recipe can be trusted, and what looked wrong with it
+ALTER TABLE scrape_jobs
+    ADD COLUMN extraction_confidence DOUBLE PRECISION,
+    ADD COLUMN quality_warnings JSONB NOT NULL DEFAULT '[]';
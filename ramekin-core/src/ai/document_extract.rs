@@ -0,0 +1,56 @@
+//! Recipe extraction from document text using AI.
+//!
+//! Used when the plain-text heuristics in `extract_recipe_from_text` can't find
+//! a recipe in an uploaded PDF or text file.
+
+use crate::ai::photo_extract::PhotoExtractResponse;
+use crate::ai::prompts::document_extract::{
+    render_document_extract_prompt, DOCUMENT_EXTRACT_PROMPT_NAME,
+};
+use crate::ai::{AiClient, AiError, ChatMessage, ChatRequest, Usage};
+use crate::types::RawRecipe;
+
+/// Maximum characters of document text sent to the model.
+/// Recipes are short; anything past this is almost always unrelated pages.
+const MAX_DOCUMENT_CHARS: usize = 50_000;
+
+pub struct DocumentExtractResult {
+    pub raw_recipe: RawRecipe,
+    pub cached: bool,
+    pub usage: Usage,
+}
+
+pub async fn extract_recipe_from_document_text(
+    ai_client: &dyn AiClient,
+    text: &str,
+) -> Result<DocumentExtractResult, AiError> {
+    let text = match text.char_indices().nth(MAX_DOCUMENT_CHARS) {
+        Some((end, _)) => text.get(..end).unwrap_or(text),
+        None => text,
+    };
+
+    let prompt = render_document_extract_prompt(text);
+    let request = ChatRequest {
+        messages: vec![ChatMessage::user(prompt)],
+        json_response: true,
+        max_tokens: Some(4096),
+        temperature: Some(0.1),
+    };
+
+    let response = ai_client
+        .complete(DOCUMENT_EXTRACT_PROMPT_NAME, request)
+        .await?;
+
+    let extracted: PhotoExtractResponse = serde_json::from_str(&response.content).map_err(|e| {
+        AiError::ParseError(format!(
+            "Failed to parse document extraction response: {}",
+            e
+        ))
+    })?;
+
+    Ok(DocumentExtractResult {
+        raw_recipe: RawRecipe::from(extracted),
+        cached: response.cached,
+        usage: response.usage,
+    })
+}
@@ -6,8 +6,9 @@ use crate::ai::prompts::photo_extract::{render_photo_extract_prompt, PHOTO_EXTRA
 use crate::ai::{AiClient, AiError, ChatMessage, ChatRequest, ImageData, Usage};
 use crate::types::RawRecipe;
 
+/// JSON shape returned by the extraction prompts (also used for document text).
 #[derive(Debug, Deserialize)]
-struct PhotoExtractResponse {
+pub(super) struct PhotoExtractResponse {
     title: String,
     #[serde(default)]
     description: Option<String>,
@@ -25,21 +26,134 @@ struct PhotoExtractResponse {
     notes: Option<String>,
 }
 
-pub struct PhotoExtractResult {
+impl From<PhotoExtractResponse> for RawRecipe {
+    fn from(extracted: PhotoExtractResponse) -> Self {
+        RawRecipe {
+            title: extracted.title,
+            description: extracted.description,
+            ingredients: extracted.ingredients,
+            instructions: extracted.instructions,
+            image_urls: vec![],
+            source_url: None,
+            source_name: None,
+            servings: extracted.servings,
+            prep_time: extracted.prep_time,
+            cook_time: extracted.cook_time,
+            total_time: extracted.total_time,
+            rating: None,
+            difficulty: None,
+            nutritional_info: None,
+            notes: extracted.notes,
+            categories: None,
+            video_url: None,
+            equipment: None,
+            author: None,
+            published_date: None,
+            language: None,
+            ingredient_sections: vec![],
+            instruction_sections: vec![],
+        }
+    }
+}
+
+/// One recipe found in a batch of photos.
+pub struct ExtractedPhotoRecipe {
     pub raw_recipe: RawRecipe,
+    /// Indexes into the uploaded photos showing this recipe, in reading (page) order
+    pub photo_indices: Vec<usize>,
+}
+
+pub struct PhotoExtractResult {
+    /// Recipes in the order the model reported them (at least one)
+    pub recipes: Vec<ExtractedPhotoRecipe>,
     pub cached: bool,
     pub usage: Usage,
 }
 
-pub async fn extract_recipe_from_photos(
+#[derive(Debug, Deserialize)]
+struct PhotoExtractBatchResponse {
+    recipes: Vec<PhotoExtractBatchRecipe>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PhotoExtractBatchRecipe {
+    /// 1-based photo numbers in reading order
+    #[serde(default)]
+    photos: Vec<usize>,
+    #[serde(flatten)]
+    recipe: PhotoExtractResponse,
+}
+
+/// Parse the model's response into recipes with 0-based photo indices.
+///
+/// Photo numbers are validated against the batch: out-of-range numbers and
+/// repeats are dropped, a recipe with no usable numbers gets every photo, and
+/// photos the model didn't assign to any recipe are appended to the first one
+/// so no upload is lost. A single bare recipe object (the pre-batch response
+/// shape) is accepted as one recipe spanning all photos.
+fn parse_photo_extract_response(
+    content: &str,
+    photo_count: usize,
+) -> Result<Vec<ExtractedPhotoRecipe>, AiError> {
+    let batch: Vec<PhotoExtractBatchRecipe> =
+        match serde_json::from_str::<PhotoExtractBatchResponse>(content) {
+            Ok(batch) => batch.recipes,
+            Err(batch_err) => match serde_json::from_str::<PhotoExtractResponse>(content) {
+                Ok(recipe) => vec![PhotoExtractBatchRecipe {
+                    photos: vec![],
+                    recipe,
+                }],
+                Err(_) => {
+                    return Err(AiError::ParseError(format!(
+                        "Failed to parse photo extraction response: {}",
+                        batch_err
+                    )))
+                }
+            },
+        };
+
+    if batch.is_empty() {
+        return Err(AiError::ParseError("No recipe found in photos".to_string()));
+    }
+
+    let mut recipes: Vec<ExtractedPhotoRecipe> = batch
+        .into_iter()
+        .map(|entry| {
+            let mut photo_indices: Vec<usize> = Vec::new();
+            for number in entry.photos {
+                if (1..=photo_count).contains(&number) && !photo_indices.contains(&(number - 1)) {
+                    photo_indices.push(number - 1);
+                }
+            }
+            if photo_indices.is_empty() {
+                photo_indices = (0..photo_count).collect();
+            }
+            ExtractedPhotoRecipe {
+                raw_recipe: RawRecipe::from(entry.recipe),
+                photo_indices,
+            }
+        })
+        .collect();
+
+    let unassigned: Vec<usize> = (0..photo_count)
+        .filter(|i| !recipes.iter().any(|r| r.photo_indices.contains(i)))
+        .collect();
+    recipes[0].photo_indices.extend(unassigned);
+
+    Ok(recipes)
+}
+
+/// Extract every recipe in a batch of photos, with each recipe's photos in page order.
+pub async fn extract_recipes_from_photos(
     ai_client: &dyn AiClient,
     images: Vec<ImageData>,
 ) -> Result<PhotoExtractResult, AiError> {
-    let prompt = render_photo_extract_prompt();
+    let photo_count = images.len();
+    let prompt = render_photo_extract_prompt(photo_count);
     let request = ChatRequest {
         messages: vec![ChatMessage::user_with_images(prompt, images)],
         json_response: true,
-        max_tokens: Some(4096),
+        max_tokens: Some(8192),
         temperature: Some(0.1),
     };
 
@@ -47,32 +161,184 @@ pub async fn extract_recipe_from_photos(
         .complete(PHOTO_EXTRACT_PROMPT_NAME, request)
         .await?;
 
-    let extracted: PhotoExtractResponse = serde_json::from_str(&response.content).map_err(|e| {
-        AiError::ParseError(format!("Failed to parse photo extraction response: {}", e))
-    })?;
-
-    let raw_recipe = RawRecipe {
-        title: extracted.title,
-        description: extracted.description,
-        ingredients: extracted.ingredients,
-        instructions: extracted.instructions,
-        image_urls: vec![],
-        source_url: None,
-        source_name: None,
-        servings: extracted.servings,
-        prep_time: extracted.prep_time,
-        cook_time: extracted.cook_time,
-        total_time: extracted.total_time,
-        rating: None,
-        difficulty: None,
-        nutritional_info: None,
-        notes: extracted.notes,
-        categories: None,
-    };
+    let recipes = parse_photo_extract_response(&response.content, photo_count)?;
 
     Ok(PhotoExtractResult {
-        raw_recipe,
+        recipes,
         cached: response.cached,
         usage: response.usage,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::{AiCache, AiConfig, CacheKey, CachingAiClient, ChatResponse};
+    use async_trait::async_trait;
+
+    /// AiClient that replays a canned response, like a cache hit.
+    struct CannedAiClient {
+        content: String,
+    }
+
+    #[async_trait]
+    impl AiClient for CannedAiClient {
+        async fn complete(
+            &self,
+            prompt_name: &str,
+            _request: ChatRequest,
+        ) -> Result<ChatResponse, AiError> {
+            assert_eq!(prompt_name, PHOTO_EXTRACT_PROMPT_NAME);
+            Ok(ChatResponse {
+                content: self.content.clone(),
+                usage: Usage::default(),
+                cached: true,
+            })
+        }
+    }
+
+    fn images(count: usize) -> Vec<ImageData> {
+        (0..count)
+            .map(|i| ImageData {
+                base64: format!("photo{}", i),
+                content_type: "image/jpeg".to_string(),
+            })
+            .collect()
+    }
+
+    fn recipe_json(title: &str, photos: &[usize]) -> serde_json::Value {
+        serde_json::json!({
+            "photos": photos,
+            "title": title,
+            "ingredients": "1 cup flour",
+            "instructions": "Bake.",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_spread_with_two_recipes_is_split() {
+        let client = CannedAiClient {
+            content: serde_json::json!({
+                "recipes": [recipe_json("Focaccia", &[3, 1]), recipe_json("Grissini", &[2])]
+            })
+            .to_string(),
+        };
+
+        let result = extract_recipes_from_photos(&client, images(3))
+            .await
+            .unwrap();
+
+        assert!(result.cached);
+        assert_eq!(result.recipes.len(), 2);
+        assert_eq!(result.recipes[0].raw_recipe.title, "Focaccia");
+        // Page order, not upload order
+        assert_eq!(result.recipes[0].photo_indices, vec![2, 0]);
+        assert_eq!(result.recipes[1].raw_recipe.title, "Grissini");
+        assert_eq!(result.recipes[1].photo_indices, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_and_missing_photo_numbers() {
+        let client = CannedAiClient {
+            content: serde_json::json!({
+                "recipes": [recipe_json("Soup", &[2, 2, 9]), recipe_json("Salad", &[])]
+            })
+            .to_string(),
+        };
+
+        let result = extract_recipes_from_photos(&client, images(3))
+            .await
+            .unwrap();
+
+        // Duplicates and out-of-range numbers are dropped; a recipe without
+        // photo numbers gets every photo
+        assert_eq!(result.recipes[0].photo_indices, vec![1]);
+        assert_eq!(result.recipes[1].photo_indices, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_unassigned_photos_go_to_first_recipe() {
+        let client = CannedAiClient {
+            content: serde_json::json!({
+                "recipes": [recipe_json("Stew", &[2])]
+            })
+            .to_string(),
+        };
+
+        let result = extract_recipes_from_photos(&client, images(3))
+            .await
+            .unwrap();
+
+        assert_eq!(result.recipes[0].photo_indices, vec![1, 0, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_single_recipe_response_shape() {
+        let client = CannedAiClient {
+            content: r#"{"title": "Pie", "ingredients": "2 apples", "instructions": "Bake."}"#
+                .to_string(),
+        };
+
+        let result = extract_recipes_from_photos(&client, images(2))
+            .await
+            .unwrap();
+
+        assert_eq!(result.recipes.len(), 1);
+        assert_eq!(result.recipes[0].raw_recipe.title, "Pie");
+        assert_eq!(result.recipes[0].photo_indices, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_no_recipes_is_an_error() {
+        let client = CannedAiClient {
+            content: r#"{"recipes": []}"#.to_string(),
+        };
+
+        let result = extract_recipes_from_photos(&client, images(1)).await;
+
+        assert!(matches!(result, Err(AiError::ParseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cached_response_is_used_offline() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("ramekin-photo-extract-{}", uuid::Uuid::new_v4()));
+        let config = AiConfig {
+            api_key: "unused".to_string(),
+            model: "test/model".to_string(),
+            base_url: "http://localhost:1".to_string(),
+            cache_dir: cache_dir.clone(),
+            offline: true,
+            rate_limit_ms: 0,
+            request_timeout_secs: 1,
+        };
+
+        // Seed the cache with the response for exactly this request
+        let batch = images(2);
+        let messages = vec![ChatMessage::user_with_images(
+            render_photo_extract_prompt(batch.len()),
+            batch.clone(),
+        )];
+        let key = CacheKey::new(PHOTO_EXTRACT_PROMPT_NAME, &config.model, &messages);
+        let cached = ChatResponse {
+            content: serde_json::json!({
+                "recipes": [recipe_json("Tart", &[2, 1])]
+            })
+            .to_string(),
+            usage: Usage::default(),
+            cached: false,
+        };
+        AiCache::new(cache_dir.clone())
+            .put(&key, &cached, &config.model)
+            .unwrap();
+
+        let client = CachingAiClient::new(config);
+        let result = extract_recipes_from_photos(&client, batch).await.unwrap();
+
+        assert!(result.cached);
+        assert_eq!(result.recipes[0].raw_recipe.title, "Tart");
+        assert_eq!(result.recipes[0].photo_indices, vec![1, 0]);
+
+        let _ = std::fs::remove_dir_all(cache_dir);
+    }
+}
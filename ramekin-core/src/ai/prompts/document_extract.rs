@@ -0,0 +1,47 @@
+//! Prompt template for extracting recipes from document text (PDF or plain text).
+
+pub const DOCUMENT_EXTRACT_PROMPT_NAME: &str = "document_extract";
+
+pub fn render_document_extract_prompt(text: &str) -> String {
+    format!(
+        r#"You are a recipe extraction assistant. You are given the text of a document (a PDF or plain text file) that contains a recipe. The text may have odd line breaks, page headers or footers left over from the original layout.
+
+Extract the recipe from the text and return it as JSON with this exact structure:
+{{
+  "title": "Recipe Title",
+  "description": "Brief description (optional, null if not present)",
+  "ingredients": "Each ingredient on its own line, exactly as written",
+  "instructions": "Full instructions, preserving paragraph breaks with double newlines",
+  "servings": "Servings info if present (optional, null if not present)",
+  "prep_time": "Prep time if present (optional, null if not present)",
+  "cook_time": "Cook time if present (optional, null if not present)",
+  "total_time": "Total time if present (optional, null if not present)",
+  "notes": "Any notes, tips, or variations mentioned (optional, null if not present)"
+}}
+
+Rules:
+- Extract the text EXACTLY as written in the recipe - do not paraphrase or modify
+- Rejoin sentences that were split across lines, but keep each ingredient on its own line
+- Leave out page numbers, headers, footers and other text that isn't part of the recipe
+- If the document contains several recipes, extract only the first one
+- If information is not present in the text, use null for that field
+- Return ONLY the JSON, no other text
+
+Document text:
+{text}"#,
+        text = text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_document_extract_prompt() {
+        let prompt = render_document_extract_prompt("Pancakes\n1 cup flour");
+        assert!(prompt.contains("recipe extraction assistant"));
+        assert!(prompt.contains("\"ingredients\""));
+        assert!(prompt.ends_with("Pancakes\n1 cup flour"));
+    }
+}
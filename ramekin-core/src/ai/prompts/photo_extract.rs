@@ -2,27 +2,54 @@
 
 pub const PHOTO_EXTRACT_PROMPT_NAME: &str = "photo_extract";
 
-pub fn render_photo_extract_prompt() -> String {
-    r#"You are a recipe extraction assistant. You are given one or more photos of a recipe from a cookbook or printed page.
+pub fn render_photo_extract_prompt(photo_count: usize) -> String {
+    format!(
+        r#"You are a recipe extraction assistant. You are given {photo_count} photo(s) of recipes from a cookbook or printed pages. The photos are numbered 1 to {photo_count} in the order they were uploaded, which is NOT necessarily the page order.
 
-Extract the complete recipe from the photos and return it as JSON with this exact structure:
-{
-  "title": "Recipe Title",
-  "description": "Brief description (optional, null if not present)",
-  "ingredients": "Each ingredient on its own line, exactly as written",
-  "instructions": "Full instructions, preserving paragraph breaks with double newlines",
-  "servings": "Servings info if present (optional, null if not present)",
-  "prep_time": "Prep time if present (optional, null if not present)",
-  "cook_time": "Cook time if present (optional, null if not present)",
-  "total_time": "Total time if present (optional, null if not present)",
-  "notes": "Any notes, tips, or variations mentioned (optional, null if not present)"
-}
+A batch may contain more than one recipe (for example a cookbook spread with two recipes), and a single recipe may continue across several photos.
+
+Extract every complete recipe from the photos and return them as JSON with this exact structure:
+{{
+  "recipes": [
+    {{
+      "photos": [2, 1],
+      "title": "Recipe Title",
+      "description": "Brief description (optional, null if not present)",
+      "ingredients": "Each ingredient on its own line, exactly as written",
+      "instructions": "Full instructions, preserving paragraph breaks with double newlines",
+      "servings": "Servings info if present (optional, null if not present)",
+      "prep_time": "Prep time if present (optional, null if not present)",
+      "cook_time": "Cook time if present (optional, null if not present)",
+      "total_time": "Total time if present (optional, null if not present)",
+      "notes": "Any notes, tips, or variations mentioned (optional, null if not present)"
+    }}
+  ]
+}}
 
 Rules:
+- "photos" lists the numbers of the photos that show this recipe, in reading order (the page where the recipe starts first, then the pages it continues onto)
+- Use page numbers, "continued" markers and sentences or ingredient lists that run across pages to work out the reading order
+- Assign every photo to at least one recipe; a photo of the finished dish belongs to the recipe it illustrates
+- A photo showing the end of one recipe and the start of another belongs to both
+- When a recipe continues across photos, join its text in reading order into a single recipe
 - Extract the text EXACTLY as written in the recipe - do not paraphrase or modify
 - For ingredients, put each ingredient on its own line separated by newlines
 - For instructions, preserve the original step numbering and paragraph structure
 - If information is not present in the photos, use null for that field
-- Return ONLY the JSON, no other text"#
-        .to_string()
+- Return ONLY the JSON, no other text"#,
+        photo_count = photo_count
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_photo_extract_prompt() {
+        let prompt = render_photo_extract_prompt(3);
+        assert!(prompt.contains("given 3 photo(s)"));
+        assert!(prompt.contains("numbered 1 to 3"));
+        assert!(prompt.contains("\"recipes\""));
+    }
 }
@@ -0,0 +1,228 @@
+//! Parse recipe times ("PT1H15M", "1 hr 15 mins", "overnight") into minutes.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::ingredient_parser::normalize_unicode;
+use crate::metric_weights::parse_amount;
+
+/// How long "overnight" is taken to mean.
+const OVERNIGHT_MINUTES: f64 = 8.0 * 60.0;
+
+/// Regex for ISO 8601 durations ("PT1H30M", "P0DT0H45M0S", "P1D").
+static ISO_DURATION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^P(?:(\d+(?:\.\d+)?)W)?(?:(\d+(?:\.\d+)?)D)?(?:T(?:(\d+(?:\.\d+)?)H)?(?:(\d+(?:\.\d+)?)M)?(?:(\d+(?:\.\d+)?)S)?)?$",
+    )
+    .expect("Invalid ISO duration regex")
+});
+
+/// Regex for an amount (or range of amounts) followed by a time unit, e.g.
+/// "1 1/2 hours", "20-25 mins", "an hour and a half". Word amounts need a
+/// space before the unit, so the "an d" in "and" isn't read as a day.
+static HUMAN_DURATION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    let amount = r"\d+(?:\.\d+)?(?:\s+\d+/\d+)?|\d+/\d+";
+    Regex::new(&format!(
+        r"(?x)
+        \b(?:
+            (?P<amount>{amount})(?:\s*(?:-|to)\s*(?P<max>{amount}))?\s*
+            | (?P<word>an?|one|half\s+an?|half)\s+
+        )
+        (?P<unit>days?|d|hours?|hrs?|h|minutes?|mins?|m|seconds?|secs?|s)\b
+        (?P<half>\s+and\s+a\s+half)?",
+    ))
+    .expect("Invalid human duration regex")
+});
+
+/// Regex to separate run-together amounts and units ("1h15m" -> "1h 15m").
+static LETTER_DIGIT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([a-z])(\d)").expect("Invalid letter-digit regex"));
+
+/// Parse an ISO 8601 duration such as "PT1H30M" or "P0DT0H45M0S".
+pub fn parse_iso8601_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    // A bare "P" or "PT" matches the pattern but says nothing
+    if text.len() < 3 {
+        return None;
+    }
+    let cap = ISO_DURATION_REGEX.captures(text)?;
+    let part = |i: usize| {
+        cap.get(i)
+            .and_then(|m| m.as_str().parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+    let secs =
+        part(1) * 7.0 * 86400.0 + part(2) * 86400.0 + part(3) * 3600.0 + part(4) * 60.0 + part(5);
+    // Absurd values ("PT9999999999999999999H") don't fit in a Duration
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Parse a recipe time into whole minutes.
+///
+/// Accepts ISO 8601 durations and the ways people write times: "1 hr 15 mins",
+/// "1h15m", "1 1/2 hours", "an hour and a half", "20-25 minutes" (the upper end
+/// is used), "overnight" (8 hours) and bare numbers, taken as minutes.
+pub fn parse_duration_minutes(text: &str) -> Option<u32> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    if ISO_DURATION_REGEX.is_match(text) && text.len() >= 3 {
+        let duration = parse_iso8601_duration(text)?;
+        return Some(round_minutes(duration.as_secs_f64() / 60.0));
+    }
+
+    let normalized = normalize_unicode(&text.to_lowercase()).replace('-', " - ");
+    let normalized = LETTER_DIGIT_REGEX.replace_all(&normalized, "$1 $2");
+
+    let mut minutes = 0.0;
+    let mut found = false;
+    for cap in HUMAN_DURATION_REGEX.captures_iter(&normalized) {
+        let amount = cap
+            .name("max")
+            .and_then(|m| parse_amount(m.as_str()))
+            .or_else(|| cap.name("word").and_then(|m| word_amount(m.as_str())))
+            .or_else(|| cap.name("amount").and_then(|m| parse_amount(m.as_str())));
+        let Some(mut amount) = amount else {
+            continue;
+        };
+        if cap.name("half").is_some() {
+            amount += 0.5;
+        }
+        let unit_minutes = match cap["unit"].chars().next() {
+            Some('d') => 1440.0,
+            Some('h') => 60.0,
+            Some('m') => 1.0,
+            _ => 1.0 / 60.0,
+        };
+        minutes += amount * unit_minutes;
+        found = true;
+    }
+
+    if normalized.contains("overnight") {
+        minutes += OVERNIGHT_MINUTES;
+        found = true;
+    }
+
+    if !found {
+        // A bare number ("45") is minutes
+        minutes = parse_amount(&normalized)?;
+    }
+    Some(round_minutes(minutes))
+}
+
+/// Amounts written as words ("an hour", "half an hour").
+fn word_amount(word: &str) -> Option<f64> {
+    match word.split_whitespace().next()? {
+        "a" | "an" | "one" => Some(1.0),
+        "half" => Some(0.5),
+        _ => None,
+    }
+}
+
+fn round_minutes(minutes: f64) -> u32 {
+    minutes.max(0.0).round() as u32
+}
+
+/// Render a duration for display: "1 hour 30 minutes", "1 day 2 hours", or
+/// "30 seconds" for anything under a minute.
+pub fn format_duration(duration: Duration) -> String {
+    let plural = |n: u64, unit: &str| {
+        if n == 1 {
+            format!("{} {}", n, unit)
+        } else {
+            format!("{} {}s", n, unit)
+        }
+    };
+
+    let total_secs = duration.as_secs_f64().round() as u64;
+    if total_secs < 60 {
+        return plural(total_secs, "second");
+    }
+
+    // Seconds don't matter at recipe scale once there's at least a minute
+    let total_mins = (total_secs + 30) / 60;
+    let (days, hours, mins) = (total_mins / 1440, total_mins % 1440 / 60, total_mins % 60);
+    [(days, "day"), (hours, "hour"), (mins, "minute")]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| plural(*n, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(parse_duration_minutes("PT15M"), Some(15));
+        assert_eq!(parse_duration_minutes("PT1H30M"), Some(90));
+        assert_eq!(parse_duration_minutes("P0DT1H0M0S"), Some(60));
+        assert_eq!(parse_duration_minutes("P1DT2H"), Some(1560));
+        assert_eq!(parse_duration_minutes("pt45m"), Some(45));
+        assert_eq!(parse_duration_minutes("PT0M"), Some(0));
+        assert_eq!(parse_iso8601_duration("PT"), None);
+        assert_eq!(parse_iso8601_duration("PT9999999999999999999H"), None);
+        assert_eq!(parse_duration_minutes("PT9999999999999999999H"), None);
+    }
+
+    #[test]
+    fn test_human_durations() {
+        assert_eq!(parse_duration_minutes("15 minutes"), Some(15));
+        assert_eq!(parse_duration_minutes("1 hr 15 mins"), Some(75));
+        assert_eq!(parse_duration_minutes("1 hour and 15 minutes"), Some(75));
+        assert_eq!(parse_duration_minutes("10 minutes and 5 seconds"), Some(10));
+        assert_eq!(
+            parse_duration_minutes("20 minutes and 10 seconds"),
+            Some(20)
+        );
+        assert_eq!(parse_duration_minutes("1h15m"), Some(75));
+        assert_eq!(parse_duration_minutes("1 1/2 hours"), Some(90));
+        assert_eq!(parse_duration_minutes("1½ hours"), Some(90));
+        assert_eq!(parse_duration_minutes("1.5 hrs"), Some(90));
+        assert_eq!(parse_duration_minutes("an hour and a half"), Some(90));
+        assert_eq!(parse_duration_minutes("half an hour"), Some(30));
+        assert_eq!(parse_duration_minutes("2 days"), Some(2880));
+        assert_eq!(parse_duration_minutes("30 seconds"), Some(1));
+        assert_eq!(parse_duration_minutes("Total: 45 min"), Some(45));
+    }
+
+    #[test]
+    fn test_ranges_use_upper_end() {
+        assert_eq!(parse_duration_minutes("20-25 minutes"), Some(25));
+        assert_eq!(parse_duration_minutes("20–25 minutes"), Some(25));
+        assert_eq!(parse_duration_minutes("2 to 3 hours"), Some(180));
+    }
+
+    #[test]
+    fn test_overnight_and_bare_numbers() {
+        assert_eq!(parse_duration_minutes("overnight"), Some(480));
+        assert_eq!(
+            parse_duration_minutes("20 minutes plus overnight chilling"),
+            Some(500)
+        );
+        assert_eq!(parse_duration_minutes("45"), Some(45));
+        assert_eq!(parse_duration_minutes("a while"), None);
+        assert_eq!(parse_duration_minutes(""), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(15 * 60)), "15 minutes");
+        assert_eq!(format_duration(Duration::from_secs(60 * 60)), "1 hour");
+        assert_eq!(
+            format_duration(Duration::from_secs(90 * 60)),
+            "1 hour 30 minutes"
+        );
+        assert_eq!(
+            format_duration(Duration::from_secs(26 * 3600)),
+            "1 day 2 hours"
+        );
+        assert_eq!(format_duration(Duration::from_secs(30)), "30 seconds");
+    }
+}
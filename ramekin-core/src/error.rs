@@ -10,6 +10,38 @@ pub enum FetchError {
 
     #[error("Invalid response encoding: {0}")]
     InvalidEncoding(String),
+
+    #[error("URL blocked: {0}")]
+    Blocked(String),
+
+    #[error("Disallowed by robots.txt: {0}")]
+    RobotsDisallowed(String),
+
+    #[error("robots.txt unavailable for {0}")]
+    RobotsUnavailable(String),
+
+    #[error("Browser rendering failed: {0}")]
+    RenderFailed(String),
+}
+
+impl FetchError {
+    /// Whether the failure may go away on its own (timeouts, connection resets,
+    /// 429 and 5xx gateway responses), so the request is worth retrying and the
+    /// error shouldn't be cached.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::RequestFailed(e) => match e.status() {
+                Some(status) => crate::http::retry::is_retryable_status(status),
+                None => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+            },
+            FetchError::RobotsUnavailable(_) => true,
+            FetchError::InvalidUrl(_)
+            | FetchError::InvalidEncoding(_)
+            | FetchError::Blocked(_)
+            | FetchError::RobotsDisallowed(_)
+            | FetchError::RenderFailed(_) => false,
+        }
+    }
 }
 
 #[derive(Error, Debug)]
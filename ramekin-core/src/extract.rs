@@ -2,8 +2,14 @@ use std::sync::LazyLock;
 
 use regex::Regex;
 
+use crate::duration_parser::{format_duration, parse_iso8601_duration};
 use crate::error::ExtractError;
-use crate::types::{ExtractRecipeOutput, ExtractionAttempt, ExtractionMethod, RawRecipe};
+use crate::ingredient_parser::detect_section_header;
+use crate::recipe_cards;
+use crate::types::{
+    AlternateKind, AlternatePage, ExtractRecipeOutput, ExtractionAttempt, ExtractionMethod,
+    RawRecipe, RecipeSection,
+};
 use scraper::{Html, Selector};
 
 /// Regex to find JSON-LD script tags (case-insensitive for type attribute)
@@ -25,13 +31,13 @@ static OG_IMAGE_REGEX_ALT: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 /// Extract a recipe from HTML containing JSON-LD structured data.
-/// Falls back to microdata extraction if JSON-LD fails, then tries
+/// Falls back to recipe card plugin markup, then microdata, then tries
 /// supplementing partial structured data with HTML class-based extraction.
 ///
 /// Uses a fast regex-based path for JSON-LD to avoid full DOM parsing.
 pub fn extract_recipe(html: &str, source_url: &str) -> Result<RawRecipe, ExtractError> {
     // Fast path: extract JSON-LD using regex (avoids DOM parsing)
-    if let Some(recipe) = extract_jsonld_fast(html, source_url) {
+    if let Some(recipe) = extract_jsonld_fast(html, source_url).into_iter().next() {
         return Ok(recipe);
     }
 
@@ -39,22 +45,32 @@ pub fn extract_recipe(html: &str, source_url: &str) -> Result<RawRecipe, Extract
     let document = Html::parse_document(html);
 
     // Try JSON-LD via DOM (handles edge cases regex might miss)
-    if let Ok(recipe) = extract_recipe_from_jsonld(&document, source_url) {
-        return Ok(recipe);
+    if let Ok(mut recipes) = extract_recipes_from_jsonld(&document, source_url) {
+        add_card_sections(html, &document, source_url, &mut recipes);
+        return Ok(recipes.swap_remove(0));
+    }
+
+    // Recipe card plugins (WP Recipe Maker, Tasty Recipes, ...)
+    for format in recipe_cards::formats_present(html) {
+        if let Ok(mut recipes) = format.extract_all(&document, source_url) {
+            return Ok(recipes.swap_remove(0));
+        }
     }
 
     // Fall back to microdata
-    if let Ok(recipe) = extract_recipe_from_microdata(&document, source_url) {
-        return Ok(recipe);
+    if let Ok(mut recipes) = extract_recipes_from_microdata(&document, source_url) {
+        return Ok(recipes.swap_remove(0));
     }
 
     // Last resort: supplement partial structured data with HTML fallbacks
     extract_recipe_with_html_fallback(html, &document, source_url)
 }
 
-/// Fast JSON-LD extraction using regex to avoid DOM parsing.
-/// Returns None if no valid JSON-LD recipe is found.
-fn extract_jsonld_fast(html: &str, source_url: &str) -> Option<RawRecipe> {
+/// Fast JSON-LD extraction using regex to avoid DOM parsing (unless the page
+/// also has a recipe card to take ingredient groups from).
+/// Returns every valid JSON-LD recipe on the page, in page order (empty if none).
+fn extract_jsonld_fast(html: &str, source_url: &str) -> Vec<RawRecipe> {
+    let mut recipes: Vec<RawRecipe> = Vec::new();
     for cap in JSONLD_REGEX.captures_iter(html) {
         let json_text = match cap.get(1) {
             Some(m) => m.as_str(),
@@ -68,20 +84,114 @@ fn extract_jsonld_fast(html: &str, source_url: &str) -> Option<RawRecipe> {
             Err(_) => continue, // Try next script tag
         };
 
-        // Look for Recipe type
-        if let Some(recipe) = find_recipe_in_json(&json) {
-            if let Ok(mut raw_recipe) = extract_recipe_data(recipe, source_url) {
-                // Fallback to og:image if no images found
-                if raw_recipe.image_urls.is_empty() {
-                    if let Some(og_image) = extract_og_image_fast(html) {
-                        raw_recipe.image_urls.push(og_image);
-                    }
-                }
-                return Some(raw_recipe);
+        // Look for Recipe types
+        for recipe in find_recipes_in_json(&json) {
+            if let Ok(raw_recipe) = extract_recipe_data(recipe, source_url) {
+                push_unique(&mut recipes, raw_recipe);
             }
         }
     }
-    None
+
+    // Fallback to og:image if no images found
+    if recipes.iter().any(|r| r.image_urls.is_empty()) {
+        if let Some(og_image) = extract_og_image_fast(html) {
+            for recipe in recipes.iter_mut().filter(|r| r.image_urls.is_empty()) {
+                recipe.image_urls.push(og_image.clone());
+            }
+        }
+    }
+
+    // Only pages with a recipe card need the DOM, to read its ingredient groups
+    if !recipes.is_empty() && recipe_cards::formats_present(html).next().is_some() {
+        let document = Html::parse_document(html);
+        add_card_sections(html, &document, source_url, &mut recipes);
+    }
+    recipes
+}
+
+/// JSON-LD has no way to group recipeIngredient, but the recipe card plugins
+/// that emit it still show their groups ("For the crust") in the card. Give each
+/// JSON-LD recipe the groups of the card for the same recipe.
+fn add_card_sections(html: &str, document: &Html, source_url: &str, recipes: &mut [RawRecipe]) {
+    let cards: Vec<RawRecipe> = recipe_cards::formats_present(html)
+        .filter_map(|format| format.extract_all(document, source_url).ok())
+        .flatten()
+        .filter(|card| !card.ingredient_sections.is_empty())
+        .collect();
+    // A lone recipe and a lone card are the same recipe even if the titles differ
+    let only_one = recipes.len() == 1 && cards.len() == 1;
+
+    for recipe in recipes
+        .iter_mut()
+        .filter(|r| r.ingredient_sections.is_empty())
+    {
+        let card = cards
+            .iter()
+            .find(|card| only_one || card.title.trim().eq_ignore_ascii_case(recipe.title.trim()));
+        if let Some(card) = card {
+            recipe.ingredient_sections =
+                regroup_lines(&recipe.ingredients, &card.ingredient_sections);
+        }
+    }
+}
+
+/// Split a blob's lines into groups the same sizes and names as `sections`.
+/// Returns no groups when the line counts differ, since then the two sources
+/// don't list the same ingredients.
+fn regroup_lines(blob: &str, sections: &[RecipeSection]) -> Vec<RecipeSection> {
+    let lines: Vec<&str> = blob
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let total: usize = sections.iter().map(|section| section.items.len()).sum();
+    if lines.len() != total {
+        return vec![];
+    }
+
+    let mut lines = lines.into_iter();
+    sections
+        .iter()
+        .map(|section| RecipeSection {
+            name: section.name.clone(),
+            items: lines
+                .by_ref()
+                .take(section.items.len())
+                .map(str::to_string)
+                .collect(),
+        })
+        .collect()
+}
+
+/// Add a recipe unless the page already produced the same one (sites often
+/// repeat a recipe's JSON-LD, or mark it up in more than one script tag).
+fn push_unique(recipes: &mut Vec<RawRecipe>, recipe: RawRecipe) {
+    let duplicate = recipes
+        .iter()
+        .any(|r| r.title == recipe.title && r.ingredients == recipe.ingredients);
+    if !duplicate {
+        recipes.push(recipe);
+    }
+}
+
+/// Collect the recipes that extracted successfully. Fails with the first error
+/// when none did, or NoRecipe when there was nothing to extract.
+pub(crate) fn collect_recipes(
+    results: impl IntoIterator<Item = Result<RawRecipe, ExtractError>>,
+) -> Result<Vec<RawRecipe>, ExtractError> {
+    let mut recipes = Vec::new();
+    let mut first_error = None;
+    for result in results {
+        match result {
+            Ok(recipe) => push_unique(&mut recipes, recipe),
+            Err(e) if first_error.is_none() => first_error = Some(e),
+            Err(_) => {}
+        }
+    }
+    if recipes.is_empty() {
+        return Err(first_error.unwrap_or(ExtractError::NoRecipe));
+    }
+    Ok(recipes)
 }
 
 /// Fast og:image extraction using regex.
@@ -97,8 +207,9 @@ fn extract_og_image_fast(html: &str) -> Option<String> {
     None
 }
 
-/// Extract a recipe, trying all methods and reporting which ones work.
-/// Returns the first successful recipe along with stats for all methods tried.
+/// Extract every recipe on the page, trying all methods and reporting which ones work.
+/// The first method that finds a recipe supplies all of them (round-up posts,
+/// a main recipe plus its sauce), along with stats for all methods tried.
 ///
 /// Uses fast regex-based JSON-LD extraction when possible to avoid DOM parsing.
 pub fn extract_recipe_with_stats(
@@ -106,91 +217,156 @@ pub fn extract_recipe_with_stats(
     source_url: &str,
 ) -> Result<ExtractRecipeOutput, ExtractError> {
     // Fast path: try regex-based JSON-LD extraction (avoids DOM parsing)
-    if let Some(recipe) = extract_jsonld_fast(html, source_url) {
+    let mut recipes = extract_jsonld_fast(html, source_url);
+    if !recipes.is_empty() {
+        let raw_recipe = recipes.remove(0);
         return Ok(ExtractRecipeOutput {
-            raw_recipe: recipe,
+            raw_recipe,
             method_used: ExtractionMethod::JsonLd,
             all_attempts: vec![ExtractionAttempt {
                 method: ExtractionMethod::JsonLd,
                 success: true,
                 error: None,
             }],
+            other_recipes: recipes,
+            alternate: None,
+            quality: None,
         });
     }
 
     // Slow path: full DOM parsing for malformed HTML or microdata-only sites
     let document = Html::parse_document(html);
-
-    // Try JSON-LD via DOM (handles edge cases regex might miss)
-    let jsonld_result = extract_recipe_from_jsonld(&document, source_url);
-    if let Ok(recipe) = jsonld_result {
-        return Ok(ExtractRecipeOutput {
-            raw_recipe: recipe,
-            method_used: ExtractionMethod::JsonLd,
-            all_attempts: vec![ExtractionAttempt {
-                method: ExtractionMethod::JsonLd,
-                success: true,
-                error: None,
-            }],
+    let mut all_attempts = Vec::new();
+    let mut record = |method: ExtractionMethod, result: Result<Vec<RawRecipe>, ExtractError>| {
+        all_attempts.push(ExtractionAttempt {
+            method,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
         });
+        result.ok().map(|recipes| (method, recipes))
+    };
+
+    // Try JSON-LD via DOM (handles edge cases regex might miss), then the recipe
+    // card plugins present on the page, then microdata
+    let jsonld = extract_recipes_from_jsonld(&document, source_url).map(|mut recipes| {
+        add_card_sections(html, &document, source_url, &mut recipes);
+        recipes
+    });
+    let mut found = record(ExtractionMethod::JsonLd, jsonld);
+    for format in recipe_cards::formats_present(html) {
+        if found.is_some() {
+            break;
+        }
+        found = record(format.method, format.extract_all(&document, source_url));
+    }
+    if found.is_none() {
+        found = record(
+            ExtractionMethod::Microdata,
+            extract_recipes_from_microdata(&document, source_url),
+        );
     }
 
-    // Fall back to microdata
-    let microdata_result = extract_recipe_from_microdata(&document, source_url);
-    if let Ok(recipe) = microdata_result {
-        return Ok(ExtractRecipeOutput {
-            raw_recipe: recipe,
-            method_used: ExtractionMethod::Microdata,
-            all_attempts: vec![
-                ExtractionAttempt {
-                    method: ExtractionMethod::JsonLd,
-                    success: false,
-                    error: jsonld_result.as_ref().err().map(|e| e.to_string()),
-                },
-                ExtractionAttempt {
-                    method: ExtractionMethod::Microdata,
-                    success: true,
-                    error: None,
-                },
-            ],
+    // Last resort: supplement partial structured data with HTML fallbacks
+    if found.is_none() {
+        let recipe = extract_recipe_with_html_fallback(html, &document, source_url)?;
+        found = record(ExtractionMethod::HtmlFallback, Ok(vec![recipe]));
+    }
+
+    let (method_used, mut recipes) = found.ok_or(ExtractError::NoRecipe)?;
+    let raw_recipe = recipes.remove(0);
+    Ok(ExtractRecipeOutput {
+        raw_recipe,
+        method_used,
+        all_attempts,
+        other_recipes: recipes,
+        alternate: None,
+        quality: None,
+    })
+}
+
+/// Most alternate pages worth trying for one page.
+const MAX_ALTERNATE_PAGES: usize = 3;
+
+/// Find printer-friendly and AMP versions of a page, which often carry clean
+/// recipe markup when the page itself doesn't. Print views come first. Only
+/// links on the page's own host are returned, so following them never reaches
+/// a server the user didn't ask for.
+pub fn find_alternate_pages(html: &str, page_url: &str) -> Vec<AlternatePage> {
+    let Ok(base) = url::Url::parse(page_url) else {
+        return vec![];
+    };
+    let document = Html::parse_document(html);
+    let print_selector = Selector::parse("a[href]").expect("Invalid selector");
+    let amp_selector = Selector::parse(r#"link[rel~="amphtml"][href]"#).expect("Invalid selector");
+
+    let print_links = document
+        .select(&print_selector)
+        .filter(|a| is_print_link(a))
+        .map(|a| (a, AlternateKind::Print));
+    let amp_links = document
+        .select(&amp_selector)
+        .map(|link| (link, AlternateKind::Amp));
+
+    let mut pages: Vec<AlternatePage> = Vec::new();
+    for (element, kind) in print_links.chain(amp_links) {
+        let Some(url) = element
+            .value()
+            .attr("href")
+            .and_then(|href| base.join(href.trim()).ok())
+        else {
+            continue;
+        };
+        let mut page_only = url.clone();
+        page_only.set_fragment(None);
+        if !matches!(url.scheme(), "http" | "https")
+            || !same_site(&base, &url)
+            || page_only.as_str() == base.as_str()
+            || pages.iter().any(|p| p.url == url.as_str())
+        {
+            continue;
+        }
+        pages.push(AlternatePage {
+            url: url.to_string(),
+            kind,
         });
+        if pages.len() == MAX_ALTERNATE_PAGES {
+            break;
+        }
     }
+    pages
+}
 
-    // Last resort: supplement partial structured data with HTML fallbacks
-    let html_fallback_result = extract_recipe_with_html_fallback(html, &document, source_url);
-    match html_fallback_result {
-        Ok(recipe) => Ok(ExtractRecipeOutput {
-            raw_recipe: recipe,
-            method_used: ExtractionMethod::HtmlFallback,
-            all_attempts: vec![
-                ExtractionAttempt {
-                    method: ExtractionMethod::JsonLd,
-                    success: false,
-                    error: jsonld_result.as_ref().err().map(|e| e.to_string()),
-                },
-                ExtractionAttempt {
-                    method: ExtractionMethod::Microdata,
-                    success: false,
-                    error: microdata_result.as_ref().err().map(|e| e.to_string()),
-                },
-                ExtractionAttempt {
-                    method: ExtractionMethod::HtmlFallback,
-                    success: true,
-                    error: None,
-                },
-            ],
-        }),
-        Err(e) => Err(e),
+/// Whether a link points at a printer-friendly view of the recipe: WPRM's
+/// `/wprm_print/` pages, `?print=1`-style query strings, `/print/` paths, and
+/// the print buttons recipe card plugins render.
+fn is_print_link(a: &scraper::ElementRef) -> bool {
+    let href = a.value().attr("href").unwrap_or_default().to_lowercase();
+    if href.starts_with('#') || href.starts_with("javascript:") {
+        return false;
     }
+    let class = a.value().attr("class").unwrap_or_default().to_lowercase();
+    href.contains("/wprm_print/")
+        || href.contains("/print/")
+        || href.contains("?print=")
+        || href.contains("&print=")
+        || class
+            .split_whitespace()
+            .any(|c| c.contains("recipe-print") || c.contains("print-button"))
 }
 
-/// Extract recipe from JSON-LD script tags.
-fn extract_recipe_from_jsonld(
+/// Whether two URLs are on the same host and port.
+fn same_site(a: &url::Url, b: &url::Url) -> bool {
+    a.host_str().is_some() && a.host_str() == b.host_str() && a.port() == b.port()
+}
+
+/// Extract every recipe from JSON-LD script tags.
+fn extract_recipes_from_jsonld(
     document: &Html,
     source_url: &str,
-) -> Result<RawRecipe, ExtractError> {
+) -> Result<Vec<RawRecipe>, ExtractError> {
     let selector = Selector::parse("script[type='application/ld+json']").expect("Invalid selector");
 
+    let mut results = Vec::new();
     for element in document.select(&selector) {
         let json_text = element.inner_html();
 
@@ -203,20 +379,20 @@ fn extract_recipe_from_jsonld(
             Err(_) => continue, // Try next script tag
         };
 
-        // Look for Recipe type
-        if let Some(recipe) = find_recipe_in_json(&json) {
-            let mut raw_recipe = extract_recipe_data(recipe, source_url)?;
-            // Fallback to og:image if no images found in JSON-LD structured data
-            if raw_recipe.image_urls.is_empty() {
-                if let Some(og_image) = extract_og_image(document) {
-                    raw_recipe.image_urls.push(og_image);
-                }
-            }
-            return Ok(raw_recipe);
+        // Look for Recipe types
+        for recipe in find_recipes_in_json(&json) {
+            results.push(extract_recipe_data(recipe, source_url));
         }
     }
 
-    Err(ExtractError::NoRecipe)
+    let mut recipes = collect_recipes(results)?;
+    // Fallback to og:image if no images found in JSON-LD structured data
+    if let Some(og_image) = extract_og_image(document) {
+        for recipe in recipes.iter_mut().filter(|r| r.image_urls.is_empty()) {
+            recipe.image_urls.push(og_image.clone());
+        }
+    }
+    Ok(recipes)
 }
 
 /// Sanitize JSON-LD content to handle common malformed patterns.
@@ -251,9 +427,18 @@ fn sanitize_json(json: &str) -> String {
     result
 }
 
-/// Recursively search for a Recipe object in JSON-LD.
-/// Handles @graph arrays and nested structures.
-fn find_recipe_in_json(json: &serde_json::Value) -> Option<&serde_json::Value> {
+/// Recursively search for Recipe objects in JSON-LD, in document order.
+/// Handles @graph arrays, ItemList round-ups and other nested structures.
+fn find_recipes_in_json(json: &serde_json::Value) -> Vec<&serde_json::Value> {
+    let mut recipes = Vec::new();
+    collect_recipes_in_json(json, &mut recipes);
+    recipes
+}
+
+fn collect_recipes_in_json<'a>(
+    json: &'a serde_json::Value,
+    recipes: &mut Vec<&'a serde_json::Value>,
+) {
     match json {
         serde_json::Value::Object(obj) => {
             // Check if this object is a Recipe
@@ -264,34 +449,23 @@ fn find_recipe_in_json(json: &serde_json::Value) -> Option<&serde_json::Value> {
                     _ => false,
                 };
                 if is_recipe {
-                    return Some(json);
+                    recipes.push(json);
+                    return;
                 }
             }
 
-            // Check @graph for array of items
-            if let Some(graph) = obj.get("@graph") {
-                if let Some(recipe) = find_recipe_in_json(graph) {
-                    return Some(recipe);
-                }
-            }
-
-            // Recursively search other fields
+            // Recursively search all fields (including @graph)
             for (_, value) in obj {
-                if let Some(recipe) = find_recipe_in_json(value) {
-                    return Some(recipe);
-                }
+                collect_recipes_in_json(value, recipes);
             }
         }
         serde_json::Value::Array(arr) => {
             for item in arr {
-                if let Some(recipe) = find_recipe_in_json(item) {
-                    return Some(recipe);
-                }
+                collect_recipes_in_json(item, recipes);
             }
         }
         _ => {}
     }
-    None
 }
 
 /// Extract recipe data from a JSON-LD Recipe object.
@@ -311,7 +485,8 @@ fn extract_recipe_data(
         .map(|s| s.to_string());
 
     let ingredients = extract_ingredients(recipe)?;
-    let instructions = extract_instructions(recipe)?;
+    let instruction_sections = extract_instruction_sections(recipe)?;
+    let instructions = RecipeSection::join(&instruction_sections, "\n\n");
     let image_urls = extract_image_urls(recipe);
     let source_name = extract_source_name(source_url);
 
@@ -323,6 +498,8 @@ fn extract_recipe_data(
         _ => None,
     });
 
+    let metadata = RecipeMetadata::from_jsonld(recipe);
+
     Ok(RawRecipe {
         title,
         description,
@@ -332,14 +509,21 @@ fn extract_recipe_data(
         source_url: Some(source_url.to_string()),
         source_name,
         servings,
-        prep_time: None,
-        cook_time: None,
-        total_time: None,
-        rating: None,
+        prep_time: metadata.prep_time,
+        cook_time: metadata.cook_time,
+        total_time: metadata.total_time,
+        rating: metadata.rating,
         difficulty: None,
-        nutritional_info: None,
+        nutritional_info: metadata.nutritional_info,
         notes: None,
-        categories: None,
+        categories: metadata.categories,
+        video_url: metadata.video_url,
+        equipment: metadata.equipment,
+        author: metadata.author,
+        published_date: metadata.published_date,
+        language: None,
+        ingredient_sections: vec![],
+        instruction_sections: RecipeSection::if_named(instruction_sections),
     })
 }
 
@@ -369,49 +553,77 @@ fn extract_ingredients(recipe: &serde_json::Value) -> Result<String, ExtractErro
     Ok(ingredients.join("\n"))
 }
 
-/// Extract instructions from recipeInstructions field.
-/// Handles both string and array formats.
+/// Extract instructions from recipeInstructions field, with a "Heading:" line
+/// before each named HowToSection.
 fn extract_instructions(recipe: &serde_json::Value) -> Result<String, ExtractError> {
+    let sections = extract_instruction_sections(recipe)?;
+    Ok(RecipeSection::join(&sections, "\n\n"))
+}
+
+/// Extract the steps of recipeInstructions grouped by HowToSection.
+/// Handles both string and array formats; steps outside any section go in
+/// unnamed sections.
+fn extract_instruction_sections(
+    recipe: &serde_json::Value,
+) -> Result<Vec<RecipeSection>, ExtractError> {
     let instructions_raw = recipe
         .get("recipeInstructions")
         .ok_or_else(|| ExtractError::MissingField("recipeInstructions".to_string()))?;
 
     match instructions_raw {
-        serde_json::Value::String(s) => Ok(s.trim().to_string()),
+        serde_json::Value::String(s) => Ok(vec![RecipeSection {
+            name: None,
+            items: vec![s.trim().to_string()],
+        }]),
         serde_json::Value::Array(arr) => {
-            let steps: Vec<String> = arr
-                .iter()
-                .filter_map(|item| {
-                    // Handle HowToStep objects
-                    if let Some(text) = item.get("text").and_then(|v| v.as_str()) {
-                        return Some(text.trim().to_string());
-                    }
-                    // Handle plain strings
-                    if let Some(s) = item.as_str() {
-                        return Some(s.trim().to_string());
-                    }
-                    // Handle HowToSection with itemListElement
-                    if let Some(items) = item.get("itemListElement").and_then(|v| v.as_array()) {
-                        let section_steps: Vec<String> = items
-                            .iter()
-                            .filter_map(|step| step.get("text").and_then(|v| v.as_str()))
+            let mut sections: Vec<RecipeSection> = Vec::new();
+            for item in arr {
+                // Handle HowToSection with itemListElement
+                if let Some(items) = item.get("itemListElement").and_then(|v| v.as_array()) {
+                    let section_steps: Vec<String> = items
+                        .iter()
+                        .filter_map(|step| step.get("text").and_then(|v| v.as_str()))
+                        .map(|s| s.trim().to_string())
+                        .collect();
+                    if !section_steps.is_empty() {
+                        let name = item
+                            .get("name")
+                            .and_then(|v| v.as_str())
                             .map(|s| s.trim().to_string())
-                            .collect();
-                        if !section_steps.is_empty() {
-                            return Some(section_steps.join("\n"));
+                            .filter(|s| !s.is_empty());
+                        sections.push(RecipeSection {
+                            name,
+                            items: section_steps,
+                        });
+                    }
+                    continue;
+                }
+
+                // Handle HowToStep objects and plain strings
+                let step = item
+                    .get("text")
+                    .and_then(|v| v.as_str())
+                    .or_else(|| item.as_str());
+                if let Some(step) = step {
+                    match sections.last_mut() {
+                        Some(section) if section.name.is_none() => {
+                            section.items.push(step.trim().to_string())
                         }
+                        _ => sections.push(RecipeSection {
+                            name: None,
+                            items: vec![step.trim().to_string()],
+                        }),
                     }
-                    None
-                })
-                .collect();
+                }
+            }
 
-            if steps.is_empty() {
+            if sections.is_empty() {
                 return Err(ExtractError::MissingField(
                     "recipeInstructions (empty)".to_string(),
                 ));
             }
 
-            Ok(steps.join("\n\n"))
+            Ok(sections)
         }
         _ => Err(ExtractError::InvalidJson(
             "recipeInstructions is not a string or array".to_string(),
@@ -452,7 +664,7 @@ fn extract_image_urls(recipe: &serde_json::Value) -> Vec<String> {
 }
 
 /// Extract a friendly source name from a URL.
-fn extract_source_name(url: &str) -> Option<String> {
+pub(crate) fn extract_source_name(url: &str) -> Option<String> {
     url::Url::parse(url).ok().and_then(|parsed| {
         parsed.host_str().map(|host| {
             // Remove www. prefix
@@ -467,24 +679,348 @@ fn extract_source_name(url: &str) -> Option<String> {
     })
 }
 
-/// Extract recipe from schema.org microdata markup.
+/// Nutrition properties of schema.org NutritionInformation, with display labels,
+/// in the order they're listed.
+const NUTRITION_FIELDS: &[(&str, &str)] = &[
+    ("servingSize", "Serving size"),
+    ("calories", "Calories"),
+    ("fatContent", "Fat"),
+    ("saturatedFatContent", "Saturated fat"),
+    ("transFatContent", "Trans fat"),
+    ("unsaturatedFatContent", "Unsaturated fat"),
+    ("cholesterolContent", "Cholesterol"),
+    ("sodiumContent", "Sodium"),
+    ("carbohydrateContent", "Carbohydrates"),
+    ("fiberContent", "Fiber"),
+    ("sugarContent", "Sugar"),
+    ("proteinContent", "Protein"),
+];
+
+/// Optional recipe metadata shared by the JSON-LD and microdata paths.
+#[derive(Debug, Default)]
+struct RecipeMetadata {
+    prep_time: Option<String>,
+    cook_time: Option<String>,
+    total_time: Option<String>,
+    /// One "Label: value" line per nutrient
+    nutritional_info: Option<String>,
+    /// recipeCategory and recipeCuisine, deduplicated. `keywords` is left out:
+    /// it's mostly SEO phrases ("rice a roni", "easy") that make poor tags.
+    categories: Option<Vec<String>>,
+    /// aggregateRating scaled to 1-5
+    rating: Option<i32>,
+    video_url: Option<String>,
+    /// Names from `tool`, deduplicated
+    equipment: Option<Vec<String>>,
+    author: Option<String>,
+    /// datePublished as YYYY-MM-DD
+    published_date: Option<String>,
+}
+
+impl RecipeMetadata {
+    /// Read metadata from a JSON-LD Recipe object.
+    fn from_jsonld(recipe: &serde_json::Value) -> Self {
+        let time = |key: &str| {
+            recipe
+                .get(key)
+                .and_then(json_scalar_string)
+                .and_then(|s| humanize_duration(&s))
+        };
+
+        let nutritional_info = recipe.get("nutrition").and_then(|nutrition| {
+            format_nutrition(NUTRITION_FIELDS.iter().filter_map(|(key, label)| {
+                nutrition
+                    .get(*key)
+                    .and_then(json_scalar_string)
+                    .map(|value| (*label, value))
+            }))
+        });
+
+        let mut categories = Vec::new();
+        for key in ["recipeCategory", "recipeCuisine"] {
+            match recipe.get(key) {
+                Some(serde_json::Value::Array(items)) => {
+                    categories.extend(items.iter().filter_map(json_scalar_string))
+                }
+                Some(value) => categories.extend(json_scalar_string(value)),
+                None => {}
+            }
+        }
+
+        let rating = recipe.get("aggregateRating").and_then(|rating| {
+            let value = rating.get("ratingValue").and_then(json_number)?;
+            let best = rating.get("bestRating").and_then(json_number);
+            scale_rating(value, best)
+        });
+
+        // A VideoObject (or a list of them), or just the video's URL
+        let video_url = json_items(recipe.get("video")).find_map(|video| match video {
+            serde_json::Value::Object(_) => ["contentUrl", "embedUrl", "url"]
+                .iter()
+                .filter_map(|key| video.get(*key).and_then(json_scalar_string))
+                .find_map(|url| http_url(&url)),
+            _ => json_scalar_string(video).and_then(|url| http_url(&url)),
+        });
+
+        // HowToTool objects or plain names
+        let equipment: Vec<String> = json_items(recipe.get("tool"))
+            .filter_map(|tool| {
+                json_scalar_string(tool)
+                    .or_else(|| tool.get("name").and_then(json_scalar_string))
+                    .or_else(|| tool.get("text").and_then(json_scalar_string))
+            })
+            .collect();
+
+        // A Person/Organization (or several), or just a name
+        let authors: Vec<String> = json_items(recipe.get("author"))
+            .filter_map(|author| {
+                json_scalar_string(author)
+                    .or_else(|| author.get("name").and_then(json_scalar_string))
+            })
+            .collect();
+
+        Self {
+            prep_time: time("prepTime"),
+            cook_time: time("cookTime"),
+            total_time: time("totalTime"),
+            nutritional_info,
+            categories: split_categories(&categories),
+            rating,
+            video_url,
+            equipment: dedupe_names(&equipment),
+            author: join_authors(&authors),
+            published_date: recipe
+                .get("datePublished")
+                .and_then(json_scalar_string)
+                .and_then(|date| parse_published_date(&date)),
+        }
+    }
+
+    /// Read metadata from a microdata Recipe element.
+    fn from_microdata(recipe_element: &scraper::ElementRef) -> Self {
+        let time = |prop: &str| {
+            extract_microdata_text(recipe_element, prop).and_then(|s| humanize_duration(&s))
+        };
+
+        let nutrition_selector =
+            Selector::parse(r#"[itemprop="nutrition"]"#).expect("Invalid selector");
+        let nutritional_info =
+            recipe_element
+                .select(&nutrition_selector)
+                .next()
+                .and_then(|nutrition| {
+                    format_nutrition(NUTRITION_FIELDS.iter().filter_map(|(prop, label)| {
+                        extract_microdata_text(&nutrition, prop).map(|value| (*label, value))
+                    }))
+                });
+
+        let categories: Vec<String> = ["recipeCategory", "recipeCuisine"]
+            .iter()
+            .flat_map(|prop| extract_microdata_texts(recipe_element, prop))
+            .collect();
+
+        let rating_selector =
+            Selector::parse(r#"[itemprop="aggregateRating"]"#).expect("Invalid selector");
+        let rating = recipe_element
+            .select(&rating_selector)
+            .next()
+            .and_then(|rating| {
+                let value = extract_microdata_text(&rating, "ratingValue")?
+                    .parse::<f64>()
+                    .ok()?;
+                let best = extract_microdata_text(&rating, "bestRating")
+                    .and_then(|s| s.parse::<f64>().ok());
+                scale_rating(value, best)
+            });
+
+        let video_selector = Selector::parse(r#"[itemprop="video"]"#).expect("Invalid selector");
+        let video_url = recipe_element
+            .select(&video_selector)
+            .next()
+            .and_then(|video| {
+                ["contentUrl", "embedUrl"]
+                    .iter()
+                    .filter_map(|prop| extract_microdata_text(&video, prop))
+                    .chain(video.value().attr("src").map(str::to_string))
+                    .find_map(|url| http_url(&url))
+            });
+
+        // An author is either plain text or a nested Person with a name
+        let author_selector = Selector::parse(r#"[itemprop="author"]"#).expect("Invalid selector");
+        let authors: Vec<String> = recipe_element
+            .select(&author_selector)
+            .filter_map(|author| {
+                if author.value().attr("itemscope").is_some() {
+                    extract_microdata_text(&author, "name")
+                } else {
+                    Some(microdata_value(author))
+                }
+            })
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        Self {
+            prep_time: time("prepTime"),
+            cook_time: time("cookTime"),
+            total_time: time("totalTime"),
+            nutritional_info,
+            categories: split_categories(&categories),
+            rating,
+            video_url,
+            equipment: dedupe_names(&extract_microdata_texts(recipe_element, "tool")),
+            author: join_authors(&authors),
+            published_date: extract_microdata_text(recipe_element, "datePublished")
+                .and_then(|date| parse_published_date(&date)),
+        }
+    }
+
+    /// Fill in fields missing here from `other`.
+    fn or(self, other: Self) -> Self {
+        Self {
+            prep_time: self.prep_time.or(other.prep_time),
+            cook_time: self.cook_time.or(other.cook_time),
+            total_time: self.total_time.or(other.total_time),
+            nutritional_info: self.nutritional_info.or(other.nutritional_info),
+            categories: self.categories.or(other.categories),
+            rating: self.rating.or(other.rating),
+            video_url: self.video_url.or(other.video_url),
+            equipment: self.equipment.or(other.equipment),
+            author: self.author.or(other.author),
+            published_date: self.published_date.or(other.published_date),
+        }
+    }
+}
+
+/// The URL, trimmed, if it is an absolute http(s) URL. Pages sometimes put
+/// `javascript:` or `data:` URLs in video fields, and we render them as links.
+pub fn http_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let parsed = url::Url::parse(url).ok()?;
+    matches!(parsed.scheme(), "http" | "https").then(|| url.to_string())
+}
+
+/// The items of a JSON-LD property that may be a single value or an array.
+fn json_items(value: Option<&serde_json::Value>) -> impl Iterator<Item = &serde_json::Value> {
+    let items: &[serde_json::Value] = match value {
+        Some(serde_json::Value::Array(items)) => items,
+        Some(value) => std::slice::from_ref(value),
+        None => &[],
+    };
+    items.iter()
+}
+
+/// Decode and deduplicate (case-insensitively) a list of names, dropping empty ones.
+fn dedupe_names(values: &[String]) -> Option<Vec<String>> {
+    let mut seen = std::collections::HashSet::new();
+    let names: Vec<String> = values
+        .iter()
+        .map(|s| decode_html_entities(s.trim()))
+        .filter(|s| !s.is_empty() && seen.insert(s.to_lowercase()))
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
+
+/// Credit several authors as "A, B".
+fn join_authors(authors: &[String]) -> Option<String> {
+    dedupe_names(authors).map(|names| names.join(", "))
+}
+
+/// The date part of a datePublished value ("2024-03-05T08:00:00+00:00").
+fn parse_published_date(value: &str) -> Option<String> {
+    let date = value.trim().get(..10)?;
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// A JSON string or number as a trimmed, non-empty string.
+fn json_scalar_string(value: &serde_json::Value) -> Option<String> {
+    let s = match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    (!s.is_empty()).then_some(s)
+}
+
+/// A JSON number, or a string holding one (ratings are often quoted).
+fn json_number(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Scale a rating out of `best` (default 5) to a whole number of stars, 1-5.
+fn scale_rating(value: f64, best: Option<f64>) -> Option<i32> {
+    let best = best.filter(|b| *b > 0.0).unwrap_or(5.0);
+    if !value.is_finite() || value <= 0.0 {
+        return None;
+    }
+    Some(((value / best * 5.0).round() as i32).clamp(1, 5))
+}
+
+/// Render nutrition (label, value) pairs one per line.
+fn format_nutrition<'a>(fields: impl Iterator<Item = (&'a str, String)>) -> Option<String> {
+    let lines: Vec<String> = fields
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(label, value)| format!("{}: {}", label, value))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Split comma-separated category strings, dropping duplicates
+/// (case-insensitively) and empty entries.
+fn split_categories(values: &[String]) -> Option<Vec<String>> {
+    let categories: Vec<String> = values
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(str::to_string)
+        .collect();
+    dedupe_names(&categories)
+}
+
+/// Render a recipe time for display: ISO 8601 durations become "1 hour 30 minutes",
+/// anything else ("15 mins") is kept as written. Zero durations are dropped.
+fn humanize_duration(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    match parse_iso8601_duration(value) {
+        Some(duration) if duration.as_secs_f64() < 0.5 => None,
+        Some(duration) => Some(format_duration(duration)),
+        None => Some(value.to_string()),
+    }
+}
+
+/// Extract every recipe from schema.org microdata markup.
 /// This is a fallback for sites that don't use JSON-LD but have microdata attributes.
-fn extract_recipe_from_microdata(
+fn extract_recipes_from_microdata(
     document: &Html,
     source_url: &str,
-) -> Result<RawRecipe, ExtractError> {
-    // Find the Recipe container element
+) -> Result<Vec<RawRecipe>, ExtractError> {
+    // Find the Recipe container elements
     // Try both http and https schema.org URLs
     let recipe_selector = Selector::parse(
         r#"[itemtype="http://schema.org/Recipe"], [itemtype="https://schema.org/Recipe"]"#,
     )
     .expect("Invalid selector");
 
-    let recipe_element = document
-        .select(&recipe_selector)
-        .next()
-        .ok_or(ExtractError::NoRecipe)?;
+    collect_recipes(
+        document
+            .select(&recipe_selector)
+            .map(|element| extract_microdata_recipe(element, document, source_url)),
+    )
+}
 
+/// Extract one microdata Recipe scope.
+fn extract_microdata_recipe(
+    recipe_element: scraper::ElementRef,
+    document: &Html,
+    source_url: &str,
+) -> Result<RawRecipe, ExtractError> {
     // Extract title from itemprop="name"
     let title = extract_microdata_text(&recipe_element, "name")
         .ok_or_else(|| ExtractError::MissingField("name".to_string()))?;
@@ -522,6 +1058,7 @@ fn extract_recipe_from_microdata(
 
     let source_name = extract_source_name(source_url);
     let servings = extract_microdata_text(&recipe_element, "recipeYield");
+    let metadata = RecipeMetadata::from_microdata(&recipe_element);
 
     Ok(RawRecipe {
         title,
@@ -532,28 +1069,53 @@ fn extract_recipe_from_microdata(
         source_url: Some(source_url.to_string()),
         source_name,
         servings,
-        prep_time: None,
-        cook_time: None,
-        total_time: None,
-        rating: None,
+        prep_time: metadata.prep_time,
+        cook_time: metadata.cook_time,
+        total_time: metadata.total_time,
+        rating: metadata.rating,
         difficulty: None,
-        nutritional_info: None,
+        nutritional_info: metadata.nutritional_info,
         notes: None,
-        categories: None,
+        categories: metadata.categories,
+        video_url: metadata.video_url,
+        equipment: metadata.equipment,
+        author: metadata.author,
+        published_date: metadata.published_date,
+        language: None,
+        ingredient_sections: vec![],
+        instruction_sections: vec![],
     })
 }
 
 /// Extract text content from an element with the given itemprop.
 fn extract_microdata_text(element: &scraper::ElementRef, prop: &str) -> Option<String> {
     let selector = Selector::parse(&format!(r#"[itemprop="{}"]"#, prop)).ok()?;
-    element.select(&selector).next().map(|el| {
-        // Check for content attribute first (common for meta tags)
-        if let Some(content) = el.value().attr("content") {
-            content.trim().to_string()
-        } else {
-            el.text().collect::<String>().trim().to_string()
-        }
-    })
+    element.select(&selector).next().map(microdata_value)
+}
+
+/// Extract the text content of every element with the given itemprop.
+fn extract_microdata_texts(element: &scraper::ElementRef, prop: &str) -> Vec<String> {
+    let Ok(selector) = Selector::parse(&format!(r#"[itemprop="{}"]"#, prop)) else {
+        return Vec::new();
+    };
+    element
+        .select(&selector)
+        .map(microdata_value)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// The value of a microdata property element.
+fn microdata_value(el: scraper::ElementRef) -> String {
+    // Check for content attribute first (common for meta tags), then the
+    // machine-readable datetime of <time> elements (durations)
+    if let Some(content) = el.value().attr("content") {
+        content.trim().to_string()
+    } else if let Some(datetime) = el.value().attr("datetime") {
+        datetime.trim().to_string()
+    } else {
+        el.text().collect::<String>().trim().to_string()
+    }
 }
 
 /// Extract instructions from microdata.
@@ -634,7 +1196,7 @@ fn extract_microdata_images(recipe_element: &scraper::ElementRef) -> Vec<String>
 /// Extract image URL from og:image meta tag.
 /// This is a fallback for sites that don't include image data in their recipe structured data
 /// (e.g., smittenkitchen.com uses Jetpack recipes which omit itemprop="image").
-fn extract_og_image(document: &Html) -> Option<String> {
+pub(crate) fn extract_og_image(document: &Html) -> Option<String> {
     let selector = Selector::parse(r#"meta[property="og:image"]"#).ok()?;
     document
         .select(&selector)
@@ -843,6 +1405,13 @@ fn extract_recipe_from_unstructured_blog(html: &str, source_url: &str) -> Option
         nutritional_info: None,
         notes: None,
         categories: None,
+        video_url: None,
+        equipment: None,
+        author: None,
+        published_date: None,
+        language: None,
+        ingredient_sections: vec![],
+        instruction_sections: vec![],
     })
 }
 
@@ -954,6 +1523,212 @@ fn decode_html_entities(text: &str) -> String {
         .replace("&hellip;", "\u{2026}")
 }
 
+/// Regex to detect standalone section labels in plain-text recipes ("Ingredients", "Method:").
+static TEXT_SECTION_LABEL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(ingredients|instructions|directions|method|preparation|steps)\s*:?$")
+        .expect("Invalid text section label regex")
+});
+
+/// Regex to detect numbered instruction steps ("1. Preheat", "2) Whisk", "Step 3").
+static NUMBERED_STEP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(step\s+\d+|\d+\s*[.)]\s+\p{L})").expect("Invalid numbered step regex")
+});
+
+/// Regex to extract "Prep time: 10 minutes" style metadata lines.
+static TEXT_TIME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(prep|cook|cooking|total)(?:\s+time)?\s*:\s*(.+)$")
+        .expect("Invalid text time regex")
+});
+
+/// Extract a recipe from plain text, such as a `.txt` file or the text layer of a PDF.
+///
+/// Uses the same heuristics as `extract_recipe_from_unstructured_blog`, with blank
+/// lines playing the role of `<p>` boundaries and line breaks the role of `<br>`:
+/// 1. The first line is the title
+/// 2. Paragraphs that pass `looks_like_ingredient_list` are ingredients, with
+///    `detect_section_header` lines between them kept as section headers
+/// 3. Paragraphs after the last ingredient paragraph are instructions
+///
+/// Returns None when no ingredient list or instructions can be found, so callers
+/// can fall back to AI extraction.
+pub fn extract_recipe_from_text(text: &str) -> Option<RawRecipe> {
+    // Split into paragraphs on blank lines and on "Ingredients" / "Method" labels
+    let mut paragraphs: Vec<Vec<&str>> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || TEXT_SECTION_LABEL_REGEX.is_match(line) {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        current.push(line);
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    // The first line of the document is the title
+    let first = paragraphs.first_mut()?;
+    let title = first.remove(0).to_string();
+    if first.is_empty() {
+        paragraphs.remove(0);
+    }
+    if title.chars().count() > 120 {
+        return None;
+    }
+
+    let ingredient_paragraph_indices: Vec<usize> = paragraphs
+        .iter()
+        .enumerate()
+        .filter(|(_, lines)| {
+            let steps = lines
+                .iter()
+                .filter(|line| NUMBERED_STEP_REGEX.is_match(line))
+                .count();
+            lines.len() >= 2
+                && steps * 2 < lines.len()
+                && looks_like_ingredient_list(&lines.join("<br>"))
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut first_ingredient_idx = *ingredient_paragraph_indices.first()?;
+    let last_ingredient_idx = *ingredient_paragraph_indices.last()?;
+
+    let is_header_paragraph = |lines: &[&str]| {
+        lines
+            .iter()
+            .all(|line| detect_section_header(line).is_some())
+    };
+
+    // A header on its own just before the first list ("FOR THE DOUGH") belongs to it
+    if first_ingredient_idx > 0 && is_header_paragraph(&paragraphs[first_ingredient_idx - 1]) {
+        first_ingredient_idx -= 1;
+    }
+
+    let mut ingredient_lines: Vec<String> = Vec::new();
+    for (idx, lines) in paragraphs
+        .iter()
+        .enumerate()
+        .take(last_ingredient_idx + 1)
+        .skip(first_ingredient_idx)
+    {
+        if ingredient_paragraph_indices.contains(&idx) {
+            ingredient_lines.extend(lines.iter().map(|line| line.to_string()));
+        } else {
+            // Between two ingredient lists only section headers are kept
+            ingredient_lines.extend(
+                lines
+                    .iter()
+                    .filter(|line| detect_section_header(line).is_some())
+                    .map(|line| line.to_string()),
+            );
+        }
+    }
+
+    // Metadata and headnotes sit between the title and the ingredients
+    let mut servings: Option<String> = None;
+    let mut prep_time: Option<String> = None;
+    let mut cook_time: Option<String> = None;
+    let mut total_time: Option<String> = None;
+    let mut description_paragraphs: Vec<String> = Vec::new();
+    for lines in paragraphs.iter().take(first_ingredient_idx) {
+        let mut prose: Vec<&str> = Vec::new();
+        for line in lines {
+            let lower = line.to_lowercase();
+            if lower.starts_with("makes ")
+                || lower.starts_with("serves ")
+                || lower.starts_with("yield")
+            {
+                servings = Some(line.to_string());
+            } else if let Some(cap) = TEXT_TIME_REGEX.captures(line) {
+                let value = Some(cap[2].trim().to_string());
+                match cap[1].to_lowercase().as_str() {
+                    "prep" => prep_time = value,
+                    "total" => total_time = value,
+                    _ => cook_time = value,
+                }
+            } else {
+                prose.push(line);
+            }
+        }
+        if !prose.is_empty() {
+            description_paragraphs.push(unwrap_text_lines(&prose));
+        }
+    }
+
+    // Instructions: everything after the last ingredient list
+    let mut instruction_paragraphs: Vec<String> = Vec::new();
+    for lines in paragraphs.iter().skip(last_ingredient_idx + 1) {
+        // Skip attribution lines at the start (before any real instructions)
+        if instruction_paragraphs.is_empty() && lines.len() == 1 {
+            let lower = lines[0].to_lowercase();
+            if lower.starts_with("adapted from")
+                || lower.starts_with("from ")
+                || lower.starts_with("recipe from")
+                || lower.starts_with("source:")
+            {
+                continue;
+            }
+        }
+        instruction_paragraphs.push(unwrap_text_lines(lines));
+    }
+
+    if instruction_paragraphs.is_empty() {
+        return None;
+    }
+
+    Some(RawRecipe {
+        title,
+        description: if description_paragraphs.is_empty() {
+            None
+        } else {
+            Some(description_paragraphs.join("\n\n"))
+        },
+        ingredients: ingredient_lines.join("\n"),
+        instructions: instruction_paragraphs.join("\n\n"),
+        image_urls: vec![],
+        source_url: None,
+        source_name: None,
+        servings,
+        prep_time,
+        cook_time,
+        total_time,
+        rating: None,
+        difficulty: None,
+        nutritional_info: None,
+        notes: None,
+        categories: None,
+        video_url: None,
+        equipment: None,
+        author: None,
+        published_date: None,
+        language: None,
+        ingredient_sections: vec![],
+        instruction_sections: vec![],
+    })
+}
+
+/// Rejoin hard-wrapped lines (common in PDF text layers) into sentences, keeping
+/// numbered steps and lines after sentence-ending punctuation on their own lines.
+fn unwrap_text_lines(lines: &[&str]) -> String {
+    let mut result = String::new();
+    for line in lines {
+        if result.is_empty() {
+            result.push_str(line);
+        } else if NUMBERED_STEP_REGEX.is_match(line) || result.ends_with(['.', '!', '?', ':']) {
+            result.push('\n');
+            result.push_str(line);
+        } else {
+            result.push(' ');
+            result.push_str(line);
+        }
+    }
+    result
+}
+
 /// Extract a recipe by combining partial structured data with HTML class-based fallbacks.
 /// This handles cases where JSON-LD or microdata has a Recipe object but with empty required
 /// fields, while the actual content exists in HTML elements with common recipe plugin classes.
@@ -979,6 +1754,7 @@ fn extract_recipe_with_html_fallback(
         partial.image_urls
     };
     let servings = partial.servings.or(micro_partial.servings);
+    let metadata = partial.metadata.or(micro_partial.metadata);
 
     // For any still-missing required fields, try HTML class-based fallbacks
     let title = title.or_else(|| extract_title_from_html(document));
@@ -1014,14 +1790,21 @@ fn extract_recipe_with_html_fallback(
             source_url: Some(source_url.to_string()),
             source_name,
             servings,
-            prep_time: None,
-            cook_time: None,
-            total_time: None,
-            rating: None,
+            prep_time: metadata.prep_time,
+            cook_time: metadata.cook_time,
+            total_time: metadata.total_time,
+            rating: metadata.rating,
             difficulty: None,
-            nutritional_info: None,
+            nutritional_info: metadata.nutritional_info,
             notes: None,
-            categories: None,
+            categories: metadata.categories,
+            video_url: metadata.video_url,
+            equipment: metadata.equipment,
+            author: metadata.author,
+            published_date: metadata.published_date,
+            language: None,
+            ingredient_sections: vec![],
+            instruction_sections: vec![],
         });
     }
 
@@ -1053,6 +1836,7 @@ struct PartialRecipe {
     instructions: Option<String>,
     image_urls: Vec<String>,
     servings: Option<String>,
+    metadata: RecipeMetadata,
 }
 
 /// Extract whatever we can from JSON-LD without failing on missing required fields.
@@ -1069,7 +1853,7 @@ fn extract_partial_from_jsonld(html: &str) -> PartialRecipe {
             Err(_) => continue,
         };
 
-        if let Some(recipe) = find_recipe_in_json(&json) {
+        if let Some(recipe) = find_recipes_in_json(&json).into_iter().next() {
             let title = recipe
                 .get("name")
                 .and_then(|v| v.as_str())
@@ -1099,6 +1883,7 @@ fn extract_partial_from_jsonld(html: &str) -> PartialRecipe {
                 instructions,
                 image_urls,
                 servings,
+                metadata: RecipeMetadata::from_jsonld(recipe),
             };
         }
     }
@@ -1110,6 +1895,7 @@ fn extract_partial_from_jsonld(html: &str) -> PartialRecipe {
         instructions: None,
         image_urls: Vec::new(),
         servings: None,
+        metadata: RecipeMetadata::default(),
     }
 }
 
@@ -1130,6 +1916,7 @@ fn extract_partial_from_microdata(document: &Html) -> PartialRecipe {
                 instructions: None,
                 image_urls: Vec::new(),
                 servings: None,
+                metadata: RecipeMetadata::default(),
             }
         }
     };
@@ -1155,6 +1942,7 @@ fn extract_partial_from_microdata(document: &Html) -> PartialRecipe {
 
     let image_urls = extract_microdata_images(&recipe_element);
     let servings = extract_microdata_text(&recipe_element, "recipeYield");
+    let metadata = RecipeMetadata::from_microdata(&recipe_element);
 
     PartialRecipe {
         title,
@@ -1163,6 +1951,7 @@ fn extract_partial_from_microdata(document: &Html) -> PartialRecipe {
         instructions,
         image_urls,
         servings,
+        metadata,
     }
 }
 
@@ -1488,6 +2277,170 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_all_jsonld_recipes() {
+        // A round-up post: an ItemList of recipes in @graph, one repeated in its own script tag
+        let html = r#"
+            <html><head>
+                <script type="application/ld+json">
+                {
+                    "@context": "https://schema.org",
+                    "@graph": [
+                        {"@type": "WebPage", "name": "10 Weeknight Soups"},
+                        {"@type": "ItemList", "itemListElement": [
+                            {"@type": "ListItem", "position": 1, "item": {
+                                "@type": "Recipe", "name": "Minestrone",
+                                "recipeIngredient": ["1 can beans"],
+                                "recipeInstructions": "Simmer."
+                            }},
+                            {"@type": "ListItem", "position": 2, "item": {
+                                "@type": "Recipe", "name": "Pho",
+                                "recipeIngredient": ["1 lb brisket"],
+                                "recipeInstructions": "Simmer longer."
+                            }}
+                        ]}
+                    ]
+                }
+                </script>
+                <script type="application/ld+json">
+                {"@type": "Recipe", "name": "Pho", "recipeIngredient": ["1 lb brisket"],
+                 "recipeInstructions": "Simmer longer."}
+                </script>
+            </head><body></body></html>
+        "#;
+
+        let output = extract_recipe_with_stats(html, "https://example.com/soups").unwrap();
+
+        assert_eq!(output.method_used, ExtractionMethod::JsonLd);
+        assert_eq!(output.raw_recipe.title, "Minestrone");
+        let others: Vec<&str> = output
+            .other_recipes
+            .iter()
+            .map(|r| r.title.as_str())
+            .collect();
+        assert_eq!(others, vec!["Pho"]);
+
+        // extract_recipe still returns just the first
+        let recipe = extract_recipe(html, "https://example.com/soups").unwrap();
+        assert_eq!(recipe.title, "Minestrone");
+    }
+
+    #[test]
+    fn test_jsonld_instruction_sections() {
+        let html = r#"
+            <html><head><script type="application/ld+json">
+            {"@type": "Recipe", "name": "Pie",
+             "recipeIngredient": ["1 cup flour", "2 cups berries"],
+             "recipeInstructions": [
+                {"@type": "HowToSection", "name": "Crust", "itemListElement": [
+                    {"@type": "HowToStep", "text": "Mix the flour."},
+                    {"@type": "HowToStep", "text": "Roll it out."}
+                ]},
+                {"@type": "HowToSection", "name": "Filling", "itemListElement": [
+                    {"@type": "HowToStep", "text": "Add the berries."}
+                ]},
+                {"@type": "HowToStep", "text": "Bake."}
+             ]}
+            </script></head><body></body></html>
+        "#;
+
+        let recipe = extract_recipe(html, "https://example.com/pie").unwrap();
+
+        assert_eq!(
+            recipe.instructions,
+            "Crust:\n\nMix the flour.\n\nRoll it out.\n\nFilling:\n\nAdd the berries.\n\nBake."
+        );
+        let names: Vec<Option<&str>> = recipe
+            .instruction_sections
+            .iter()
+            .map(|section| section.name.as_deref())
+            .collect();
+        assert_eq!(names, vec![Some("Crust"), Some("Filling"), None]);
+        assert_eq!(recipe.instruction_sections[2].items, vec!["Bake."]);
+        // JSON-LD can't group ingredients, and there's no card to borrow groups from
+        assert!(recipe.ingredient_sections.is_empty());
+    }
+
+    #[test]
+    fn test_jsonld_ingredient_sections_from_recipe_card() {
+        // WP Recipe Maker emits flat JSON-LD but shows its groups in the card
+        let html = r#"
+            <html><head><script type="application/ld+json">
+            {"@type": "Recipe", "name": "Berry Pie",
+             "recipeIngredient": ["1 cup flour", "4 tbsp butter", "2 cups berries"],
+             "recipeInstructions": "Bake."}
+            </script></head><body>
+            <div class="wprm-recipe-container">
+              <h2 class="wprm-recipe-name">Berry Pie</h2>
+              <h4 class="wprm-recipe-ingredient-group-name">Crust</h4>
+              <ul>
+                <li class="wprm-recipe-ingredient">1 cup flour</li>
+                <li class="wprm-recipe-ingredient">4 tablespoons butter</li>
+              </ul>
+              <h4 class="wprm-recipe-ingredient-group-name">Filling</h4>
+              <ul><li class="wprm-recipe-ingredient">2 cups berries</li></ul>
+              <ul><li class="wprm-recipe-instruction-text">Bake.</li></ul>
+            </div>
+            </body></html>
+        "#;
+
+        let output = extract_recipe_with_stats(html, "https://example.com/pie").unwrap();
+
+        assert_eq!(output.method_used, ExtractionMethod::JsonLd);
+        let recipe = output.raw_recipe;
+        // The JSON-LD text is kept, split into the card's groups
+        assert_eq!(
+            recipe.ingredients,
+            "1 cup flour\n4 tbsp butter\n2 cups berries"
+        );
+        assert_eq!(
+            recipe.ingredient_sections,
+            vec![
+                RecipeSection {
+                    name: Some("Crust".to_string()),
+                    items: vec!["1 cup flour".to_string(), "4 tbsp butter".to_string()],
+                },
+                RecipeSection {
+                    name: Some("Filling".to_string()),
+                    items: vec!["2 cups berries".to_string()],
+                },
+            ]
+        );
+
+        // A card listing different ingredients isn't used
+        let mismatched = html.replace(
+            "<li class=\"wprm-recipe-ingredient\">2 cups berries</li>",
+            "",
+        );
+        let recipe = extract_recipe(&mismatched, "https://example.com/pie").unwrap();
+        assert!(recipe.ingredient_sections.is_empty());
+    }
+
+    #[test]
+    fn test_extract_all_microdata_recipes() {
+        let html = r#"
+            <html><body>
+                <div itemscope itemtype="https://schema.org/Recipe">
+                    <h2 itemprop="name">Carnitas</h2>
+                    <span itemprop="recipeIngredient">3 lb pork shoulder</span>
+                    <div itemprop="recipeInstructions">Braise.</div>
+                </div>
+                <div itemscope itemtype="https://schema.org/Recipe">
+                    <h2 itemprop="name">Salsa Verde</h2>
+                    <span itemprop="recipeIngredient">1 lb tomatillos</span>
+                    <div itemprop="recipeInstructions">Blend.</div>
+                </div>
+            </body></html>
+        "#;
+
+        let output = extract_recipe_with_stats(html, "https://example.com/carnitas").unwrap();
+
+        assert_eq!(output.method_used, ExtractionMethod::Microdata);
+        assert_eq!(output.raw_recipe.title, "Carnitas");
+        assert_eq!(output.other_recipes.len(), 1);
+        assert_eq!(output.other_recipes[0].title, "Salsa Verde");
+    }
+
     #[test]
     fn test_extract_og_image() {
         let html = r#"
@@ -1576,6 +2529,206 @@ mod tests {
         assert_eq!(result.servings, Some("Serves 6".to_string()));
     }
 
+    #[test]
+    fn test_humanize_duration() {
+        assert_eq!(humanize_duration("PT15M").as_deref(), Some("15 minutes"));
+        assert_eq!(humanize_duration("PT1H").as_deref(), Some("1 hour"));
+        assert_eq!(
+            humanize_duration("P0DT1H30M0S").as_deref(),
+            Some("1 hour 30 minutes")
+        );
+        assert_eq!(
+            humanize_duration("PT90M").as_deref(),
+            Some("1 hour 30 minutes")
+        );
+        assert_eq!(
+            humanize_duration("P1DT2H").as_deref(),
+            Some("1 day 2 hours")
+        );
+        assert_eq!(humanize_duration("pt1m").as_deref(), Some("1 minute"));
+        assert_eq!(humanize_duration("PT30S").as_deref(), Some("30 seconds"));
+        assert_eq!(humanize_duration("PT0M"), None);
+        assert_eq!(humanize_duration(" "), None);
+        // Not ISO 8601: kept as written
+        assert_eq!(humanize_duration("15 mins").as_deref(), Some("15 mins"));
+    }
+
+    #[test]
+    fn test_extract_metadata_from_jsonld() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": "Recipe",
+                "name": "Test Recipe",
+                "recipeIngredient": ["1 cup flour"],
+                "recipeInstructions": "Mix and bake.",
+                "prepTime": "PT10M",
+                "cookTime": "PT1H5M",
+                "totalTime": "1 hour 15 minutes",
+                "recipeCategory": "Dessert, Snack",
+                "recipeCuisine": ["French"],
+                "keywords": "easy, dessert, French",
+                "nutrition": {
+                    "@type": "NutritionInformation",
+                    "calories": "250 kcal",
+                    "proteinContent": "4 g",
+                    "fatContent": ""
+                },
+                "aggregateRating": {
+                    "@type": "AggregateRating",
+                    "ratingValue": "8.6",
+                    "bestRating": 10,
+                    "ratingCount": "52"
+                },
+                "video": [{
+                    "@type": "VideoObject",
+                    "name": "How to make it",
+                    "embedUrl": "https://www.youtube.com/embed/abc123"
+                }],
+                "tool": [
+                    {"@type": "HowToTool", "name": "9-inch pie plate"},
+                    "Rolling pin",
+                    "rolling pin"
+                ],
+                "author": [
+                    {"@type": "Person", "name": "Ann Baker"},
+                    {"@type": "Person", "name": "Sam Cook"}
+                ],
+                "datePublished": "2024-03-05T08:00:00+00:00"
+            }
+            </script>
+        "#;
+
+        let result = extract_recipe(html, "https://example.com/recipe").unwrap();
+        assert_eq!(result.prep_time.as_deref(), Some("10 minutes"));
+        assert_eq!(result.cook_time.as_deref(), Some("1 hour 5 minutes"));
+        assert_eq!(result.total_time.as_deref(), Some("1 hour 15 minutes"));
+        assert_eq!(
+            result.categories,
+            Some(vec![
+                "Dessert".to_string(),
+                "Snack".to_string(),
+                "French".to_string(),
+            ])
+        );
+        assert_eq!(
+            result.nutritional_info.as_deref(),
+            Some("Calories: 250 kcal\nProtein: 4 g")
+        );
+        assert_eq!(result.rating, Some(4));
+        assert_eq!(
+            result.video_url.as_deref(),
+            Some("https://www.youtube.com/embed/abc123")
+        );
+        assert_eq!(
+            result.equipment,
+            Some(vec![
+                "9-inch pie plate".to_string(),
+                "Rolling pin".to_string()
+            ])
+        );
+        assert_eq!(result.author.as_deref(), Some("Ann Baker, Sam Cook"));
+        assert_eq!(result.published_date.as_deref(), Some("2024-03-05"));
+    }
+
+    #[test]
+    fn test_extract_metadata_from_microdata() {
+        let html = r#"
+            <div itemscope itemtype="https://schema.org/Recipe">
+                <h1 itemprop="name">Test Recipe</h1>
+                <meta itemprop="prepTime" content="PT20M">
+                <time itemprop="cookTime" datetime="PT45M">45 mins</time>
+                <span itemprop="recipeCategory">Bread</span>
+                <span itemprop="recipeCuisine">American</span>
+                <meta itemprop="keywords" content="quick bread, bread">
+                <div itemprop="nutrition" itemscope itemtype="https://schema.org/NutritionInformation">
+                    <span itemprop="calories">180 calories</span>
+                    <span itemprop="sugarContent">12g</span>
+                </div>
+                <div itemprop="aggregateRating" itemscope itemtype="https://schema.org/AggregateRating">
+                    <span itemprop="ratingValue">4.4</span>
+                </div>
+                <span itemprop="author" itemscope itemtype="https://schema.org/Person">
+                    By <span itemprop="name">Ann Baker</span>
+                </span>
+                <time itemprop="datePublished" datetime="2023-11-20">November 20, 2023</time>
+                <div itemprop="video" itemscope itemtype="https://schema.org/VideoObject">
+                    <meta itemprop="contentUrl" content="https://example.com/bread.mp4">
+                </div>
+                <span itemprop="tool">Loaf pan</span>
+                <ul><li itemprop="recipeIngredient">1 cup flour</li></ul>
+                <div itemprop="recipeInstructions">Mix and bake.</div>
+            </div>
+        "#;
+
+        let result = extract_recipe_with_stats(html, "https://example.com/recipe").unwrap();
+        assert_eq!(result.method_used, ExtractionMethod::Microdata);
+        let recipe = result.raw_recipe;
+        assert_eq!(recipe.prep_time.as_deref(), Some("20 minutes"));
+        assert_eq!(recipe.cook_time.as_deref(), Some("45 minutes"));
+        assert_eq!(recipe.total_time, None);
+        assert_eq!(
+            recipe.categories,
+            Some(vec!["Bread".to_string(), "American".to_string(),])
+        );
+        assert_eq!(
+            recipe.nutritional_info.as_deref(),
+            Some("Calories: 180 calories\nSugar: 12g")
+        );
+        assert_eq!(recipe.rating, Some(4));
+        assert_eq!(recipe.author.as_deref(), Some("Ann Baker"));
+        assert_eq!(recipe.published_date.as_deref(), Some("2023-11-20"));
+        assert_eq!(
+            recipe.video_url.as_deref(),
+            Some("https://example.com/bread.mp4")
+        );
+        assert_eq!(recipe.equipment, Some(vec!["Loaf pan".to_string()]));
+    }
+
+    #[test]
+    fn test_video_url_must_be_http() {
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": "Recipe",
+                "name": "Test Recipe",
+                "recipeIngredient": ["1 cup flour"],
+                "recipeInstructions": "Mix and bake.",
+                "video": [
+                    {"@type": "VideoObject", "contentUrl": "javascript:alert(1)"},
+                    {"@type": "VideoObject", "embedUrl": "https://example.com/embed/1"}
+                ]
+            }
+            </script>
+        "#;
+        let result = extract_recipe(html, "https://example.com/recipe").unwrap();
+        assert_eq!(
+            result.video_url.as_deref(),
+            Some("https://example.com/embed/1")
+        );
+
+        let html = r#"
+            <script type="application/ld+json">
+            {
+                "@type": "Recipe",
+                "name": "Test Recipe",
+                "recipeIngredient": ["1 cup flour"],
+                "recipeInstructions": "Mix and bake.",
+                "video": "data:text/html,hello"
+            }
+            </script>
+        "#;
+        let result = extract_recipe(html, "https://example.com/recipe").unwrap();
+        assert_eq!(result.video_url, None);
+
+        assert_eq!(
+            http_url(" http://example.com/v.mp4 ").as_deref(),
+            Some("http://example.com/v.mp4")
+        );
+        assert_eq!(http_url("/videos/v.mp4"), None);
+        assert_eq!(http_url("JavaScript:alert(1)"), None);
+    }
+
     #[test]
     fn test_jsonld_empty_ingredients_falls_back_to_html_div() {
         // JSON-LD has empty recipeIngredient, but HTML has div.ingredients (mybakingaddiction pattern)
@@ -1790,4 +2943,125 @@ mod tests {
         assert_eq!(result.raw_recipe.title, "Test Recipe");
         assert!(result.raw_recipe.ingredients.contains("1 cup flour"));
     }
+
+    #[test]
+    fn test_extract_recipe_from_text() {
+        let text = "Grandma's Scones\n\
+            \n\
+            Light and flaky, best eaten warm.\n\
+            Serves 8\n\
+            Prep time: 15 minutes\n\
+            \n\
+            Ingredients\n\
+            2 cups flour\n\
+            1 tbsp baking powder\n\
+            1/2 cup cold butter\n\
+            3/4 cup milk\n\
+            \n\
+            Method\n\
+            Rub the butter into the flour and baking powder until it\n\
+            resembles breadcrumbs.\n\
+            \n\
+            Stir in the milk, cut into rounds and bake at 220C for 12 minutes.\n";
+
+        let recipe = extract_recipe_from_text(text).unwrap();
+
+        assert_eq!(recipe.title, "Grandma's Scones");
+        assert_eq!(
+            recipe.description.as_deref(),
+            Some("Light and flaky, best eaten warm.")
+        );
+        assert_eq!(recipe.servings.as_deref(), Some("Serves 8"));
+        assert_eq!(recipe.prep_time.as_deref(), Some("15 minutes"));
+        assert_eq!(
+            recipe.ingredients,
+            "2 cups flour\n1 tbsp baking powder\n1/2 cup cold butter\n3/4 cup milk"
+        );
+        assert_eq!(
+            recipe.instructions,
+            "Rub the butter into the flour and baking powder until it resembles breadcrumbs.\n\n\
+             Stir in the milk, cut into rounds and bake at 220C for 12 minutes."
+        );
+        assert!(recipe.source_url.is_none());
+    }
+
+    #[test]
+    fn test_extract_recipe_from_text_keeps_section_headers() {
+        let text = "Lemon Tart\n\
+            \n\
+            FOR THE PASTRY\n\
+            \n\
+            200g flour\n\
+            100g butter\n\
+            \n\
+            For the filling:\n\
+            \n\
+            3 lemons\n\
+            150g sugar\n\
+            4 eggs\n\
+            \n\
+            1. Make the pastry and blind bake.\n\
+            2. Whisk the filling, pour in and bake\n\
+            until just set.\n";
+
+        let recipe = extract_recipe_from_text(text).unwrap();
+
+        assert_eq!(
+            recipe.ingredients,
+            "FOR THE PASTRY\n200g flour\n100g butter\nFor the filling:\n3 lemons\n150g sugar\n4 eggs"
+        );
+        // Numbered steps stay on their own lines and are not mistaken for ingredients
+        assert_eq!(
+            recipe.instructions,
+            "1. Make the pastry and blind bake.\n2. Whisk the filling, pour in and bake until just set."
+        );
+    }
+
+    #[test]
+    fn test_extract_recipe_from_text_rejects_prose() {
+        let text = "Dear Sam,\n\
+            \n\
+            Here's how I make the soup. Fry an onion, add whatever vegetables\n\
+            you have, cover with stock and simmer until soft.\n";
+
+        assert!(extract_recipe_from_text(text).is_none());
+    }
+
+    #[test]
+    fn test_find_alternate_pages() {
+        let html = r##"
+            <html>
+            <head>
+                <link rel="amphtml" href="https://example.com/chili/amp/">
+            </head>
+            <body>
+                <a href="#recipe">Jump to Recipe</a>
+                <a href="javascript:window.print()">Print</a>
+                <a href="/wprm_print/chili">Print Recipe</a>
+                <a class="tasty-recipes-print-button" href="https://example.com/chili/print/12/">Print</a>
+                <a href="https://other.com/wprm_print/chili">Elsewhere</a>
+                <a href="/about">About</a>
+            </body>
+            </html>
+        "##;
+
+        let pages = find_alternate_pages(html, "https://example.com/chili/");
+        assert_eq!(
+            pages,
+            vec![
+                AlternatePage {
+                    url: "https://example.com/wprm_print/chili".to_string(),
+                    kind: AlternateKind::Print,
+                },
+                AlternatePage {
+                    url: "https://example.com/chili/print/12/".to_string(),
+                    kind: AlternateKind::Print,
+                },
+                AlternatePage {
+                    url: "https://example.com/chili/amp/".to_string(),
+                    kind: AlternateKind::Amp,
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,669 @@
+//! Headless browser fallback for pages that build their recipe client-side.
+//!
+//! Some sites ship an empty shell and render the recipe card with JavaScript,
+//! so the static HTML has nothing to extract. `BrowserClient` wraps another
+//! `HttpClient`: it always does the static fetch first (so robots.txt and the
+//! normal cache still apply), and only when the page is configured for it asks
+//! a `PageRenderer` for the rendered DOM. Rendered HTML is cached separately
+//! from the static response, with the same TTL, size limit and compression.
+//!
+//! Only the initial URL goes through the inner client's SSRF checks. The
+//! browser follows redirects, loads subresources and runs the page's scripts
+//! with its own DNS, none of which is guarded. That's acceptable for the CLI,
+//! which renders URLs its user chose on their own machine, but it's why the
+//! server's `FetchHtmlStep` doesn't use this client: it fetches URLs submitted
+//! by any user from inside the server's network.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use super::cache::{Compression, DiskCache, DiskCacheSettings, HttpCache};
+use super::charset;
+use super::client::HttpClient;
+use crate::error::FetchError;
+use crate::extract::extract_recipe_with_stats;
+
+/// When to render a page in the browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserMode {
+    /// Never render; use the static HTML.
+    Off,
+    /// Render only when the static HTML has no extractable recipe.
+    Fallback,
+    /// Always render.
+    Always,
+}
+
+impl BrowserMode {
+    /// Parse "off", "fallback" or "always".
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "false" | "0" => Some(BrowserMode::Off),
+            "fallback" | "true" | "1" => Some(BrowserMode::Fallback),
+            "always" => Some(BrowserMode::Always),
+            _ => None,
+        }
+    }
+}
+
+/// Produces the HTML of a page after its scripts have run.
+#[async_trait]
+pub trait PageRenderer: Send + Sync {
+    async fn render(&self, url: &str) -> Result<String, FetchError>;
+}
+
+/// Builder for configuring a BrowserClient.
+pub struct BrowserClientBuilder {
+    mode: BrowserMode,
+    host_modes: Vec<(String, BrowserMode)>,
+    cache_dir: Option<PathBuf>,
+    cache_settings: DiskCacheSettings,
+    cache: Option<Arc<dyn HttpCache>>,
+    never_render: bool,
+}
+
+impl Default for BrowserClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrowserClientBuilder {
+    /// Create a new builder with default settings.
+    ///
+    /// Environment variables:
+    /// - `RAMEKIN_BROWSER`: "off" (default), "fallback" or "always"
+    /// - `RAMEKIN_BROWSER_HOSTS`: comma-separated "host=mode" overrides, e.g.
+    ///   "example.com=always,other.example=off"; a bare "host" means fallback
+    /// - `RAMEKIN_BROWSER_CACHE`: "none" to disable, "disk" (default), or a path
+    /// - `RAMEKIN_HTTP_CACHE_TTL_SECS`, `RAMEKIN_HTTP_CACHE_MAX_SIZE` and
+    ///   `RAMEKIN_HTTP_CACHE_COMPRESSION`: as for `CachingClient`'s disk cache
+    /// - `RAMEKIN_OFFLINE`: "true" to only use previously cached renders
+    pub fn new() -> Self {
+        let mode = std::env::var("RAMEKIN_BROWSER")
+            .ok()
+            .and_then(|v| BrowserMode::parse(&v))
+            .unwrap_or(BrowserMode::Off);
+
+        let host_modes = std::env::var("RAMEKIN_BROWSER_HOSTS")
+            .map(|v| parse_host_modes(&v))
+            .unwrap_or_default();
+
+        let cache_dir = match std::env::var("RAMEKIN_BROWSER_CACHE").ok() {
+            Some(val) if val == "none" => None,
+            Some(val) if val == "disk" => Some(default_cache_dir()),
+            Some(path) => Some(PathBuf::from(path)),
+            None => Some(default_cache_dir()),
+        };
+
+        let never_render = std::env::var("RAMEKIN_OFFLINE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        Self {
+            mode,
+            host_modes,
+            cache_dir,
+            cache_settings: DiskCacheSettings::from_env(),
+            cache: None,
+            never_render,
+        }
+    }
+
+    /// Set the mode for hosts without an override.
+    pub fn mode(mut self, mode: BrowserMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Override the mode for one host (and its subdomains).
+    pub fn host_mode(mut self, host: &str, mode: BrowserMode) -> Self {
+        self.host_modes.push((normalize_host(host), mode));
+        self
+    }
+
+    /// Set the directory for cached renders (None to disable caching).
+    pub fn cache_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.cache_dir = dir;
+        self
+    }
+
+    /// Set how long cached renders stay valid. None keeps them forever.
+    pub fn cache_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.cache_settings.ttl = ttl;
+        self
+    }
+
+    /// Set the render cache size limit in bytes. None lets it grow without bound.
+    pub fn cache_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.cache_settings.max_size = max_size;
+        self
+    }
+
+    /// Set how the render cache compresses pages.
+    pub fn cache_compression(mut self, compression: Compression) -> Self {
+        self.cache_settings.compression = compression;
+        self
+    }
+
+    /// Use a custom cache backend for renders instead of the disk cache.
+    pub fn cache(mut self, cache: Arc<dyn HttpCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Only serve renders that are already cached; never launch the browser.
+    pub fn never_render(mut self, never: bool) -> Self {
+        self.never_render = never;
+        self
+    }
+
+    /// Whether any page could be rendered with this configuration.
+    pub fn is_enabled(&self) -> bool {
+        self.mode != BrowserMode::Off
+            || self
+                .host_modes
+                .iter()
+                .any(|(_, mode)| *mode != BrowserMode::Off)
+    }
+
+    /// Build a client that fetches through `inner` and renders with `renderer`.
+    pub fn build(
+        self,
+        inner: Arc<dyn HttpClient>,
+        renderer: Arc<dyn PageRenderer>,
+    ) -> BrowserClient {
+        let cache = self.cache.or_else(|| {
+            self.cache_dir
+                .map(|dir| Arc::new(self.cache_settings.open(dir)) as Arc<dyn HttpCache>)
+        });
+
+        BrowserClient {
+            inner,
+            renderer,
+            cache,
+            mode: self.mode,
+            host_modes: self.host_modes,
+            never_render: self.never_render,
+        }
+    }
+}
+
+/// Rendered pages live next to the static cache rather than in it: the disk
+/// cache is keyed by URL, and the static copy is still needed for revalidation.
+fn default_cache_dir() -> PathBuf {
+    DiskCache::default_dir().with_file_name("http-cache-rendered")
+}
+
+fn normalize_host(host: &str) -> String {
+    host.trim()
+        .to_lowercase()
+        .trim_start_matches("www.")
+        .to_string()
+}
+
+fn parse_host_modes(value: &str) -> Vec<(String, BrowserMode)> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let (host, mode) = match entry.split_once('=') {
+                Some((host, mode)) => (host, BrowserMode::parse(mode)?),
+                None => (entry, BrowserMode::Fallback),
+            };
+            let host = normalize_host(host);
+            (!host.is_empty()).then_some((host, mode))
+        })
+        .collect()
+}
+
+/// HTTP client that falls back to a headless browser for script-rendered pages.
+pub struct BrowserClient {
+    inner: Arc<dyn HttpClient>,
+    renderer: Arc<dyn PageRenderer>,
+    cache: Option<Arc<dyn HttpCache>>,
+    mode: BrowserMode,
+    host_modes: Vec<(String, BrowserMode)>,
+    never_render: bool,
+}
+
+impl BrowserClient {
+    /// Create a new builder for configuring the client.
+    pub fn builder() -> BrowserClientBuilder {
+        BrowserClientBuilder::new()
+    }
+
+    /// The browser mode that applies to a URL. The most specific host
+    /// override wins; otherwise the default mode applies.
+    pub fn mode_for(&self, url: &str) -> BrowserMode {
+        let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(normalize_host))
+        else {
+            return BrowserMode::Off;
+        };
+
+        self.host_modes
+            .iter()
+            .filter(|(pattern, _)| {
+                host == *pattern
+                    || host
+                        .strip_suffix(pattern.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            })
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, mode)| *mode)
+            .unwrap_or(self.mode)
+    }
+
+    /// Get the cached render of a URL, if any.
+    pub fn get_cached_render(&self, url: &str) -> Option<String> {
+        let cached = self.cache.as_ref()?.get(url)?;
+        Some(charset::decode_bytes_to_utf8(
+            cached.data,
+            cached.metadata.content_type.as_deref(),
+        ))
+    }
+
+    /// Given the static HTML for a URL, return the HTML the pipeline should
+    /// use: the static copy, or a (possibly cached) browser render when the
+    /// URL's mode calls for one. Rendering failures fall back to the static
+    /// copy.
+    pub async fn resolve_html(&self, url: &str, static_html: String) -> String {
+        let needs_render = match self.mode_for(url) {
+            BrowserMode::Off => false,
+            BrowserMode::Always => true,
+            BrowserMode::Fallback => extract_recipe_with_stats(&static_html, url).is_err(),
+        };
+        if !needs_render {
+            return static_html;
+        }
+
+        if let Some(rendered) = self.get_cached_render(url) {
+            tracing::debug!(url, "cache hit (rendered)");
+            return rendered;
+        }
+        if self.never_render {
+            tracing::debug!(url, "render not cached and rendering disabled");
+            return static_html;
+        }
+
+        tracing::debug!(url, "browser: rendering page");
+        match self.renderer.render(url).await {
+            Ok(rendered) => {
+                if let Some(cache) = &self.cache {
+                    if let Err(e) = cache.put(
+                        url,
+                        rendered.as_bytes(),
+                        Some("text/html; charset=utf-8".to_string()),
+                        None,
+                        None,
+                    ) {
+                        tracing::warn!(url, error = %e, "Failed to cache rendered page");
+                    }
+                }
+                rendered
+            }
+            Err(e) => {
+                tracing::warn!(url, error = %e, "Browser render failed, using static HTML");
+                static_html
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl HttpClient for BrowserClient {
+    async fn fetch_html(&self, url: &str) -> Result<String, FetchError> {
+        let static_html = self.inner.fetch_html(url).await?;
+        Ok(self.resolve_html(url, static_html).await)
+    }
+
+    async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+        self.inner.fetch_bytes(url).await
+    }
+}
+
+/// Allow sharing a BrowserClient via Arc for use in the generic pipeline.
+#[async_trait]
+impl HttpClient for Arc<BrowserClient> {
+    async fn fetch_html(&self, url: &str) -> Result<String, FetchError> {
+        (**self).fetch_html(url).await
+    }
+
+    async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+        (**self).fetch_bytes(url).await
+    }
+}
+
+#[cfg(feature = "browser")]
+pub use chrome::{find_chrome, ChromeRenderer};
+
+#[cfg(feature = "browser")]
+mod chrome {
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use headless_chrome::{Browser, LaunchOptions, Tab};
+
+    use super::PageRenderer;
+    use crate::error::FetchError;
+
+    /// Elements that show a recipe card has been rendered.
+    const RECIPE_SELECTOR: &str = "script[type='application/ld+json'], \
+        [itemtype*='schema.org/Recipe'], .wprm-recipe, .tasty-recipes, .mv-create-card";
+
+    /// How long to wait for a recipe card to appear after the page loads.
+    const RENDER_WAIT: Duration = Duration::from_secs(5);
+
+    /// Chrome exits after this long without a command. Pipeline runs can sit
+    /// idle between rendered pages, so keep it well above the default 30s.
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+    /// Find Chrome/Chromium executable, checking Playwright cache first
+    pub fn find_chrome() -> Option<PathBuf> {
+        // Check CHROME environment variable first
+        if let Ok(chrome_path) = std::env::var("CHROME") {
+            let path = PathBuf::from(&chrome_path);
+            if path.exists() {
+                tracing::debug!(path = %path.display(), "Using Chrome from CHROME env var");
+                return Some(path);
+            }
+        }
+
+        // Check Playwright cache directories (sorted by version, newest first)
+        if let Ok(home) = std::env::var("HOME") {
+            let playwright_cache = PathBuf::from(&home).join(".cache/ms-playwright");
+            if playwright_cache.exists() {
+                if let Ok(entries) = std::fs::read_dir(&playwright_cache) {
+                    let mut chrome_dirs: Vec<_> = entries
+                        .filter_map(|e| e.ok())
+                        .filter(|e| e.file_name().to_string_lossy().starts_with("chromium-"))
+                        .collect();
+                    // Sort by name descending to get newest version first
+                    chrome_dirs.sort_by_key(|b| std::cmp::Reverse(b.file_name()));
+
+                    for dir in chrome_dirs {
+                        // Try common Chrome binary locations within Playwright dirs
+                        for subpath in &["chrome-linux64/chrome", "chrome-linux/chrome"] {
+                            let chrome_path = dir.path().join(subpath);
+                            if chrome_path.exists() {
+                                tracing::debug!(path = %chrome_path.display(), "Found Chrome in Playwright cache");
+                                return Some(chrome_path);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Let headless_chrome try its default detection
+        tracing::debug!("No Chrome found in Playwright cache, using default detection");
+        None
+    }
+
+    /// Renders pages in a local headless Chromium, launched on first use and
+    /// relaunched if it dies.
+    pub struct ChromeRenderer {
+        chrome_path: Option<PathBuf>,
+        browser: Arc<Mutex<Option<Browser>>>,
+    }
+
+    impl Default for ChromeRenderer {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl ChromeRenderer {
+        /// Locate Chrome now; it isn't launched until the first render.
+        pub fn new() -> Self {
+            Self {
+                chrome_path: find_chrome(),
+                browser: Arc::new(Mutex::new(None)),
+            }
+        }
+    }
+
+    fn launch(chrome_path: Option<PathBuf>) -> Result<Browser, String> {
+        let mut builder = LaunchOptions::default_builder();
+        builder
+            .args(vec![
+                std::ffi::OsStr::new("--no-sandbox"),
+                std::ffi::OsStr::new("--disable-dev-shm-usage"),
+            ])
+            .idle_browser_timeout(IDLE_TIMEOUT)
+            .path(chrome_path);
+        let options = builder.build().map_err(|e| e.to_string())?;
+        Browser::new(options).map_err(|e| format!("Failed to launch browser: {}", e))
+    }
+
+    fn render_blocking(browser: &Browser, url: &str) -> Result<String, String> {
+        let tab = browser
+            .new_tab()
+            .map_err(|e| format!("Failed to create tab: {}", e))?;
+        let result = load_page(&tab, url);
+        let _ = tab.close(true);
+        result
+    }
+
+    fn load_page(tab: &Tab, url: &str) -> Result<String, String> {
+        tab.navigate_to(url)
+            .and_then(|tab| tab.wait_until_navigated())
+            .map_err(|e| format!("Failed to load page: {}", e))?;
+        // Pages without a recognizable recipe card are still returned as
+        // rendered once the wait runs out.
+        let _ = tab.wait_for_element_with_custom_timeout(RECIPE_SELECTOR, RENDER_WAIT);
+        tab.get_content()
+            .map_err(|e| format!("Failed to read page content: {}", e))
+    }
+
+    #[async_trait]
+    impl PageRenderer for ChromeRenderer {
+        async fn render(&self, url: &str) -> Result<String, FetchError> {
+            let chrome_path = self.chrome_path.clone();
+            let shared = Arc::clone(&self.browser);
+            let url = url.to_string();
+
+            tokio::task::spawn_blocking(move || {
+                let browser = {
+                    let mut guard = shared.lock().unwrap_or_else(|e| e.into_inner());
+                    match guard.as_ref() {
+                        Some(browser) => browser.clone(),
+                        None => {
+                            tracing::info!("Launching headless Chrome for rendering");
+                            let browser = launch(chrome_path)?;
+                            *guard = Some(browser.clone());
+                            browser
+                        }
+                    }
+                };
+
+                let result = render_blocking(&browser, &url);
+                if result.is_err() {
+                    // The browser may have crashed; start a fresh one next time
+                    *shared.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                }
+                result
+            })
+            .await
+            .map_err(|e| FetchError::RenderFailed(e.to_string()))?
+            .map_err(FetchError::RenderFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::MockClient;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SHELL: &str = r#"<html><body><div id="root"></div></body></html>"#;
+    const RENDERED: &str = r#"<html><head><script type="application/ld+json">
+        {"@type": "Recipe", "name": "Rendered Soup",
+         "recipeIngredient": ["1 onion"], "recipeInstructions": "Simmer."}
+        </script></head><body></body></html>"#;
+
+    struct MockRenderer {
+        html: Option<&'static str>,
+        calls: AtomicUsize,
+    }
+
+    impl MockRenderer {
+        fn new(html: Option<&'static str>) -> Arc<Self> {
+            Arc::new(Self {
+                html,
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl PageRenderer for MockRenderer {
+        async fn render(&self, _url: &str) -> Result<String, FetchError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.html
+                .map(str::to_string)
+                .ok_or_else(|| FetchError::RenderFailed("boom".to_string()))
+        }
+    }
+
+    fn builder(name: &str) -> (BrowserClientBuilder, PathBuf) {
+        let dir =
+            std::env::temp_dir().join(format!("ramekin-browser-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let builder = BrowserClientBuilder::new()
+            .mode(BrowserMode::Off)
+            .cache_dir(Some(dir.clone()))
+            .never_render(false);
+        (builder, dir)
+    }
+
+    #[tokio::test]
+    async fn test_fallback_renders_shell_and_caches() {
+        let url = "https://spa.example.com/recipe";
+        let (builder, dir) = builder("fallback");
+        let renderer = MockRenderer::new(Some(RENDERED));
+        let client = builder.mode(BrowserMode::Fallback).build(
+            Arc::new(MockClient::new().with_html(url, SHELL)),
+            renderer.clone(),
+        );
+
+        assert_eq!(client.fetch_html(url).await.unwrap(), RENDERED);
+        assert_eq!(client.fetch_html(url).await.unwrap(), RENDERED);
+        assert_eq!(renderer.calls.load(Ordering::SeqCst), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_keeps_static_recipe() {
+        let url = "https://static.example.com/recipe";
+        let (builder, dir) = builder("static");
+        let renderer = MockRenderer::new(Some(SHELL));
+        let client = builder.mode(BrowserMode::Fallback).build(
+            Arc::new(MockClient::new().with_html(url, RENDERED)),
+            renderer.clone(),
+        );
+
+        assert_eq!(client.fetch_html(url).await.unwrap(), RENDERED);
+        assert_eq!(renderer.calls.load(Ordering::SeqCst), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_render_failure_uses_static_html() {
+        let url = "https://spa.example.com/broken";
+        let (builder, dir) = builder("failure");
+        let client = builder.mode(BrowserMode::Always).build(
+            Arc::new(MockClient::new().with_html(url, SHELL)),
+            MockRenderer::new(None),
+        );
+
+        assert_eq!(client.fetch_html(url).await.unwrap(), SHELL);
+        assert!(client.get_cached_render(url).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_static_fetch_error_is_not_rendered() {
+        let url = "https://spa.example.com/missing";
+        let (builder, dir) = builder("error");
+        let renderer = MockRenderer::new(Some(RENDERED));
+        let client = builder.mode(BrowserMode::Always).build(
+            Arc::new(MockClient::new().with_error(url, "404")),
+            renderer.clone(),
+        );
+
+        assert!(client.fetch_html(url).await.is_err());
+        assert_eq!(renderer.calls.load(Ordering::SeqCst), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_never_render_uses_only_cached_renders() {
+        let url = "https://spa.example.com/offline";
+        let (builder, dir) = builder("offline");
+        let renderer = MockRenderer::new(Some(RENDERED));
+        let client = builder
+            .mode(BrowserMode::Fallback)
+            .never_render(true)
+            .build(
+                Arc::new(MockClient::new().with_html(url, SHELL)),
+                renderer.clone(),
+            );
+
+        assert_eq!(client.fetch_html(url).await.unwrap(), SHELL);
+        assert_eq!(renderer.calls.load(Ordering::SeqCst), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_mode_for_host_overrides() {
+        let (builder, _) = builder("hosts");
+        let client = builder
+            .mode(BrowserMode::Fallback)
+            .host_mode("example.com", BrowserMode::Off)
+            .host_mode("www.spa.example.com", BrowserMode::Always)
+            .build(Arc::new(MockClient::new()), MockRenderer::new(None));
+
+        assert_eq!(
+            client.mode_for("https://other.org/r"),
+            BrowserMode::Fallback
+        );
+        assert_eq!(client.mode_for("https://example.com/r"), BrowserMode::Off);
+        assert_eq!(
+            client.mode_for("https://www.example.com/r"),
+            BrowserMode::Off
+        );
+        assert_eq!(
+            client.mode_for("https://spa.example.com/r"),
+            BrowserMode::Always
+        );
+        assert_eq!(
+            client.mode_for("https://notexample.com/r"),
+            BrowserMode::Fallback
+        );
+    }
+
+    #[test]
+    fn test_parse_host_modes() {
+        assert_eq!(
+            parse_host_modes("a.com, www.b.com=always,c.com=off,d.com=bogus,"),
+            vec![
+                ("a.com".to_string(), BrowserMode::Fallback),
+                ("b.com".to_string(), BrowserMode::Always),
+                ("c.com".to_string(), BrowserMode::Off),
+            ]
+        );
+    }
+}
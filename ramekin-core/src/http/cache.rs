@@ -1,15 +1,190 @@
-//! Disk-based HTTP response cache with ETag support.
+//! HTTP response caching with ETag support.
+//!
+//! [`HttpCache`] is the storage interface used by `CachingClient`. [`DiskCache`]
+//! is the per-machine filesystem implementation; the server provides a shared
+//! database-backed one.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 use super::slugify_url;
 
+/// Storage for fetched responses and negatively cached errors.
+///
+/// Implementations are synchronous, like the filesystem calls behind
+/// [`DiskCache`]; entries past the implementation's TTL read as missing.
+pub trait HttpCache: Send + Sync {
+    /// Get cached response if it exists.
+    fn get(&self, url: &str) -> Option<CachedResponse>;
+
+    /// Get cached error if it exists.
+    fn get_error(&self, url: &str) -> Option<CachedError>;
+
+    /// Save a successful response, replacing any cached error.
+    fn put(
+        &self,
+        url: &str,
+        data: &[u8],
+        content_type: Option<String>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    ) -> std::io::Result<()>;
+
+    /// Save an error (negative caching).
+    fn put_error(&self, url: &str, error: &str) -> std::io::Result<()>;
+
+    /// Remove everything cached for one URL.
+    fn remove(&self, url: &str) -> std::io::Result<()>;
+
+    /// Get cache statistics.
+    fn stats(&self) -> CacheStats;
+
+    /// Clear all cached responses.
+    fn clear(&self) -> std::io::Result<()>;
+
+    /// Check if a response is cached for a URL (either success or error).
+    fn is_cached(&self, url: &str) -> bool {
+        self.get_error(url).is_some() || self.get(url).is_some()
+    }
+}
+
 /// Disk-based HTTP response cache.
+///
+/// Each URL gets a directory holding `metadata.json` plus the body (compressed
+/// per [`Compression`]) and/or `error.txt`. Reads bump the modification time of
+/// those files, which is what size-limited pruning uses as last access.
 pub struct DiskCache {
     cache_dir: PathBuf,
+    /// Entries older than this are treated as missing; None keeps them forever.
+    ttl: Option<Duration>,
+    /// Least recently used entries are evicted beyond this many bytes.
+    max_size: Option<u64>,
+    /// How new response bodies are written.
+    compression: Compression,
+    /// Bytes stored as of the last prune; None until a prune has measured it.
+    measured_bytes: Arc<Mutex<Option<u64>>>,
+    /// Bytes written since the last prune started (an overestimate when entries
+    /// are rewritten).
+    written_bytes: Arc<AtomicU64>,
+    /// Set while a background prune is running.
+    pruning: Arc<AtomicBool>,
+}
+
+/// How [`DiskCache`] stores response bodies. Entries written with any of them
+/// can be read whatever the current setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Stored as fetched
+    Plain,
+    Gzip,
+    #[default]
+    Zstd,
+}
+
+impl Compression {
+    const ALL: [Compression; 3] = [Compression::Zstd, Compression::Gzip, Compression::Plain];
+
+    /// Parse "none", "gzip" or "zstd".
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" | "plain" => Some(Self::Plain),
+            "gzip" | "gz" => Some(Self::Gzip),
+            "zstd" | "zst" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Plain => "response.bin",
+            Self::Gzip => "response.bin.gz",
+            Self::Zstd => "response.bin.zst",
+        }
+    }
+
+    fn compress(self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Plain => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            Self::Zstd => zstd::encode_all(data, 0),
+        }
+    }
+
+    fn decompress(self, data: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Plain => Ok(data),
+            Self::Gzip => {
+                let mut decoded = Vec::new();
+                flate2::read::GzDecoder::new(data.as_slice()).read_to_end(&mut decoded)?;
+                Ok(decoded)
+            }
+            Self::Zstd => zstd::decode_all(data.as_slice()),
+        }
+    }
+}
+
+/// Parse a size such as "500M", "20G" or "1048576" (binary multiples).
+pub fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_uppercase();
+    let value = value.trim_end_matches("IB").trim_end_matches('B');
+    let (number, multiplier) = match value.chars().last()? {
+        'K' => (&value[..value.len() - 1], 1u64 << 10),
+        'M' => (&value[..value.len() - 1], 1 << 20),
+        'G' => (&value[..value.len() - 1], 1 << 30),
+        'T' => (&value[..value.len() - 1], 1 << 40),
+        _ => (value, 1),
+    };
+    let number: f64 = number.trim().parse().ok()?;
+    (number.is_finite() && number >= 0.0).then_some((number * multiplier as f64) as u64)
+}
+
+/// TTL, size limit and compression for a disk cache, as set by the
+/// `RAMEKIN_HTTP_CACHE_*` environment variables.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DiskCacheSettings {
+    pub ttl: Option<Duration>,
+    pub max_size: Option<u64>,
+    pub compression: Compression,
+}
+
+impl DiskCacheSettings {
+    /// Read `RAMEKIN_HTTP_CACHE_TTL_SECS`, `RAMEKIN_HTTP_CACHE_MAX_SIZE` and
+    /// `RAMEKIN_HTTP_CACHE_COMPRESSION`.
+    pub fn from_env() -> Self {
+        Self {
+            ttl: std::env::var("RAMEKIN_HTTP_CACHE_TTL_SECS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .map(Duration::from_secs),
+            max_size: std::env::var("RAMEKIN_HTTP_CACHE_MAX_SIZE")
+                .ok()
+                .and_then(|v| parse_size(&v)),
+            compression: std::env::var("RAMEKIN_HTTP_CACHE_COMPRESSION")
+                .ok()
+                .and_then(|v| Compression::parse(&v))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Open a disk cache in `dir` with these settings.
+    pub fn open(self, dir: PathBuf) -> DiskCache {
+        DiskCache::new(dir)
+            .with_ttl(self.ttl)
+            .with_max_size(self.max_size)
+            .with_compression(self.compression)
+    }
 }
 
 /// Metadata stored alongside cached responses.
@@ -34,6 +209,23 @@ pub struct CachedResponse {
 pub struct CachedError {
     pub error: String,
     pub fetched_at: DateTime<Utc>,
+    /// Missing from errors cached before it was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl CachedError {
+    /// Whether this records a failure worth retrying (e.g. "HTTP 503") rather than
+    /// a permanent one. Only permanent failures are cached now, but entries
+    /// written before that shouldn't pin a transient outage.
+    pub fn is_transient(&self) -> bool {
+        self.error
+            .strip_prefix("HTTP ")
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|code| code.parse::<u16>().ok())
+            .and_then(|code| reqwest::StatusCode::from_u16(code).ok())
+            .is_some_and(super::retry::is_retryable_status)
+    }
 }
 
 /// Cache statistics.
@@ -41,12 +233,107 @@ pub struct CachedError {
 pub struct CacheStats {
     pub cached_success: usize,
     pub cached_errors: usize,
+    /// Bytes stored, where the cache tracks it
+    pub total_bytes: u64,
+    /// The same counts per host, where the cache tracks them
+    pub by_host: BTreeMap<String, HostCacheStats>,
+}
+
+/// Cache statistics for one host.
+#[derive(Debug, Clone, Default)]
+pub struct HostCacheStats {
+    pub cached_success: usize,
+    pub cached_errors: usize,
+    pub total_bytes: u64,
+}
+
+/// What [`DiskCache::prune`] removed.
+#[derive(Debug, Clone, Default)]
+pub struct PruneStats {
+    /// Entries removed because they outlived the TTL
+    pub expired: usize,
+    /// Entries evicted to get under the size limit
+    pub evicted: usize,
+    pub freed_bytes: u64,
+}
+
+/// One URL's directory, as seen by stats and pruning.
+struct EntryInfo {
+    dir: PathBuf,
+    host: String,
+    has_response: bool,
+    has_error: bool,
+    bytes: u64,
+    last_access: SystemTime,
+    fetched_at: Option<DateTime<Utc>>,
+}
+
+/// Host of a cached URL; for old error-only entries without a URL, the host
+/// part of the directory slug.
+fn entry_host(url: Option<&str>, dir: &Path) -> String {
+    url.and_then(|u| url::Url::parse(u).ok())
+        .and_then(|u| {
+            u.host_str()
+                .map(|h| h.trim_start_matches("www.").to_string())
+        })
+        .unwrap_or_else(|| {
+            let slug = dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("unknown");
+            slug.split('_').next().unwrap_or(slug).to_string()
+        })
+}
+
+/// Mark a cache file as just used.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::options().append(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
 }
 
 impl DiskCache {
     /// Create a new DiskCache with the given directory.
     pub fn new(cache_dir: PathBuf) -> Self {
-        Self { cache_dir }
+        Self {
+            cache_dir,
+            ttl: None,
+            max_size: None,
+            compression: Compression::default(),
+            measured_bytes: Arc::new(Mutex::new(None)),
+            written_bytes: Arc::new(AtomicU64::new(0)),
+            pruning: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Set how long entries stay valid. None (the default) keeps them forever.
+    pub fn with_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set the size in bytes beyond which least recently used entries are
+    /// evicted. None (the default) lets the cache grow without bound.
+    pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Set how new response bodies are compressed (default zstd).
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Whether an entry fetched at `fetched_at` has outlived the TTL.
+    fn is_expired(&self, fetched_at: DateTime<Utc>) -> bool {
+        match self
+            .ttl
+            .and_then(|ttl| chrono::Duration::from_std(ttl).ok())
+        {
+            Some(ttl) => fetched_at + ttl < Utc::now(),
+            None => false,
+        }
     }
 
     /// Get the default cache directory: ~/.ramekin/http-cache
@@ -61,26 +348,40 @@ impl DiskCache {
         self.cache_dir.join(slugify_url(url))
     }
 
+    /// Find the stored body in an entry directory, however it was compressed.
+    fn response_file(dir: &Path) -> Option<(PathBuf, Compression)> {
+        Compression::ALL
+            .into_iter()
+            .map(|c| (dir.join(c.file_name()), c))
+            .find(|(path, _)| path.exists())
+    }
+
     /// Check if a response is cached for a URL (either success or error).
     pub fn is_cached(&self, url: &str) -> bool {
+        if self.ttl.is_some() {
+            return self.get_error(url).is_some() || self.get_metadata(url).is_some();
+        }
         let dir = self.url_dir(url);
-        dir.join("response.bin").exists() || dir.join("error.txt").exists()
+        Self::response_file(&dir).is_some() || dir.join("error.txt").exists()
     }
 
     /// Get cached response if it exists.
     pub fn get(&self, url: &str) -> Option<CachedResponse> {
         let dir = self.url_dir(url);
-        let response_path = dir.join("response.bin");
+        let (response_path, compression) = Self::response_file(&dir)?;
         let metadata_path = dir.join("metadata.json");
 
-        if response_path.exists() && metadata_path.exists() {
-            let data = fs::read(&response_path).ok()?;
-            let metadata_str = fs::read_to_string(&metadata_path).ok()?;
-            let metadata: CacheMetadata = serde_json::from_str(&metadata_str).ok()?;
-            Some(CachedResponse { data, metadata })
-        } else {
-            None
+        let metadata_str = fs::read_to_string(&metadata_path).ok()?;
+        let metadata: CacheMetadata = serde_json::from_str(&metadata_str).ok()?;
+        if self.is_expired(metadata.fetched_at) {
+            return None;
         }
+        let data = compression
+            .decompress(fs::read(&response_path).ok()?)
+            .ok()?;
+
+        touch(&metadata_path);
+        Some(CachedResponse { data, metadata })
     }
 
     /// Get cached error if it exists.
@@ -90,7 +391,12 @@ impl DiskCache {
 
         if error_path.exists() {
             let error_str = fs::read_to_string(&error_path).ok()?;
-            serde_json::from_str(&error_str).ok()
+            let cached: CachedError = serde_json::from_str(&error_str).ok()?;
+            if self.is_expired(cached.fetched_at) {
+                return None;
+            }
+            touch(&error_path);
+            Some(cached)
         } else {
             None
         }
@@ -103,7 +409,8 @@ impl DiskCache {
 
         if metadata_path.exists() {
             let metadata_str = fs::read_to_string(&metadata_path).ok()?;
-            serde_json::from_str(&metadata_str).ok()
+            let metadata: CacheMetadata = serde_json::from_str(&metadata_str).ok()?;
+            (!self.is_expired(metadata.fetched_at)).then_some(metadata)
         } else {
             None
         }
@@ -129,11 +436,16 @@ impl DiskCache {
             last_modified,
         };
 
-        fs::write(dir.join("response.bin"), data)?;
-        fs::write(
-            dir.join("metadata.json"),
-            serde_json::to_string_pretty(&metadata).unwrap(),
-        )?;
+        let body = self.compression.compress(data)?;
+        let metadata_json = serde_json::to_string_pretty(&metadata).unwrap();
+        fs::write(dir.join(self.compression.file_name()), &body)?;
+        // Drop a body stored under a different compression setting
+        for other in Compression::ALL {
+            if other != self.compression {
+                let _ = fs::remove_file(dir.join(other.file_name()));
+            }
+        }
+        fs::write(dir.join("metadata.json"), &metadata_json)?;
 
         // Remove any cached error if we succeeded
         let error_path = dir.join("error.txt");
@@ -141,6 +453,7 @@ impl DiskCache {
             let _ = fs::remove_file(error_path);
         }
 
+        self.after_write((body.len() + metadata_json.len()) as u64);
         Ok(())
     }
 
@@ -152,37 +465,196 @@ impl DiskCache {
         let cached_error = CachedError {
             error: error.to_string(),
             fetched_at: Utc::now(),
+            url: Some(url.to_string()),
         };
 
-        fs::write(
-            dir.join("error.txt"),
-            serde_json::to_string_pretty(&cached_error).unwrap(),
-        )?;
+        let error_json = serde_json::to_string_pretty(&cached_error).unwrap();
+        fs::write(dir.join("error.txt"), &error_json)?;
 
+        self.after_write(error_json.len() as u64);
         Ok(())
     }
 
-    /// Get cache statistics.
+    /// Remove everything cached for one URL.
+    pub fn remove(&self, url: &str) -> std::io::Result<()> {
+        let dir = self.url_dir(url);
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the cache may be over its size limit, going by the last prune's
+    /// measurement and what's been written since.
+    fn may_be_over_limit(&self) -> bool {
+        let Some(max_size) = self.max_size else {
+            return false;
+        };
+        match *self.measured_bytes.lock().unwrap() {
+            Some(measured) => measured + self.written_bytes.load(Ordering::Acquire) > max_size,
+            None => true,
+        }
+    }
+
+    /// Count a write's bytes and, when the cache may be over its size limit,
+    /// prune on a background thread. Pruning walks the whole cache directory, so
+    /// it mustn't run on the caller's (possibly async) thread.
+    fn after_write(&self, bytes: u64) {
+        self.written_bytes.fetch_add(bytes, Ordering::AcqRel);
+        if !self.may_be_over_limit() || self.pruning.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let cache = DiskCache {
+            cache_dir: self.cache_dir.clone(),
+            ttl: self.ttl,
+            max_size: self.max_size,
+            compression: self.compression,
+            measured_bytes: self.measured_bytes.clone(),
+            written_bytes: self.written_bytes.clone(),
+            pruning: self.pruning.clone(),
+        };
+        std::thread::spawn(move || loop {
+            match cache.prune() {
+                Ok(pruned) if pruned.expired + pruned.evicted > 0 => tracing::debug!(
+                    expired = pruned.expired,
+                    evicted = pruned.evicted,
+                    freed_bytes = pruned.freed_bytes,
+                    "pruned HTTP cache"
+                ),
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to prune HTTP cache");
+                    cache.pruning.store(false, Ordering::Release);
+                    return;
+                }
+            }
+            cache.pruning.store(false, Ordering::Release);
+            // Writes that found a prune already running left it to this thread
+            if !cache.may_be_over_limit() || cache.pruning.swap(true, Ordering::AcqRel) {
+                return;
+            }
+        });
+    }
+
+    /// Read what's in one entry directory; None if it holds nothing cached.
+    fn entry_info(dir: PathBuf) -> Option<EntryInfo> {
+        let has_response = Self::response_file(&dir).is_some();
+        let error_path = dir.join("error.txt");
+        let has_error = error_path.exists();
+        if !has_response && !has_error {
+            return None;
+        }
+
+        let mut bytes = 0;
+        let mut last_access = SystemTime::UNIX_EPOCH;
+        for file in fs::read_dir(&dir).ok()?.filter_map(|e| e.ok()) {
+            if let Ok(meta) = file.metadata() {
+                bytes += meta.len();
+                if let Ok(modified) = meta.modified() {
+                    last_access = last_access.max(modified);
+                }
+            }
+        }
+
+        let metadata: Option<CacheMetadata> = has_response
+            .then(|| fs::read_to_string(dir.join("metadata.json")).ok())
+            .flatten()
+            .and_then(|s| serde_json::from_str(&s).ok());
+        let error: Option<CachedError> = has_error
+            .then(|| fs::read_to_string(&error_path).ok())
+            .flatten()
+            .and_then(|s| serde_json::from_str(&s).ok());
+
+        let url = metadata
+            .as_ref()
+            .map(|m| m.url.as_str())
+            .or_else(|| error.as_ref().and_then(|e| e.url.as_deref()));
+        let host = entry_host(url, &dir);
+        // The newest of the response and the error decides expiry
+        let fetched_at = metadata
+            .as_ref()
+            .map(|m| m.fetched_at)
+            .max(error.as_ref().map(|e| e.fetched_at));
+
+        Some(EntryInfo {
+            dir,
+            host,
+            has_response,
+            has_error,
+            bytes,
+            last_access,
+            fetched_at,
+        })
+    }
+
+    /// All entries in the cache directory.
+    fn entries(&self) -> Vec<EntryInfo> {
+        let Ok(dirs) = fs::read_dir(&self.cache_dir) else {
+            return Vec::new();
+        };
+        dirs.filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter_map(Self::entry_info)
+            .collect()
+    }
+
+    /// Get cache statistics, overall and per host.
     pub fn stats(&self) -> CacheStats {
         let mut stats = CacheStats::default();
 
-        if !self.cache_dir.exists() {
-            return stats;
+        for entry in self.entries() {
+            let host = stats.by_host.entry(entry.host).or_default();
+            if entry.has_response {
+                stats.cached_success += 1;
+                host.cached_success += 1;
+            } else if entry.has_error {
+                stats.cached_errors += 1;
+                host.cached_errors += 1;
+            }
+            stats.total_bytes += entry.bytes;
+            host.total_bytes += entry.bytes;
+        }
+
+        stats
+    }
+
+    /// Remove entries past the TTL, then evict least recently used entries
+    /// until the cache fits in the size limit.
+    pub fn prune(&self) -> std::io::Result<PruneStats> {
+        // Writes from here on may or may not be in the walk; counting them again
+        // only makes the next prune come sooner
+        self.written_bytes.store(0, Ordering::Release);
+        let mut pruned = PruneStats::default();
+        let mut kept = Vec::new();
+
+        for entry in self.entries() {
+            if entry.fetched_at.is_some_and(|at| self.is_expired(at)) {
+                fs::remove_dir_all(&entry.dir)?;
+                pruned.expired += 1;
+                pruned.freed_bytes += entry.bytes;
+            } else {
+                kept.push(entry);
+            }
         }
 
-        if let Ok(entries) = fs::read_dir(&self.cache_dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                if entry.path().is_dir() {
-                    if entry.path().join("response.bin").exists() {
-                        stats.cached_success += 1;
-                    } else if entry.path().join("error.txt").exists() {
-                        stats.cached_errors += 1;
-                    }
+        let mut total: u64 = kept.iter().map(|e| e.bytes).sum();
+        if let Some(max_size) = self.max_size {
+            kept.sort_by_key(|e| e.last_access);
+            for entry in kept {
+                if total <= max_size {
+                    break;
                 }
+                fs::remove_dir_all(&entry.dir)?;
+                total -= entry.bytes;
+                pruned.evicted += 1;
+                pruned.freed_bytes += entry.bytes;
             }
         }
 
-        stats
+        *self.measured_bytes.lock().unwrap() = Some(total);
+        Ok(pruned)
     }
 
     /// Clear all cached responses.
@@ -193,3 +665,202 @@ impl DiskCache {
         Ok(())
     }
 }
+
+impl HttpCache for DiskCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        DiskCache::get(self, url)
+    }
+
+    fn get_error(&self, url: &str) -> Option<CachedError> {
+        DiskCache::get_error(self, url)
+    }
+
+    fn put(
+        &self,
+        url: &str,
+        data: &[u8],
+        content_type: Option<String>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    ) -> std::io::Result<()> {
+        DiskCache::put(self, url, data, content_type, etag, last_modified)
+    }
+
+    fn put_error(&self, url: &str, error: &str) -> std::io::Result<()> {
+        DiskCache::put_error(self, url, error)
+    }
+
+    fn remove(&self, url: &str) -> std::io::Result<()> {
+        DiskCache::remove(self, url)
+    }
+
+    fn stats(&self) -> CacheStats {
+        DiskCache::stats(self)
+    }
+
+    fn clear(&self) -> std::io::Result<()> {
+        DiskCache::clear(self)
+    }
+
+    fn is_cached(&self, url: &str) -> bool {
+        DiskCache::is_cached(self, url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> DiskCache {
+        let dir = std::env::temp_dir().join(format!(
+            "ramekin-cache-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        DiskCache::new(dir)
+    }
+
+    #[test]
+    fn test_expired_entries_read_as_missing() {
+        let cache = temp_cache("ttl");
+        let url = "https://example.com/recipe";
+        cache.put(url, b"<html></html>", None, None, None).unwrap();
+        assert!(cache.get(url).is_some());
+
+        let cache = cache.with_ttl(Some(Duration::ZERO));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(cache.get(url).is_none());
+        assert!(!cache.is_cached(url));
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_remove() {
+        let cache = temp_cache("remove");
+        let url = "https://example.com/recipe";
+        cache.put_error(url, "HTTP 404").unwrap();
+        assert!(HttpCache::is_cached(&cache, url));
+
+        HttpCache::remove(&cache, url).unwrap();
+        assert!(!cache.is_cached(url));
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_compressed_bodies_round_trip() {
+        for compression in [Compression::Plain, Compression::Gzip, Compression::Zstd] {
+            let cache = temp_cache(&format!("{:?}", compression)).with_compression(compression);
+            let url = "https://example.com/recipe";
+            let html = "<html>".repeat(1000);
+            cache
+                .put(
+                    url,
+                    html.as_bytes(),
+                    Some("text/html".to_string()),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(cache.get(url).unwrap().data, html.as_bytes());
+
+            // Entries stay readable after the setting changes
+            let cache = cache.with_compression(Compression::Plain);
+            assert_eq!(cache.get(url).unwrap().data, html.as_bytes());
+            cache.clear().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_prune_evicts_least_recently_used() {
+        let cache = temp_cache("lru");
+        let urls = [
+            "https://a.example.com/1",
+            "https://b.example.com/2",
+            "https://b.example.com/3",
+        ];
+        for url in urls {
+            cache.put(url, &[b'x'; 4096], None, None, None).unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        // Reading the oldest entry makes the second one least recently used
+        assert!(cache.get(urls[0]).is_some());
+
+        let entry_size = cache.stats().total_bytes / 3;
+        let cache = cache.with_max_size(Some(entry_size * 2));
+        let pruned = cache.prune().unwrap();
+        assert_eq!(pruned.evicted, 1);
+        assert!(cache.get(urls[0]).is_some());
+        assert!(cache.get(urls[1]).is_none());
+        assert!(cache.get(urls[2]).is_some());
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_writes_past_size_limit_prune_in_background() {
+        let cache = temp_cache("background")
+            .with_compression(Compression::Plain)
+            .with_max_size(Some(3 * 4096));
+        for i in 0..8 {
+            let url = format!("https://example.com/{}", i);
+            cache.put(&url, &[i as u8; 4096], None, None, None).unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while cache.stats().total_bytes > 3 * 4096 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "cache was never pruned"
+            );
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(cache.get("https://example.com/7").is_some());
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_stats_by_host() {
+        let cache = temp_cache("hosts");
+        cache
+            .put("https://www.example.com/a", b"a", None, None, None)
+            .unwrap();
+        cache
+            .put_error("https://www.example.com/b", "HTTP 404")
+            .unwrap();
+        cache
+            .put("https://other.example.org/c", b"c", None, None, None)
+            .unwrap();
+
+        let stats = cache.stats();
+        assert_eq!(stats.cached_success, 2);
+        assert_eq!(stats.cached_errors, 1);
+        let host = &stats.by_host["example.com"];
+        assert_eq!((host.cached_success, host.cached_errors), (1, 1));
+        assert!(host.total_bytes > 0);
+        assert_eq!(stats.by_host["other.example.org"].cached_success, 1);
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1048576"), Some(1 << 20));
+        assert_eq!(parse_size("500M"), Some(500 << 20));
+        assert_eq!(parse_size("20GB"), Some(20 << 30));
+        assert_eq!(parse_size("1.5GiB"), Some(3 << 29));
+        assert_eq!(parse_size("lots"), None);
+    }
+
+    #[test]
+    fn test_transient_cached_errors() {
+        let error = |msg: &str| CachedError {
+            error: msg.to_string(),
+            fetched_at: Utc::now(),
+            url: None,
+        };
+        assert!(error("HTTP 503 Service Unavailable").is_transient());
+        assert!(error("HTTP 429 Too Many Requests").is_transient());
+        assert!(!error("HTTP 404 Not Found").is_transient());
+        assert!(!error("connection refused").is_transient());
+    }
+}
@@ -8,9 +8,12 @@ use std::time::Duration;
 
 use crate::error::FetchError;
 
-use super::cache::DiskCache;
+use super::cache::{CacheMetadata, Compression, DiskCache, DiskCacheSettings, HttpCache};
 use super::charset;
 use super::rate_limiter::RateLimiter;
+use super::retry::{is_retryable_status, RetryPolicy};
+use super::robots::{RobotsCache, RobotsPolicy, ROBOTS_USER_AGENT};
+use super::ssrf::{self, DestinationPolicy, GuardedResolver};
 
 /// Result of a fetch operation, including content-type for charset detection.
 struct FetchResult {
@@ -18,6 +21,28 @@ struct FetchResult {
     content_type: Option<String>,
 }
 
+/// Outcome of [`CachingClient::fetch_html_if_modified`].
+#[derive(Debug, Clone)]
+pub enum ConditionalFetch {
+    /// The page hasn't changed since the validators were recorded.
+    NotModified,
+    /// The page changed (or had no validators); `metadata` holds the validators
+    /// to send next time.
+    Modified {
+        html: String,
+        metadata: CacheMetadata,
+    },
+}
+
+/// Read a response header as a string.
+fn header_value(response: &reqwest::Response, name: &str) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
 /// Trait for HTTP clients, enabling mockability in tests.
 #[async_trait]
 pub trait HttpClient: Send + Sync {
@@ -32,11 +57,17 @@ pub trait HttpClient: Send + Sync {
 #[derive(Clone)]
 pub struct CachingClientBuilder {
     cache_dir: Option<PathBuf>,
+    cache_settings: DiskCacheSettings,
+    cache: Option<Arc<dyn HttpCache>>,
     rate_limit_ms: u64,
     offline_mode: bool,
     never_network: bool,
     timeout: Duration,
     user_agent: String,
+    block_private_networks: bool,
+    allowed_private_hosts: Vec<String>,
+    robots_policy: RobotsPolicy,
+    retry_policy: RetryPolicy,
 }
 
 impl Default for CachingClientBuilder {
@@ -50,8 +81,14 @@ impl CachingClientBuilder {
     ///
     /// Environment variables:
     /// - `RAMEKIN_HTTP_CACHE`: "none" to disable, "disk" (default), or a path
+    /// - `RAMEKIN_HTTP_CACHE_TTL_SECS`: age after which cached entries are ignored
+    /// - `RAMEKIN_HTTP_CACHE_MAX_SIZE`: size (e.g. "20G") beyond which least recently
+    ///   used entries are evicted
+    /// - `RAMEKIN_HTTP_CACHE_COMPRESSION`: "zstd" (default), "gzip" or "none"
     /// - `RAMEKIN_HTTP_CACHE_OFFLINE`: "true" to skip network validation for cached responses
     /// - `RAMEKIN_OFFLINE`: "true" to never hit network (error if not cached)
+    /// - `RAMEKIN_HTTP_ALLOW_PRIVATE_HOSTS`: comma-separated "host" or "host:port"
+    ///   entries exempt from private network blocking (e.g. local test fixtures)
     pub fn new() -> Self {
         // Check environment variables for configuration
         let cache_dir = match std::env::var("RAMEKIN_HTTP_CACHE").ok() {
@@ -69,13 +106,28 @@ impl CachingClientBuilder {
             .map(|v| v == "true" || v == "1")
             .unwrap_or(false);
 
+        let allowed_private_hosts = std::env::var("RAMEKIN_HTTP_ALLOW_PRIVATE_HOSTS")
+            .map(|v| {
+                v.split(',')
+                    .map(|h| h.trim().to_string())
+                    .filter(|h| !h.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         Self {
             cache_dir,
+            cache_settings: DiskCacheSettings::from_env(),
+            cache: None,
             rate_limit_ms: 200, // Default 200ms between requests to same host
             offline_mode,
             never_network,
             timeout: Duration::from_secs(30),
             user_agent: "Mozilla/5.0 (compatible; Ramekin/1.0; +https://ramekin.app)".to_string(),
+            block_private_networks: true,
+            allowed_private_hosts,
+            robots_policy: RobotsPolicy::UserInitiated,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -85,6 +137,31 @@ impl CachingClientBuilder {
         self
     }
 
+    /// Set how long disk cache entries stay valid. None keeps them forever.
+    pub fn cache_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.cache_settings.ttl = ttl;
+        self
+    }
+
+    /// Set the disk cache size limit in bytes. None lets it grow without bound.
+    pub fn cache_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.cache_settings.max_size = max_size;
+        self
+    }
+
+    /// Set how the disk cache compresses response bodies.
+    pub fn cache_compression(mut self, compression: Compression) -> Self {
+        self.cache_settings.compression = compression;
+        self
+    }
+
+    /// Use a cache shared with other clients (e.g. one backed by a database)
+    /// instead of a disk cache in `cache_dir`.
+    pub fn cache(mut self, cache: Arc<dyn HttpCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Set the rate limit delay in milliseconds. 0 disables rate limiting.
     pub fn rate_limit_ms(mut self, ms: u64) -> Self {
         self.rate_limit_ms = ms;
@@ -115,15 +192,62 @@ impl CachingClientBuilder {
         self
     }
 
+    /// Set whether to refuse loopback, link-local, private and unique-local
+    /// destinations, non-HTTP schemes and non-standard ports (default true).
+    pub fn block_private_networks(mut self, block: bool) -> Self {
+        self.block_private_networks = block;
+        self
+    }
+
+    /// Exempt a "host" or "host:port" from private network blocking.
+    pub fn allow_private_host(mut self, host: &str) -> Self {
+        self.allowed_private_hosts.push(host.to_string());
+        self
+    }
+
+    /// Set whether robots.txt is consulted (default [`RobotsPolicy::UserInitiated`],
+    /// which doesn't). Bulk crawls should use [`RobotsPolicy::Crawl`].
+    pub fn robots_policy(mut self, policy: RobotsPolicy) -> Self {
+        self.robots_policy = policy;
+        self
+    }
+
+    /// Set how transient failures (timeouts, connection resets, 429 and 5xx
+    /// responses) are retried. [`RetryPolicy::none`] disables retries.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Build the CachingClient.
     pub fn build(self) -> Result<CachingClient, reqwest::Error> {
-        let inner = reqwest::Client::builder()
+        let mut inner = reqwest::Client::builder()
             .timeout(self.timeout)
-            .user_agent(&self.user_agent)
-            .build()?;
+            .user_agent(&self.user_agent);
+
+        let destination_policy = if self.block_private_networks {
+            let policy = Arc::new(DestinationPolicy::new(self.allowed_private_hosts));
+            inner = inner
+                .dns_resolver(Arc::new(GuardedResolver::new(Arc::clone(&policy))))
+                .redirect(policy.redirect_policy());
+            Some(policy)
+        } else {
+            None
+        };
 
-        let cache = self.cache_dir.map(DiskCache::new);
+        let inner = inner.build()?;
+
+        let cache = match self.cache {
+            Some(cache) => Some(cache),
+            None => self
+                .cache_dir
+                .map(|dir| Arc::new(self.cache_settings.open(dir)) as Arc<dyn HttpCache>),
+        };
         let rate_limiter = RateLimiter::new(Duration::from_millis(self.rate_limit_ms));
+        let robots = match self.robots_policy {
+            RobotsPolicy::UserInitiated => None,
+            RobotsPolicy::Crawl => Some(RobotsCache::new(ROBOTS_USER_AGENT)),
+        };
 
         Ok(CachingClient {
             inner: Arc::new(inner),
@@ -131,6 +255,9 @@ impl CachingClientBuilder {
             rate_limiter,
             offline_mode: self.offline_mode,
             never_network: self.never_network,
+            destination_policy,
+            robots,
+            retry_policy: self.retry_policy,
         })
     }
 }
@@ -139,14 +266,20 @@ impl CachingClientBuilder {
 pub struct CachingClient {
     /// Shared reqwest client for connection pooling.
     inner: Arc<reqwest::Client>,
-    /// Optional disk cache.
-    cache: Option<DiskCache>,
+    /// Optional response cache.
+    cache: Option<Arc<dyn HttpCache>>,
     /// Per-host rate limiter.
     rate_limiter: RateLimiter,
     /// When true, use cached responses without network validation.
     offline_mode: bool,
     /// When true, never access network - return error if not cached.
     never_network: bool,
+    /// SSRF protection; None when private network blocking is disabled.
+    destination_policy: Option<Arc<DestinationPolicy>>,
+    /// robots.txt rules; None unless built with [`RobotsPolicy::Crawl`].
+    robots: Option<RobotsCache>,
+    /// Retries for transient failures.
+    retry_policy: RetryPolicy,
 }
 
 impl CachingClient {
@@ -173,6 +306,14 @@ impl CachingClient {
         Ok(())
     }
 
+    /// Remove everything cached for a URL.
+    pub fn remove_cached(&self, url: &str) -> std::io::Result<()> {
+        if let Some(cache) = &self.cache {
+            cache.remove(url)?;
+        }
+        Ok(())
+    }
+
     /// Check if a URL is cached.
     pub fn is_cached(&self, url: &str) -> bool {
         self.cache
@@ -199,7 +340,9 @@ impl CachingClient {
     pub fn get_cached_error(&self, url: &str) -> Option<String> {
         self.cache
             .as_ref()
-            .and_then(|c| c.get_error(url).map(|e| e.error))
+            .and_then(|c| c.get_error(url))
+            .filter(|e| !e.is_transient())
+            .map(|e| e.error)
     }
 
     /// Inject HTML content into the cache without fetching.
@@ -230,11 +373,187 @@ impl CachingClient {
         Ok(())
     }
 
-    /// Extract host from URL for rate limiting.
-    fn get_host(url: &str) -> Option<String> {
-        reqwest::Url::parse(url)
-            .ok()
-            .and_then(|u| u.host_str().map(|h| h.to_string()))
+    /// Get ready to send a request: enforce robots.txt (when crawling), picking up
+    /// the host's Crawl-delay, then wait for the rate limiter.
+    async fn prepare_request(&self, url: &reqwest::Url) -> Result<(), FetchError> {
+        let Some(host) = url.host_str() else {
+            return Ok(());
+        };
+
+        if let Some(robots) = &self.robots {
+            let rules = robots.rules_for(&self.inner, url).await?;
+            if let Some(delay) = rules.crawl_delay() {
+                self.rate_limiter.set_host_delay(host, delay);
+            }
+
+            let path = match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            };
+            if !rules.is_allowed(&path) {
+                tracing::debug!(url = %url, "robots.txt disallows fetch");
+                return Err(FetchError::RobotsDisallowed(url.to_string()));
+            }
+        }
+
+        self.rate_limiter.wait(host).await;
+        Ok(())
+    }
+
+    /// Send a request built by `build`, retrying transient failures per the retry
+    /// policy. Each attempt goes through [`Self::prepare_request`]. A retryable
+    /// status is returned as the response once attempts run out, or when its
+    /// `Retry-After` asks for longer than we're willing to wait.
+    async fn send_with_retry(
+        &self,
+        url: &reqwest::Url,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, FetchError> {
+        let mut attempt = 1;
+        loop {
+            self.prepare_request(url).await?;
+            let result = build().send().await.map_err(Self::request_error);
+            if attempt >= self.retry_policy.max_attempts {
+                return result;
+            }
+
+            let wait = match &result {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    let retry_after = header_value(response, "retry-after");
+                    match self.retry_policy.retry_after(retry_after.as_deref()) {
+                        Some(Some(wait)) => wait,
+                        Some(None) => {
+                            tracing::debug!(url = %url, status = %response.status(), "Retry-After too long, not retrying");
+                            return result;
+                        }
+                        None => self.retry_policy.backoff(attempt),
+                    }
+                }
+                Err(e) if e.is_retryable() => self.retry_policy.backoff(attempt),
+                _ => return result,
+            };
+
+            match &result {
+                Ok(response) => {
+                    tracing::debug!(url = %url, status = %response.status(), attempt, ?wait, "network: retrying")
+                }
+                Err(e) => {
+                    tracing::debug!(url = %url, error = %e, attempt, ?wait, "network: retrying")
+                }
+            }
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+
+    /// Convert a request error, picking out destinations refused by the SSRF checks.
+    fn request_error(err: reqwest::Error) -> FetchError {
+        match ssrf::find_blocked(&err) {
+            Some(blocked) => FetchError::Blocked(blocked.to_string()),
+            None => FetchError::RequestFailed(err),
+        }
+    }
+
+    /// Fetch HTML only if it changed since `previous` was recorded.
+    ///
+    /// Unlike `fetch_html`, this always asks the origin (sending `If-None-Match` /
+    /// `If-Modified-Since` from `previous`) rather than trusting the cache, so it
+    /// suits periodic checks for upstream edits. Fresh content also refreshes the
+    /// cache. In never-network mode the cached copy stands in for the origin.
+    pub async fn fetch_html_if_modified(
+        &self,
+        url: &str,
+        previous: Option<&CacheMetadata>,
+    ) -> Result<ConditionalFetch, FetchError> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| FetchError::InvalidUrl(e.to_string()))?;
+
+        if let Some(policy) = &self.destination_policy {
+            policy
+                .check_url(&parsed)
+                .map_err(|blocked| FetchError::Blocked(blocked.to_string()))?;
+        }
+
+        let validators = previous.map(|p| (&p.etag, &p.last_modified));
+
+        if self.never_network {
+            let cached = self
+                .cache
+                .as_ref()
+                .and_then(|c| c.get(url))
+                .ok_or_else(|| {
+                    FetchError::InvalidUrl(format!(
+                        "URL not cached and RAMEKIN_OFFLINE is set: {}",
+                        url
+                    ))
+                })?;
+            let unchanged = validators.is_some_and(|(etag, last_modified)| {
+                (etag.is_some() || last_modified.is_some())
+                    && *etag == cached.metadata.etag
+                    && *last_modified == cached.metadata.last_modified
+            });
+            if unchanged {
+                return Ok(ConditionalFetch::NotModified);
+            }
+            return Ok(ConditionalFetch::Modified {
+                html: charset::decode_bytes_to_utf8(
+                    cached.data,
+                    cached.metadata.content_type.as_deref(),
+                ),
+                metadata: cached.metadata,
+            });
+        }
+
+        tracing::debug!(url, "network: conditional fetch");
+        let response = self
+            .send_with_retry(&parsed, || {
+                let mut request = self.inner.get(parsed.clone());
+                if let Some((etag, last_modified)) = validators {
+                    if let Some(etag) = etag {
+                        request = request.header("If-None-Match", etag);
+                    }
+                    if let Some(last_modified) = last_modified {
+                        request = request.header("If-Modified-Since", last_modified);
+                    }
+                }
+                request
+            })
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            tracing::debug!(url, "upstream unchanged (304 Not Modified)");
+            return Ok(ConditionalFetch::NotModified);
+        }
+
+        if !response.status().is_success() {
+            tracing::debug!(url, status = %response.status(), "network: request failed");
+            return Err(FetchError::RequestFailed(
+                response.error_for_status().unwrap_err(),
+            ));
+        }
+
+        let metadata = CacheMetadata {
+            url: url.to_string(),
+            content_type: header_value(&response, "content-type"),
+            fetched_at: chrono::Utc::now(),
+            etag: header_value(&response, "etag"),
+            last_modified: header_value(&response, "last-modified"),
+        };
+        let bytes = response.bytes().await?.to_vec();
+
+        if let Some(cache) = &self.cache {
+            let _ = cache.put(
+                url,
+                &bytes,
+                metadata.content_type.clone(),
+                metadata.etag.clone(),
+                metadata.last_modified.clone(),
+            );
+        }
+
+        Ok(ConditionalFetch::Modified {
+            html: charset::decode_bytes_to_utf8(bytes, metadata.content_type.as_deref()),
+            metadata,
+        })
     }
 
     /// Internal fetch implementation with caching logic.
@@ -242,10 +561,16 @@ impl CachingClient {
         // Validate URL first
         let parsed = reqwest::Url::parse(url).map_err(|e| FetchError::InvalidUrl(e.to_string()))?;
 
+        if let Some(policy) = &self.destination_policy {
+            policy
+                .check_url(&parsed)
+                .map_err(|blocked| FetchError::Blocked(blocked.to_string()))?;
+        }
+
         // Check cache
         if let Some(cache) = &self.cache {
             // Check for cached error first
-            if let Some(cached_error) = cache.get_error(url) {
+            if let Some(cached_error) = cache.get_error(url).filter(|e| !e.is_transient()) {
                 tracing::debug!(url, error = %cached_error.error, "cache hit (cached error)");
                 return Err(FetchError::InvalidUrl(format!(
                     "Cached error: {}",
@@ -265,21 +590,20 @@ impl CachingClient {
                 }
 
                 // Online mode: validate with ETag/If-Modified-Since
-                if let Some(host) = Self::get_host(url) {
-                    self.rate_limiter.wait(&host).await;
-                }
-
-                let mut request = self.inner.get(parsed.clone());
-
-                if let Some(etag) = &cached.metadata.etag {
-                    request = request.header("If-None-Match", etag);
-                }
-                if let Some(last_modified) = &cached.metadata.last_modified {
-                    request = request.header("If-Modified-Since", last_modified);
-                }
-
                 tracing::debug!(url, "network: validating cached response");
-                match request.send().await {
+                let result = self
+                    .send_with_retry(&parsed, || {
+                        let mut request = self.inner.get(parsed.clone());
+                        if let Some(etag) = &cached.metadata.etag {
+                            request = request.header("If-None-Match", etag);
+                        }
+                        if let Some(last_modified) = &cached.metadata.last_modified {
+                            request = request.header("If-Modified-Since", last_modified);
+                        }
+                        request
+                    })
+                    .await;
+                match result {
                     Ok(response) => {
                         if response.status() == reqwest::StatusCode::NOT_MODIFIED {
                             // 304 Not Modified: use cached response
@@ -320,20 +644,34 @@ impl CachingClient {
                             });
                         }
 
+                        if is_retryable_status(response.status()) {
+                            // Still failing after retries: the copy we have beats an error
+                            tracing::debug!(url, status = %response.status(), "network: transient failure, using cached fallback");
+                            return Ok(FetchResult {
+                                data: cached.data,
+                                content_type: cached.metadata.content_type,
+                            });
+                        }
+
                         // Non-success status, fall through to error
                         tracing::debug!(url, status = %response.status(), "network: request failed");
                         return Err(FetchError::RequestFailed(
                             response.error_for_status().unwrap_err(),
                         ));
                     }
-                    Err(e) => {
-                        // Network error, use cached response as fallback
+                    Err(e) if e.is_retryable() => {
+                        // Network error or robots.txt unavailable: use cached response as fallback
                         tracing::debug!(url, error = %e, "network error, using cached fallback");
                         return Ok(FetchResult {
                             data: cached.data,
                             content_type: cached.metadata.content_type,
                         });
                     }
+                    Err(e) => {
+                        // e.g. the host now points somewhere it mustn't; don't serve it from cache
+                        tracing::warn!(url, error = %e, "network: request refused");
+                        return Err(e);
+                    }
                 }
             }
         }
@@ -348,22 +686,22 @@ impl CachingClient {
         }
 
         // Fetch from network
-        if let Some(host) = Self::get_host(url) {
-            self.rate_limiter.wait(&host).await;
-        }
-
         tracing::debug!(url, "network: fetching (not cached)");
-        let response = self.inner.get(parsed).send().await?;
+        let response = self
+            .send_with_retry(&parsed, || self.inner.get(parsed.clone()))
+            .await?;
 
         if !response.status().is_success() {
             let error_msg = format!("HTTP {}", response.status());
             tracing::debug!(url, status = %response.status(), "network: request failed");
-            if let Some(cache) = &self.cache {
-                let _ = cache.put_error(url, &error_msg);
+            let error = FetchError::RequestFailed(response.error_for_status().unwrap_err());
+            // Only cache failures that retrying later won't fix
+            if !error.is_retryable() {
+                if let Some(cache) = &self.cache {
+                    let _ = cache.put_error(url, &error_msg);
+                }
             }
-            return Err(FetchError::RequestFailed(
-                response.error_for_status().unwrap_err(),
-            ));
+            return Err(error);
         }
 
         tracing::debug!(url, status = %response.status(), "network: fetched successfully");
@@ -501,3 +839,92 @@ impl HttpClient for MockClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_client(name: &str) -> (CachingClient, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "ramekin-conditional-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let client = CachingClient::builder()
+            .cache_dir(Some(dir.clone()))
+            .never_network(true)
+            .build()
+            .unwrap();
+        (client, dir)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_if_modified_matching_validators() {
+        let (client, dir) = offline_client("matching");
+        let url = "https://example.com/recipe";
+        client
+            .cache
+            .as_ref()
+            .unwrap()
+            .put(
+                url,
+                b"<html></html>",
+                Some("text/html".to_string()),
+                Some("\"v1\"".to_string()),
+                None,
+            )
+            .unwrap();
+
+        let first = client.fetch_html_if_modified(url, None).await.unwrap();
+        let ConditionalFetch::Modified { html, metadata } = first else {
+            panic!("expected new content without validators");
+        };
+        assert_eq!(html, "<html></html>");
+        assert_eq!(metadata.etag.as_deref(), Some("\"v1\""));
+
+        let second = client
+            .fetch_html_if_modified(url, Some(&metadata))
+            .await
+            .unwrap();
+        assert!(matches!(second, ConditionalFetch::NotModified));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_if_modified_changed_validators() {
+        let (client, dir) = offline_client("changed");
+        let url = "https://example.com/recipe";
+        let cache = client.cache.as_ref().unwrap();
+        cache
+            .put(url, b"old", None, Some("\"v1\"".to_string()), None)
+            .unwrap();
+        let previous = cache.get(url).unwrap().metadata;
+        cache
+            .put(url, b"new", None, Some("\"v2\"".to_string()), None)
+            .unwrap();
+
+        let result = client
+            .fetch_html_if_modified(url, Some(&previous))
+            .await
+            .unwrap();
+        let ConditionalFetch::Modified { html, .. } = result else {
+            panic!("expected new content after the ETag changed");
+        };
+        assert_eq!(html, "new");
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_if_modified_offline_uncached() {
+        let (client, dir) = offline_client("uncached");
+        let result = client
+            .fetch_html_if_modified("https://example.com/missing", None)
+            .await;
+        assert!(matches!(result, Err(FetchError::InvalidUrl(_))));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
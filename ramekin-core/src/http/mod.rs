@@ -3,14 +3,29 @@
 //! All outgoing HTTP requests should go through this module to ensure
 //! consistent caching behavior and avoid hammering external servers.
 
+mod browser;
 mod cache;
 pub(crate) mod charset;
 mod client;
 mod rate_limiter;
+pub(crate) mod retry;
+mod robots;
+mod ssrf;
 
-pub use cache::{CacheStats, CachedError, CachedResponse, DiskCache};
-pub use client::{CachingClient, CachingClientBuilder, HttpClient, MockClient, MockResponse};
+#[cfg(feature = "browser")]
+pub use browser::{find_chrome, ChromeRenderer};
+pub use browser::{BrowserClient, BrowserClientBuilder, BrowserMode, PageRenderer};
+pub use cache::{
+    parse_size, CacheMetadata, CacheStats, CachedError, CachedResponse, Compression, DiskCache,
+    HostCacheStats, HttpCache, PruneStats,
+};
+pub use client::{
+    CachingClient, CachingClientBuilder, ConditionalFetch, HttpClient, MockClient, MockResponse,
+};
 pub use rate_limiter::RateLimiter;
+pub use retry::RetryPolicy;
+pub use robots::{RobotsPolicy, RobotsRules, ROBOTS_USER_AGENT};
+pub use ssrf::is_public_ip;
 
 /// Convert a URL to a filesystem-safe slug.
 /// e.g., "https://www.seriouseats.com/best-chili-recipe-123" -> "seriouseats-com_best-chili-recipe-123"
@@ -1,15 +1,18 @@
 //! Per-host rate limiting for HTTP requests.
 
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 use std::time::{Duration, Instant};
-use tokio::time::sleep;
+use tokio::time::sleep_until;
 
 /// Per-host rate limiter to avoid hammering external servers.
 pub struct RateLimiter {
     /// Minimum delay between requests to the same host.
     min_delay: Duration,
-    /// Last request time per host.
+    /// Time of the latest reserved request slot per host (may be in the future).
     last_request: DashMap<String, Instant>,
+    /// Per-host delays (e.g. robots.txt Crawl-delay) that override a shorter min_delay.
+    host_delays: DashMap<String, Duration>,
 }
 
 impl RateLimiter {
@@ -18,29 +21,49 @@ impl RateLimiter {
         Self {
             min_delay,
             last_request: DashMap::new(),
+            host_delays: DashMap::new(),
         }
     }
 
     /// Wait if necessary before making a request to this host.
-    /// This ensures we don't make requests to the same host faster than min_delay.
+    /// This ensures we don't make requests to the same host faster than min_delay,
+    /// or than the host's own delay if one was set and is longer.
     pub async fn wait(&self, host: &str) {
-        if self.min_delay.is_zero() {
+        let delay = self.delay_for(host);
+        if delay.is_zero() {
             return;
         }
 
+        // Reserve the next slot while holding the entry, so concurrent callers for
+        // the same host queue up behind each other instead of firing together.
         let now = Instant::now();
-
-        // Check if we need to wait
-        if let Some(last) = self.last_request.get(host) {
-            let elapsed = now.duration_since(*last);
-            if elapsed < self.min_delay {
-                let wait_time = self.min_delay - elapsed;
-                sleep(wait_time).await;
+        let slot = match self.last_request.entry(host.to_string()) {
+            Entry::Occupied(mut entry) => {
+                let next = (*entry.get() + delay).max(now);
+                entry.insert(next);
+                next
             }
-        }
+            Entry::Vacant(entry) => {
+                entry.insert(now);
+                now
+            }
+        };
 
-        // Update last request time
-        self.last_request.insert(host.to_string(), Instant::now());
+        sleep_until(slot.into()).await;
+    }
+
+    /// Set a delay for one host, such as the Crawl-delay from its robots.txt.
+    /// It only takes effect when longer than min_delay.
+    pub fn set_host_delay(&self, host: &str, delay: Duration) {
+        self.host_delays.insert(host.to_string(), delay);
+    }
+
+    /// The delay enforced between requests to this host.
+    pub fn delay_for(&self, host: &str) -> Duration {
+        match self.host_delays.get(host) {
+            Some(delay) => (*delay).max(self.min_delay),
+            None => self.min_delay,
+        }
     }
 
     /// Get the number of hosts we've tracked.
@@ -55,3 +78,64 @@ impl Default for RateLimiter {
         Self::new(Duration::from_millis(200))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_delay_overrides_shorter_min_delay() {
+        let limiter = RateLimiter::new(Duration::from_millis(200));
+        limiter.set_host_delay("slow.example", Duration::from_secs(5));
+        limiter.set_host_delay("fast.example", Duration::from_millis(50));
+
+        assert_eq!(limiter.delay_for("slow.example"), Duration::from_secs(5));
+        assert_eq!(
+            limiter.delay_for("fast.example"),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            limiter.delay_for("other.example"),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_waits_are_spaced() {
+        let delay = Duration::from_millis(100);
+        let limiter = std::sync::Arc::new(RateLimiter::new(delay));
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move {
+                    limiter.wait("example.com").await;
+                    Instant::now()
+                })
+            })
+            .collect();
+        let mut finished = Vec::new();
+        for handle in handles {
+            finished.push(handle.await.unwrap());
+        }
+        finished.sort();
+
+        for (i, at) in finished.iter().enumerate() {
+            assert!(
+                at.duration_since(start) >= delay * i as u32,
+                "request {} went out after {:?}",
+                i,
+                at.duration_since(start)
+            );
+        }
+    }
+
+    #[test]
+    fn test_host_delay_applies_without_min_delay() {
+        let limiter = RateLimiter::new(Duration::ZERO);
+        limiter.set_host_delay("slow.example", Duration::from_secs(2));
+        assert_eq!(limiter.delay_for("slow.example"), Duration::from_secs(2));
+        assert_eq!(limiter.delay_for("other.example"), Duration::ZERO);
+    }
+}
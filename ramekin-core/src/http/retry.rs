@@ -0,0 +1,141 @@
+//! Retry policy for transient HTTP failures.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+/// How many times, and how patiently, a request is retried after a transient
+/// failure (see [`FetchError::is_retryable`](crate::FetchError::is_retryable)).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first; 1 disables retries.
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubled for each one after.
+    pub base_delay: Duration,
+    /// Cap on any single wait. A `Retry-After` longer than this isn't waited
+    /// out: the failure is returned instead.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Try each request once.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Wait before retry number `retry` (1-based): exponential backoff with
+    /// jitter, so clients that failed together don't retry together.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        // Equal jitter: somewhere between half and all of the delay
+        delay / 2 + delay.mul_f64(jitter() / 2.0)
+    }
+
+    /// How long a `Retry-After` header asks us to wait, if it's within
+    /// max_delay. Returns None for a missing or unparseable header, and
+    /// Some(None) for one asking for more than we're willing to wait.
+    pub fn retry_after(&self, header: Option<&str>) -> Option<Option<Duration>> {
+        let wait = parse_retry_after(header?)?;
+        Some((wait <= self.max_delay).then_some(wait))
+    }
+}
+
+/// Statuses that signal a temporary condition on the server's side.
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 408 | 425 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// Parse a `Retry-After` value: delay-seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let until = SystemTime::from(date);
+    // A date in the past means "now"
+    Some(
+        until
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// A random fraction in [0, 1). Randomly keyed hashers are plenty for jitter.
+fn jitter() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_with_jitter_and_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+        };
+        for _ in 0..20 {
+            let first = policy.backoff(1);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let second = policy.backoff(2);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+            let capped = policy.backoff(10);
+            assert!(capped >= Duration::from_millis(150) && capped <= Duration::from_millis(300));
+        }
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(is_retryable_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(!is_retryable_status(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(reqwest::StatusCode::FORBIDDEN));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.retry_after(None), None);
+        assert_eq!(policy.retry_after(Some("soon")), None);
+        assert_eq!(
+            policy.retry_after(Some("2")),
+            Some(Some(Duration::from_secs(2)))
+        );
+        // Longer than we're willing to wait
+        assert_eq!(policy.retry_after(Some("3600")), Some(None));
+        // A date in the past
+        assert_eq!(
+            policy.retry_after(Some("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Some(Duration::ZERO))
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! robots.txt fetching, caching and matching.
+//!
+//! Follows RFC 9309: the group naming our product token is used (falling back
+//! to `*`), the longest matching Allow/Disallow pattern wins with Allow winning
+//! ties, and `*` / `$` wildcards are supported. A robots.txt that answers 4xx
+//! allows everything; one that can't be reached (5xx or network error) means
+//! complete disallow until it is retried. The non-standard `Crawl-delay` of the
+//! matched group is picked up for the rate limiter.
+
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::FetchError;
+
+/// Product token matched against robots.txt User-agent lines.
+pub const ROBOTS_USER_AGENT: &str = "Ramekin";
+
+/// Largest robots.txt we read; the rest is ignored (RFC 9309 asks for at least 500 KiB).
+const MAX_ROBOTS_SIZE: usize = 500 * 1024;
+
+/// Cap on Crawl-delay so one site can't stall a crawl indefinitely.
+const MAX_CRAWL_DELAY: Duration = Duration::from_secs(60);
+
+/// How long a fetched robots.txt is trusted.
+const ROBOTS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long an unreachable robots.txt blocks the site before it is retried.
+const UNAVAILABLE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Whether a client consults robots.txt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RobotsPolicy {
+    /// A person asked for this page, as with a browser; robots.txt isn't consulted.
+    #[default]
+    UserInitiated,
+    /// Bulk crawl: enforce Disallow rules and honor Crawl-delay.
+    Crawl,
+}
+
+/// The rules of one robots.txt that apply to our user agent.
+#[derive(Debug, Clone, Default)]
+pub struct RobotsRules {
+    /// (allow, pattern) pairs
+    rules: Vec<(bool, String)>,
+    crawl_delay: Option<Duration>,
+}
+
+impl RobotsRules {
+    /// Rules that allow everything (no robots.txt).
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Parse robots.txt content, keeping the groups that apply to `user_agent`.
+    pub fn parse(content: &str, user_agent: &str) -> Self {
+        let user_agent = user_agent.to_lowercase();
+
+        let mut specific = Self::default();
+        let mut wildcard = Self::default();
+        let mut found_specific = false;
+
+        // Which of the two rule sets the current group feeds
+        let (mut to_specific, mut to_wildcard) = (false, false);
+        let mut in_agents = false;
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            match key.as_str() {
+                "user-agent" => {
+                    if !in_agents {
+                        // A new group starts
+                        to_specific = false;
+                        to_wildcard = false;
+                        in_agents = true;
+                    }
+                    let agent = value.to_lowercase();
+                    if agent == "*" {
+                        to_wildcard = true;
+                    } else if agent == user_agent {
+                        to_specific = true;
+                        found_specific = true;
+                    }
+                }
+                "allow" | "disallow" | "crawl-delay" => {
+                    in_agents = false;
+                    for (matched, target) in
+                        [(to_specific, &mut specific), (to_wildcard, &mut wildcard)]
+                    {
+                        if matched {
+                            target.add(&key, value);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if found_specific {
+            specific
+        } else {
+            wildcard
+        }
+    }
+
+    fn add(&mut self, key: &str, value: &str) {
+        match key {
+            // An empty Disallow allows everything, so it adds nothing
+            "allow" | "disallow" if !value.is_empty() => {
+                self.rules.push((key == "allow", value.to_string()));
+            }
+            "crawl-delay" => {
+                if let Ok(secs) = value.parse::<f64>() {
+                    if secs.is_finite() && secs > 0.0 {
+                        self.crawl_delay = Some(Duration::from_secs_f64(secs).min(MAX_CRAWL_DELAY));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether a path (with query string) may be fetched.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        // robots.txt itself is always allowed
+        if path == "/robots.txt" {
+            return true;
+        }
+
+        let mut best: Option<(usize, bool)> = None;
+        for (allow, pattern) in &self.rules {
+            if !pattern_matches(pattern, path) {
+                continue;
+            }
+            let len = pattern.len();
+            best = match best {
+                Some((best_len, best_allow))
+                    if best_len > len || (best_len == len && best_allow) =>
+                {
+                    Some((best_len, best_allow))
+                }
+                _ => Some((len, *allow)),
+            };
+        }
+        best.is_none_or(|(_, allow)| allow)
+    }
+
+    /// The Crawl-delay for our user agent, capped at a minute.
+    pub fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+/// Match a robots.txt path pattern (`*` matches anything, a trailing `$`
+/// anchors the end) against the start of `path`.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(rest) = path.strip_prefix(parts[0]) else {
+        return false;
+    };
+    if parts.len() == 1 {
+        return !anchored || rest.is_empty();
+    }
+
+    let mut rest = rest;
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+
+    let last = parts[parts.len() - 1];
+    if anchored {
+        rest.ends_with(last)
+    } else {
+        rest.contains(last)
+    }
+}
+
+struct CachedRobots {
+    /// None when robots.txt couldn't be fetched
+    rules: Option<Arc<RobotsRules>>,
+    expires: Instant,
+}
+
+/// In-memory robots.txt cache, keyed by origin.
+pub(crate) struct RobotsCache {
+    user_agent: String,
+    entries: DashMap<String, CachedRobots>,
+}
+
+impl RobotsCache {
+    pub(crate) fn new(user_agent: &str) -> Self {
+        Self {
+            user_agent: user_agent.to_string(),
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Get the rules for the origin of `url`, fetching robots.txt if needed.
+    pub(crate) async fn rules_for(
+        &self,
+        client: &reqwest::Client,
+        url: &reqwest::Url,
+    ) -> Result<Arc<RobotsRules>, FetchError> {
+        let origin = url.origin().ascii_serialization();
+
+        if let Some(cached) = self.entries.get(&origin) {
+            if cached.expires > Instant::now() {
+                return cached
+                    .rules
+                    .clone()
+                    .ok_or_else(|| FetchError::RobotsUnavailable(origin.clone()));
+            }
+        }
+
+        let (rules, ttl) = match self.fetch(client, &origin).await? {
+            Some(rules) => (Some(Arc::new(rules)), ROBOTS_TTL),
+            None => (None, UNAVAILABLE_TTL),
+        };
+        self.entries.insert(
+            origin.clone(),
+            CachedRobots {
+                rules: rules.clone(),
+                expires: Instant::now() + ttl,
+            },
+        );
+        rules.ok_or(FetchError::RobotsUnavailable(origin))
+    }
+
+    /// Fetch and parse robots.txt. Ok(None) means it couldn't be reached.
+    async fn fetch(
+        &self,
+        client: &reqwest::Client,
+        origin: &str,
+    ) -> Result<Option<RobotsRules>, FetchError> {
+        let robots_url = format!("{}/robots.txt", origin);
+        tracing::debug!(url = %robots_url, "network: fetching robots.txt");
+
+        let response = match client.get(&robots_url).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(blocked) = super::ssrf::find_blocked(&e) {
+                    return Err(FetchError::Blocked(blocked.to_string()));
+                }
+                tracing::debug!(url = %robots_url, error = %e, "robots.txt unreachable");
+                return Ok(None);
+            }
+        };
+
+        let status = response.status();
+        if status.is_client_error() {
+            // No robots.txt (or not for us to read): everything is allowed
+            return Ok(Some(RobotsRules::allow_all()));
+        }
+        if !status.is_success() {
+            tracing::debug!(url = %robots_url, %status, "robots.txt unavailable");
+            return Ok(None);
+        }
+
+        let bytes = match response.bytes().await {
+            Ok(bytes) => bytes,
+            Err(_) => return Ok(None),
+        };
+        let body = &bytes[..bytes.len().min(MAX_ROBOTS_SIZE)];
+        Ok(Some(RobotsRules::parse(
+            &String::from_utf8_lossy(body),
+            &self.user_agent,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS: &str = "\
+# Example
+User-agent: *
+Disallow: /wp-admin/
+Allow: /wp-admin/admin-ajax.php
+Disallow: /*?print=
+Disallow: /*.pdf$
+Crawl-delay: 5
+
+User-agent: GPTBot
+User-agent: Ramekin
+Disallow: /recipes/drafts/
+Crawl-delay: 2.5
+
+Sitemap: https://example.com/sitemap.xml
+";
+
+    #[test]
+    fn test_wildcard_group() {
+        let rules = RobotsRules::parse(ROBOTS, "OtherBot");
+        assert!(rules.is_allowed("/recipes/chili/"));
+        assert!(!rules.is_allowed("/wp-admin/options.php"));
+        assert!(rules.is_allowed("/wp-admin/admin-ajax.php"));
+        assert!(!rules.is_allowed("/recipes/chili/?print=1"));
+        assert!(!rules.is_allowed("/files/chili.pdf"));
+        assert!(rules.is_allowed("/files/chili.pdf?download=1"));
+        assert_eq!(rules.crawl_delay(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_specific_group_replaces_wildcard() {
+        let rules = RobotsRules::parse(ROBOTS, ROBOTS_USER_AGENT);
+        assert!(!rules.is_allowed("/recipes/drafts/chili"));
+        // Only the matching group applies, not the * rules
+        assert!(rules.is_allowed("/wp-admin/options.php"));
+        assert_eq!(rules.crawl_delay(), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_agent_matching_is_case_insensitive() {
+        let rules = RobotsRules::parse("user-agent: RAMEKIN\ndisallow: /", "Ramekin");
+        assert!(!rules.is_allowed("/anything"));
+        assert!(rules.is_allowed("/robots.txt"));
+    }
+
+    #[test]
+    fn test_allow_wins_ties_and_empty_disallow_allows() {
+        let rules = RobotsRules::parse("User-agent: *\nDisallow: /page\nAllow: /page", "Ramekin");
+        assert!(rules.is_allowed("/page"));
+
+        let rules = RobotsRules::parse("User-agent: *\nDisallow:", "Ramekin");
+        assert!(rules.is_allowed("/"));
+    }
+
+    #[test]
+    fn test_crawl_delay_capped() {
+        let rules = RobotsRules::parse("User-agent: *\nCrawl-delay: 3600", "Ramekin");
+        assert_eq!(rules.crawl_delay(), Some(MAX_CRAWL_DELAY));
+
+        let rules = RobotsRules::parse("User-agent: *\nCrawl-delay: soon", "Ramekin");
+        assert_eq!(rules.crawl_delay(), None);
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        assert!(pattern_matches("/", "/anything"));
+        assert!(pattern_matches("/a*c", "/abbbc/d"));
+        assert!(pattern_matches("/a*c$", "/abc"));
+        assert!(!pattern_matches("/a*c$", "/abcd"));
+        assert!(pattern_matches("/exact$", "/exact"));
+        assert!(!pattern_matches("/exact$", "/exact/"));
+        assert!(!pattern_matches("/b", "/a/b"));
+    }
+}
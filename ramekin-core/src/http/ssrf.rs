@@ -0,0 +1,301 @@
+//! Server-side request forgery protection.
+//!
+//! Scraped URLs (and image URLs found in scraped pages) are user controlled, so
+//! the client must not be usable to reach the server's own network: loopback,
+//! link-local (cloud metadata), private and unique-local addresses are refused.
+//! Hostnames are checked against the addresses they actually resolve to at
+//! connect time, so redirects and DNS rebinding can't get around the check.
+
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use url::Url;
+
+/// Ports scraping is allowed to connect to (unless the host is exempt).
+const ALLOWED_PORTS: &[u16] = &[80, 443];
+
+/// Maximum number of redirects followed for one request.
+const MAX_REDIRECTS: usize = 10;
+
+/// A URL or address the client refuses to connect to.
+#[derive(Debug, Clone)]
+pub(crate) struct BlockedDestination(pub(crate) String);
+
+impl fmt::Display for BlockedDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for BlockedDestination {}
+
+/// Which destinations the client may connect to.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DestinationPolicy {
+    /// Hosts exempt from the checks, as "host" or "host:port" (e.g. local test fixtures)
+    allowed_private_hosts: Vec<String>,
+}
+
+impl DestinationPolicy {
+    pub(crate) fn new(allowed_private_hosts: Vec<String>) -> Self {
+        Self {
+            allowed_private_hosts,
+        }
+    }
+
+    /// Whether the host is exempt on any port. Used at resolution time, where
+    /// the port isn't known; the port was already checked against the URL.
+    fn is_exempt_host(&self, host: &str) -> bool {
+        self.allowed_private_hosts.iter().any(|allowed| {
+            let allowed_host = match allowed.rsplit_once(':') {
+                Some((h, port)) if port.chars().all(|c| c.is_ascii_digit()) => h,
+                _ => allowed.as_str(),
+            };
+            allowed_host.eq_ignore_ascii_case(host)
+        })
+    }
+
+    fn is_exempt(&self, host: &str, port: Option<u16>) -> bool {
+        self.allowed_private_hosts.iter().any(|allowed| {
+            allowed.eq_ignore_ascii_case(host)
+                || port.is_some_and(|p| allowed.eq_ignore_ascii_case(&format!("{}:{}", host, p)))
+        })
+    }
+
+    /// Check the parts of a URL that can be judged without DNS: scheme, port and
+    /// IP-literal hosts. Hostnames are checked by [`GuardedResolver`].
+    pub(crate) fn check_url(&self, url: &Url) -> Result<(), BlockedDestination> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(BlockedDestination(format!(
+                "scheme '{}' is not allowed",
+                url.scheme()
+            )));
+        }
+
+        let host = url
+            .host_str()
+            .ok_or_else(|| BlockedDestination("URL has no host".to_string()))?;
+        if self.is_exempt(host, url.port()) {
+            return Ok(());
+        }
+
+        if let Some(port) = url.port_or_known_default() {
+            if !ALLOWED_PORTS.contains(&port) {
+                return Err(BlockedDestination(format!("port {} is not allowed", port)));
+            }
+        }
+
+        let ip = match url.host() {
+            Some(url::Host::Ipv4(ip)) => Some(IpAddr::V4(ip)),
+            Some(url::Host::Ipv6(ip)) => Some(IpAddr::V6(ip)),
+            _ => None,
+        };
+        if let Some(ip) = ip {
+            if !is_public_ip(ip) {
+                return Err(BlockedDestination(format!(
+                    "{} is not a public address",
+                    ip
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Redirect policy that applies [`Self::check_url`] to every hop.
+    pub(crate) fn redirect_policy(self: &Arc<Self>) -> reqwest::redirect::Policy {
+        let policy = Arc::clone(self);
+        reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            match policy.check_url(attempt.url()) {
+                Ok(()) => attempt.follow(),
+                Err(blocked) => attempt.error(blocked),
+            }
+        })
+    }
+}
+
+/// DNS resolver that refuses hostnames resolving to non-public addresses.
+///
+/// The addresses checked here are the ones the connection uses, so a host
+/// can't pass the check and then re-resolve somewhere else.
+pub(crate) struct GuardedResolver {
+    policy: Arc<DestinationPolicy>,
+}
+
+impl GuardedResolver {
+    pub(crate) fn new(policy: Arc<DestinationPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+impl Resolve for GuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = Arc::clone(&self.policy);
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+
+            if !policy.is_exempt_host(&host) {
+                if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+                    return Err(BlockedDestination(format!(
+                        "{} resolves to non-public address {}",
+                        host,
+                        addr.ip()
+                    ))
+                    .into());
+                }
+            }
+
+            Ok::<Addrs, Box<dyn Error + Send + Sync>>(Box::new(addrs.into_iter()))
+        })
+    }
+}
+
+/// Find a [`BlockedDestination`] in an error's source chain.
+pub(crate) fn find_blocked<'a>(err: &'a (dyn Error + 'static)) -> Option<&'a BlockedDestination> {
+    let mut current = Some(err);
+    while let Some(e) = current {
+        if let Some(blocked) = e.downcast_ref::<BlockedDestination>() {
+            return Some(blocked);
+        }
+        current = e.source();
+    }
+    None
+}
+
+/// Whether an address is on the public internet.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_ipv4(v4),
+        IpAddr::V6(v6) => is_public_ipv6(v6),
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0 // "this network"
+        || (a == 100 && (64..128).contains(&b)) // carrier-grade NAT
+        || (a == 192 && b == 0 && c == 0) // IETF protocol assignments
+        || (a == 198 && (b == 18 || b == 19)) // benchmarking
+        || a >= 240) // reserved
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    // IPv4-mapped (::ffff:a.b.c.d) and NAT64 (64:ff9b::a.b.c.d) reach IPv4 hosts
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(v4);
+    }
+    let segments = ip.segments();
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., hi, lo] = segments;
+        let v4 = Ipv4Addr::new((hi >> 8) as u8, hi as u8, (lo >> 8) as u8, lo as u8);
+        return is_public_ipv4(v4);
+    }
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || (segments[0] & 0xfe00) == 0xfc00 // unique local fc00::/7
+        || (segments[0] & 0xffc0) == 0xfe80 // link local fe80::/10
+        || (segments[0] & 0xffc0) == 0xfec0 // deprecated site local fec0::/10
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)) // documentation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(policy: &DestinationPolicy, url: &str) -> Result<(), BlockedDestination> {
+        policy.check_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn test_non_public_addresses() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a9fe:a9fe",
+        ] {
+            assert!(
+                !is_public_ip(ip.parse().unwrap()),
+                "{} should be blocked",
+                ip
+            );
+        }
+        for ip in ["93.184.216.34", "8.8.8.8", "2606:4700::1111"] {
+            assert!(
+                is_public_ip(ip.parse().unwrap()),
+                "{} should be allowed",
+                ip
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_url_rejects_schemes_ports_and_literals() {
+        let policy = DestinationPolicy::default();
+        assert!(check(&policy, "https://example.com/recipe").is_ok());
+        assert!(check(&policy, "http://example.com:80/recipe").is_ok());
+        assert!(check(&policy, "ftp://example.com/recipe").is_err());
+        assert!(check(&policy, "file:///etc/passwd").is_err());
+        assert!(check(&policy, "http://example.com:5432/").is_err());
+        assert!(check(&policy, "http://169.254.169.254/latest/meta-data/").is_err());
+        assert!(check(&policy, "http://[::1]/").is_err());
+    }
+
+    #[test]
+    fn test_exempt_hosts() {
+        let policy = DestinationPolicy::new(vec!["localhost:8080".to_string()]);
+        assert!(check(&policy, "http://localhost:8080/recipe").is_ok());
+        assert!(check(&policy, "http://localhost:9090/recipe").is_err());
+        assert!(policy.is_exempt_host("localhost"));
+        assert!(!policy.is_exempt_host("127.0.0.1"));
+    }
+
+    #[tokio::test]
+    async fn test_client_refuses_metadata_address() {
+        use crate::http::{CachingClient, HttpClient};
+        use crate::FetchError;
+
+        let client = CachingClient::builder().cache_dir(None).build().unwrap();
+        let err = client
+            .fetch_html("http://169.254.169.254/latest/meta-data/")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FetchError::Blocked(_)), "got {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_resolver_blocks_hostnames_resolving_to_loopback() {
+        let resolver = GuardedResolver::new(Arc::new(DestinationPolicy::default()));
+        let name: Name = "localhost".parse().unwrap();
+        let err = match resolver.resolve(name).await {
+            Ok(_) => panic!("localhost should be blocked"),
+            Err(e) => e,
+        };
+        assert!(find_blocked(&*err).is_some());
+    }
+}
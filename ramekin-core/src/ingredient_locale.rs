@@ -0,0 +1,697 @@
+//! Unit and phrase tables for non-English ingredient lines.
+//!
+//! Rather than teach every step of the ingredient parser about every
+//! language, a locale line is rewritten into the shape the English parser
+//! already understands ("2 EL Olivenöl" → "2 tbsp Olivenöl", "25 cl de lait"
+//! → "250 ml lait"), and locale notes ("gesiebt", "q.b.") are split off first
+//! since the English prep-note list wouldn't recognize them.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::ingredient_parser::format_decimal_amount;
+use crate::language::Language;
+use crate::metric_weights::parse_amount;
+
+/// Everything language-specific the ingredient parser needs.
+pub(crate) struct LocaleTable {
+    /// Unit spellings (case-insensitive regex) and the English unit they stand
+    /// for. Listed most specific first, since the first match wins.
+    units: &'static [(&'static str, &'static str)],
+    /// Words between a unit and its ingredient ("de", "di", "d'")
+    connectives: &'static [&'static str],
+    /// Number words that start a line ("eine Prise")
+    number_words: &'static [(&'static str, &'static str)],
+    /// Words that follow a number word when it isn't really a quantity ("un peu")
+    vague_quantities: &'static [&'static str],
+    /// Words joining the two ends of a range ("2 bis 3")
+    range_words: &'static [&'static str],
+    /// Prep notes, matched as substrings of the text after the last comma.
+    /// Stems cover gender and number ("hach" for haché/hachée/hachés).
+    prep_notes: &'static [&'static str],
+    /// "To taste" phrases that may end a line without a comma
+    to_taste: &'static [&'static str],
+    /// How group headings start ("Für den Teig:")
+    section_prefixes: &'static [&'static str],
+    /// Words that mark a heading when they appear anywhere in it
+    section_keywords: &'static [&'static str],
+    /// The word for "ingredients"
+    ingredients_word: &'static str,
+    /// Whole lines that are scraper artifacts
+    ignored_lines: &'static [&'static str],
+    /// Line prefixes that mark notes and equipment rather than ingredients
+    ignored_prefixes: &'static [&'static str],
+}
+
+/// Metric units shared by every locale. The ingredient parser knows the
+/// English spellings; these let connectives after them be stripped.
+const METRIC_UNITS: &[(&str, &str)] = &[
+    ("kg", "kg"),
+    ("mg", "mg"),
+    ("g", "g"),
+    ("ml", "ml"),
+    ("cl", "cl"),
+    ("dl", "dl"),
+    ("l", "l"),
+];
+
+const GERMAN: LocaleTable = LocaleTable {
+    units: &[
+        (r"e(?:ssl(?:öffel|\.)?|l)", "tbsp"),
+        (r"t(?:eel(?:öffel|\.)?|l)", "tsp"),
+        (r"messerspitzen?|msp\.?", "pinch"),
+        (r"prisen?", "pinch"),
+        (r"tassen?", "cup"),
+        (r"knoblauchzehen?|zehen?", "clove"),
+        (r"bund", "bunch"),
+        (r"dosen?", "can"),
+        (r"gläser|glas", "jar"),
+        (r"stücke?|stk\.?", "piece"),
+        (r"scheiben?", "slice"),
+        (r"packungen?|päckchen|pck\.?", "package"),
+        (r"zweige?", "sprig"),
+        (r"handvoll", "handful"),
+        (r"kilogramm", "kg"),
+        (r"gramm", "g"),
+        (r"milliliter", "ml"),
+        (r"liter", "l"),
+    ],
+    connectives: &[],
+    number_words: &[
+        ("einen", "1"),
+        ("eine", "1"),
+        ("ein", "1"),
+        ("zwei", "2"),
+        ("drei", "3"),
+        ("vier", "4"),
+        ("fünf", "5"),
+    ],
+    vague_quantities: &["paar", "bisschen", "wenig"],
+    range_words: &["bis"],
+    prep_notes: &[
+        "gehackt",
+        "gesiebt",
+        "gerieben",
+        "geschält",
+        "gewürfelt",
+        "geschnitten",
+        "gepresst",
+        "geschmolzen",
+        "zerlassen",
+        "halbiert",
+        "entkernt",
+        "abgetropft",
+        "gekocht",
+        "weich",
+        "zimmertemperatur",
+        "zimmerwarm",
+        "frisch gemahlen",
+        "zum bestreuen",
+        "zum servieren",
+        "nach geschmack",
+        "nach belieben",
+        "optional",
+    ],
+    to_taste: &["nach geschmack", "nach belieben"],
+    section_prefixes: &["für den ", "für die ", "für das ", "für ", "zum ", "zur "],
+    section_keywords: &[
+        "teig", "füllung", "soße", "sauce", "creme", "glasur", "belag", "streusel", "dressing",
+        "marinade", "topping", "garnitur",
+    ],
+    ingredients_word: "zutaten",
+    ignored_lines: &[
+        "zutaten",
+        "zutatenliste",
+        "das brauchst du",
+        "was du brauchst",
+    ],
+    ignored_prefixes: &["hinweis:", "tipp:", "notiz:", "zubehör:", "utensilien:"],
+};
+
+const FRENCH: LocaleTable = LocaleTable {
+    units: &[
+        (r"cuill(?:ère|ere|erée)s?\s+à\s+soupe", "tbsp"),
+        (r"cuill(?:ère|ere|erée)s?\s+à\s+café", "tsp"),
+        (r"c\.?\s*à\.?\s*s(?:oupe|\.)?", "tbsp"),
+        (r"c\.?\s*à\.?\s*c(?:afé|\.)?", "tsp"),
+        (r"pincées?", "pinch"),
+        (r"tasses?", "cup"),
+        (r"gousses?", "clove"),
+        (r"bottes?", "bunch"),
+        (r"boîtes?", "can"),
+        (r"pots?", "jar"),
+        (r"tranches?", "slice"),
+        (r"sachets?", "package"),
+        (r"brins?", "sprig"),
+        (r"poignées?", "handful"),
+        (r"morceaux?", "piece"),
+        (r"kilos?", "kg"),
+        (r"grammes?", "g"),
+        (r"litres?", "l"),
+    ],
+    connectives: &["de ", "d'", "d’", "du ", "des "],
+    number_words: &[
+        ("une", "1"),
+        ("un", "1"),
+        ("deux", "2"),
+        ("trois", "3"),
+        ("quatre", "4"),
+        ("cinq", "5"),
+    ],
+    vague_quantities: &["peu"],
+    range_words: &["à"],
+    prep_notes: &[
+        "hach",
+        "tamis",
+        "râp",
+        "émincé",
+        "coupé",
+        "pelé",
+        "épépiné",
+        "égoutté",
+        "fondu",
+        "ramolli",
+        "pressé",
+        "battu",
+        "en dés",
+        "en rondelles",
+        "température ambiante",
+        "pour servir",
+        "au goût",
+        "facultatif",
+    ],
+    to_taste: &["au goût", "selon le goût", "à volonté"],
+    section_prefixes: &["pour le ", "pour la ", "pour les ", "pour l'", "pour "],
+    section_keywords: &[
+        "pâte",
+        "garniture",
+        "sauce",
+        "crème",
+        "glaçage",
+        "vinaigrette",
+        "marinade",
+        "décoration",
+    ],
+    ingredients_word: "ingrédients",
+    ignored_lines: &["ingrédients", "liste des ingrédients", "ce qu'il vous faut"],
+    ignored_prefixes: &[
+        "remarque:",
+        "note:",
+        "astuce:",
+        "conseil:",
+        "matériel:",
+        "ustensiles:",
+    ],
+};
+
+const ITALIAN: LocaleTable = LocaleTable {
+    units: &[
+        (r"cucchiain[oi]", "tsp"),
+        (r"cucchia(?:io|i)", "tbsp"),
+        (r"pizzic(?:o|hi)", "pinch"),
+        (r"tazz[ae]", "cup"),
+        (r"spicchi[o]?", "clove"),
+        (r"mazz(?:etto|etti|o|i)", "bunch"),
+        (r"lattin[ae]|scatol[ae]", "can"),
+        (r"vasett[oi]", "jar"),
+        (r"fett[ae]", "slice"),
+        (r"bustin[ae]", "package"),
+        (r"ramett[oi]", "sprig"),
+        (r"manciat[ae]", "handful"),
+        (r"pezz[oi]", "piece"),
+        (r"grammi|grammo", "g"),
+        (r"litri|litro", "l"),
+    ],
+    connectives: &[
+        "di ", "d'", "d’", "del ", "dello ", "della ", "dei ", "degli ", "delle ",
+    ],
+    number_words: &[
+        ("uno", "1"),
+        ("una", "1"),
+        ("un", "1"),
+        ("due", "2"),
+        ("tre", "3"),
+        ("quattro", "4"),
+        ("cinque", "5"),
+        ("mezzo", "1/2"),
+        ("mezza", "1/2"),
+    ],
+    vague_quantities: &["po'", "poco", "pochino"],
+    range_words: &["o"],
+    prep_notes: &[
+        "tritat",
+        "grattugiat",
+        "sbucciat",
+        "tagliat",
+        "sgocciolat",
+        "schiacciat",
+        "ammorbidit",
+        "setacciat",
+        "sciolt",
+        "fuso",
+        "fusa",
+        "a dadini",
+        "a fette",
+        "a rondelle",
+        "a temperatura ambiente",
+        "per servire",
+        "q.b.",
+        "quanto basta",
+        "a piacere",
+        "facoltativ",
+    ],
+    to_taste: &["q.b.", "qb", "quanto basta", "a piacere"],
+    section_prefixes: &[
+        "per il ", "per lo ", "per la ", "per i ", "per gli ", "per le ", "per l'", "per ",
+    ],
+    section_keywords: &[
+        "impasto",
+        "ripieno",
+        "salsa",
+        "crema",
+        "glassa",
+        "condimento",
+        "marinata",
+        "guarnizione",
+    ],
+    ingredients_word: "ingredienti",
+    ignored_lines: &["ingredienti", "cosa serve", "occorrente"],
+    ignored_prefixes: &["nota:", "consiglio:", "attrezzatura:", "utensili:"],
+};
+
+const SPANISH: LocaleTable = LocaleTable {
+    units: &[
+        (r"cucharaditas?", "tsp"),
+        (r"cucharadas?", "tbsp"),
+        (r"pizcas?", "pinch"),
+        (r"tazas?", "cup"),
+        (r"dientes?", "clove"),
+        (r"manojos?", "bunch"),
+        (r"latas?", "can"),
+        (r"frascos?", "jar"),
+        (r"rebanadas?|lonchas?", "slice"),
+        (r"sobres?", "package"),
+        (r"ramitas?", "sprig"),
+        (r"puñados?", "handful"),
+        (r"trozos?", "piece"),
+        (r"kilos?", "kg"),
+        (r"gramos?", "g"),
+        (r"litros?", "l"),
+    ],
+    connectives: &["de ", "del "],
+    number_words: &[
+        ("una", "1"),
+        ("uno", "1"),
+        ("un", "1"),
+        ("dos", "2"),
+        ("tres", "3"),
+        ("cuatro", "4"),
+        ("cinco", "5"),
+        ("media", "1/2"),
+        ("medio", "1/2"),
+    ],
+    vague_quantities: &["poco", "poquito", "chorrito"],
+    range_words: &["a", "o"],
+    prep_notes: &[
+        "picad",
+        "rallad",
+        "pelad",
+        "cortad",
+        "tamizad",
+        "escurrid",
+        "machacad",
+        "derretid",
+        "batid",
+        "en cubos",
+        "en dados",
+        "en rodajas",
+        "a temperatura ambiente",
+        "para servir",
+        "al gusto",
+        "opcional",
+    ],
+    to_taste: &["al gusto", "a gusto"],
+    section_prefixes: &["para el ", "para la ", "para los ", "para las ", "para "],
+    section_keywords: &[
+        "masa",
+        "relleno",
+        "salsa",
+        "crema",
+        "glaseado",
+        "aderezo",
+        "marinada",
+        "cobertura",
+    ],
+    ingredients_word: "ingredientes",
+    ignored_lines: &["ingredientes", "lo que necesitas"],
+    ignored_prefixes: &["nota:", "consejo:", "utensilios:"],
+};
+
+/// The tables for a language, or None for English, which the parser handles natively.
+pub(crate) fn table(language: Language) -> Option<&'static LocaleTable> {
+    match language {
+        Language::En => None,
+        Language::De => Some(&GERMAN),
+        Language::Fr => Some(&FRENCH),
+        Language::It => Some(&ITALIAN),
+        Language::Es => Some(&SPANISH),
+    }
+}
+
+/// A locale's patterns, compiled.
+struct CompiledLocale {
+    /// "2 bis 3" with the range word captured away
+    range: Regex,
+    /// Unit spellings, each matching at the start of the text after the amount
+    /// and consuming the whitespace after the unit
+    units: Vec<(Regex, &'static str)>,
+}
+
+static COMPILED: LazyLock<HashMap<Language, CompiledLocale>> = LazyLock::new(|| {
+    Language::ALL
+        .into_iter()
+        .filter_map(|language| table(language).map(|t| (language, t)))
+        .map(|(language, t)| {
+            let units = t
+                .units
+                .iter()
+                .chain(METRIC_UNITS)
+                .map(|&(pattern, unit)| {
+                    let regex = Regex::new(&format!(r"(?i)^(?:{})(?:\s+|$)", pattern))
+                        .expect("Invalid locale unit regex");
+                    (regex, unit)
+                })
+                .collect();
+            let range_words: Vec<String> = t.range_words.iter().map(|w| regex::escape(w)).collect();
+            let range = Regex::new(&format!(r"(?i)(\d)\s+(?:{})\s+(\d)", range_words.join("|")))
+                .expect("Invalid locale range regex");
+            (language, CompiledLocale { range, units })
+        })
+        .collect()
+});
+
+/// A leading amount: whole, decimal, fraction, mixed number or range.
+static AMOUNT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\d+(?:\.\d+)?(?:\s+\d+/\d+|/\d+)?(?:\s*-\s*\d+(?:\.\d+)?(?:/\d+)?)?)\s*")
+        .expect("Invalid amount regex")
+});
+
+/// A decimal comma between digits ("1,5 kg").
+static DECIMAL_COMMA_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\d),(\d)").expect("Invalid decimal comma regex"));
+
+/// Rewrite a locale ingredient line for the English parser, returning the
+/// rewritten line and any locale note split off its end.
+pub(crate) fn localize_line(
+    line: &str,
+    language: Language,
+    table: &LocaleTable,
+) -> (String, Option<String>) {
+    // Decimal commas first, so "1,5 kg" isn't mistaken for a note separator
+    let line = DECIMAL_COMMA_REGEX.replace_all(line, "$1.$2");
+    let (body, note) = split_note(&line, table);
+    (localize_quantity(&body, language, table), note)
+}
+
+/// Split a trailing locale note off an ingredient line, before the English
+/// parser sees it: "Mehl, gesiebt" → ("Mehl", "gesiebt"),
+/// "sale q.b." → ("sale", "q.b.").
+fn split_note(line: &str, table: &LocaleTable) -> (String, Option<String>) {
+    if let Some((body, note)) = line.rsplit_once(',') {
+        let note_lower = note.trim().to_lowercase();
+        let body = body.trim();
+        if !body.is_empty() && table.prep_notes.iter().any(|p| note_lower.contains(p)) {
+            return (body.to_string(), Some(note.trim().to_string()));
+        }
+    }
+
+    for phrase in table.to_taste {
+        let Some(split) = line.len().checked_sub(phrase.len()) else {
+            continue;
+        };
+        let (Some(body), Some(tail)) = (line.get(..split), line.get(split..)) else {
+            continue;
+        };
+        if tail.to_lowercase() == *phrase && body.ends_with(char::is_whitespace) {
+            let body = body.trim();
+            if !body.is_empty() {
+                return (body.to_string(), Some(tail.to_string()));
+            }
+        }
+    }
+
+    (line.to_string(), None)
+}
+
+/// Rewrite the quantity at the start of a locale line into English units:
+/// "2 c. à soupe d'huile" → "2 tbsp huile", "eine Prise Salz" →
+/// "1 pinch Salz", "2 bis 3 Zehen" → "2-3 clove".
+fn localize_quantity(line: &str, language: Language, table: &LocaleTable) -> String {
+    let Some(compiled) = COMPILED.get(&language) else {
+        return line.to_string();
+    };
+    let line = compiled.range.replace_all(line, "$1-$2");
+    let line = replace_number_word(&line, table);
+
+    let Some((amount, amount_len)) = AMOUNT_REGEX
+        .captures(&line)
+        .map(|caps| (caps[1].to_string(), caps[0].len()))
+    else {
+        return line;
+    };
+    let after_amount = &line[amount_len..];
+
+    let Some((unit_len, unit)) = compiled
+        .units
+        .iter()
+        .find_map(|(regex, unit)| regex.find(after_amount).map(|m| (m.end(), *unit)))
+    else {
+        return line;
+    };
+
+    let mut rest = &after_amount[unit_len..];
+    let rest_lower = rest.to_lowercase();
+    if let Some(connective) = table
+        .connectives
+        .iter()
+        .find(|c| rest_lower.starts_with(*c))
+    {
+        rest = rest.get(connective.len()..).unwrap_or(rest);
+    }
+
+    let (amount, unit) = match unit {
+        "cl" => scale_amount(&amount, 10.0).map_or((amount, "cl"), |a| (a, "ml")),
+        "dl" => scale_amount(&amount, 100.0).map_or((amount, "dl"), |a| (a, "ml")),
+        unit => (amount, unit),
+    };
+    format!("{} {} {}", amount, unit, rest.trim_start())
+        .trim_end()
+        .to_string()
+}
+
+/// Multiply an amount (or both ends of a range) by a factor.
+fn scale_amount(amount: &str, factor: f64) -> Option<String> {
+    amount
+        .split('-')
+        .map(|part| parse_amount(part).map(|value| format_decimal_amount(value * factor)))
+        .collect::<Option<Vec<_>>>()
+        .map(|parts| parts.join("-"))
+}
+
+/// Turn a leading number word into digits ("eine Prise" → "1 Prise"), unless
+/// it's part of a vague quantity like "un peu".
+fn replace_number_word(line: &str, table: &LocaleTable) -> String {
+    let lower = line.to_lowercase();
+    for &(word, digit) in table.number_words {
+        let Some(after) = lower.strip_prefix(word) else {
+            continue;
+        };
+        if !after.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let next_word = after.split_whitespace().next().unwrap_or("");
+        if table.vague_quantities.contains(&next_word) {
+            return line.to_string();
+        }
+        return match line.get(word.len()..) {
+            Some(rest) => format!("{}{}", digit, rest),
+            None => line.to_string(),
+        };
+    }
+    line.to_string()
+}
+
+/// Whether a line is a locale scraper artifact or note rather than an ingredient.
+pub(crate) fn should_ignore_line(line: &str, table: &LocaleTable) -> bool {
+    // French puts a space before colons ("Remarque :")
+    let lower = line.trim().to_lowercase().replace(" :", ":");
+    let bare = lower.trim_end_matches(':').trim();
+    table.ignored_lines.contains(&bare)
+        || table
+            .ignored_prefixes
+            .iter()
+            .any(|prefix| lower.starts_with(prefix))
+}
+
+/// Detect a locale group heading such as "Für den Teig:", "Pour la pâte :"
+/// or "PER LA CREMA". Unlike English headings these aren't title-cased:
+/// German capitalizes nouns and the Romance languages capitalize only the
+/// first word.
+pub(crate) fn detect_section_header(line: &str, table: &LocaleTable) -> Option<String> {
+    let trimmed = line.trim();
+    let has_letters = trimmed.chars().any(|c| c.is_alphabetic());
+    let all_caps = has_letters
+        && trimmed
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .all(|c| c.is_uppercase());
+    let has_digits = trimmed.chars().any(|c| c.is_ascii_digit());
+
+    let name = match trimmed.strip_suffix(':') {
+        Some(name) => name.trim_end(),
+        // Without a colon, only an all-caps line reads as a heading
+        None if all_caps && !has_digits && trimmed.chars().count() <= 40 => {
+            return Some(normalize_section_name(trimmed));
+        }
+        None => return None,
+    };
+    if name.is_empty() || has_digits || name.chars().count() > 50 {
+        return None;
+    }
+
+    let lower = name.to_lowercase();
+    let is_heading = table.section_prefixes.iter().any(|p| lower.starts_with(p))
+        || lower.ends_with(table.ingredients_word)
+        || table.section_keywords.iter().any(|k| lower.contains(k));
+    is_heading.then(|| normalize_section_name(name))
+}
+
+/// "FÜR DEN TEIG" → "Für den teig", "für den Teig" → "Für den Teig".
+pub(crate) fn normalize_section_name(name: &str) -> String {
+    let all_caps = name
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .all(|c| c.is_uppercase());
+    let name = if all_caps {
+        name.to_lowercase()
+    } else {
+        name.to_string()
+    };
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_quantity() {
+        let cases = [
+            (Language::De, "2 EL Olivenöl", "2 tbsp Olivenöl"),
+            (Language::De, "1.5 kg Kartoffeln", "1.5 kg Kartoffeln"),
+            (Language::De, "eine Prise Salz", "1 pinch Salz"),
+            (
+                Language::De,
+                "2 bis 3 Zehen Knoblauch",
+                "2-3 clove Knoblauch",
+            ),
+            (Language::De, "2 Eier", "2 Eier"),
+            (
+                Language::Fr,
+                "2 c. à soupe d'huile d'olive",
+                "2 tbsp huile d'olive",
+            ),
+            (Language::Fr, "1 cuillère à café de sel", "1 tsp sel"),
+            (Language::Fr, "25 cl de lait", "250 ml lait"),
+            (Language::Fr, "un peu de sel", "un peu de sel"),
+            (Language::It, "2 cucchiai di olio", "2 tbsp olio"),
+            (Language::It, "1 cucchiaino di sale", "1 tsp sale"),
+            (Language::It, "2 spicchi d'aglio", "2 clove aglio"),
+            (
+                Language::Es,
+                "2 cucharadas de aceite de oliva",
+                "2 tbsp aceite de oliva",
+            ),
+            (Language::Es, "1 cucharadita de sal", "1 tsp sal"),
+            (Language::Es, "200 g de harina", "200 g harina"),
+        ];
+        for (language, raw, expected) in cases {
+            let table = table(language).unwrap();
+            assert_eq!(localize_quantity(raw, language, table), expected, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_localize_line() {
+        assert_eq!(
+            localize_line("1,5 kg Kartoffeln, gekocht", Language::De, &GERMAN),
+            ("1.5 kg Kartoffeln".to_string(), Some("gekocht".to_string()))
+        );
+        assert_eq!(
+            localize_line("3 gousses d'ail, hachées", Language::Fr, &FRENCH),
+            ("3 clove ail".to_string(), Some("hachées".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_split_note() {
+        assert_eq!(
+            split_note("200 g Mehl, gesiebt", &GERMAN),
+            ("200 g Mehl".to_string(), Some("gesiebt".to_string()))
+        );
+        assert_eq!(
+            split_note("Salz und Pfeffer nach Geschmack", &GERMAN),
+            (
+                "Salz und Pfeffer".to_string(),
+                Some("nach Geschmack".to_string())
+            )
+        );
+        assert_eq!(
+            split_note("sale q.b.", &ITALIAN),
+            ("sale".to_string(), Some("q.b.".to_string()))
+        );
+        assert_eq!(
+            split_note("Mehl, Type 405", &GERMAN),
+            ("Mehl, Type 405".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_detect_section_header() {
+        assert_eq!(
+            detect_section_header("Für den Teig:", &GERMAN),
+            Some("Für den Teig".to_string())
+        );
+        assert_eq!(
+            detect_section_header("Pour la pâte :", &FRENCH),
+            Some("Pour la pâte".to_string())
+        );
+        assert_eq!(
+            detect_section_header("PER LA CREMA", &ITALIAN),
+            Some("Per la crema".to_string())
+        );
+        assert_eq!(
+            detect_section_header("para el relleno:", &SPANISH),
+            Some("Para el relleno".to_string())
+        );
+        assert_eq!(detect_section_header("200 g Mehl", &GERMAN), None);
+        assert_eq!(
+            detect_section_header("Zutaten für 4 Personen:", &GERMAN),
+            None
+        );
+    }
+
+    #[test]
+    fn test_should_ignore_line() {
+        assert!(should_ignore_line("Zutaten:", &GERMAN));
+        assert!(should_ignore_line("Remarque : servir chaud", &FRENCH));
+        assert!(should_ignore_line("Nota: si conserva in frigo", &ITALIAN));
+        assert!(!should_ignore_line("sal al gusto", &SPANISH));
+    }
+}
@@ -8,7 +8,10 @@ use std::sync::LazyLock;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use crate::ingredient_locale;
+use crate::language::Language;
 use crate::metric_weights::parse_amount;
+use crate::types::RecipeSection;
 
 /// A single measurement (amount + unit pair)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -355,7 +358,7 @@ fn strip_measurement_modifier(s: &str) -> (Option<String>, String) {
 
 /// Decode HTML entities using the html-escape crate.
 /// Also handles double-encoded entities like "&amp;#8531;" by decoding twice.
-fn decode_html_entities(s: &str) -> String {
+pub(crate) fn decode_html_entities(s: &str) -> String {
     // First pass: decode entities (this handles &amp; -> & among others)
     let decoded = html_escape::decode_html_entities(s);
 
@@ -371,7 +374,7 @@ fn decode_html_entities(s: &str) -> String {
 /// - Non-breaking spaces → regular spaces
 /// - Unicode fractions (½, ⅓, etc.) → ASCII fractions (1/2, 1/3, etc.)
 /// - Unicode dashes (en-dash, em-dash) → ASCII hyphen
-fn normalize_unicode(s: &str) -> String {
+pub(crate) fn normalize_unicode(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 10);
     let chars: Vec<char> = s.chars().collect();
 
@@ -421,7 +424,7 @@ fn normalize_unicode(s: &str) -> String {
 
 /// Format a decimal amount, stripping trailing zeros.
 /// "0.50" -> "0.5", "1.00" -> "1", "2.50" -> "2.5"
-fn format_decimal_amount(value: f64) -> String {
+pub(crate) fn format_decimal_amount(value: f64) -> String {
     let rounded = (value * 100.0).round() / 100.0;
     if rounded == rounded.floor() {
         format!("{}", rounded as i64)
@@ -1217,6 +1220,33 @@ pub fn parse_ingredient(raw: &str) -> ParsedIngredient {
     }
 }
 
+/// Parse a single ingredient line written in `language`.
+///
+/// Non-English lines have their units, decimal commas and notes rewritten
+/// for the English parser first ("200 g Mehl, gesiebt" parses like
+/// "200 g Mehl" with the note "gesiebt").
+pub fn parse_ingredient_in(raw: &str, language: Language) -> ParsedIngredient {
+    let Some(table) = ingredient_locale::table(language) else {
+        return parse_ingredient(raw);
+    };
+
+    let raw = raw.trim();
+    let decoded = decode_html_entities(raw);
+    let normalized = normalize_unicode(&decoded);
+    let normalized = strip_leading_list_marker(&normalized);
+    let (line, locale_note) = ingredient_locale::localize_line(&normalized, language, table);
+
+    let mut parsed = parse_ingredient(&line);
+    parsed.raw = Some(raw.to_string());
+    if let Some(locale_note) = locale_note {
+        parsed.note = Some(match parsed.note {
+            Some(note) => format!("{}, {}", note, locale_note),
+            None => locale_note,
+        });
+    }
+    parsed
+}
+
 /// Try to parse a string as a measurement (amount + optional unit).
 /// Preserves "each" qualifier as part of the unit (e.g., "8 ounces each" -> unit: "ounces each")
 fn try_parse_measurement(s: &str) -> Option<Measurement> {
@@ -1959,6 +1989,14 @@ pub fn should_ignore_line(raw: &str) -> bool {
     false
 }
 
+/// Like [`should_ignore_line`], also skipping the language's own scraper
+/// artifacts and note lines ("Zutaten:", "Astuce :").
+pub fn should_ignore_line_in(raw: &str, language: Language) -> bool {
+    should_ignore_line(raw)
+        || ingredient_locale::table(language)
+            .is_some_and(|table| ingredient_locale::should_ignore_line(raw, table))
+}
+
 /// Normalize section header capitalization.
 /// - All-caps like "FILLING" → "Filling"
 /// - Mixed case like "For the Steak Fajita Marinade" → kept as-is
@@ -2118,6 +2156,15 @@ pub fn detect_section_header(raw: &str) -> Option<String> {
     None
 }
 
+/// Like [`detect_section_header`], also recognizing the language's group
+/// headings ("Für den Teig:", "Pour la pâte :"). English headings are still
+/// detected, since non-English blogs often use them.
+pub fn detect_section_header_in(raw: &str, language: Language) -> Option<String> {
+    ingredient_locale::table(language)
+        .and_then(|table| ingredient_locale::detect_section_header(raw, table))
+        .or_else(|| detect_section_header(raw))
+}
+
 /// Split a compound item string into individual items.
 ///
 /// Splitting rules:
@@ -2200,6 +2247,11 @@ pub fn expand_each_ingredients(ingredient: ParsedIngredient) -> Vec<ParsedIngred
 /// applies the section name to subsequent ingredients.
 /// Skips lines that should be ignored (scraper artifacts like "Gather Your Ingredients").
 pub fn parse_ingredients(blob: &str) -> Vec<ParsedIngredient> {
+    parse_ingredients_in(blob, Language::En)
+}
+
+/// [`parse_ingredients`] for a blob written in `language`.
+pub fn parse_ingredients_in(blob: &str, language: Language) -> Vec<ParsedIngredient> {
     let mut current_section: Option<String> = None;
     let mut results = Vec::new();
 
@@ -2215,18 +2267,18 @@ pub fn parse_ingredients(blob: &str) -> Vec<ParsedIngredient> {
         }
 
         // Skip lines that should be ignored (scraper artifacts)
-        if should_ignore_line(trimmed) {
+        if should_ignore_line_in(trimmed, language) {
             continue;
         }
 
         // Check if this line is a section header
-        if let Some(section_name) = detect_section_header(trimmed) {
+        if let Some(section_name) = detect_section_header_in(trimmed, language) {
             current_section = Some(section_name);
             continue; // Don't emit the header as an ingredient
         }
 
         // Parse the ingredient and apply current section
-        let mut ingredient = parse_ingredient(trimmed);
+        let mut ingredient = parse_ingredient_in(trimmed, language);
         ingredient.section = current_section.clone();
         // Expand "each" compound ingredients (e.g., "1/2 tsp each salt and pepper" -> 2 ingredients)
         results.extend(expand_each_ingredients(ingredient));
@@ -2235,6 +2287,41 @@ pub fn parse_ingredients(blob: &str) -> Vec<ParsedIngredient> {
     results
 }
 
+/// Parse ingredients using the groups the source marked up ("For the crust").
+/// Each named group sets the section of its ingredients; the header heuristics
+/// of [`parse_ingredients`] only apply to lines outside a named group, or to the
+/// whole blob when the source had no groups.
+pub fn parse_sectioned_ingredients(
+    blob: &str,
+    sections: &[RecipeSection],
+    language: Language,
+) -> Vec<ParsedIngredient> {
+    if sections.is_empty() {
+        return parse_ingredients_in(blob, language);
+    }
+
+    let mut results = Vec::new();
+    for section in sections {
+        let mut ingredients = parse_ingredients_in(&section.items.join("\n"), language);
+        let name = section
+            .name
+            .as_deref()
+            .map(|name| name.trim_end_matches(':').trim_end())
+            .map(|name| match ingredient_locale::table(language) {
+                Some(_) => ingredient_locale::normalize_section_name(name),
+                None => normalize_section_name(name),
+            })
+            .filter(|name| !name.is_empty());
+        if let Some(name) = name {
+            for ingredient in &mut ingredients {
+                ingredient.section = Some(name.clone());
+            }
+        }
+        results.extend(ingredients);
+    }
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2470,6 +2557,50 @@ mod tests {
         assert_eq!(result[2].item, "spaghetti");
     }
 
+    #[test]
+    fn test_parse_sectioned_ingredients() {
+        // "Crust" has no colon or caps, so only the source's grouping knows it's a header
+        let blob = "1 cup flour\n4 tbsp butter\n2 cups berries";
+        let sections = vec![
+            RecipeSection {
+                name: Some("Crust".to_string()),
+                items: vec!["1 cup flour".to_string(), "4 tbsp butter".to_string()],
+            },
+            RecipeSection {
+                name: Some("for the filling".to_string()),
+                items: vec!["2 cups berries".to_string()],
+            },
+        ];
+        let result = parse_sectioned_ingredients(blob, &sections, Language::En);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].section, Some("Crust".to_string()));
+        assert_eq!(result[1].section, Some("Crust".to_string()));
+        assert_eq!(result[2].section, Some("For the Filling".to_string()));
+        assert_eq!(result[2].item, "berries");
+
+        // Without source groups, the header heuristics still apply
+        let result = parse_sectioned_ingredients("FILLING\n2 cups berries", &[], Language::En);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].section, Some("Filling".to_string()));
+    }
+
+    #[test]
+    fn test_parse_ingredients_in_german() {
+        let blob = "Zutaten:\nFür den Teig:\n200 g Mehl, gesiebt\n1 Prise Salz\n\
+                    Für die Füllung:\n3 Äpfel\nTipp: Die Äpfel nicht schälen.";
+        let result = parse_ingredients_in(blob, Language::De);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].item, "Mehl");
+        assert_eq!(result[0].note, Some("gesiebt".to_string()));
+        assert_eq!(result[0].raw, Some("200 g Mehl, gesiebt".to_string()));
+        assert_eq!(result[0].section, Some("Für den Teig".to_string()));
+        assert_eq!(result[1].measurements[0].unit, Some("pinch".to_string()));
+        assert_eq!(result[2].item, "Äpfel");
+        assert_eq!(result[2].section, Some("Für die Füllung".to_string()));
+    }
+
     #[test]
     fn test_parse_ingredients_no_sections() {
         let blob = "1 cup flour\n2 eggs\n1 tsp salt";
@@ -0,0 +1,222 @@
+//! Recipe language detection.
+//!
+//! Ingredient parsing is table-driven per language ("2 EL" is German for
+//! "2 tbsp"), so we need to know which tables to use. Recipes don't reliably
+//! declare their language, so we guess it from the words they use.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::RawRecipe;
+
+/// A language we have ingredient parsing tables for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    De,
+    Fr,
+    It,
+    Es,
+}
+
+impl Language {
+    pub const ALL: [Language; 5] = [
+        Language::En,
+        Language::De,
+        Language::Fr,
+        Language::It,
+        Language::Es,
+    ];
+
+    /// ISO 639-1 code, e.g. "de".
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::De => "de",
+            Language::Fr => "fr",
+            Language::It => "it",
+            Language::Es => "es",
+        }
+    }
+
+    /// Parse a language code or tag such as "de", "fr-CA" or "it_IT".
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        Language::ALL
+            .into_iter()
+            .find(|language| language.code() == primary)
+    }
+
+    /// Common words that give the language away. Cooking vocabulary is
+    /// included because ingredient lists have few function words.
+    fn marker_words(self) -> &'static [&'static str] {
+        match self {
+            Language::En => &[
+                "the", "and", "with", "until", "into", "of", "to", "for", "or", "cup", "cups",
+                "minutes", "add", "about", "chopped",
+            ],
+            Language::De => &[
+                "und", "der", "die", "das", "mit", "den", "dem", "bis", "ein", "eine", "auf",
+                "für", "oder", "zu", "im", "minuten", "el", "tl", "prise", "salz",
+            ],
+            Language::Fr => &[
+                "le",
+                "les",
+                "et",
+                "du",
+                "des",
+                "avec",
+                "dans",
+                "pour",
+                "une",
+                "au",
+                "aux",
+                "à",
+                "sur",
+                "puis",
+                "pendant",
+                "cuillère",
+                "sel",
+                "farine",
+            ],
+            Language::It => &[
+                "il",
+                "gli",
+                "e",
+                "di",
+                "del",
+                "della",
+                "con",
+                "per",
+                "una",
+                "nel",
+                "nella",
+                "poi",
+                "fino",
+                "minuti",
+                "cucchiaio",
+                "cucchiai",
+                "sale",
+                "farina",
+            ],
+            Language::Es => &[
+                "el",
+                "los",
+                "las",
+                "y",
+                "del",
+                "con",
+                "para",
+                "en",
+                "una",
+                "hasta",
+                "minutos",
+                "cucharada",
+                "cucharadas",
+                "que",
+                "al",
+                "sal",
+                "harina",
+            ],
+        }
+    }
+}
+
+/// A non-English language must outscore English by this many marker words
+/// before we trust it; short or mixed text stays English.
+const MIN_MARGIN: usize = 3;
+
+/// Guess the language of a piece of recipe text. Falls back to English when
+/// nothing else is clearly ahead.
+pub fn detect_language(text: &str) -> Language {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let score = |language: Language| {
+        words
+            .iter()
+            .filter(|w| language.marker_words().contains(*w))
+            .count()
+    };
+    let english = score(Language::En);
+
+    Language::ALL
+        .into_iter()
+        .filter(|&language| language != Language::En)
+        .map(|language| (language, score(language)))
+        .filter(|&(_, s)| s >= english + MIN_MARGIN)
+        .max_by_key(|&(_, s)| s)
+        .map(|(language, _)| language)
+        .unwrap_or(Language::En)
+}
+
+/// The recipe's language: what extraction recorded, or a guess from its text.
+pub fn recipe_language(recipe: &RawRecipe) -> Language {
+    recipe.language.unwrap_or_else(|| {
+        detect_language(&format!(
+            "{}\n{}\n{}",
+            recipe.title, recipe.ingredients, recipe.instructions
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(
+            detect_language(
+                "200 g Mehl, gesiebt\n2 EL Olivenöl\n1 Prise Salz\n\
+                 Den Ofen auf 180 Grad vorheizen. Mehl mit dem Salz und der Butter mischen."
+            ),
+            Language::De
+        );
+        assert_eq!(
+            detect_language(
+                "200 g de farine\n2 c. à soupe d'huile d'olive\n\
+                 Mélanger la farine avec le sel dans un saladier, puis ajouter les œufs."
+            ),
+            Language::Fr
+        );
+        assert_eq!(
+            detect_language(
+                "300 g di farina 00\n2 cucchiai di olio\n\
+                 Unire la farina con il sale e le uova, poi impastare fino a ottenere una palla."
+            ),
+            Language::It
+        );
+        assert_eq!(
+            detect_language(
+                "200 g de harina\n2 cucharadas de aceite\n\
+                 Mezclar la harina con la sal y los huevos hasta que quede una masa."
+            ),
+            Language::Es
+        );
+        assert_eq!(
+            detect_language(
+                "2 cups flour\n1 tsp salt\nMix the flour and salt with the butter until crumbly."
+            ),
+            Language::En
+        );
+        assert_eq!(detect_language(""), Language::En);
+    }
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(Language::from_code("de"), Some(Language::De));
+        assert_eq!(Language::from_code("fr-CA"), Some(Language::Fr));
+        assert_eq!(Language::from_code("it_IT"), Some(Language::It));
+        assert_eq!(Language::from_code("nl"), None);
+    }
+}
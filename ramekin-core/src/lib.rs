@@ -1,29 +1,39 @@
 pub mod ai;
+pub mod duration_parser;
 pub mod error;
 pub mod extract;
 pub mod fetch;
 pub mod http;
 pub mod image;
 pub mod ingredient_categorizer;
+mod ingredient_locale;
 pub mod ingredient_parser;
+pub mod language;
 pub mod metric_weights;
 pub mod pipeline;
+pub mod quality;
+mod recipe_cards;
 pub mod types;
 pub mod volume_to_weight;
+pub mod yield_parser;
 
 pub use error::{ExtractError, FetchError};
-pub use extract::{extract_recipe, extract_recipe_with_stats};
+pub use extract::{
+    extract_recipe, extract_recipe_from_text, extract_recipe_with_stats, find_alternate_pages,
+};
 pub use fetch::{fetch_bytes, fetch_html};
 pub use http::{
-    CacheStats, CachingClient, CachingClientBuilder, DiskCache, HttpClient, MockClient,
-    MockResponse,
+    CacheMetadata, CacheStats, CachingClient, CachingClientBuilder, ConditionalFetch, DiskCache,
+    HttpCache, HttpClient, MockClient, MockResponse, RetryPolicy, RobotsPolicy,
 };
 pub use image::{fetch_and_validate_image, validate_image, FetchedImage, MAX_FILE_SIZE};
+pub use language::{detect_language, recipe_language, Language};
+pub use quality::{assess_recipe, ExtractionQuality, QualityWarning, QualityWarningKind};
 pub use types::{
-    EnrichAutoTagOutput, EnrichGeneratePhotoOutput, EnrichNormalizeIngredientsOutput,
-    ExtractRecipeOutput, ExtractionAttempt, ExtractionMethod, FailedImageFetch, FetchHtmlOutput,
-    FetchImagesOutput, ParseIngredientsOutput, PipelineStep, RawRecipe, SaveRecipeOutput,
-    StepOutput,
+    AlternateKind, AlternatePage, EnrichAutoTagOutput, EnrichGeneratePhotoOutput,
+    EnrichNormalizeIngredientsOutput, ExtractRecipeOutput, ExtractionAttempt, ExtractionMethod,
+    FailedImageFetch, FetchHtmlOutput, FetchImagesOutput, ParseIngredientsOutput, PipelineStep,
+    RawRecipe, RecipeSection, SaveRecipeOutput, StepOutput,
 };
 pub use volume_to_weight::enrich_ingredient_measurements;
 
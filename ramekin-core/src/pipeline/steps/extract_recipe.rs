@@ -1,18 +1,76 @@
 //! ExtractRecipe step - extracts recipe data from HTML.
 
+use std::sync::Arc;
 use std::time::Instant;
 
 use async_trait::async_trait;
 
-use crate::extract::extract_recipe_with_stats;
+use crate::error::ExtractError;
+use crate::extract::{extract_recipe_with_stats, find_alternate_pages};
+use crate::http::HttpClient;
+use crate::language::recipe_language;
 use crate::pipeline::{PipelineStep, StepContext, StepMetadata, StepResult};
+use crate::quality::assess_recipe;
+use crate::types::{ExtractRecipeOutput, ExtractionMethod};
 
 /// Step that extracts recipe data from HTML using JSON-LD or microdata.
-pub struct ExtractRecipeStep;
+///
+/// When the page has no structured recipe, its printer-friendly or AMP version
+/// is fetched with the injected HTTP client and tried instead.
+pub struct ExtractRecipeStep {
+    client: Arc<dyn HttpClient>,
+}
 
 impl ExtractRecipeStep {
     /// Step name constant.
     pub const NAME: &'static str = "extract_recipe";
+
+    /// Create a new ExtractRecipeStep that fetches alternate pages with the
+    /// given HTTP client.
+    pub fn new(client: Arc<dyn HttpClient>) -> Self {
+        Self { client }
+    }
+
+    /// Extract from the page, falling back to its alternates when the page
+    /// only yields an HTML heuristic result or nothing at all. A structured
+    /// result from an alternate wins; otherwise the page's own result stands.
+    /// The recipe keeps the page's URL as its source.
+    async fn extract(&self, html: &str, url: &str) -> Result<ExtractRecipeOutput, ExtractError> {
+        let result = extract_recipe_with_stats(html, url);
+        if matches!(&result, Ok(output) if output.method_used != ExtractionMethod::HtmlFallback) {
+            return result;
+        }
+
+        let mut fallback = None;
+        for alternate in find_alternate_pages(html, url) {
+            let alternate_html = match self.client.fetch_html(&alternate.url).await {
+                Ok(h) => h,
+                Err(e) => {
+                    tracing::debug!("Failed to fetch alternate page {}: {}", alternate.url, e);
+                    continue;
+                }
+            };
+            let Ok(mut output) = extract_recipe_with_stats(&alternate_html, &alternate.url) else {
+                continue;
+            };
+            for recipe in std::iter::once(&mut output.raw_recipe).chain(&mut output.other_recipes) {
+                recipe.source_url = Some(url.to_string());
+            }
+            let structured = output.method_used != ExtractionMethod::HtmlFallback;
+            output.alternate = Some(alternate);
+            if structured {
+                return Ok(output);
+            }
+            if fallback.is_none() {
+                fallback = Some(output);
+            }
+        }
+
+        match (result, fallback) {
+            (Err(_), Some(output)) => Ok(output),
+            (result, _) => result,
+        }
+    }
 }
 
 #[async_trait]
@@ -57,15 +115,23 @@ impl PipelineStep for ExtractRecipeStep {
             }
         };
 
-        match extract_recipe_with_stats(html, ctx.url) {
-            Ok(output) => StepResult {
-                step_name: Self::NAME.to_string(),
-                success: true,
-                output: serde_json::to_value(&output).unwrap_or_default(),
-                error: None,
-                duration_ms: start.elapsed().as_millis() as u64,
-                next_step: Some("fetch_images".to_string()),
-            },
+        match self.extract(html, ctx.url).await {
+            Ok(mut output) => {
+                for recipe in
+                    std::iter::once(&mut output.raw_recipe).chain(&mut output.other_recipes)
+                {
+                    recipe.language = Some(recipe_language(recipe));
+                }
+                output.quality = Some(assess_recipe(&output.raw_recipe, output.method_used));
+                StepResult {
+                    step_name: Self::NAME.to_string(),
+                    success: true,
+                    output: serde_json::to_value(&output).unwrap_or_default(),
+                    error: None,
+                    duration_ms: start.elapsed().as_millis() as u64,
+                    next_step: Some("fetch_images".to_string()),
+                }
+            }
             Err(e) => StepResult {
                 step_name: Self::NAME.to_string(),
                 success: false,
@@ -77,3 +143,63 @@ impl PipelineStep for ExtractRecipeStep {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use serde_json::{json, Value as JsonValue};
+
+    use super::*;
+    use crate::http::MockClient;
+    use crate::pipeline::StepOutputStore;
+    use crate::types::AlternateKind;
+
+    struct PageStore(JsonValue);
+
+    impl StepOutputStore for PageStore {
+        fn get_output(&self, step_name: &str) -> Option<JsonValue> {
+            (step_name == "fetch_html").then(|| self.0.clone())
+        }
+
+        fn save_output(
+            &mut self,
+            _step_name: &str,
+            _output: &JsonValue,
+        ) -> Result<(), Box<dyn Error + Send + Sync>> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extracts_from_print_view() {
+        let page = r#"<html><body>
+            <h1>Chili</h1>
+            <a class="wprm-recipe-print" href="/wprm_print/chili">Print Recipe</a>
+        </body></html>"#;
+        let print_view = r#"<html><head><script type="application/ld+json">
+            {"@type": "Recipe", "name": "Chili", "recipeIngredient": ["1 lb beef"],
+             "recipeInstructions": "Simmer."}
+        </script></head></html>"#;
+        let client =
+            MockClient::new().with_html("https://example.com/wprm_print/chili", print_view);
+        let step = ExtractRecipeStep::new(Arc::new(client));
+        let store = PageStore(json!({ "html": page }));
+        let ctx = StepContext {
+            url: "https://example.com/chili/",
+            outputs: &store,
+        };
+
+        let result = step.execute(&ctx).await;
+        assert!(result.success, "{:?}", result.error);
+        let output: ExtractRecipeOutput = serde_json::from_value(result.output).unwrap();
+        assert_eq!(output.method_used, ExtractionMethod::JsonLd);
+        assert_eq!(
+            output.raw_recipe.source_url.as_deref(),
+            Some("https://example.com/chili/")
+        );
+        let alternate = output.alternate.unwrap();
+        assert_eq!(alternate.url, "https://example.com/wprm_print/chili");
+        assert_eq!(alternate.kind, AlternateKind::Print);
+    }
+}
@@ -5,7 +5,8 @@ use std::time::Instant;
 
 use async_trait::async_trait;
 
-use crate::ingredient_parser::parse_ingredients;
+use crate::ingredient_parser::parse_sectioned_ingredients;
+use crate::language::recipe_language;
 use crate::metric_weights::{add_metric_weight_alternative, MetricConversionStats};
 use crate::pipeline::{PipelineStep, StepContext, StepMetadata, StepResult};
 use crate::types::{ParseIngredientsOutput, RawRecipe};
@@ -71,8 +72,12 @@ impl PipelineStep for ParseIngredientsStep {
             }
         };
 
-        // Parse the ingredients blob into structured data
-        let parsed = parse_ingredients(&raw_recipe.ingredients);
+        // Parse the ingredients blob into structured data, grouped as the source grouped them
+        let parsed = parse_sectioned_ingredients(
+            &raw_recipe.ingredients,
+            &raw_recipe.ingredient_sections,
+            recipe_language(&raw_recipe),
+        );
 
         // Enrich with metric weight alternatives (oz/lb → g)
         let mut weight_stats = MetricConversionStats::default();
@@ -0,0 +1,309 @@
+//! Extraction quality scoring.
+//!
+//! Structured data tells us a page *has* a recipe, not that what we pulled out
+//! of it is any good. This pass looks for the tell-tale signs of a bad
+//! extraction so the user knows to review the recipe before trusting it.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::ingredient_parser::detect_section_header_in;
+use crate::language::recipe_language;
+use crate::types::{ExtractionMethod, RawRecipe};
+
+/// What a quality warning is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityWarningKind {
+    /// The ingredient list reads like instructions
+    IngredientsLookLikeInstructions,
+    /// An ingredient line holds several ingredients run together
+    ConcatenatedIngredients,
+    /// Unfinished placeholder text such as "TK" or "TODO"
+    PlaceholderText,
+    /// The instructions carry ads or newsletter sign-up text
+    PromotionalInstructions,
+    /// No prep, cook or total time
+    MissingTimes,
+}
+
+impl QualityWarningKind {
+    /// How much the warning lowers confidence in the extraction.
+    fn penalty(self) -> f64 {
+        match self {
+            QualityWarningKind::IngredientsLookLikeInstructions => 0.4,
+            QualityWarningKind::ConcatenatedIngredients => 0.2,
+            QualityWarningKind::PlaceholderText => 0.15,
+            QualityWarningKind::PromotionalInstructions => 0.15,
+            QualityWarningKind::MissingTimes => 0.05,
+        }
+    }
+}
+
+/// A suspicious part of an extracted recipe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityWarning {
+    pub kind: QualityWarningKind,
+    /// Human-readable explanation, quoting the offending text where there is one
+    pub message: String,
+}
+
+/// How far an extracted recipe can be trusted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractionQuality {
+    /// From 0.0 (almost certainly wrong) to 1.0 (clean structured data)
+    pub confidence: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<QualityWarning>,
+}
+
+/// Placeholder words left in unfinished recipes. TK is journalism shorthand
+/// for "to come"; it's only matched in capitals so words like "tk" in URLs
+/// don't count.
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(TK|TODO|TBD|XXX)\b|(?i:lorem ipsum)").expect("Invalid placeholder regex")
+});
+
+/// A quantity followed by a unit, e.g. "3/4 cup", "60ml", "1 1/2 tbsp".
+static QUANTITY_UNIT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b\d+(?:[./]\d+)?(?:\s+\d+/\d+)?\s*(?:cups?|tablespoons?|tbsps?|teaspoons?|tsps?|ounces?|oz|pounds?|lbs?|grams?|g|kg|ml|l|liters?|litres?|cloves?|pinch(?:es)?)\b",
+    )
+    .expect("Invalid quantity regex")
+});
+
+/// Parenthesized asides, which often repeat a quantity in other units.
+static PARENTHETICAL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\([^)]*\)").expect("Invalid parenthetical regex"));
+
+/// Words that join two quantities of the same ingredient.
+const QUANTITY_JOINERS: &[&str] = &["or", "plus", "and", "to", "-", "+", "/", "x"];
+
+/// Verbs an instruction step starts with but an ingredient line doesn't.
+const INSTRUCTION_VERBS: &[&str] = &[
+    "preheat", "heat", "mix", "stir", "whisk", "combine", "add", "bake", "cook", "bring", "pour",
+    "place", "remove", "transfer", "serve", "simmer", "fold", "beat", "cover", "let", "season",
+    "spread", "reduce", "roll",
+];
+
+/// Ad and newsletter phrases that slip into scraped instructions.
+const PROMOTIONAL_PHRASES: &[&str] = &[
+    "subscribe",
+    "newsletter",
+    "sign up",
+    "advertisement",
+    "affiliate link",
+    "click here",
+    "follow us",
+    "pin this",
+    "jump to recipe",
+    "as an amazon associate",
+];
+
+/// Score an extracted recipe and list what looks wrong with it.
+pub fn assess_recipe(recipe: &RawRecipe, method: ExtractionMethod) -> ExtractionQuality {
+    let language = recipe_language(recipe);
+    let ingredient_lines: Vec<&str> = recipe
+        .ingredients
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && detect_section_header_in(line, language).is_none())
+        .collect();
+
+    let mut warnings = Vec::new();
+    let mut warn = |kind, message: String| warnings.push(QualityWarning { kind, message });
+
+    let sentence_lines = ingredient_lines
+        .iter()
+        .filter(|line| looks_like_instruction(line))
+        .count();
+    if ingredient_lines.len() >= 2 && sentence_lines * 2 > ingredient_lines.len() {
+        warn(
+            QualityWarningKind::IngredientsLookLikeInstructions,
+            format!(
+                "{} of {} ingredient lines read like instructions",
+                sentence_lines,
+                ingredient_lines.len()
+            ),
+        );
+    }
+
+    if let Some(line) = ingredient_lines
+        .iter()
+        .find(|line| looks_concatenated(line))
+    {
+        warn(
+            QualityWarningKind::ConcatenatedIngredients,
+            format!("Several ingredients may be run together: \"{}\"", line),
+        );
+    }
+
+    let placeholder = [&recipe.title, &recipe.ingredients, &recipe.instructions]
+        .into_iter()
+        .find_map(|text| PLACEHOLDER_REGEX.find(text));
+    if let Some(found) = placeholder {
+        warn(
+            QualityWarningKind::PlaceholderText,
+            format!("Contains placeholder text \"{}\"", found.as_str()),
+        );
+    }
+
+    let instructions = recipe.instructions.to_lowercase();
+    if let Some(phrase) = PROMOTIONAL_PHRASES
+        .iter()
+        .find(|phrase| instructions.contains(*phrase))
+    {
+        warn(
+            QualityWarningKind::PromotionalInstructions,
+            format!("Instructions contain promotional text (\"{}\")", phrase),
+        );
+    }
+
+    if recipe.prep_time.is_none() && recipe.cook_time.is_none() && recipe.total_time.is_none() {
+        warn(
+            QualityWarningKind::MissingTimes,
+            "No prep, cook or total time".to_string(),
+        );
+    }
+
+    let penalty: f64 = warnings.iter().map(|w| w.kind.penalty()).sum();
+    let confidence = (method_confidence(method) - penalty).clamp(0.0, 1.0);
+    ExtractionQuality {
+        confidence: (confidence * 100.0).round() / 100.0,
+        warnings,
+    }
+}
+
+/// How much to trust a recipe from this method before looking at its content.
+fn method_confidence(method: ExtractionMethod) -> f64 {
+    match method {
+        ExtractionMethod::Paprika => 1.0,
+        ExtractionMethod::JsonLd => 0.95,
+        ExtractionMethod::Microdata
+        | ExtractionMethod::WpRecipeMaker
+        | ExtractionMethod::TastyRecipes
+        | ExtractionMethod::MediavineCreate
+        | ExtractionMethod::ZipRecipes => 0.9,
+        ExtractionMethod::PhotoUpload | ExtractionMethod::DocumentAi => 0.75,
+        ExtractionMethod::HtmlFallback | ExtractionMethod::DocumentText => 0.6,
+    }
+}
+
+/// A long sentence, or a line opening with a cooking verb and no quantity.
+fn looks_like_instruction(line: &str) -> bool {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    if words.len() >= 12 && line.ends_with('.') {
+        return true;
+    }
+    let first = words
+        .first()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphabetic()).to_lowercase())
+        .unwrap_or_default();
+    words.len() >= 4 && INSTRUCTION_VERBS.contains(&first.as_str())
+}
+
+/// Two quantities with ingredient words between them, like
+/// "3/4 cup (180ml) milk 1/4 cup (60ml) vegetable oil". Quantities joined by
+/// "or"/"plus" ("1 cup plus 2 tbsp flour") are one ingredient.
+fn looks_concatenated(line: &str) -> bool {
+    let stripped = PARENTHETICAL_REGEX.replace_all(line, " ");
+    let matches: Vec<_> = QUANTITY_UNIT_REGEX.find_iter(&stripped).collect();
+    matches.windows(2).any(|pair| {
+        let between = &stripped[pair[0].end()..pair[1].start()];
+        let words: Vec<String> = between
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        words.iter().any(|w| w.chars().any(char::is_alphabetic))
+            && !words.iter().any(|w| QUANTITY_JOINERS.contains(&w.as_str()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipe(ingredients: &str, instructions: &str) -> RawRecipe {
+        RawRecipe {
+            title: "Pancakes".to_string(),
+            description: None,
+            ingredients: ingredients.to_string(),
+            instructions: instructions.to_string(),
+            image_urls: vec![],
+            source_url: None,
+            source_name: None,
+            servings: None,
+            prep_time: Some("10 minutes".to_string()),
+            cook_time: None,
+            total_time: None,
+            rating: None,
+            difficulty: None,
+            nutritional_info: None,
+            notes: None,
+            categories: None,
+            video_url: None,
+            equipment: None,
+            author: None,
+            published_date: None,
+            language: None,
+            ingredient_sections: vec![],
+            instruction_sections: vec![],
+        }
+    }
+
+    fn kinds(quality: &ExtractionQuality) -> Vec<QualityWarningKind> {
+        quality.warnings.iter().map(|w| w.kind).collect()
+    }
+
+    #[test]
+    fn test_clean_recipe_has_no_warnings() {
+        let quality = assess_recipe(
+            &recipe(
+                "1 cup (120g) flour\n1 cup plus 2 tbsp milk\n2 eggs",
+                "Whisk everything together. Cook on a hot griddle.",
+            ),
+            ExtractionMethod::JsonLd,
+        );
+        assert!(quality.warnings.is_empty(), "{:?}", quality.warnings);
+        assert_eq!(quality.confidence, 0.95);
+    }
+
+    #[test]
+    fn test_flags_suspicious_extractions() {
+        let mut raw = recipe(
+            "3/4 cup (180ml) milk 1/4 cup (60ml) vegetable oil\n(TK g) panko bread crumbs",
+            "Fry until golden. Sign up for our newsletter!",
+        );
+        raw.prep_time = None;
+        let quality = assess_recipe(&raw, ExtractionMethod::WpRecipeMaker);
+        assert_eq!(
+            kinds(&quality),
+            vec![
+                QualityWarningKind::ConcatenatedIngredients,
+                QualityWarningKind::PlaceholderText,
+                QualityWarningKind::PromotionalInstructions,
+                QualityWarningKind::MissingTimes,
+            ]
+        );
+        assert_eq!(quality.confidence, 0.35);
+    }
+
+    #[test]
+    fn test_flags_ingredients_that_read_like_instructions() {
+        let quality = assess_recipe(
+            &recipe(
+                "Preheat the oven to 350 degrees.\nMix the flour and sugar in a bowl.\n2 eggs",
+                "Bake.",
+            ),
+            ExtractionMethod::HtmlFallback,
+        );
+        assert_eq!(
+            kinds(&quality),
+            vec![QualityWarningKind::IngredientsLookLikeInstructions]
+        );
+        assert_eq!(quality.confidence, 0.2);
+    }
+}
@@ -0,0 +1,580 @@
+//! Extractors for the recipe card plugins most food blogs use (WP Recipe Maker,
+//! Tasty Recipes, Mediavine Create, Zip Recipes).
+//!
+//! Each plugin renders its card with a fixed set of classes, so one generic
+//! extractor driven by a table of selectors covers them all. Cards keep things the
+//! generic class heuristics flatten away, like ingredient and instruction groups.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::error::ExtractError;
+use crate::extract::{collect_recipes, extract_og_image, extract_source_name, http_url};
+use crate::types::{ExtractionMethod, RawRecipe, RecipeSection};
+
+/// A recipe card plugin.
+pub(crate) struct RecipeCardFormat {
+    pub method: ExtractionMethod,
+    /// Appears in the raw HTML of any page using the plugin, so pages without it
+    /// can skip this format without a selector match
+    pub marker: &'static str,
+    /// Parsed on first use, so formats that never show up cost nothing
+    selectors: LazyLock<CardSelectors>,
+}
+
+/// Where a recipe card plugin puts each part of the recipe. Selectors are
+/// matched inside the card's container.
+struct CardSelectors {
+    container: Selector,
+    title: Selector,
+    summary: Selector,
+    ingredient: Selector,
+    /// Headings of ingredient groups ("For the sauce")
+    ingredient_group: Selector,
+    instruction: Selector,
+    instruction_group: Selector,
+    notes: Selector,
+    servings: Selector,
+    prep_time: Selector,
+    cook_time: Selector,
+    total_time: Selector,
+    image: Selector,
+    /// Names of pieces of equipment, for plugins that list them
+    equipment: Option<Selector>,
+    /// Embedded video players (iframes or video elements)
+    video: Option<Selector>,
+}
+
+/// Recipe card formats, tried in order.
+pub(crate) static RECIPE_CARD_FORMATS: [RecipeCardFormat; 4] = [
+    RecipeCardFormat {
+        method: ExtractionMethod::WpRecipeMaker,
+        marker: "wprm-recipe-container",
+        selectors: LazyLock::new(|| CardSelectors {
+            container: selector(".wprm-recipe-container"),
+            title: selector(".wprm-recipe-name"),
+            summary: selector(".wprm-recipe-summary"),
+            ingredient: selector(".wprm-recipe-ingredient"),
+            ingredient_group: selector(".wprm-recipe-ingredient-group-name"),
+            instruction: selector(".wprm-recipe-instruction-text"),
+            instruction_group: selector(".wprm-recipe-instruction-group-name"),
+            notes: selector(".wprm-recipe-notes"),
+            servings: selector(".wprm-recipe-servings-container"),
+            prep_time: selector(".wprm-recipe-prep-time-container"),
+            cook_time: selector(".wprm-recipe-cook-time-container"),
+            total_time: selector(".wprm-recipe-total-time-container"),
+            image: selector(".wprm-recipe-image img"),
+            equipment: Some(selector(".wprm-recipe-equipment-name")),
+            video: Some(selector(
+                ".wprm-recipe-video iframe, .wprm-recipe-video video",
+            )),
+        }),
+    },
+    RecipeCardFormat {
+        method: ExtractionMethod::TastyRecipes,
+        marker: "tasty-recipes",
+        selectors: LazyLock::new(|| CardSelectors {
+            container: selector(".tasty-recipes"),
+            title: selector(".tasty-recipes-title"),
+            summary: selector(".tasty-recipes-description-body"),
+            ingredient: selector(".tasty-recipes-ingredients li"),
+            ingredient_group: selector(".tasty-recipes-ingredients h4"),
+            instruction: selector(".tasty-recipes-instructions li"),
+            instruction_group: selector(".tasty-recipes-instructions h4"),
+            notes: selector(".tasty-recipes-notes-body"),
+            servings: selector(".tasty-recipes-yield"),
+            prep_time: selector(".tasty-recipes-prep-time"),
+            cook_time: selector(".tasty-recipes-cook-time"),
+            total_time: selector(".tasty-recipes-total-time"),
+            image: selector(".tasty-recipes-image img"),
+            equipment: Some(selector(".tasty-recipes-equipment li")),
+            video: Some(selector(
+                ".tasty-recipe-video-embed iframe, .tasty-recipe-video-embed video",
+            )),
+        }),
+    },
+    RecipeCardFormat {
+        method: ExtractionMethod::MediavineCreate,
+        marker: "mv-create-card",
+        selectors: LazyLock::new(|| CardSelectors {
+            container: selector(".mv-create-card"),
+            title: selector(".mv-create-title"),
+            summary: selector(".mv-create-description"),
+            ingredient: selector(".mv-create-ingredients li"),
+            ingredient_group: selector(".mv-create-ingredients h4"),
+            instruction: selector(".mv-create-instructions li"),
+            instruction_group: selector(".mv-create-instructions h4"),
+            notes: selector(".mv-create-notes-content"),
+            servings: selector(".mv-create-yield"),
+            prep_time: selector(".mv-create-time-prep .mv-create-time-format"),
+            cook_time: selector(".mv-create-time-active .mv-create-time-format"),
+            total_time: selector(".mv-create-time-total .mv-create-time-format"),
+            image: selector("img.mv-create-image, .mv-create-image img"),
+            equipment: None,
+            video: None,
+        }),
+    },
+    RecipeCardFormat {
+        method: ExtractionMethod::ZipRecipes,
+        marker: "zlrecipe-container",
+        selectors: LazyLock::new(|| CardSelectors {
+            container: selector("[id^='zlrecipe-container']"),
+            title: selector("#zlrecipe-title"),
+            summary: selector("#zlrecipe-summary"),
+            ingredient: selector("#zlrecipe-ingredients-list .ingredient"),
+            ingredient_group: selector("#zlrecipe-ingredients-list .ingredient-label"),
+            instruction: selector("#zlrecipe-instructions-list .instruction"),
+            instruction_group: selector("#zlrecipe-instructions-list .instruction-label"),
+            notes: selector("#zlrecipe-notes-list"),
+            servings: selector("#zlrecipe-yield"),
+            prep_time: selector("#zlrecipe-prep-time"),
+            cook_time: selector("#zlrecipe-cook-time"),
+            total_time: selector("#zlrecipe-total-time"),
+            image: selector("img#zlrecipe-image, #zlrecipe-image img"),
+            equipment: None,
+            video: None,
+        }),
+    },
+];
+
+/// Regex for the labels cards put in front of times and yields ("Prep Time:", "Yield:").
+static FIELD_LABEL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(?:(?:prep|cook|cooking|total|active|inactive|additional)\s+time|yield|servings)\s*:?\s*",
+    )
+    .expect("Invalid field label regex")
+});
+
+/// Blocks of a notes-style section
+static BLOCK_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("p, li"));
+
+static IMG_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("img"));
+
+/// Classes whose text is for screen readers or is a label, not the value.
+const SKIPPED_CLASS_PARTS: &[&str] = &["sr-only", "screen-reader-text", "-label", "yield-scale"];
+
+/// Formats whose marker appears in the page, in registry order.
+pub(crate) fn formats_present(html: &str) -> impl Iterator<Item = &'static RecipeCardFormat> + '_ {
+    RECIPE_CARD_FORMATS
+        .iter()
+        .filter(move |format| html.contains(format.marker))
+}
+
+impl RecipeCardFormat {
+    /// Extract every card of this format on the page, in page order.
+    pub(crate) fn extract_all(
+        &self,
+        document: &Html,
+        source_url: &str,
+    ) -> Result<Vec<RawRecipe>, ExtractError> {
+        collect_recipes(
+            document
+                .select(&self.selectors.container)
+                .map(|card| self.extract_card(card, document, source_url)),
+        )
+    }
+
+    fn extract_card(
+        &self,
+        card: ElementRef,
+        document: &Html,
+        source_url: &str,
+    ) -> Result<RawRecipe, ExtractError> {
+        let title = first_text(card, &self.selectors.title)
+            .ok_or_else(|| ExtractError::MissingField("name".to_string()))?;
+        let ingredient_sections = grouped_sections(
+            card,
+            &self.selectors.ingredient,
+            &self.selectors.ingredient_group,
+        )
+        .ok_or_else(|| ExtractError::MissingField("recipeIngredient (empty)".to_string()))?;
+        let instruction_sections = grouped_sections(
+            card,
+            &self.selectors.instruction,
+            &self.selectors.instruction_group,
+        )
+        .ok_or_else(|| ExtractError::MissingField("recipeInstructions (empty)".to_string()))?;
+
+        let mut image_urls: Vec<String> = card
+            .select(&self.selectors.image)
+            .filter_map(image_src)
+            .take(1)
+            .collect();
+        if image_urls.is_empty() {
+            image_urls.extend(extract_og_image(document));
+        }
+
+        Ok(RawRecipe {
+            title,
+            description: first_text(card, &self.selectors.summary),
+            ingredients: RecipeSection::join(&ingredient_sections, "\n"),
+            instructions: RecipeSection::join(&instruction_sections, "\n\n"),
+            image_urls,
+            source_url: Some(source_url.to_string()),
+            source_name: extract_source_name(source_url),
+            servings: labeled_value(card, &self.selectors.servings),
+            prep_time: labeled_value(card, &self.selectors.prep_time),
+            cook_time: labeled_value(card, &self.selectors.cook_time),
+            total_time: labeled_value(card, &self.selectors.total_time),
+            rating: None,
+            difficulty: None,
+            nutritional_info: None,
+            notes: card
+                .select(&self.selectors.notes)
+                .next()
+                .and_then(block_text),
+            categories: None,
+            video_url: self.selectors.video.as_ref().and_then(|video| {
+                // Lazy-loaded players keep the real URL in data-src
+                card.select(video)
+                    .flat_map(|player| {
+                        ["data-src", "src"]
+                            .into_iter()
+                            .filter_map(move |attr| player.value().attr(attr))
+                    })
+                    .find_map(http_url)
+            }),
+            equipment: self.selectors.equipment.as_ref().and_then(|equipment| {
+                let names: Vec<String> = card.select(equipment).filter_map(element_text).collect();
+                (!names.is_empty()).then_some(names)
+            }),
+            author: None,
+            published_date: None,
+            language: None,
+            ingredient_sections: RecipeSection::if_named(ingredient_sections),
+            instruction_sections: RecipeSection::if_named(instruction_sections),
+        })
+    }
+}
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("Invalid recipe card selector")
+}
+
+/// The card's items split at its group headings. Items before the first heading
+/// go in an unnamed section.
+fn grouped_sections(
+    card: ElementRef,
+    item: &Selector,
+    group: &Selector,
+) -> Option<Vec<RecipeSection>> {
+    let mut sections: Vec<RecipeSection> = vec![];
+    let items_and_groups = card
+        .descendants()
+        .skip(1)
+        .filter_map(ElementRef::wrap)
+        .filter(|el| item.matches(el) || group.matches(el));
+    for el in items_and_groups {
+        let Some(text) = element_text(el) else {
+            continue;
+        };
+        if group.matches(&el) {
+            sections.push(RecipeSection {
+                name: Some(text.trim_end_matches(':').to_string()),
+                items: vec![],
+            });
+        } else {
+            match sections.last_mut() {
+                Some(section) => section.items.push(text),
+                None => sections.push(RecipeSection {
+                    name: None,
+                    items: vec![text],
+                }),
+            }
+        }
+    }
+
+    // Group headings alone aren't a recipe
+    if sections.iter().all(|section| section.items.is_empty()) {
+        return None;
+    }
+    Some(sections)
+}
+
+fn first_text(card: ElementRef, selector: &Selector) -> Option<String> {
+    card.select(selector).find_map(element_text)
+}
+
+/// A time or yield with its "Prep Time:" style label removed.
+fn labeled_value(card: ElementRef, selector: &Selector) -> Option<String> {
+    let text = first_text(card, selector)?;
+    let value = FIELD_LABEL_REGEX.replace(&text, "").trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Text of a notes-style block, one line per paragraph or list item.
+fn block_text(el: ElementRef) -> Option<String> {
+    let blocks: Vec<String> = el
+        .select(&BLOCK_SELECTOR)
+        .filter_map(element_text)
+        .collect();
+    if blocks.is_empty() {
+        element_text(el)
+    } else {
+        Some(blocks.join("\n"))
+    }
+}
+
+/// An element's visible text with whitespace collapsed, leaving out
+/// screen-reader-only text and labels.
+fn element_text(el: ElementRef) -> Option<String> {
+    let mut text = String::new();
+    collect_text(el, &mut text);
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+fn collect_text(el: ElementRef, out: &mut String) {
+    for child in el.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(element) => {
+                let skipped = element
+                    .classes()
+                    .any(|class| SKIPPED_CLASS_PARTS.iter().any(|part| class.contains(part)));
+                if skipped {
+                    // Keep words on either side of the skipped element apart
+                    out.push(' ');
+                    continue;
+                }
+                if let Some(child) = ElementRef::wrap(child) {
+                    collect_text(child, out);
+                }
+                // Blocks and line breaks separate words even without whitespace
+                if matches!(element.name(), "br" | "p" | "div" | "li") {
+                    out.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The real source of an image, looking past lazy-loading placeholders.
+fn image_src(el: ElementRef) -> Option<String> {
+    let img = if el.value().name() == "img" {
+        el
+    } else {
+        el.select(&IMG_SELECTOR).next()?
+    };
+    ["data-lazy-src", "data-src", "src"]
+        .iter()
+        .filter_map(|attr| img.value().attr(attr))
+        .map(str::trim)
+        .find(|src| !src.is_empty() && !src.starts_with("data:"))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_format(method: ExtractionMethod) -> &'static RecipeCardFormat {
+        RECIPE_CARD_FORMATS
+            .iter()
+            .find(|f| f.method == method)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wprm_card() {
+        let html = r#"<html><body>
+            <div id="wprm-recipe-container-42" class="wprm-recipe-container">
+              <h2 class="wprm-recipe-name">Weeknight Chili</h2>
+              <div class="wprm-recipe-summary"><span>Quick &amp; hearty.</span></div>
+              <div class="wprm-recipe-image"><img src="data:image/svg+xml,..." data-lazy-src="https://example.com/chili.jpg"></div>
+              <div class="wprm-recipe-block-container wprm-recipe-servings-container">
+                <span class="wprm-recipe-details-label">Servings</span>
+                <span class="wprm-recipe-servings">6</span> <span class="wprm-recipe-servings-unit">servings</span>
+              </div>
+              <div class="wprm-recipe-block-container wprm-recipe-prep-time-container">
+                <span class="wprm-recipe-details-label">Prep Time</span>
+                <span class="wprm-recipe-time"><span class="wprm-recipe-details">15<span class="sr-only">minutes</span></span> <span class="wprm-recipe-details-unit">mins</span></span>
+              </div>
+              <div class="wprm-recipe-block-container wprm-recipe-total-time-container">
+                <span class="wprm-recipe-details-label">Total Time</span>
+                <span class="wprm-recipe-time">1 hr 15 mins</span>
+              </div>
+              <div class="wprm-recipe-ingredient-group">
+                <h4 class="wprm-recipe-group-name wprm-recipe-ingredient-group-name">Chili</h4>
+                <ul>
+                  <li class="wprm-recipe-ingredient"><span class="wprm-recipe-ingredient-amount">1</span> <span class="wprm-recipe-ingredient-unit">lb</span> <span class="wprm-recipe-ingredient-name">ground beef</span></li>
+                  <li class="wprm-recipe-ingredient"><span class="wprm-recipe-ingredient-amount">2</span> <span class="wprm-recipe-ingredient-unit">cans</span> <span class="wprm-recipe-ingredient-name">kidney beans</span></li>
+                </ul>
+              </div>
+              <div class="wprm-recipe-ingredient-group">
+                <h4 class="wprm-recipe-group-name wprm-recipe-ingredient-group-name">For serving</h4>
+                <ul><li class="wprm-recipe-ingredient"><span class="wprm-recipe-ingredient-name">sour cream</span></li></ul>
+              </div>
+              <ul>
+                <li class="wprm-recipe-instruction"><div class="wprm-recipe-instruction-text">Brown the beef.</div></li>
+                <li class="wprm-recipe-instruction"><div class="wprm-recipe-instruction-text">Add the beans and simmer.</div></li>
+              </ul>
+              <div class="wprm-recipe-notes"><p>Freezes well.</p><p>Double it.</p></div>
+              <div class="wprm-recipe-equipment-container"><ul class="wprm-recipe-equipment">
+                <li class="wprm-recipe-equipment-item"><div class="wprm-recipe-equipment-name">Dutch oven</div></li>
+                <li class="wprm-recipe-equipment-item"><div class="wprm-recipe-equipment-name">Wooden spoon</div></li>
+              </ul></div>
+              <div class="wprm-recipe-video-container"><div class="wprm-recipe-video">
+                <iframe src="about:blank" data-src="https://www.youtube.com/embed/chili42"></iframe>
+              </div></div>
+            </div>
+        </body></html>"#;
+        let document = Html::parse_document(html);
+        let recipe = card_format(ExtractionMethod::WpRecipeMaker)
+            .extract_all(&document, "https://www.example.com/chili")
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(recipe.title, "Weeknight Chili");
+        assert_eq!(recipe.description.as_deref(), Some("Quick & hearty."));
+        assert_eq!(
+            recipe.ingredients,
+            "Chili:\n1 lb ground beef\n2 cans kidney beans\nFor serving:\nsour cream"
+        );
+        assert_eq!(
+            recipe.ingredient_sections,
+            vec![
+                RecipeSection {
+                    name: Some("Chili".to_string()),
+                    items: vec![
+                        "1 lb ground beef".to_string(),
+                        "2 cans kidney beans".to_string()
+                    ],
+                },
+                RecipeSection {
+                    name: Some("For serving".to_string()),
+                    items: vec!["sour cream".to_string()],
+                },
+            ]
+        );
+        // No instruction groups, so nothing beyond the flat list
+        assert!(recipe.instruction_sections.is_empty());
+        assert_eq!(
+            recipe.instructions,
+            "Brown the beef.\n\nAdd the beans and simmer."
+        );
+        assert_eq!(recipe.servings.as_deref(), Some("6 servings"));
+        assert_eq!(recipe.prep_time.as_deref(), Some("15 mins"));
+        assert_eq!(recipe.total_time.as_deref(), Some("1 hr 15 mins"));
+        assert_eq!(recipe.cook_time, None);
+        assert_eq!(recipe.notes.as_deref(), Some("Freezes well.\nDouble it."));
+        assert_eq!(recipe.image_urls, vec!["https://example.com/chili.jpg"]);
+        assert_eq!(recipe.source_name.as_deref(), Some("Example.com"));
+        assert_eq!(
+            recipe.equipment,
+            Some(vec!["Dutch oven".to_string(), "Wooden spoon".to_string()])
+        );
+        assert_eq!(
+            recipe.video_url.as_deref(),
+            Some("https://www.youtube.com/embed/chili42")
+        );
+    }
+
+    #[test]
+    fn test_tasty_card() {
+        let html = r#"<div class="tasty-recipes">
+              <h2 class="tasty-recipes-title">Lemon Bars</h2>
+              <div class="tasty-recipes-details">
+                <span class="tasty-recipes-label">Prep Time:</span> <span class="tasty-recipes-prep-time">20 minutes</span>
+                <span class="tasty-recipes-label">Yield:</span> <span class="tasty-recipes-yield">16 bars <span class="tasty-recipes-yield-scale">1x</span></span>
+              </div>
+              <div class="tasty-recipes-ingredients"><div class="tasty-recipes-ingredients-body">
+                <h4>Crust</h4><ul><li>1 cup flour</li><li>1/2 cup butter</li></ul>
+                <h4>Filling</h4><ul><li>3 lemons</li></ul>
+              </div></div>
+              <div class="tasty-recipes-instructions"><ol><li>Bake the crust.</li><li>Pour in the filling.</li></ol></div>
+              <div class="tasty-recipes-equipment"><ul><li>9x13 pan</li></ul></div>
+              <div class="tasty-recipe-video-embed"><iframe src="javascript:void(0)"></iframe></div>
+            </div>"#;
+        let document = Html::parse_document(html);
+        let recipe = card_format(ExtractionMethod::TastyRecipes)
+            .extract_all(&document, "https://example.com/lemon-bars")
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(recipe.title, "Lemon Bars");
+        assert_eq!(
+            recipe.ingredients,
+            "Crust:\n1 cup flour\n1/2 cup butter\nFilling:\n3 lemons"
+        );
+        assert_eq!(
+            recipe.instructions,
+            "Bake the crust.\n\nPour in the filling."
+        );
+        assert_eq!(recipe.servings.as_deref(), Some("16 bars"));
+        assert_eq!(recipe.prep_time.as_deref(), Some("20 minutes"));
+        assert_eq!(recipe.equipment, Some(vec!["9x13 pan".to_string()]));
+        // Only http(s) video URLs are kept
+        assert_eq!(recipe.video_url, None);
+    }
+
+    #[test]
+    fn test_zip_recipes_labels() {
+        let html = r#"<div id="zlrecipe-container-7" class="zlrecipe-container-border">
+              <h2 id="zlrecipe-title">Pancakes</h2>
+              <p id="zlrecipe-prep-time">Prep Time: <span>10 minutes</span></p>
+              <p id="zlrecipe-yield">Yield: 8 pancakes</p>
+              <ul id="zlrecipe-ingredients-list"><li class="ingredient">1 cup flour</li><li class="ingredient">1 egg</li></ul>
+              <ol id="zlrecipe-instructions-list"><li class="instruction">Mix.</li><li class="instruction">Fry.</li></ol>
+            </div>"#;
+        let document = Html::parse_document(html);
+        let recipe = card_format(ExtractionMethod::ZipRecipes)
+            .extract_all(&document, "https://example.com/pancakes")
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(recipe.ingredients, "1 cup flour\n1 egg");
+        assert_eq!(recipe.prep_time.as_deref(), Some("10 minutes"));
+        assert_eq!(recipe.servings.as_deref(), Some("8 pancakes"));
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let html = r#"<div class="mv-create-card"><h2 class="mv-create-title">Toast</h2>
+            <div class="mv-create-ingredients"><h4>Bread</h4></div></div>"#;
+        let document = Html::parse_document(html);
+        let result = card_format(ExtractionMethod::MediavineCreate)
+            .extract_all(&document, "https://example.com/toast");
+        assert!(matches!(result, Err(ExtractError::MissingField(_))));
+
+        let document = Html::parse_document("<p>No card here</p>");
+        let result = card_format(ExtractionMethod::MediavineCreate)
+            .extract_all(&document, "https://example.com/toast");
+        assert!(matches!(result, Err(ExtractError::NoRecipe)));
+    }
+
+    #[test]
+    fn test_multiple_cards() {
+        let card = |title: &str| {
+            format!(
+                r#"<div class="tasty-recipes"><h2 class="tasty-recipes-title">{title}</h2>
+                <div class="tasty-recipes-ingredients"><ul><li>1 cup stock</li></ul></div>
+                <div class="tasty-recipes-instructions"><ol><li>Simmer.</li></ol></div></div>"#
+            )
+        };
+        let html = format!(
+            "<h1>Weeknight Soups</h1>{}<p>Next up</p>{}",
+            card("Minestrone"),
+            card("Pho")
+        );
+        let document = Html::parse_document(&html);
+        let recipes = card_format(ExtractionMethod::TastyRecipes)
+            .extract_all(&document, "https://example.com/soups")
+            .unwrap();
+
+        let titles: Vec<&str> = recipes.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["Minestrone", "Pho"]);
+    }
+
+    #[test]
+    fn test_formats_present() {
+        let methods: Vec<_> = formats_present(r#"<div class="mv-create-card">"#)
+            .map(|f| f.method)
+            .collect();
+        assert_eq!(methods, vec![ExtractionMethod::MediavineCreate]);
+        assert_eq!(formats_present("<p>plain</p>").count(), 0);
+    }
+}
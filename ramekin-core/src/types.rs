@@ -102,12 +102,24 @@ pub struct EnrichGeneratePhotoOutput {
 pub enum ExtractionMethod {
     JsonLd,
     Microdata,
+    /// WP Recipe Maker recipe card
+    WpRecipeMaker,
+    /// Tasty Recipes recipe card
+    TastyRecipes,
+    /// Mediavine Create recipe card
+    MediavineCreate,
+    /// Zip Recipes recipe card
+    ZipRecipes,
     /// Structured data supplemented with HTML class-based fallbacks
     HtmlFallback,
     /// Imported from Paprika app
     Paprika,
     /// Extracted from uploaded photos using vision AI
     PhotoUpload,
+    /// Extracted from an uploaded PDF or text file using the plain-text heuristics
+    DocumentText,
+    /// Extracted from an uploaded PDF or text file using AI
+    DocumentAi,
 }
 
 /// Result of attempting a single extraction method
@@ -160,6 +172,66 @@ pub struct RawRecipe {
     /// Categories/tags from import source
     #[serde(skip_serializing_if = "Option::is_none")]
     pub categories: Option<Vec<String>>,
+    /// Link to a video of the recipe (VideoObject contentUrl or embedUrl)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_url: Option<String>,
+    /// Equipment the recipe calls for (schema.org `tool`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equipment: Option<Vec<String>>,
+    /// Author as credited by the source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// When the source published the recipe, as YYYY-MM-DD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_date: Option<String>,
+    /// Language the recipe is written in, detected at extraction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<crate::language::Language>,
+    /// Ingredient groups as the source marked them up ("For the crust"). When
+    /// present, ingredient sections come from these instead of from headers
+    /// guessed in the `ingredients` blob.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ingredient_sections: Vec<RecipeSection>,
+    /// Instruction groups (HowToSection, recipe card groups) as the source marked them up
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instruction_sections: Vec<RecipeSection>,
+}
+
+/// A group of ingredients or instructions from the source's markup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeSection {
+    /// The group's heading; None for items before the first heading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub items: Vec<String>,
+}
+
+impl RecipeSection {
+    /// The sections' items as a blob, with a "Heading:" line before each named
+    /// section so text-based parsing still sees the groups.
+    pub fn join(sections: &[RecipeSection], separator: &str) -> String {
+        sections
+            .iter()
+            .flat_map(|section| {
+                section
+                    .name
+                    .iter()
+                    .map(|name| format!("{}:", name.trim_end_matches(':')))
+                    .chain(section.items.iter().cloned())
+            })
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// The sections, or none at all when no section has a heading (the grouping
+    /// then adds nothing over the flat list).
+    pub fn if_named(sections: Vec<RecipeSection>) -> Vec<RecipeSection> {
+        if sections.iter().any(|section| section.name.is_some()) {
+            sections
+        } else {
+            vec![]
+        }
+    }
 }
 
 /// Output from a pipeline step, stored in step_data
@@ -185,6 +257,34 @@ pub struct ExtractRecipeOutput {
     /// Results from all attempted extraction methods
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub all_attempts: Vec<ExtractionAttempt>,
+    /// The other recipes on the page (round-up posts, a recipe plus its sauce),
+    /// in page order after `raw_recipe`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_recipes: Vec<RawRecipe>,
+    /// The printer-friendly or AMP version of the page the recipe was
+    /// extracted from, when the page itself had none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alternate: Option<AlternatePage>,
+    /// How far `raw_recipe` can be trusted, and what looks wrong with it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<crate::quality::ExtractionQuality>,
+}
+
+/// Another version of a recipe page that may carry cleaner markup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlternatePage {
+    pub url: String,
+    pub kind: AlternateKind,
+}
+
+/// How an alternate version of a page was linked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlternateKind {
+    /// Printer-friendly view (`/wprm_print/...`, `?print=1`, a print button)
+    Print,
+    /// AMP version advertised by `<link rel="amphtml">`
+    Amp,
 }
 
 /// Output from the save_recipe step (for disk-based pipeline testing)
@@ -0,0 +1,227 @@
+//! Parse recipe yields ("Serves 4-6", "2 loaves", "24 cookies") into a number and unit.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::ingredient_parser::normalize_unicode;
+use crate::metric_weights::parse_amount;
+
+/// Unit used for yields counted in servings.
+pub const SERVINGS_UNIT: &str = "servings";
+
+/// A parsed recipe yield.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeYield {
+    /// The yield, or the low end of a range
+    pub quantity: f64,
+    /// The high end of a range ("Serves 4-6")
+    pub quantity_max: Option<f64>,
+    /// What is counted: "servings", "loaves", "cookies". None when a "Makes 12"
+    /// doesn't say what it makes.
+    pub unit: Option<String>,
+}
+
+/// Regex for an amount (or range), optionally in dozens, followed by a unit.
+/// Mixed numbers and fractions come before plain integers so "1/2 cup" isn't
+/// read as 1. A unit may start with a size, as in "1 9-inch pie".
+static YIELD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    let amount = r"\d+\s+\d+/\d+|\d+/\d+|\d+(?:\.\d+)?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve";
+    Regex::new(&format!(
+        r"(?x)
+        \b(?P<amount>{amount})\b
+        (?:\s*(?:-|to|or)\s*(?P<max>{amount})\b)?
+        (?P<dozen>\s*dozen\b)?
+        \s*(?P<unit>(?:\d+(?:\.\d+)?\s*-?\s*(?:inch|in|cm)\b\s*)?[a-z][^,;.:()\d]*)?",
+    ))
+    .expect("Invalid yield regex")
+});
+
+/// Regex for "a dozen", rewritten to "1 dozen" so it parses like "2 dozen".
+static A_DOZEN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\ban?\s+dozen\b").expect("Invalid dozen regex"));
+
+/// Regex for parenthetical asides ("(about 24)", "(9-inch)").
+static PARENTHETICAL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\([^)]*\)").expect("Invalid parenthetical regex"));
+
+/// Words that mean the count is of servings.
+const SERVING_WORDS: &[&str] = &[
+    "serving", "servings", "people", "person", "persons", "portion", "portions",
+];
+
+/// Words that can't start a unit ("Serves 4 as a main course").
+const UNIT_STOP_WORDS: &[&str] = &["as", "to", "for", "or", "and", "with", "each", "if"];
+
+/// Most words kept in a unit ("12 large muffins").
+const MAX_UNIT_WORDS: usize = 3;
+
+/// Parse a yield such as "Serves 4-6", "2 loaves", "Makes about 2 dozen cookies"
+/// or a bare "4" (taken as servings).
+pub fn parse_yield(text: &str) -> Option<RecipeYield> {
+    let lower = normalize_unicode(&text.to_lowercase());
+    let lower = PARENTHETICAL_REGEX.replace_all(&lower, " ");
+    let lower = A_DOZEN_REGEX.replace_all(&lower, "1 dozen");
+
+    let serves = ["serves", "serving", "feeds"]
+        .iter()
+        .any(|word| lower.contains(word));
+    let makes = ["makes", "yield"].iter().any(|word| lower.contains(word));
+
+    let cap = YIELD_REGEX.captures(&lower)?;
+    let mut quantity = yield_amount(&cap["amount"])?;
+    let mut quantity_max = cap.name("max").and_then(|m| yield_amount(m.as_str()));
+    if cap.name("dozen").is_some() {
+        quantity *= 12.0;
+        quantity_max = quantity_max.map(|max| max * 12.0);
+    }
+    if quantity <= 0.0 {
+        return None;
+    }
+
+    let unit = cap.name("unit").and_then(|m| {
+        let words: Vec<&str> = m.as_str().split_whitespace().take(MAX_UNIT_WORDS).collect();
+        let first = *words.first()?;
+        if UNIT_STOP_WORDS.contains(&first) {
+            return None;
+        }
+        // "Serves 4 people (as a main)": anything after the serving word is noise
+        if words.iter().any(|w| SERVING_WORDS.contains(w)) {
+            return Some(SERVINGS_UNIT.to_string());
+        }
+        Some(words.join(" "))
+    });
+
+    let unit = match unit {
+        Some(unit) => Some(unit),
+        None if serves || !makes => Some(SERVINGS_UNIT.to_string()),
+        None => None,
+    };
+
+    Some(RecipeYield {
+        quantity,
+        quantity_max: quantity_max.filter(|max| *max > quantity),
+        unit,
+    })
+}
+
+/// A yield amount as digits, a fraction or a small number word.
+fn yield_amount(amount: &str) -> Option<f64> {
+    let word = match amount {
+        "one" => 1.0,
+        "two" => 2.0,
+        "three" => 3.0,
+        "four" => 4.0,
+        "five" => 5.0,
+        "six" => 6.0,
+        "seven" => 7.0,
+        "eight" => 8.0,
+        "nine" => 9.0,
+        "ten" => 10.0,
+        "eleven" => 11.0,
+        "twelve" => 12.0,
+        _ => return parse_amount(amount),
+    };
+    Some(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yield_of(quantity: f64, quantity_max: Option<f64>, unit: Option<&str>) -> RecipeYield {
+        RecipeYield {
+            quantity,
+            quantity_max,
+            unit: unit.map(|u| u.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_servings() {
+        assert_eq!(
+            parse_yield("Serves 4-6"),
+            Some(yield_of(4.0, Some(6.0), Some("servings")))
+        );
+        assert_eq!(
+            parse_yield("4 servings"),
+            Some(yield_of(4.0, None, Some("servings")))
+        );
+        assert_eq!(
+            parse_yield("6"),
+            Some(yield_of(6.0, None, Some("servings")))
+        );
+        assert_eq!(
+            parse_yield("Serves 4 as a main course"),
+            Some(yield_of(4.0, None, Some("servings")))
+        );
+        assert_eq!(
+            parse_yield("6 to 8 people"),
+            Some(yield_of(6.0, Some(8.0), Some("servings")))
+        );
+        assert_eq!(
+            parse_yield("Serves four"),
+            Some(yield_of(4.0, None, Some("servings")))
+        );
+    }
+
+    #[test]
+    fn test_counted_units() {
+        assert_eq!(
+            parse_yield("2 loaves"),
+            Some(yield_of(2.0, None, Some("loaves")))
+        );
+        assert_eq!(
+            parse_yield("24 cookies"),
+            Some(yield_of(24.0, None, Some("cookies")))
+        );
+        assert_eq!(
+            parse_yield("Makes about 2 dozen cookies"),
+            Some(yield_of(24.0, None, Some("cookies")))
+        );
+        assert_eq!(
+            parse_yield("Yield: 1 1/2 cups"),
+            Some(yield_of(1.5, None, Some("cups")))
+        );
+        assert_eq!(
+            parse_yield("Makes 12 large muffins (about 3 inches)"),
+            Some(yield_of(12.0, None, Some("large muffins")))
+        );
+        assert_eq!(
+            parse_yield("1 9-inch pie"),
+            Some(yield_of(1.0, None, Some("9-inch pie")))
+        );
+        assert_eq!(parse_yield("Makes 12"), Some(yield_of(12.0, None, None)));
+        assert_eq!(
+            parse_yield("Makes a dozen rolls"),
+            Some(yield_of(12.0, None, Some("rolls")))
+        );
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(
+            parse_yield("Makes 1/2 cup"),
+            Some(yield_of(0.5, None, Some("cup")))
+        );
+        assert_eq!(
+            parse_yield("3/4 cup"),
+            Some(yield_of(0.75, None, Some("cup")))
+        );
+        assert_eq!(
+            parse_yield("Makes 2 1/2 cups"),
+            Some(yield_of(2.5, None, Some("cups")))
+        );
+        assert_eq!(
+            parse_yield("1.5 quarts"),
+            Some(yield_of(1.5, None, Some("quarts")))
+        );
+    }
+
+    #[test]
+    fn test_unparseable() {
+        assert_eq!(parse_yield("a lot"), None);
+        assert_eq!(parse_yield(""), None);
+    }
+}
@@ -27,6 +27,22 @@ struct ExpectedRecipe {
     description: Option<String>,
     /// Raw ingredients as newline-separated string (current behavior)
     ingredients_raw: String,
+    #[serde(default)]
+    prep_time: Option<String>,
+    #[serde(default)]
+    cook_time: Option<String>,
+    #[serde(default)]
+    total_time: Option<String>,
+    /// Nutrition lines joined with newlines
+    #[serde(default)]
+    nutritional_info: Option<String>,
+    #[serde(default)]
+    categories: Option<Vec<String>>,
+    #[serde(default)]
+    rating: Option<i32>,
+    /// Extraction method, as serialized ("json_ld", "wp_recipe_maker")
+    #[serde(default)]
+    method_used: Option<String>,
 }
 
 /// Get the project root directory
@@ -103,5 +119,45 @@ fn test_extraction_golden_files() {
             "Ingredients mismatch for {}\n\nExpected:\n{}\n\nActual:\n{}",
             name, case.expected.ingredients_raw, result.raw_recipe.ingredients
         );
+
+        // Verify optional metadata if expected
+        let expected = &case.expected;
+        let recipe = &result.raw_recipe;
+        for (field, expected, actual) in [
+            ("prep_time", &expected.prep_time, &recipe.prep_time),
+            ("cook_time", &expected.cook_time, &recipe.cook_time),
+            ("total_time", &expected.total_time, &recipe.total_time),
+            (
+                "nutritional_info",
+                &expected.nutritional_info,
+                &recipe.nutritional_info,
+            ),
+        ] {
+            if expected.is_some() {
+                assert_eq!(actual, expected, "{} mismatch for {}", field, name);
+            }
+        }
+        if expected.categories.is_some() {
+            assert_eq!(
+                recipe.categories, expected.categories,
+                "Categories mismatch for {}",
+                name
+            );
+        }
+        if expected.rating.is_some() {
+            assert_eq!(
+                recipe.rating, expected.rating,
+                "Rating mismatch for {}",
+                name
+            );
+        }
+        if let Some(expected_method) = &expected.method_used {
+            assert_eq!(
+                serde_json::to_value(result.method_used).unwrap(),
+                serde_json::Value::String(expected_method.clone()),
+                "Extraction method mismatch for {}",
+                name
+            );
+        }
     }
 }
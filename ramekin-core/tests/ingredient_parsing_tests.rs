@@ -7,6 +7,7 @@
 //! - `curated/` - Hand-picked test cases grouped by category (edge.json, unit_test.json, etc.)
 //! - `pipeline/` - Auto-generated from pipeline runs, one file per recipe
 //! - `paprika/` - Auto-generated from paprikarecipes file, one file per recipe
+//! - `locale/` - Hand-picked non-English lines, one file per language (de.json, fr.json, ...)
 //!
 //! Curated format (category files):
 //! ```json
@@ -18,6 +19,9 @@
 //! }
 //! ```
 //!
+//! Locale files use the curated format with `"language": "de"` in place of
+//! `category`, and check parsing only (no enrichment or categorization).
+//!
 //! Recipe format (pipeline/paprika):
 //! ```json
 //! {
@@ -32,8 +36,9 @@
 use glob::glob;
 use ramekin_core::ingredient_categorizer;
 use ramekin_core::ingredient_parser::{
-    parse_ingredient, parse_ingredients, Measurement, ParsedIngredient,
+    parse_ingredient, parse_ingredient_in, parse_ingredients, Measurement, ParsedIngredient,
 };
+use ramekin_core::language::Language;
 use ramekin_core::metric_weights::{add_metric_weight_alternative, MetricConversionStats};
 use ramekin_core::volume_to_weight::{
     add_volume_to_weight_alternative, apply_ingredient_rewrites, VolumeConversionStats,
@@ -82,6 +87,13 @@ struct CuratedTestFile {
     test_cases: Vec<CuratedTestCase>,
 }
 
+/// Locale test file (one per language)
+#[derive(Debug, Deserialize)]
+struct LocaleTestFile {
+    language: Language,
+    test_cases: Vec<CuratedTestCase>,
+}
+
 /// A single test case within a curated category file
 #[derive(Debug, Deserialize)]
 struct CuratedTestCase {
@@ -359,3 +371,56 @@ fn test_ingredient_parsing_curated() {
 
     println!("All {} curated tests passed!", cases.len());
 }
+
+/// Run the locale fixtures, parsing each line in its file's language
+#[test]
+fn test_ingredient_parsing_locale() {
+    let fixtures_dir =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ingredient_parsing/locale");
+    let pattern = fixtures_dir.join("*.json");
+    let pattern_str = pattern.to_string_lossy();
+
+    let mut total = 0;
+    let mut failures = Vec::new();
+    for entry in glob(&pattern_str).expect("Failed to read glob pattern") {
+        let path = entry.expect("Failed to read directory entry");
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+        let file: LocaleTestFile = serde_json::from_str(&content)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+
+        for tc in &file.test_cases {
+            total += 1;
+            let parsed = parse_ingredient_in(&tc.raw, file.language);
+            let actual = Expected {
+                item: parsed.item,
+                measurements: parsed.measurements,
+                note: parsed.note,
+                section: parsed.section,
+                category: None,
+            };
+            if actual != tc.expected {
+                let name = format!("locale/{}/{}", file.language.code(), tc.name);
+                failures.push((name, tc.raw.clone(), tc.expected.clone(), actual));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        let mut msg = format!(
+            "\n{} failures across {} locale tests:\n",
+            failures.len(),
+            total
+        );
+        for (name, raw, expected, actual) in &failures {
+            msg.push_str(&format!("\n=== {} ===\n", name));
+            msg.push_str(&format!("Input: {:?}\n", raw));
+            msg.push_str(&format!("Expected: {:#?}\n", expected));
+            msg.push_str(&format!("Actual:   {:#?}\n", actual));
+        }
+        panic!("{}", msg);
+    }
+
+    assert!(total > 0, "No locale test fixtures found");
+    println!("All {} locale tests passed!", total);
+}
@@ -82,6 +82,81 @@
     };
   }
 
+  function escapeHtml(text) {
+    var div = document.createElement("div");
+    div.textContent = text;
+    return div.innerHTML;
+  }
+
+  // The page has several recipes: let the user tick which ones to save
+  function showRecipeChoice(jobId, titles) {
+    setStatus("This page has " + titles.length + " recipes. Which should be saved?", false, true);
+    actionsEl.style.display = "block";
+    actionsEl.innerHTML = [
+      '<div style="max-height:200px;overflow-y:auto;margin-bottom:12px;">',
+      titles.map(function(title, i) {
+        return [
+          '<label style="display:flex;align-items:center;gap:8px;padding:4px 0;cursor:pointer;">',
+          '<input type="checkbox" class="ramekin-recipe-choice" value="', i, '"',
+          i === 0 ? ' checked' : '', '>',
+          escapeHtml(title),
+          '</label>'
+        ].join("");
+      }).join(""),
+      '</div>',
+      '<div style="display:flex;gap:8px;">',
+      '<button id="ramekin-save-selected" style="padding:8px 16px;background:#4a9eff;color:#fff;',
+      'border:none;border-radius:6px;cursor:pointer;font-weight:500;">Save selected</button>',
+      '<button id="ramekin-close" style="padding:8px 16px;background:#e0e0e0;',
+      'border:none;border-radius:6px;cursor:pointer;">Close</button>',
+      '</div>'
+    ].join("");
+    document.getElementById("ramekin-close").onclick = function() {
+      overlay.remove();
+    };
+    document.getElementById("ramekin-save-selected").onclick = function() {
+      var boxes = actionsEl.querySelectorAll(".ramekin-recipe-choice");
+      var indices = [];
+      for (var i = 0; i < boxes.length; i++) {
+        if (boxes[i].checked) {
+          indices.push(parseInt(boxes[i].value, 10));
+        }
+      }
+      if (indices.length === 0) {
+        return;
+      }
+      selectRecipes(jobId, indices);
+    };
+  }
+
+  function selectRecipes(jobId, indices) {
+    actionsEl.style.display = "none";
+    actionsEl.innerHTML = "";
+    spinnerEl.style.display = "block";
+    setStatus("Saving recipes...");
+    fetch(apiOrigin + "/api/scrape/" + jobId + "/select", {
+      method: "POST",
+      headers: {
+        "Content-Type": "application/json",
+        "Authorization": "Bearer " + token
+      },
+      body: JSON.stringify({ recipe_indices: indices })
+    })
+    .then(function(r) {
+      if (!r.ok) {
+        return r.json().then(function(body) {
+          throw new Error(body.error || "Request failed");
+        });
+      }
+      pollJob(jobId);
+    })
+    .catch(function(err) {
+      console.error("[Ramekin] Select error:", err);
+      setStatus(err.message || "Failed to save recipes", true);
+      showCloseButton();
+    });
+  }
+
   function pollJob(jobId) {
     fetch(apiOrigin + "/api/scrape/" + jobId, {
       headers: { "Authorization": "Bearer " + token }
@@ -89,8 +164,11 @@
     .then(function(r) { return r.json(); })
     .then(function(job) {
       if (job.status === "completed" && job.recipe_id) {
-        setStatus("Recipe saved!", false, true);
+        var count = 1 + (job.split_job_ids || []).length;
+        setStatus(count > 1 ? count + " recipes saved!" : "Recipe saved!", false, true);
         showActions(job.recipe_id);
+      } else if (job.status === "awaiting_selection") {
+        showRecipeChoice(jobId, job.recipe_candidates || []);
       } else if (job.status === "failed") {
         setStatus(job.error || "Failed to extract recipe", true);
         showCloseButton();
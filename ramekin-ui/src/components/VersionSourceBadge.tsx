@@ -11,6 +11,10 @@ export default function VersionSourceBadge(props: VersionSourceBadgeProps) {
         return "Imported";
       case "enrich":
         return "AI Enriched";
+      case "bulk_edit":
+        return "Bulk Edit";
+      case "paprika":
+        return "Paprika Sync";
       default:
         return props.source;
     }
@@ -1,4 +1,4 @@
-import { createSignal, onMount, onCleanup, Show } from "solid-js";
+import { createSignal, onMount, onCleanup, For, Show } from "solid-js";
 
 interface CaptureMessage {
   type: "html";
@@ -16,6 +16,8 @@ interface ScrapeJobResponse {
   status: string;
   recipe_id?: string;
   error?: string;
+  split_job_ids: string[];
+  recipe_candidates: string[];
 }
 
 interface ErrorResponse {
@@ -25,7 +27,8 @@ interface ErrorResponse {
 type Status =
   | { type: "waiting" }
   | { type: "capturing"; jobId?: string; jobStatus?: string }
-  | { type: "success"; recipeId: string }
+  | { type: "selecting"; jobId: string; titles: string[] }
+  | { type: "success"; recipeId: string; count: number }
   | { type: "error"; message: string };
 
 const POLL_INITIAL_MS = 500;
@@ -35,6 +38,7 @@ const POLL_TIMEOUT_MS = 120_000; // 2 minutes
 
 export default function CapturePage() {
   const [status, setStatus] = createSignal<Status>({ type: "waiting" });
+  const [selected, setSelected] = createSignal<number[]>([]);
   let pollTimeout: ReturnType<typeof setTimeout> | null = null;
   let pollStartTime: number | null = null;
 
@@ -60,6 +64,15 @@ export default function CapturePage() {
         setStatus({
           type: "success",
           recipeId: job.recipe_id,
+          count: 1 + job.split_job_ids.length,
+        });
+      } else if (job.status === "awaiting_selection") {
+        // The page has several recipes; wait for the user to choose
+        setSelected([0]);
+        setStatus({
+          type: "selecting",
+          jobId: job.id,
+          titles: job.recipe_candidates,
         });
       } else if (job.status === "failed") {
         console.error("[Ramekin Capture] Job failed:", job.error);
@@ -190,6 +203,49 @@ export default function CapturePage() {
     }
   });
 
+  const toggleSelected = (index: number) => {
+    setSelected((current) =>
+      current.includes(index)
+        ? current.filter((i) => i !== index)
+        : [...current, index].sort((a, b) => a - b),
+    );
+  };
+
+  const handleSaveSelected = async (jobId: string) => {
+    const token = localStorage.getItem("token");
+    if (!token) {
+      setStatus({ type: "error", message: "Please log in to Ramekin first" });
+      return;
+    }
+
+    setStatus({ type: "capturing", jobId, jobStatus: "parsing" });
+    try {
+      const response = await fetch(`/api/scrape/${jobId}/select`, {
+        method: "POST",
+        headers: {
+          "Content-Type": "application/json",
+          Authorization: `Bearer ${token}`,
+        },
+        body: JSON.stringify({ recipe_indices: selected() }),
+      });
+
+      if (!response.ok) {
+        const error: ErrorResponse = await response.json();
+        setStatus({ type: "error", message: error.error });
+        return;
+      }
+
+      pollStartTime = Date.now();
+      pollTimeout = setTimeout(
+        () => pollJobStatus(jobId, token, POLL_INITIAL_MS),
+        POLL_INITIAL_MS,
+      );
+    } catch (err) {
+      console.error("[Ramekin Capture] Network error:", err);
+      setStatus({ type: "error", message: "Network error" });
+    }
+  };
+
   const handleClose = () => {
     window.parent.postMessage({ type: "close" }, "*");
   };
@@ -229,13 +285,53 @@ export default function CapturePage() {
         </div>
       </Show>
 
+      <Show when={status().type === "selecting"}>
+        {(() => {
+          const s = status();
+          if (s.type !== "selecting") return null;
+          return (
+            <div class="capture-status">
+              <p>
+                This page has {s.titles.length} recipes. Which should be saved?
+              </p>
+              <div class="capture-choices">
+                <For each={s.titles}>
+                  {(title, index) => (
+                    <label>
+                      <input
+                        type="checkbox"
+                        checked={selected().includes(index())}
+                        onChange={() => toggleSelected(index())}
+                      />
+                      {title}
+                    </label>
+                  )}
+                </For>
+              </div>
+              <div class="capture-actions">
+                <button
+                  class="btn-view"
+                  disabled={selected().length === 0}
+                  onClick={() => handleSaveSelected(s.jobId)}
+                >
+                  Save selected
+                </button>
+                <button onClick={handleClose}>Close</button>
+              </div>
+            </div>
+          );
+        })()}
+      </Show>
+
       <Show when={status().type === "success"}>
         {(() => {
           const s = status();
           if (s.type !== "success") return null;
           return (
             <div class="capture-status capture-success">
-              <p>Recipe saved!</p>
+              <p>
+                {s.count > 1 ? `${s.count} recipes saved!` : "Recipe saved!"}
+              </p>
               <div class="capture-actions">
                 <button
                   class="btn-view"
@@ -1,4 +1,4 @@
-import { createSignal, createMemo, Show, onCleanup } from "solid-js";
+import { createSignal, createMemo, Show, For, onCleanup } from "solid-js";
 import bookmarkletSource from "../bookmarklet.js?raw";
 import { createStore } from "solid-js/store";
 import { useNavigate, A } from "@solidjs/router";
@@ -275,24 +275,40 @@ export default function CreateRecipePage() {
         <Show
           when={scrapeJob()?.status !== "completed"}
           fallback={
-            <div class="import-success">
-              <span>Recipe imported!</span>
-              <A
-                href={`/recipes/${scrapeJob()?.recipeId}`}
-                class="btn btn-small"
-              >
-                View
-              </A>
-              <A
-                href={`/recipes/${scrapeJob()?.recipeId}/edit`}
-                class="btn btn-small"
-              >
-                Edit
-              </A>
-              <button type="button" class="btn btn-small" onClick={clearImport}>
-                Import another
-              </button>
-            </div>
+            <>
+              <div class="import-success">
+                <span>Recipe imported!</span>
+                <A
+                  href={`/recipes/${scrapeJob()?.recipeId}`}
+                  class="btn btn-small"
+                >
+                  View
+                </A>
+                <A
+                  href={`/recipes/${scrapeJob()?.recipeId}/edit`}
+                  class="btn btn-small"
+                >
+                  Edit
+                </A>
+                <button
+                  type="button"
+                  class="btn btn-small"
+                  onClick={clearImport}
+                >
+                  Import another
+                </button>
+              </div>
+              <Show when={(scrapeJob()?.warnings ?? []).length > 0}>
+                <div class="import-warnings">
+                  <span>This import looks off in places, so review it:</span>
+                  <ul>
+                    <For each={scrapeJob()?.warnings}>
+                      {(warning) => <li>{warning.message}</li>}
+                    </For>
+                  </ul>
+                </div>
+              </Show>
+            </>
           }
         >
           <div class="import-row">
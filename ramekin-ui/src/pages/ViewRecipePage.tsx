@@ -274,6 +274,10 @@ export default function ViewRecipePage() {
           nutritionalInfo: oldRecipe.nutritionalInfo,
           sourceName: oldRecipe.sourceName,
           sourceUrl: oldRecipe.sourceUrl,
+          videoUrl: oldRecipe.videoUrl,
+          equipment: oldRecipe.equipment,
+          author: oldRecipe.author,
+          publishedDate: oldRecipe.publishedDate,
         },
       });
 
@@ -712,18 +716,35 @@ export default function ViewRecipePage() {
                   </For>
                 </div>
               </Show>
-              <Show when={r().sourceUrl || r().sourceName}>
+              <Show when={r().sourceUrl || r().sourceName || r().videoUrl}>
                 <div class="recipe-source-inline">
-                  <Show
-                    when={r().sourceUrl}
-                    fallback={<span>{r().sourceName}</span>}
-                  >
+                  <Show when={r().sourceUrl || r().sourceName}>
+                    <Show
+                      when={r().sourceUrl}
+                      fallback={<span>{r().sourceName}</span>}
+                    >
+                      <a
+                        href={r().sourceUrl!}
+                        target="_blank"
+                        rel="noopener noreferrer"
+                      >
+                        {r().sourceName || "Source"}
+                      </a>
+                    </Show>
+                    <Show when={r().author}>
+                      <span> by {r().author}</span>
+                    </Show>
+                  </Show>
+                  <Show when={r().videoUrl}>
+                    <Show when={r().sourceUrl || r().sourceName}>
+                      {" · "}
+                    </Show>
                     <a
-                      href={r().sourceUrl!}
+                      href={r().videoUrl!}
                       target="_blank"
                       rel="noopener noreferrer"
                     >
-                      {r().sourceName || "Source"}
+                      Video
                     </a>
                   </Show>
                 </div>
@@ -837,6 +858,16 @@ export default function ViewRecipePage() {
                       )}
                     </For>
                   </section>
+                  <Show when={r().equipment && r().equipment.length > 0}>
+                    <section class="recipe-section">
+                      <h3>Equipment</h3>
+                      <ul class="ingredients-list">
+                        <For each={r().equipment}>
+                          {(item) => <li>{item}</li>}
+                        </For>
+                      </ul>
+                    </section>
+                  </Show>
                 </div>
               </Show>
 
@@ -0,0 +1,390 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::{DbConn, DbPool};
+use crate::get_conn;
+use crate::models::{MealPlan, Photo, Recipe, RecipeVersion, ShoppingListItem};
+use crate::schema::{
+    meal_plans, photos, recipe_version_tags, recipe_versions, recipes, shopping_list_items,
+    user_tags,
+};
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::sync::Arc;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// Version of the backup archive layout. Bump when the format changes incompatibly.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Name of the JSON document inside the backup archive
+pub const BACKUP_DATA_FILE: &str = "backup.json";
+
+/// Path of a photo's original bytes inside the backup archive
+pub fn photo_data_path(id: Uuid) -> String {
+    format!("photos/{}", id)
+}
+
+/// Path of a photo's thumbnail bytes inside the backup archive
+pub fn photo_thumbnail_path(id: Uuid) -> String {
+    format!("photos/{}.thumbnail", id)
+}
+
+/// Top-level backup document. Every row belonging to the user is included,
+/// soft-deleted rows too, so a restore reproduces the account exactly.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupData {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub recipes: Vec<BackupRecipe>,
+    pub recipe_versions: Vec<BackupRecipeVersion>,
+    pub recipe_version_tags: Vec<BackupRecipeVersionTag>,
+    pub user_tags: Vec<BackupUserTag>,
+    /// Photo metadata; the bytes live in separate archive entries
+    pub photos: Vec<BackupPhoto>,
+    pub meal_plans: Vec<BackupMealPlan>,
+    pub shopping_list_items: Vec<BackupShoppingListItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupRecipe {
+    pub id: Uuid,
+    pub current_version_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupRecipeVersion {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub ingredients: serde_json::Value,
+    pub instructions: String,
+    pub source_url: Option<String>,
+    pub source_name: Option<String>,
+    pub photo_ids: Vec<Option<Uuid>>,
+    pub servings: Option<String>,
+    pub prep_time: Option<String>,
+    pub cook_time: Option<String>,
+    pub total_time: Option<String>,
+    pub rating: Option<i32>,
+    pub difficulty: Option<String>,
+    pub nutritional_info: Option<String>,
+    pub notes: Option<String>,
+    pub version_source: String,
+    pub created_at: DateTime<Utc>,
+    // Backups made before these fields existed lack them; serde(default) keeps
+    // those backups restorable
+    #[serde(default)]
+    pub video_url: Option<String>,
+    #[serde(default)]
+    pub equipment: Vec<Option<String>>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub published_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupRecipeVersionTag {
+    pub recipe_version_id: Uuid,
+    pub tag_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupUserTag {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupPhoto {
+    pub id: Uuid,
+    pub content_type: String,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupMealPlan {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub meal_date: NaiveDate,
+    pub meal_type: String,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupShoppingListItem {
+    pub id: Uuid,
+    pub item: String,
+    pub amount: Option<String>,
+    pub note: Option<String>,
+    pub source_recipe_id: Option<Uuid>,
+    pub source_recipe_title: Option<String>,
+    pub is_checked: bool,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// (id, name, created_at, deleted_at) of a user tag
+type TagRow = (Uuid, String, DateTime<Utc>, Option<DateTime<Utc>>);
+
+/// Load every row belonging to the user into a backup document, plus photo bytes.
+fn load_backup(
+    conn: &mut DbConn,
+    user_id: Uuid,
+) -> Result<(BackupData, Vec<Photo>), diesel::result::Error> {
+    let recipe_rows: Vec<Recipe> = recipes::table
+        .filter(recipes::user_id.eq(user_id))
+        .select(Recipe::as_select())
+        .load(conn)?;
+
+    let version_rows: Vec<RecipeVersion> = recipe_versions::table
+        .inner_join(recipes::table.on(recipes::id.eq(recipe_versions::recipe_id)))
+        .filter(recipes::user_id.eq(user_id))
+        .select(RecipeVersion::as_select())
+        .load(conn)?;
+
+    let tag_rows: Vec<TagRow> = user_tags::table
+        .filter(user_tags::user_id.eq(user_id))
+        .select((
+            user_tags::id,
+            user_tags::name,
+            user_tags::created_at,
+            user_tags::deleted_at,
+        ))
+        .load(conn)?;
+
+    let version_tag_rows: Vec<(Uuid, Uuid)> = recipe_version_tags::table
+        .inner_join(user_tags::table)
+        .filter(user_tags::user_id.eq(user_id))
+        .select((
+            recipe_version_tags::recipe_version_id,
+            recipe_version_tags::tag_id,
+        ))
+        .load(conn)?;
+
+    let photo_rows: Vec<Photo> = photos::table
+        .filter(photos::user_id.eq(user_id))
+        .select(Photo::as_select())
+        .load(conn)?;
+
+    let meal_plan_rows: Vec<MealPlan> = meal_plans::table
+        .filter(meal_plans::user_id.eq(user_id))
+        .select(MealPlan::as_select())
+        .load(conn)?;
+
+    let shopping_rows: Vec<ShoppingListItem> = shopping_list_items::table
+        .filter(shopping_list_items::user_id.eq(user_id))
+        .select(ShoppingListItem::as_select())
+        .load(conn)?;
+
+    let data = BackupData {
+        format_version: BACKUP_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        recipes: recipe_rows
+            .into_iter()
+            .map(|r| BackupRecipe {
+                id: r.id,
+                current_version_id: r.current_version_id,
+                created_at: r.created_at,
+                deleted_at: r.deleted_at,
+            })
+            .collect(),
+        recipe_versions: version_rows
+            .into_iter()
+            .map(|v| BackupRecipeVersion {
+                id: v.id,
+                recipe_id: v.recipe_id,
+                title: v.title,
+                description: v.description,
+                ingredients: v.ingredients,
+                instructions: v.instructions,
+                source_url: v.source_url,
+                source_name: v.source_name,
+                photo_ids: v.photo_ids,
+                servings: v.servings,
+                prep_time: v.prep_time,
+                cook_time: v.cook_time,
+                total_time: v.total_time,
+                rating: v.rating,
+                difficulty: v.difficulty,
+                nutritional_info: v.nutritional_info,
+                notes: v.notes,
+                version_source: v.version_source,
+                created_at: v.created_at,
+                video_url: v.video_url,
+                equipment: v.equipment,
+                author: v.author,
+                published_date: v.published_date,
+            })
+            .collect(),
+        recipe_version_tags: version_tag_rows
+            .into_iter()
+            .map(|(recipe_version_id, tag_id)| BackupRecipeVersionTag {
+                recipe_version_id,
+                tag_id,
+            })
+            .collect(),
+        user_tags: tag_rows
+            .into_iter()
+            .map(|(id, name, created_at, deleted_at)| BackupUserTag {
+                id,
+                name,
+                created_at,
+                deleted_at,
+            })
+            .collect(),
+        photos: photo_rows
+            .iter()
+            .map(|p| BackupPhoto {
+                id: p.id,
+                content_type: p.content_type.clone(),
+                created_at: p.created_at,
+                deleted_at: p.deleted_at,
+            })
+            .collect(),
+        meal_plans: meal_plan_rows
+            .into_iter()
+            .map(|m| BackupMealPlan {
+                id: m.id,
+                recipe_id: m.recipe_id,
+                meal_date: m.meal_date,
+                meal_type: m.meal_type,
+                notes: m.notes,
+                created_at: m.created_at,
+                deleted_at: m.deleted_at,
+            })
+            .collect(),
+        shopping_list_items: shopping_rows
+            .into_iter()
+            .map(|s| BackupShoppingListItem {
+                id: s.id,
+                item: s.item,
+                amount: s.amount,
+                note: s.note,
+                source_recipe_id: s.source_recipe_id,
+                source_recipe_title: s.source_recipe_title,
+                is_checked: s.is_checked,
+                sort_order: s.sort_order,
+                created_at: s.created_at,
+                updated_at: s.updated_at,
+                deleted_at: s.deleted_at,
+            })
+            .collect(),
+    };
+
+    Ok((data, photo_rows))
+}
+
+/// Write the backup document and photo bytes into a ZIP archive
+fn write_backup_archive(data: &BackupData, photo_rows: &[Photo]) -> Result<Vec<u8>, String> {
+    let json = serde_json::to_vec_pretty(data).map_err(|e| e.to_string())?;
+
+    let mut zip_buffer = Vec::new();
+    {
+        let mut zip = ZipWriter::new(std::io::Cursor::new(&mut zip_buffer));
+        let json_options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        // Images are already compressed
+        let photo_options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+        zip.start_file(BACKUP_DATA_FILE, json_options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(&json).map_err(|e| e.to_string())?;
+
+        for photo in photo_rows {
+            zip.start_file(photo_data_path(photo.id), photo_options)
+                .map_err(|e| e.to_string())?;
+            zip.write_all(&photo.data).map_err(|e| e.to_string())?;
+
+            zip.start_file(photo_thumbnail_path(photo.id), photo_options)
+                .map_err(|e| e.to_string())?;
+            zip.write_all(&photo.thumbnail).map_err(|e| e.to_string())?;
+        }
+
+        zip.finish().map_err(|e| e.to_string())?;
+    }
+
+    Ok(zip_buffer)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/account/backup",
+    tag = "account",
+    responses(
+        (status = 200, description = "Full account backup archive (ZIP)", content_type = "application/zip"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn backup_account(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+) -> impl IntoResponse {
+    let mut conn = get_conn!(pool);
+
+    let (data, photo_rows) = match load_backup(&mut conn, user.id) {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to load account backup: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to load account data".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let archive = match write_backup_archive(&data, &photo_rows) {
+        Ok(a) => a,
+        Err(e) => {
+            tracing::error!("Failed to create backup archive: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to create backup archive".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let timestamp = Utc::now().format("%Y%m%d-%H%M%S");
+    let filename = format!("ramekin-backup-{}.zip", timestamp);
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/zip")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        )
+        .body(Body::from(archive))
+        .unwrap()
+        .into_response()
+}
@@ -0,0 +1,25 @@
+pub mod backup;
+pub mod restore;
+
+use crate::AppState;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+use utoipa::OpenApi;
+
+/// Returns the router for /api/account endpoints (mounted at /api/account)
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/backup", get(backup::backup_account))
+        .route(
+            "/restore",
+            post(restore::restore_account).layer(DefaultBodyLimit::max(restore::MAX_BACKUP_SIZE)),
+        )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(backup::backup_account, restore::restore_account),
+    components(schemas(restore::RestoreBackupRequest, restore::RestoreBackupResponse))
+)]
+pub struct ApiDoc;
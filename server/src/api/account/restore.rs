@@ -0,0 +1,444 @@
+use super::backup::{
+    photo_data_path, photo_thumbnail_path, BackupData, BACKUP_DATA_FILE, BACKUP_FORMAT_VERSION,
+};
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::get_conn;
+use crate::models::{
+    NewUserTag, ParsedRecipeFields, RecipeVersionTag, RestoredMealPlan, RestoredPhoto,
+    RestoredRecipe, RestoredRecipeVersion, RestoredShoppingListItem, SourceDetails,
+};
+use crate::schema::{
+    meal_plans, photos, recipe_version_tags, recipe_versions, recipes, shopping_list_items,
+    user_tags,
+};
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use ramekin_core::extract::http_url;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use zip::ZipArchive;
+
+/// Maximum accepted backup archive size (photos make these large)
+pub const MAX_BACKUP_SIZE: usize = 512 * 1024 * 1024;
+
+/// Rows per batched INSERT (keeps bind parameters well under Postgres' 65535 limit)
+const INSERT_BATCH_SIZE: usize = 1000;
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct RestoreBackupRequest {
+    /// Backup archive produced by GET /api/account/backup
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RestoreBackupResponse {
+    pub recipes: usize,
+    pub recipe_versions: usize,
+    pub tags: usize,
+    pub photos: usize,
+    pub meal_plans: usize,
+    pub shopping_list_items: usize,
+}
+
+#[derive(Debug)]
+enum RestoreError {
+    /// The archive is malformed or internally inconsistent
+    Invalid(String),
+    Database(diesel::result::Error),
+}
+
+impl From<diesel::result::Error> for RestoreError {
+    fn from(e: diesel::result::Error) -> Self {
+        RestoreError::Database(e)
+    }
+}
+
+/// Photo bytes read from the archive, keyed by the photo's id in the backup
+struct PhotoBytes {
+    data: Vec<u8>,
+    thumbnail: Vec<u8>,
+}
+
+fn read_zip_entry(
+    archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+    name: &str,
+) -> Result<Vec<u8>, String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|_| format!("Backup is missing {}", name))?;
+    let mut buf = Vec::new();
+    entry
+        .read_to_end(&mut buf)
+        .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+    Ok(buf)
+}
+
+/// Parse the archive into the backup document and its photo bytes
+fn read_backup_archive(bytes: Vec<u8>) -> Result<(BackupData, HashMap<Uuid, PhotoBytes>), String> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| format!("Not a valid backup archive: {}", e))?;
+
+    let json = read_zip_entry(&mut archive, BACKUP_DATA_FILE)?;
+    let data: BackupData = serde_json::from_slice(&json)
+        .map_err(|e| format!("Invalid {}: {}", BACKUP_DATA_FILE, e))?;
+
+    if data.format_version > BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "Backup format version {} is newer than supported version {}",
+            data.format_version, BACKUP_FORMAT_VERSION
+        ));
+    }
+
+    let mut photo_bytes = HashMap::new();
+    for photo in &data.photos {
+        let bytes = PhotoBytes {
+            data: read_zip_entry(&mut archive, &photo_data_path(photo.id))?,
+            thumbnail: read_zip_entry(&mut archive, &photo_thumbnail_path(photo.id))?,
+        };
+        photo_bytes.insert(photo.id, bytes);
+    }
+
+    Ok((data, photo_bytes))
+}
+
+/// Generate a fresh id for every backed-up id so a backup can be restored into
+/// any account (including the one it came from) without primary key collisions.
+fn remap_ids(ids: impl Iterator<Item = Uuid>) -> HashMap<Uuid, Uuid> {
+    ids.map(|old| (old, Uuid::new_v4())).collect()
+}
+
+/// Insert the backup's rows for `user_id` inside a single transaction
+fn restore_backup(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    data: &BackupData,
+    photo_bytes: &HashMap<Uuid, PhotoBytes>,
+) -> Result<RestoreBackupResponse, RestoreError> {
+    let recipe_ids = remap_ids(data.recipes.iter().map(|r| r.id));
+    let version_ids = remap_ids(data.recipe_versions.iter().map(|v| v.id));
+    let photo_ids = remap_ids(data.photos.iter().map(|p| p.id));
+
+    conn.transaction(|conn| {
+        // 1. Photos (one at a time; rows carry full image bytes)
+        for photo in &data.photos {
+            let bytes = &photo_bytes[&photo.id];
+            diesel::insert_into(photos::table)
+                .values(RestoredPhoto {
+                    id: photo_ids[&photo.id],
+                    user_id,
+                    content_type: &photo.content_type,
+                    data: &bytes.data,
+                    thumbnail: &bytes.thumbnail,
+                    created_at: photo.created_at,
+                    deleted_at: photo.deleted_at,
+                })
+                .execute(conn)?;
+        }
+
+        // 2. Tags: merge by name with any tags the account already has
+        let mut tag_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        for tag in &data.user_tags {
+            let new_id: Uuid = if tag.deleted_at.is_none() {
+                diesel::insert_into(user_tags::table)
+                    .values(NewUserTag {
+                        user_id,
+                        name: &tag.name,
+                    })
+                    .on_conflict((user_tags::user_id, user_tags::name))
+                    .do_update()
+                    .set(user_tags::deleted_at.eq(None::<DateTime<Utc>>)) // Revive soft-deleted tags
+                    .returning(user_tags::id)
+                    .get_result(conn)?
+            } else {
+                // A deleted tag must not hide a live tag of the same name
+                let existing: Option<Uuid> = user_tags::table
+                    .filter(user_tags::user_id.eq(user_id))
+                    .filter(user_tags::name.eq(&tag.name))
+                    .select(user_tags::id)
+                    .first(conn)
+                    .optional()?;
+                match existing {
+                    Some(id) => id,
+                    None => diesel::insert_into(user_tags::table)
+                        .values((
+                            user_tags::user_id.eq(user_id),
+                            user_tags::name.eq(&tag.name),
+                            user_tags::created_at.eq(tag.created_at),
+                            user_tags::deleted_at.eq(tag.deleted_at),
+                        ))
+                        .returning(user_tags::id)
+                        .get_result(conn)?,
+                }
+            };
+            tag_ids.insert(tag.id, new_id);
+        }
+
+        // 3. Recipes (current_version_id is set once versions exist)
+        let new_recipes: Vec<RestoredRecipe> = data
+            .recipes
+            .iter()
+            .map(|r| RestoredRecipe {
+                id: recipe_ids[&r.id],
+                user_id,
+                created_at: r.created_at,
+                deleted_at: r.deleted_at,
+            })
+            .collect();
+        for chunk in new_recipes.chunks(INSERT_BATCH_SIZE) {
+            diesel::insert_into(recipes::table)
+                .values(chunk)
+                .execute(conn)?;
+        }
+
+        // 4. Versions
+        let mut new_versions = Vec::with_capacity(data.recipe_versions.len());
+        for v in &data.recipe_versions {
+            let recipe_id = *recipe_ids.get(&v.recipe_id).ok_or_else(|| {
+                RestoreError::Invalid(format!(
+                    "Recipe version {} references unknown recipe {}",
+                    v.id, v.recipe_id
+                ))
+            })?;
+            new_versions.push(RestoredRecipeVersion {
+                id: version_ids[&v.id],
+                recipe_id,
+                title: &v.title,
+                description: v.description.as_deref(),
+                ingredients: v.ingredients.clone(),
+                instructions: &v.instructions,
+                source_url: v.source_url.as_deref(),
+                source_name: v.source_name.as_deref(),
+                // Photos missing from the backup are dropped rather than left dangling
+                photo_ids: v
+                    .photo_ids
+                    .iter()
+                    .filter_map(|id| id.and_then(|id| photo_ids.get(&id).copied()))
+                    .map(Some)
+                    .collect(),
+                servings: v.servings.as_deref(),
+                prep_time: v.prep_time.as_deref(),
+                cook_time: v.cook_time.as_deref(),
+                total_time: v.total_time.as_deref(),
+                rating: v.rating,
+                difficulty: v.difficulty.as_deref(),
+                nutritional_info: v.nutritional_info.as_deref(),
+                notes: v.notes.as_deref(),
+                version_source: &v.version_source,
+                created_at: v.created_at,
+                parsed: ParsedRecipeFields::parse(
+                    v.servings.as_deref(),
+                    v.prep_time.as_deref(),
+                    v.cook_time.as_deref(),
+                    v.total_time.as_deref(),
+                ),
+                source_details: SourceDetails {
+                    video_url: v.video_url.as_deref().and_then(http_url),
+                    equipment: v.equipment.clone(),
+                    author: v.author.clone(),
+                    published_date: v.published_date,
+                },
+            });
+        }
+        for chunk in new_versions.chunks(INSERT_BATCH_SIZE) {
+            diesel::insert_into(recipe_versions::table)
+                .values(chunk)
+                .execute(conn)?;
+        }
+
+        // 5. Point each recipe at its restored current version
+        for r in &data.recipes {
+            if let Some(version_id) = r.current_version_id.and_then(|id| version_ids.get(&id)) {
+                diesel::update(recipes::table.find(recipe_ids[&r.id]))
+                    .set(recipes::current_version_id.eq(*version_id))
+                    .execute(conn)?;
+            }
+        }
+
+        // 6. Version <-> tag associations
+        let new_version_tags: Vec<RecipeVersionTag> = data
+            .recipe_version_tags
+            .iter()
+            .filter_map(|vt| {
+                Some(RecipeVersionTag {
+                    recipe_version_id: *version_ids.get(&vt.recipe_version_id)?,
+                    tag_id: *tag_ids.get(&vt.tag_id)?,
+                })
+            })
+            .collect();
+        for chunk in new_version_tags.chunks(INSERT_BATCH_SIZE) {
+            diesel::insert_into(recipe_version_tags::table)
+                .values(chunk)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+        }
+
+        // 7. Meal plans (skip slots the account already has planned)
+        let mut new_meal_plans = Vec::with_capacity(data.meal_plans.len());
+        for m in &data.meal_plans {
+            let recipe_id = *recipe_ids.get(&m.recipe_id).ok_or_else(|| {
+                RestoreError::Invalid(format!(
+                    "Meal plan {} references unknown recipe {}",
+                    m.id, m.recipe_id
+                ))
+            })?;
+            new_meal_plans.push(RestoredMealPlan {
+                id: Uuid::new_v4(),
+                user_id,
+                recipe_id,
+                meal_date: m.meal_date,
+                meal_type: &m.meal_type,
+                notes: m.notes.as_deref(),
+                created_at: m.created_at,
+                deleted_at: m.deleted_at,
+            });
+        }
+        let mut meal_plan_count = 0;
+        for chunk in new_meal_plans.chunks(INSERT_BATCH_SIZE) {
+            meal_plan_count += diesel::insert_into(meal_plans::table)
+                .values(chunk)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+        }
+
+        // 8. Shopping list items. client_id is a per-device sync key and is not carried over.
+        let new_items: Vec<RestoredShoppingListItem> = data
+            .shopping_list_items
+            .iter()
+            .map(|s| RestoredShoppingListItem {
+                id: Uuid::new_v4(),
+                user_id,
+                item: &s.item,
+                amount: s.amount.as_deref(),
+                note: s.note.as_deref(),
+                source_recipe_id: s
+                    .source_recipe_id
+                    .and_then(|id| recipe_ids.get(&id).copied()),
+                source_recipe_title: s.source_recipe_title.as_deref(),
+                is_checked: s.is_checked,
+                sort_order: s.sort_order,
+                created_at: s.created_at,
+                updated_at: s.updated_at,
+                deleted_at: s.deleted_at,
+            })
+            .collect();
+        for chunk in new_items.chunks(INSERT_BATCH_SIZE) {
+            diesel::insert_into(shopping_list_items::table)
+                .values(chunk)
+                .execute(conn)?;
+        }
+
+        Ok(RestoreBackupResponse {
+            recipes: new_recipes.len(),
+            recipe_versions: new_versions.len(),
+            tags: tag_ids.len(),
+            photos: data.photos.len(),
+            meal_plans: meal_plan_count,
+            shopping_list_items: new_items.len(),
+        })
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/account/restore",
+    tag = "account",
+    request_body(content_type = "multipart/form-data", content = RestoreBackupRequest),
+    responses(
+        (status = 201, description = "Backup restored", body = RestoreBackupResponse),
+        (status = 400, description = "Invalid backup archive", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_account(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "No file provided".to_string(),
+                }),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            tracing::warn!("Multipart read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read multipart data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let bytes = match field.bytes().await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!("Field read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read file data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let (data, photo_bytes) = match read_backup_archive(bytes.to_vec()) {
+        Ok(result) => result,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+        }
+    };
+
+    let mut conn = get_conn!(pool);
+
+    match restore_backup(&mut conn, user.id, &data, &photo_bytes) {
+        Ok(response) => {
+            tracing::info!(
+                "Restored backup for user {}: {} recipes, {} photos",
+                user.id,
+                response.recipes,
+                response.photos
+            );
+            (StatusCode::CREATED, Json(response)).into_response()
+        }
+        Err(RestoreError::Invalid(error)) => {
+            (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+        }
+        Err(RestoreError::Database(e)) => {
+            tracing::error!("Failed to restore backup: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to restore backup".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
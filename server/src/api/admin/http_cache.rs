@@ -0,0 +1,123 @@
+use super::{forbidden, is_admin};
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::http_cache;
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use ramekin_core::CachingClient;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HttpCacheStatsResponse {
+    /// URLs with a cached response
+    pub cached_success: i64,
+    /// URLs with only a cached error
+    pub cached_errors: i64,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PurgeHttpCacheParams {
+    /// Purge only this URL instead of the whole cache
+    pub url: Option<String>,
+}
+
+fn server_error(message: &str) -> Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse {
+            error: message.to_string(),
+        }),
+    )
+        .into_response()
+}
+
+fn cache_client() -> Option<CachingClient> {
+    http_cache::client_builder()
+        .build()
+        .map_err(|e| tracing::error!("Failed to create HTTP client: {}", e))
+        .ok()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/http-cache",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Shared HTTP cache statistics", body = HttpCacheStatsResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Admin access required", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_http_cache_stats(AuthUser(user): AuthUser) -> impl IntoResponse {
+    if !is_admin(&user) {
+        return forbidden();
+    }
+
+    let Some(client) = cache_client() else {
+        return server_error("Failed to open HTTP cache");
+    };
+    let stats = client.cache_stats().unwrap_or_default();
+
+    (
+        StatusCode::OK,
+        Json(HttpCacheStatsResponse {
+            cached_success: stats.cached_success as i64,
+            cached_errors: stats.cached_errors as i64,
+        }),
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/http-cache",
+    tag = "admin",
+    params(PurgeHttpCacheParams),
+    responses(
+        (status = 204, description = "Cached responses purged"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Admin access required", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn purge_http_cache(
+    AuthUser(user): AuthUser,
+    Query(params): Query<PurgeHttpCacheParams>,
+) -> impl IntoResponse {
+    if !is_admin(&user) {
+        return forbidden();
+    }
+
+    let Some(client) = cache_client() else {
+        return server_error("Failed to open HTTP cache");
+    };
+    let result = match &params.url {
+        Some(url) => client.remove_cached(url),
+        None => client.clear_cache(),
+    };
+
+    match result {
+        Ok(()) => {
+            tracing::info!(
+                "HTTP cache purged by {} ({})",
+                user.username,
+                params.url.as_deref().unwrap_or("all entries")
+            );
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to purge HTTP cache: {}", e);
+            server_error("Failed to purge HTTP cache")
+        }
+    }
+}
@@ -0,0 +1,45 @@
+pub mod http_cache;
+
+use crate::api::ErrorResponse;
+use crate::models::User;
+use crate::AppState;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use std::env;
+use utoipa::OpenApi;
+
+/// Whether the user is an administrator, i.e. listed in the comma-separated
+/// ADMIN_USERNAMES environment variable.
+pub fn is_admin(user: &User) -> bool {
+    env::var("ADMIN_USERNAMES")
+        .map(|names| names.split(',').any(|name| name.trim() == user.username))
+        .unwrap_or(false)
+}
+
+/// Response for authenticated users who aren't administrators.
+pub fn forbidden() -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse {
+            error: "Admin access required".to_string(),
+        }),
+    )
+        .into_response()
+}
+
+/// Returns the router for /api/admin endpoints (mounted at /api/admin)
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        "/http-cache",
+        get(http_cache::get_http_cache_stats).delete(http_cache::purge_http_cache),
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(http_cache::get_http_cache_stats, http_cache::purge_http_cache),
+    components(schemas(http_cache::HttpCacheStatsResponse))
+)]
+pub struct ApiDoc;
@@ -0,0 +1,239 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping;
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use ramekin_core::{extract_recipe_from_text, ExtractionMethod};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Maximum accepted document size
+pub const MAX_DOCUMENT_SIZE: usize = 20 * 1024 * 1024;
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImportFromDocumentRequest {
+    /// PDF (with a text layer) or plain text file containing a recipe
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ImportFromDocumentResponse {
+    /// The created job ID
+    pub job_id: Uuid,
+    /// Current job status
+    pub status: String,
+    /// True when the text heuristics found no recipe and AI extraction is used instead
+    pub ai_extraction: bool,
+}
+
+enum DocumentKind {
+    Pdf,
+    Text,
+}
+
+fn detect_document_kind(
+    data: &[u8],
+    file_name: Option<&str>,
+    content_type: Option<&str>,
+) -> Option<DocumentKind> {
+    let extension = file_name
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase());
+
+    if data.starts_with(b"%PDF-")
+        || content_type == Some("application/pdf")
+        || extension.as_deref() == Some("pdf")
+    {
+        return Some(DocumentKind::Pdf);
+    }
+
+    if content_type.is_some_and(|ct| ct.starts_with("text/"))
+        || matches!(extension.as_deref(), Some("txt" | "text" | "md"))
+    {
+        return Some(DocumentKind::Text);
+    }
+
+    None
+}
+
+/// Extract the text layer of a PDF. Scanned PDFs have no text layer and yield
+/// an empty string; there is no OCR.
+async fn extract_pdf_text(data: Vec<u8>) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || {
+        // pdf-extract can panic on malformed files rather than returning an error
+        std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem(&data))
+            .map_err(|_| "Could not read PDF".to_string())?
+            .map_err(|e| format!("Could not read PDF: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Could not read PDF: {}", e))?
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/import/document",
+    tag = "import",
+    request_body(content = ImportFromDocumentRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Document import job created", body = ImportFromDocumentResponse),
+        (status = 400, description = "Unsupported or unreadable document", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 413, description = "Document too large", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import_from_document(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "No file provided".to_string(),
+                }),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            tracing::warn!("Multipart read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read multipart data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let file_name = field.file_name().map(|s| s.to_string());
+    let content_type = field.content_type().map(|s| s.to_string());
+
+    let data = match field.bytes().await {
+        Ok(bytes) => bytes.to_vec(),
+        Err(e) => {
+            tracing::warn!("Field read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read file data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let text = match detect_document_kind(&data, file_name.as_deref(), content_type.as_deref()) {
+        Some(DocumentKind::Pdf) => match extract_pdf_text(data).await {
+            Ok(text) => text,
+            Err(error) => {
+                tracing::warn!("PDF text extraction failed: {}", error);
+                return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response();
+            }
+        },
+        Some(DocumentKind::Text) => String::from_utf8_lossy(&data)
+            .trim_start_matches('\u{feff}')
+            .to_string(),
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Unsupported document type. Upload a PDF or plain text file".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    };
+
+    if text.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "No text found in document. Scanned PDFs are not supported; import them as photos instead".to_string(),
+            }),
+        )
+            .into_response();
+    }
+
+    // Try the plain-text heuristics first; fall back to AI in the background
+    let (job, ai_extraction) = match extract_recipe_from_text(&text) {
+        Some(raw_recipe) => {
+            let job = match scraping::create_import_job(
+                &pool,
+                user.id,
+                None,
+                &raw_recipe,
+                ExtractionMethod::DocumentText,
+                vec![],
+                None,
+            ) {
+                Ok(j) => j,
+                Err(e) => {
+                    tracing::error!("Failed to create document import job: {}", e);
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: "Failed to create import job".to_string(),
+                        }),
+                    )
+                        .into_response();
+                }
+            };
+            tracing::info!(
+                "Created document import job {} for recipe '{}'",
+                job.id,
+                raw_recipe.title
+            );
+            (job, false)
+        }
+        None => {
+            let text_len = text.len();
+            let job = match scraping::create_document_import_job(&pool, user.id, text) {
+                Ok(j) => j,
+                Err(e) => {
+                    tracing::error!("Failed to create document import job: {}", e);
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ErrorResponse {
+                            error: "Failed to create import job".to_string(),
+                        }),
+                    )
+                        .into_response();
+                }
+            };
+            tracing::info!(
+                "Created document import job {} using AI extraction ({} chars)",
+                job.id,
+                text_len
+            );
+            (job, true)
+        }
+    };
+
+    scraping::wake_workers();
+
+    (
+        StatusCode::CREATED,
+        Json(ImportFromDocumentResponse {
+            job_id: job.id,
+            status: job.status,
+            ai_extraction,
+        }),
+    )
+        .into_response()
+}
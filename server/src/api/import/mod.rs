@@ -1,6 +1,10 @@
+mod document;
+mod paprika;
 mod photos;
 mod recipe;
 
+pub use document::{import_from_document, MAX_DOCUMENT_SIZE};
+pub use paprika::{get_paprika_sync_state, import_paprika_recipe};
 pub use photos::import_from_photos;
 pub use recipe::import_recipe;
 
@@ -8,12 +12,25 @@ use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(recipe::import_recipe, photos::import_from_photos),
+    paths(
+        recipe::import_recipe,
+        photos::import_from_photos,
+        document::import_from_document,
+        paprika::get_paprika_sync_state,
+        paprika::import_paprika_recipe
+    ),
     components(schemas(
         recipe::ImportRecipeRequest,
         recipe::ImportRecipeResponse,
         photos::ImportFromPhotosRequest,
         photos::ImportFromPhotosResponse,
+        document::ImportFromDocumentRequest,
+        document::ImportFromDocumentResponse,
+        paprika::PaprikaSyncEntry,
+        paprika::PaprikaSyncStateResponse,
+        paprika::ImportPaprikaRecipeRequest,
+        paprika::PaprikaImportOutcome,
+        paprika::ImportPaprikaRecipeResponse,
     ))
 )]
 pub struct ApiDoc;
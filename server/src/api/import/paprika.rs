@@ -0,0 +1,339 @@
+use super::recipe::ImportRawRecipe;
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::get_conn;
+use crate::models::NewPaprikaRecipeLink;
+use crate::schema::{paprika_recipes, recipes, scrape_jobs};
+use crate::scraping;
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use chrono::Utc;
+use diesel::prelude::*;
+use ramekin_core::{ExtractionMethod, RawRecipe};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A previously imported Paprika recipe
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PaprikaSyncEntry {
+    /// Paprika's recipe uid
+    pub uid: String,
+    /// Paprika's content hash from the most recent import
+    pub hash: String,
+    /// The Ramekin recipe it was imported into (None while the import job is still running)
+    pub recipe_id: Option<Uuid>,
+}
+
+/// Paprika recipes already imported into this account
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PaprikaSyncStateResponse {
+    pub recipes: Vec<PaprikaSyncEntry>,
+}
+
+/// Request body for importing a single Paprika recipe
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ImportPaprikaRecipeRequest {
+    /// The raw recipe data (converted from the Paprika export by client)
+    pub raw_recipe: ImportRawRecipe,
+    /// Photo IDs that have already been uploaded via POST /api/photos
+    pub photo_ids: Vec<Uuid>,
+    /// Paprika's stable recipe uid
+    pub uid: String,
+    /// Paprika's content hash for this recipe
+    pub hash: String,
+}
+
+/// What happened to an imported Paprika recipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaprikaImportOutcome {
+    /// Not seen before; an import job creates a new recipe
+    Added,
+    /// Hash changed; an import job adds a new version to the existing recipe
+    Updated,
+    /// Hash matches the previous import; nothing to do
+    Unchanged,
+}
+
+/// Response from a Paprika recipe import
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ImportPaprikaRecipeResponse {
+    pub outcome: PaprikaImportOutcome,
+    /// The created job ID (None when unchanged)
+    pub job_id: Option<Uuid>,
+    /// The existing recipe, if this Paprika recipe was imported before
+    pub recipe_id: Option<Uuid>,
+}
+
+/// A paprika_recipes row with its recipe resolved through the import job
+struct ResolvedLink {
+    uid: String,
+    hash: String,
+    recipe_id: Option<Uuid>,
+    in_progress: bool,
+}
+
+/// Load the user's Paprika links (optionally just one uid), resolving each to a
+/// live recipe. Links whose job failed or whose recipe was deleted resolve to
+/// no recipe and are not in progress, so they get imported again.
+fn load_links(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    uid: Option<&str>,
+) -> QueryResult<Vec<ResolvedLink>> {
+    let mut query = paprika_recipes::table
+        .left_join(scrape_jobs::table)
+        .filter(paprika_recipes::user_id.eq(user_id))
+        .select((
+            paprika_recipes::uid,
+            paprika_recipes::hash,
+            paprika_recipes::recipe_id,
+            scrape_jobs::status.nullable(),
+            scrape_jobs::recipe_id.nullable(),
+        ))
+        .order(paprika_recipes::uid.asc())
+        .into_boxed();
+    if let Some(uid) = uid {
+        query = query.filter(paprika_recipes::uid.eq(uid.to_string()));
+    }
+
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(String, String, Option<Uuid>, Option<String>, Option<Uuid>)> =
+        query.load(conn)?;
+
+    let candidate_ids: Vec<Uuid> = rows
+        .iter()
+        .flat_map(|(_, _, link_recipe, _, job_recipe)| [*link_recipe, *job_recipe])
+        .flatten()
+        .collect();
+    let live_ids: HashSet<Uuid> = recipes::table
+        .filter(recipes::id.eq_any(&candidate_ids))
+        .filter(recipes::user_id.eq(user_id))
+        .filter(recipes::deleted_at.is_null())
+        .select(recipes::id)
+        .load::<Uuid>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(rows
+        .into_iter()
+        .map(|(uid, hash, link_recipe, job_status, job_recipe)| {
+            let in_progress = job_status
+                .as_deref()
+                .is_some_and(|status| !scraping::is_finished(status));
+            let job_recipe =
+                job_recipe.filter(|_| job_status.as_deref() == Some(scraping::STATUS_COMPLETED));
+            let recipe_id = link_recipe
+                .or(job_recipe)
+                .filter(|id| live_ids.contains(id));
+            ResolvedLink {
+                uid,
+                hash,
+                recipe_id,
+                in_progress,
+            }
+        })
+        .collect())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/import/paprika",
+    tag = "import",
+    responses(
+        (status = 200, description = "Paprika recipes already imported", body = PaprikaSyncStateResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_paprika_sync_state(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+) -> impl IntoResponse {
+    let mut conn = get_conn!(pool);
+
+    let links = match load_links(&mut conn, user.id, None) {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::error!("Failed to load Paprika sync state: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to load Paprika sync state".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let recipes = links
+        .into_iter()
+        .filter(|link| link.recipe_id.is_some() || link.in_progress)
+        .map(|link| PaprikaSyncEntry {
+            uid: link.uid,
+            hash: link.hash,
+            recipe_id: link.recipe_id,
+        })
+        .collect();
+
+    (StatusCode::OK, Json(PaprikaSyncStateResponse { recipes })).into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/import/paprika",
+    tag = "import",
+    request_body = ImportPaprikaRecipeRequest,
+    responses(
+        (status = 200, description = "Recipe unchanged since the previous import", body = ImportPaprikaRecipeResponse),
+        (status = 201, description = "Import job created", body = ImportPaprikaRecipeResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "A previous import of this recipe is still running", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import_paprika_recipe(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Json(request): Json<ImportPaprikaRecipeRequest>,
+) -> impl IntoResponse {
+    if request.uid.trim().is_empty() || request.hash.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "uid and hash are required".to_string(),
+            }),
+        )
+            .into_response();
+    }
+
+    let mut conn = get_conn!(pool);
+
+    let existing = match load_links(&mut conn, user.id, Some(&request.uid)) {
+        Ok(mut links) => links.pop(),
+        Err(e) => {
+            tracing::error!("Failed to load Paprika link: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to load Paprika sync state".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let existing_recipe_id = existing.as_ref().and_then(|link| link.recipe_id);
+
+    if let Some(ref link) = existing {
+        if link.hash == request.hash && (link.recipe_id.is_some() || link.in_progress) {
+            // Remember the resolved recipe so later lookups don't depend on the job row
+            if let Some(recipe_id) = link.recipe_id {
+                let _ = diesel::update(paprika_recipes::table.find((user.id, &request.uid)))
+                    .set(paprika_recipes::recipe_id.eq(Some(recipe_id)))
+                    .execute(&mut conn);
+            }
+            return (
+                StatusCode::OK,
+                Json(ImportPaprikaRecipeResponse {
+                    outcome: PaprikaImportOutcome::Unchanged,
+                    job_id: None,
+                    recipe_id: link.recipe_id,
+                }),
+            )
+                .into_response();
+        }
+
+        if link.in_progress && link.recipe_id.is_none() {
+            return (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    error: "A previous import of this recipe is still running".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    let raw_recipe: RawRecipe = request.raw_recipe.into();
+
+    // With an existing recipe, save_recipe adds a "paprika" version instead of a new recipe
+    let job = match scraping::create_import_job(
+        &pool,
+        user.id,
+        raw_recipe.source_url.as_deref(),
+        &raw_recipe,
+        ExtractionMethod::Paprika,
+        request.photo_ids,
+        existing_recipe_id,
+    ) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!("Failed to create import job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to create import job: {}", e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let link = NewPaprikaRecipeLink {
+        user_id: user.id,
+        uid: &request.uid,
+        hash: &request.hash,
+        recipe_id: existing_recipe_id,
+        scrape_job_id: Some(job.id),
+    };
+    if let Err(e) = diesel::insert_into(paprika_recipes::table)
+        .values(&link)
+        .on_conflict((paprika_recipes::user_id, paprika_recipes::uid))
+        .do_update()
+        .set((
+            paprika_recipes::hash.eq(&request.hash),
+            paprika_recipes::recipe_id.eq(existing_recipe_id),
+            paprika_recipes::scrape_job_id.eq(Some(job.id)),
+            paprika_recipes::updated_at.eq(Utc::now()),
+        ))
+        .execute(&mut conn)
+    {
+        // The job still runs; the next sync just won't recognise this recipe
+        tracing::error!("Failed to record Paprika link for {}: {}", request.uid, e);
+    }
+
+    let outcome = if existing_recipe_id.is_some() {
+        PaprikaImportOutcome::Updated
+    } else {
+        PaprikaImportOutcome::Added
+    };
+
+    tracing::info!(
+        "Created Paprika import job {} ({:?}) for recipe '{}'",
+        job.id,
+        outcome,
+        raw_recipe.title
+    );
+
+    // The job is queued; wake a worker to run the pipeline
+    scraping::wake_workers();
+
+    (
+        StatusCode::CREATED,
+        Json(ImportPaprikaRecipeResponse {
+            outcome,
+            job_id: Some(job.id),
+            recipe_id: existing_recipe_id,
+        }),
+    )
+        .into_response()
+}
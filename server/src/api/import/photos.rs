@@ -18,7 +18,8 @@ pub struct ImportFromPhotosRequest {
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct ImportFromPhotosResponse {
-    /// The created job ID
+    /// The created job ID. When the photos contain several recipes, this job imports
+    /// the first and the job's split_job_ids lists the jobs importing the rest
     pub job_id: Uuid,
     /// Current job status
     pub status: String,
@@ -99,8 +100,9 @@ pub async fn import_from_photos(
         }
     }
 
-    // Create a pending scrape job (no URL for photo imports)
-    let job = match scraping::create_pending_photo_job(&pool, user.id) {
+    // Create a queued photo import job (no URL for photo imports)
+    let photo_count = request.photo_ids.len();
+    let job = match scraping::create_photo_import_job(&pool, user.id, request.photo_ids) {
         Ok(j) => j,
         Err(e) => {
             tracing::error!("Failed to create photo import job: {}", e);
@@ -117,11 +119,11 @@ pub async fn import_from_photos(
     tracing::info!(
         "Created photo import job {} with {} photos",
         job.id,
-        request.photo_ids.len()
+        photo_count
     );
 
-    // Spawn background task
-    scraping::spawn_photo_import_job(pool.clone(), job.id, user.id, request.photo_ids);
+    // Wake a worker to run the extraction
+    scraping::wake_workers();
 
     (
         StatusCode::CREATED,
@@ -87,6 +87,13 @@ impl From<ImportRawRecipe> for RawRecipe {
             nutritional_info: recipe.nutritional_info,
             notes: recipe.notes,
             categories: recipe.categories,
+            video_url: None,
+            equipment: None,
+            author: None,
+            published_date: None,
+            language: None,
+            ingredient_sections: vec![],
+            instruction_sections: vec![],
         }
     }
 }
@@ -141,6 +148,7 @@ pub async fn import_recipe(
         &raw_recipe,
         extraction_method,
         request.photo_ids,
+        None,
     ) {
         Ok(j) => j,
         Err(e) => {
@@ -161,8 +169,8 @@ pub async fn import_recipe(
         raw_recipe.title
     );
 
-    // Spawn background task to run the pipeline
-    scraping::spawn_import_job(pool.clone(), job.id);
+    // The job is queued; wake a worker to run the pipeline
+    scraping::wake_workers();
 
     (
         StatusCode::CREATED,
@@ -1,3 +1,5 @@
+pub mod account;
+pub mod admin;
 pub mod enrich;
 pub mod import;
 pub mod meal_plans;
@@ -8,6 +10,7 @@ pub mod scrape;
 pub mod shopping_list;
 pub mod tags;
 pub mod testing;
+pub mod upstream_updates;
 
 use serde::Serialize;
 use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
@@ -50,6 +53,9 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
         import::ApiDoc::openapi(),
         meal_plans::ApiDoc::openapi(),
         shopping_list::ApiDoc::openapi(),
+        account::ApiDoc::openapi(),
+        upstream_updates::ApiDoc::openapi(),
+        admin::ApiDoc::openapi(),
     ];
 
     for module_spec in modules {
@@ -2,11 +2,14 @@ use crate::api::ErrorResponse;
 use crate::auth::AuthUser;
 use crate::db::DbPool;
 use crate::get_conn;
-use crate::models::{NewRecipe, NewRecipeVersion, NewUserTag, RecipeVersionTag};
+use crate::models::{
+    NewRecipe, NewRecipeVersion, NewUserTag, ParsedRecipeFields, RecipeVersionTag, SourceDetails,
+};
 use crate::schema::{recipe_version_tags, recipe_versions, recipes, user_tags};
 use crate::types::RecipeContent;
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use diesel::prelude::*;
+use ramekin_core::extract::http_url;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -63,6 +66,18 @@ pub async fn create_recipe(
             .into_response();
     }
 
+    if let Some(ref video_url) = request.content.video_url {
+        if http_url(video_url).is_none() {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Video URL must be an http or https URL".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    }
+
     let mut conn = get_conn!(pool);
 
     let ingredients_json = match serde_json::to_value(&request.content.ingredients) {
@@ -116,6 +131,18 @@ pub async fn create_recipe(
             nutritional_info: request.content.nutritional_info.as_deref(),
             notes: request.content.notes.as_deref(),
             version_source: "user",
+            parsed: ParsedRecipeFields::parse(
+                request.content.servings.as_deref(),
+                request.content.prep_time.as_deref(),
+                request.content.cook_time.as_deref(),
+                request.content.total_time.as_deref(),
+            ),
+            source_details: SourceDetails::new(
+                request.content.video_url.clone(),
+                request.content.equipment.clone(),
+                request.content.author.clone(),
+                request.content.published_date,
+            ),
         };
 
         let version_id: Uuid = diesel::insert_into(recipe_versions::table)
@@ -123,7 +123,7 @@ fn convert_to_paprika(
         ingredients: ingredients_str,
         directions: version.instructions.clone(),
         description: version.description.clone().unwrap_or_default(),
-        notes: version.notes.clone().unwrap_or_default(),
+        notes: paprika_notes(version),
         source: version.source_name.clone().unwrap_or_default(),
         source_url: version.source_url.clone().unwrap_or_default(),
         categories: tags,
@@ -141,6 +141,37 @@ fn convert_to_paprika(
     }
 }
 
+/// Paprika has no fields for the video, equipment, author or publish date, so
+/// they're kept as lines after the recipe's own notes.
+fn paprika_notes(version: &RecipeVersion) -> String {
+    let equipment: Vec<&str> = version
+        .equipment
+        .iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    let details: Vec<String> = [
+        version.author.as_ref().map(|a| format!("Author: {}", a)),
+        version
+            .published_date
+            .map(|d| format!("Published: {}", d.format("%Y-%m-%d"))),
+        version.video_url.as_ref().map(|v| format!("Video: {}", v)),
+        (!equipment.is_empty()).then(|| format!("Equipment: {}", equipment.join(", "))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let notes = version.notes.clone().unwrap_or_default();
+    if details.is_empty() {
+        notes
+    } else if notes.trim().is_empty() {
+        details.join("\n")
+    } else {
+        format!("{}\n\n{}", notes.trim_end(), details.join("\n"))
+    }
+}
+
 /// Compress a recipe to gzip format (for .paprikarecipe files)
 fn gzip_recipe(recipe: &PaprikaRecipe) -> Result<Vec<u8>, String> {
     let json = serde_json::to_string(recipe).map_err(|e| e.to_string())?;
@@ -0,0 +1,206 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::get_conn;
+use crate::raw_sql;
+use crate::schema::{recipe_versions, recipes};
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{NaiveDate, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Columns of the recipe metadata CSV, in export order.
+/// `id` identifies the recipe on import; every other column is editable.
+pub const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "source_name",
+    "source_url",
+    "servings",
+    "prep_time",
+    "cook_time",
+    "total_time",
+    "rating",
+    "difficulty",
+    "tags",
+    "author",
+    "published_date",
+    "video_url",
+    "equipment",
+];
+
+/// Separator used to join multiple tags (or pieces of equipment) into one cell
+pub const TAG_SEPARATOR: &str = "; ";
+
+/// Join tags (or equipment) into one cell. A `;` or `\` inside an entry is
+/// escaped with a backslash so the import splits the cell back into the same
+/// entries.
+pub fn join_list<S: AsRef<str>>(items: impl IntoIterator<Item = S>) -> String {
+    items
+        .into_iter()
+        .map(|item| item.as_ref().replace('\\', "\\\\").replace(';', "\\;"))
+        .collect::<Vec<_>>()
+        .join(TAG_SEPARATOR)
+}
+
+// Type alias for the metadata query result row
+#[allow(clippy::type_complexity)]
+type MetadataRow = (
+    Uuid,                // recipes.id
+    String,              // title
+    Option<String>,      // source_name
+    Option<String>,      // source_url
+    Option<String>,      // servings
+    Option<String>,      // prep_time
+    Option<String>,      // cook_time
+    Option<String>,      // total_time
+    Option<i32>,         // rating
+    Option<String>,      // difficulty
+    Vec<String>,         // tags (from correlated subquery)
+    Option<String>,      // author
+    Option<NaiveDate>,   // published_date
+    Option<String>,      // video_url
+    Vec<Option<String>>, // equipment
+);
+
+fn write_csv(rows: Vec<MetadataRow>) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(CSV_COLUMNS)?;
+
+    for (
+        id,
+        title,
+        source_name,
+        source_url,
+        servings,
+        prep_time,
+        cook_time,
+        total_time,
+        rating,
+        difficulty,
+        tags,
+        author,
+        published_date,
+        video_url,
+        equipment,
+    ) in rows
+    {
+        writer.write_record([
+            id.to_string(),
+            title,
+            source_name.unwrap_or_default(),
+            source_url.unwrap_or_default(),
+            servings.unwrap_or_default(),
+            prep_time.unwrap_or_default(),
+            cook_time.unwrap_or_default(),
+            total_time.unwrap_or_default(),
+            rating.map(|r| r.to_string()).unwrap_or_default(),
+            difficulty.unwrap_or_default(),
+            join_list(&tags),
+            author.unwrap_or_default(),
+            published_date
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            video_url.unwrap_or_default(),
+            join_list(equipment.into_iter().flatten()),
+        ])?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| csv::Error::from(e.into_error()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/recipes/export/csv",
+    tag = "recipes",
+    responses(
+        (status = 200, description = "Recipe metadata spreadsheet, one row per recipe", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn export_recipes_csv(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+) -> impl IntoResponse {
+    let mut conn = get_conn!(pool);
+
+    let rows: Vec<MetadataRow> = match recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::user_id.eq(user.id))
+        .filter(recipes::deleted_at.is_null())
+        .order((recipe_versions::title.asc(), recipes::id.asc()))
+        .select((
+            recipes::id,
+            recipe_versions::title,
+            recipe_versions::source_name,
+            recipe_versions::source_url,
+            recipe_versions::servings,
+            recipe_versions::prep_time,
+            recipe_versions::cook_time,
+            recipe_versions::total_time,
+            recipe_versions::rating,
+            recipe_versions::difficulty,
+            raw_sql::tags_subquery(),
+            recipe_versions::author,
+            recipe_versions::published_date,
+            recipe_versions::video_url,
+            recipe_versions::equipment,
+        ))
+        .load(&mut conn)
+    {
+        Ok(r) => r,
+        Err(_) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to fetch recipes".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    };
+
+    let data = match write_csv(rows) {
+        Ok(d) => d,
+        Err(e) => {
+            tracing::error!("Failed to write recipe CSV: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to create CSV export".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let timestamp = Utc::now().format("%Y%m%d-%H%M%S");
+    let filename = format!("recipes-{}.csv", timestamp);
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/csv; charset=utf-8")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        )
+        .body(Body::from(data))
+        .unwrap()
+        .into_response()
+}
@@ -11,7 +11,7 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -41,6 +41,23 @@ pub struct RecipeResponse {
     pub difficulty: Option<String>,
     pub nutritional_info: Option<String>,
     pub notes: Option<String>,
+    /// Times parsed from prep_time/cook_time/total_time, in minutes. The total
+    /// is prep + cook when the recipe only gives those.
+    pub prep_time_minutes: Option<i32>,
+    pub cook_time_minutes: Option<i32>,
+    pub total_time_minutes: Option<i32>,
+    /// Yield parsed from servings: "Serves 4-6" is 4 to 6 "servings"
+    pub yield_quantity: Option<f64>,
+    pub yield_quantity_max: Option<f64>,
+    pub yield_unit: Option<String>,
+    /// Link to a video of the recipe
+    pub video_url: Option<String>,
+    /// Equipment the recipe calls for
+    pub equipment: Vec<String>,
+    /// Author as credited by the source
+    pub author: Option<String>,
+    /// When the source published the recipe
+    pub published_date: Option<NaiveDate>,
     /// Version metadata
     pub version_id: Uuid,
     pub version_source: String,
@@ -55,26 +72,36 @@ pub struct GetRecipeParams {
 // Type alias for the query result row (all version fields plus tags via correlated subquery)
 #[allow(clippy::type_complexity)]
 type RecipeRow = (
-    DateTime<Utc>,     // recipes.created_at
-    Uuid,              // recipe_versions.id (version_id)
-    String,            // title
-    Option<String>,    // description
-    serde_json::Value, // ingredients (JSON)
-    String,            // instructions
-    Option<String>,    // source_url
-    Option<String>,    // source_name
-    Vec<Option<Uuid>>, // photo_ids
-    DateTime<Utc>,     // recipe_versions.created_at (updated_at)
-    Option<String>,    // servings
-    Option<String>,    // prep_time
-    Option<String>,    // cook_time
-    Option<String>,    // total_time
-    Option<i32>,       // rating
-    Option<String>,    // difficulty
-    Option<String>,    // nutritional_info
-    Option<String>,    // notes
-    String,            // version_source
-    Vec<String>,       // tags (from correlated subquery)
+    DateTime<Utc>,       // recipes.created_at
+    Uuid,                // recipe_versions.id (version_id)
+    String,              // title
+    Option<String>,      // description
+    serde_json::Value,   // ingredients (JSON)
+    String,              // instructions
+    Option<String>,      // source_url
+    Option<String>,      // source_name
+    Vec<Option<Uuid>>,   // photo_ids
+    DateTime<Utc>,       // recipe_versions.created_at (updated_at)
+    Option<String>,      // servings
+    Option<String>,      // prep_time
+    Option<String>,      // cook_time
+    Option<String>,      // total_time
+    Option<i32>,         // rating
+    Option<String>,      // difficulty
+    Option<String>,      // nutritional_info
+    Option<String>,      // notes
+    String,              // version_source
+    Option<i32>,         // prep_time_minutes
+    Option<i32>,         // cook_time_minutes
+    Option<i32>,         // total_time_minutes
+    Option<f64>,         // yield_quantity
+    Option<f64>,         // yield_quantity_max
+    Option<String>,      // yield_unit
+    Vec<String>,         // tags (from correlated subquery)
+    Option<String>,      // video_url
+    Vec<Option<String>>, // equipment
+    Option<String>,      // author
+    Option<NaiveDate>,   // published_date
 );
 
 /// Common select columns for recipe queries, including tags via correlated subquery
@@ -100,7 +127,17 @@ macro_rules! recipe_select {
             recipe_versions::nutritional_info,
             recipe_versions::notes,
             recipe_versions::version_source,
+            recipe_versions::prep_time_minutes,
+            recipe_versions::cook_time_minutes,
+            recipe_versions::total_time_minutes,
+            recipe_versions::yield_quantity,
+            recipe_versions::yield_quantity_max,
+            recipe_versions::yield_unit,
             raw_sql::tags_subquery(),
+            recipe_versions::video_url,
+            recipe_versions::equipment,
+            recipe_versions::author,
+            recipe_versions::published_date,
         )
     };
 }
@@ -197,7 +234,17 @@ pub async fn get_recipe(
         nutritional_info,
         notes,
         version_source,
+        prep_time_minutes,
+        cook_time_minutes,
+        total_time_minutes,
+        yield_quantity,
+        yield_quantity_max,
+        yield_unit,
         tags,
+        video_url,
+        equipment,
+        author,
+        published_date,
     ) = row;
 
     let ingredients: Vec<Ingredient> = serde_json::from_value(ingredients_json).unwrap_or_default();
@@ -222,6 +269,16 @@ pub async fn get_recipe(
         difficulty,
         nutritional_info,
         notes,
+        prep_time_minutes,
+        cook_time_minutes,
+        total_time_minutes,
+        yield_quantity,
+        yield_quantity_max,
+        yield_unit,
+        video_url,
+        equipment: equipment.into_iter().flatten().collect(),
+        author,
+        published_date,
         version_id,
         version_source,
     };
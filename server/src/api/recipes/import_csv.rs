@@ -0,0 +1,642 @@
+use super::export_csv::CSV_COLUMNS;
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::get_conn;
+use crate::models::{
+    NewRecipeVersion, NewUserTag, ParsedRecipeFields, RecipeVersion, RecipeVersionTag,
+    SourceDetails,
+};
+use crate::raw_sql;
+use crate::schema::{recipe_version_tags, recipe_versions, recipes, user_tags};
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use ramekin_core::extract::http_url;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Version source recorded on versions created by a CSV import
+pub const BULK_EDIT_VERSION_SOURCE: &str = "bulk_edit";
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImportRecipesCsvRequest {
+    /// CSV file in the format produced by GET /api/recipes/export/csv.
+    /// Only the `id` column is required; missing columns are left unchanged.
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CsvRowError {
+    /// Line number in the uploaded file (the header is line 1)
+    pub line: u64,
+    /// Recipe ID from the row, if it could be parsed
+    pub recipe_id: Option<Uuid>,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ImportRecipesCsvResponse {
+    /// Rows that created a new version
+    pub updated: usize,
+    /// Rows that matched the current version exactly
+    pub unchanged: usize,
+    /// Rows that were rejected; other rows are still applied
+    pub errors: Vec<CsvRowError>,
+}
+
+/// Edits parsed from one CSV row. `None` means the column wasn't present;
+/// `Some(None)` means the cell was blank and clears the field.
+#[derive(Debug, Default, PartialEq)]
+struct RowEdits {
+    title: Option<String>,
+    source_name: Option<Option<String>>,
+    source_url: Option<Option<String>>,
+    servings: Option<Option<String>>,
+    prep_time: Option<Option<String>>,
+    cook_time: Option<Option<String>>,
+    total_time: Option<Option<String>>,
+    rating: Option<Option<i32>>,
+    difficulty: Option<Option<String>>,
+    tags: Option<Vec<String>>,
+    author: Option<Option<String>>,
+    published_date: Option<Option<NaiveDate>>,
+    video_url: Option<Option<String>>,
+    equipment: Option<Vec<String>>,
+}
+
+/// Map of column name to index in the uploaded file
+type ColumnIndex = HashMap<String, usize>;
+
+fn index_columns(headers: &csv::StringRecord) -> Result<ColumnIndex, String> {
+    let mut index = ColumnIndex::new();
+    for (i, name) in headers.iter().enumerate() {
+        let name = name.trim().to_lowercase();
+        if !CSV_COLUMNS.contains(&name.as_str()) {
+            return Err(format!(
+                "Unknown column '{}'. Expected columns: {}",
+                name,
+                CSV_COLUMNS.join(", ")
+            ));
+        }
+        if index.insert(name.clone(), i).is_some() {
+            return Err(format!("Duplicate column '{}'", name));
+        }
+    }
+    if !index.contains_key("id") {
+        return Err("Missing required column 'id'".to_string());
+    }
+    Ok(index)
+}
+
+/// Blank cells become None so they clear the field
+fn non_empty(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Split a tags or equipment cell into its entries on unescaped `;`s (see
+/// `join_list`), dropping exact repeats
+fn parse_list(value: &str) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut entry = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => entry.extend(chars.next()),
+            ';' => entries.push(std::mem::take(&mut entry)),
+            c => entry.push(c),
+        }
+    }
+    entries.push(entry);
+
+    let mut items: Vec<String> = Vec::new();
+    for item in entries {
+        let item = item.trim();
+        if !item.is_empty() && !items.iter().any(|i| i == item) {
+            items.push(item.to_string());
+        }
+    }
+    items
+}
+
+/// Split a tags cell. Tags are case-insensitive (citext), so repeats are
+/// dropped regardless of case.
+fn parse_tags(value: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in parse_list(value) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Parse and validate a single row against the header
+fn parse_row(
+    columns: &ColumnIndex,
+    record: &csv::StringRecord,
+) -> Result<(Uuid, RowEdits), String> {
+    let cell = |name: &str| columns.get(name).map(|&i| record.get(i).unwrap_or(""));
+
+    let id_str = cell("id").unwrap_or("").trim();
+    let id = Uuid::parse_str(id_str).map_err(|_| format!("Invalid recipe id '{}'", id_str))?;
+
+    let mut edits = RowEdits::default();
+
+    if let Some(title) = cell("title") {
+        match non_empty(title) {
+            Some(t) => edits.title = Some(t),
+            None => return Err("Title cannot be empty".to_string()),
+        }
+    }
+
+    edits.source_name = cell("source_name").map(non_empty);
+    edits.source_url = cell("source_url").map(non_empty);
+    edits.servings = cell("servings").map(non_empty);
+    edits.prep_time = cell("prep_time").map(non_empty);
+    edits.cook_time = cell("cook_time").map(non_empty);
+    edits.total_time = cell("total_time").map(non_empty);
+    edits.difficulty = cell("difficulty").map(non_empty);
+
+    if let Some(rating) = cell("rating") {
+        edits.rating = Some(match non_empty(rating) {
+            None => None,
+            Some(r) => match r.parse::<i32>() {
+                Ok(n) if (1..=5).contains(&n) => Some(n),
+                _ => {
+                    return Err(format!(
+                        "Rating must be a whole number from 1 to 5, got '{}'",
+                        r
+                    ))
+                }
+            },
+        });
+    }
+
+    if let Some(date) = cell("published_date") {
+        edits.published_date = Some(match non_empty(date) {
+            None => None,
+            Some(d) => match NaiveDate::parse_from_str(&d, "%Y-%m-%d") {
+                Ok(date) => Some(date),
+                Err(_) => return Err(format!("Published date must be YYYY-MM-DD, got '{}'", d)),
+            },
+        });
+    }
+
+    edits.tags = cell("tags").map(parse_tags);
+    edits.author = cell("author").map(non_empty);
+    if let Some(url) = cell("video_url") {
+        edits.video_url = Some(match non_empty(url) {
+            None => None,
+            Some(u) => match http_url(&u) {
+                Some(u) => Some(u),
+                None => {
+                    return Err(format!(
+                        "Video URL must be an http or https URL, got '{}'",
+                        u
+                    ))
+                }
+            },
+        });
+    }
+    edits.equipment = cell("equipment").map(parse_list);
+
+    Ok((id, edits))
+}
+
+enum RowOutcome {
+    Updated,
+    Unchanged,
+}
+
+/// Apply one row's edits as a new version of the recipe, if anything changed
+fn apply_row(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    recipe_id: Uuid,
+    edits: RowEdits,
+) -> Result<RowOutcome, String> {
+    let (current, cur_tags): (RecipeVersion, Vec<String>) = recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::id.eq(recipe_id))
+        .filter(recipes::user_id.eq(user_id))
+        .filter(recipes::deleted_at.is_null())
+        .select((RecipeVersion::as_select(), raw_sql::tags_subquery()))
+        .first(conn)
+        .map_err(|e| match e {
+            diesel::NotFound => "Recipe not found".to_string(),
+            e => {
+                tracing::error!("Failed to fetch recipe {}: {}", recipe_id, e);
+                "Failed to fetch recipe".to_string()
+            }
+        })?;
+
+    let new_title = edits.title.unwrap_or_else(|| current.title.clone());
+    let new_source_name = edits
+        .source_name
+        .unwrap_or_else(|| current.source_name.clone());
+    let new_source_url = edits
+        .source_url
+        .unwrap_or_else(|| current.source_url.clone());
+    let new_servings = edits.servings.unwrap_or_else(|| current.servings.clone());
+    let new_prep_time = edits.prep_time.unwrap_or_else(|| current.prep_time.clone());
+    let new_cook_time = edits.cook_time.unwrap_or_else(|| current.cook_time.clone());
+    let new_total_time = edits
+        .total_time
+        .unwrap_or_else(|| current.total_time.clone());
+    let new_rating = edits.rating.unwrap_or(current.rating);
+    let new_difficulty = edits
+        .difficulty
+        .unwrap_or_else(|| current.difficulty.clone());
+    let new_tags = edits.tags.unwrap_or_else(|| cur_tags.clone());
+    let cur_source_details = SourceDetails::from_version(&current);
+    let new_source_details = SourceDetails {
+        author: edits.author.unwrap_or_else(|| current.author.clone()),
+        published_date: edits.published_date.unwrap_or(current.published_date),
+        video_url: edits.video_url.unwrap_or_else(|| current.video_url.clone()),
+        equipment: match edits.equipment {
+            Some(equipment) => equipment.into_iter().map(Some).collect(),
+            None => current.equipment.clone(),
+        },
+    };
+
+    let tags_changed = {
+        let mut a: Vec<String> = new_tags.iter().map(|t| t.to_lowercase()).collect();
+        let mut b: Vec<String> = cur_tags.iter().map(|t| t.to_lowercase()).collect();
+        a.sort();
+        b.sort();
+        a != b
+    };
+
+    if new_title == current.title
+        && new_source_name == current.source_name
+        && new_source_url == current.source_url
+        && new_servings == current.servings
+        && new_prep_time == current.prep_time
+        && new_cook_time == current.cook_time
+        && new_total_time == current.total_time
+        && new_rating == current.rating
+        && new_difficulty == current.difficulty
+        && new_source_details == cur_source_details
+        && !tags_changed
+    {
+        return Ok(RowOutcome::Unchanged);
+    }
+
+    let result: Result<(), diesel::result::Error> = conn.transaction(|conn| {
+        let new_version = NewRecipeVersion {
+            recipe_id,
+            title: &new_title,
+            description: current.description.as_deref(),
+            ingredients: current.ingredients.clone(),
+            instructions: &current.instructions,
+            source_url: new_source_url.as_deref(),
+            source_name: new_source_name.as_deref(),
+            photo_ids: &current.photo_ids,
+            servings: new_servings.as_deref(),
+            prep_time: new_prep_time.as_deref(),
+            cook_time: new_cook_time.as_deref(),
+            total_time: new_total_time.as_deref(),
+            rating: new_rating,
+            difficulty: new_difficulty.as_deref(),
+            nutritional_info: current.nutritional_info.as_deref(),
+            notes: current.notes.as_deref(),
+            version_source: BULK_EDIT_VERSION_SOURCE,
+            parsed: ParsedRecipeFields::parse(
+                new_servings.as_deref(),
+                new_prep_time.as_deref(),
+                new_cook_time.as_deref(),
+                new_total_time.as_deref(),
+            ),
+            source_details: new_source_details,
+        };
+
+        let version_id: Uuid = diesel::insert_into(recipe_versions::table)
+            .values(&new_version)
+            .returning(recipe_versions::id)
+            .get_result(conn)?;
+
+        diesel::update(recipes::table.find(recipe_id))
+            .set(recipes::current_version_id.eq(version_id))
+            .execute(conn)?;
+
+        for tag_name in &new_tags {
+            let tag_id: Uuid = diesel::insert_into(user_tags::table)
+                .values(NewUserTag {
+                    user_id,
+                    name: tag_name,
+                })
+                .on_conflict((user_tags::user_id, user_tags::name))
+                .do_update()
+                .set(user_tags::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)) // Revive soft-deleted tags
+                .returning(user_tags::id)
+                .get_result(conn)?;
+
+            diesel::insert_into(recipe_version_tags::table)
+                .values(RecipeVersionTag {
+                    recipe_version_id: version_id,
+                    tag_id,
+                })
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+        }
+
+        Ok(())
+    });
+
+    result.map(|_| RowOutcome::Updated).map_err(|e| {
+        tracing::error!("Failed to apply CSV edits to recipe {}: {}", recipe_id, e);
+        "Failed to save recipe".to_string()
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recipes/import/csv",
+    tag = "recipes",
+    request_body(content_type = "multipart/form-data", content = ImportRecipesCsvRequest),
+    responses(
+        (status = 200, description = "CSV processed; see errors for rejected rows", body = ImportRecipesCsvResponse),
+        (status = 400, description = "Unreadable file or invalid header", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn import_recipes_csv(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "No file provided".to_string(),
+                }),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            tracing::warn!("Multipart read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read multipart data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let data = match field.bytes().await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!("Field read error: {}", e);
+            return (
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read file data: {}", e.body_text()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(data.as_ref());
+
+    let columns = match reader
+        .headers()
+        .map_err(|e| format!("Failed to read CSV header: {}", e))
+        .and_then(index_columns)
+    {
+        Ok(c) => c,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+        }
+    };
+
+    let mut conn = get_conn!(pool);
+
+    let mut response = ImportRecipesCsvResponse {
+        updated: 0,
+        unchanged: 0,
+        errors: Vec::new(),
+    };
+
+    for (i, record) in reader.records().enumerate() {
+        // Header is line 1; fall back to the row index if the reader has no position
+        let fallback_line = i as u64 + 2;
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                response.errors.push(CsvRowError {
+                    line: e.position().map(|p| p.line()).unwrap_or(fallback_line),
+                    recipe_id: None,
+                    error: format!("Malformed row: {}", e),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or(fallback_line);
+
+        let (recipe_id, edits) = match parse_row(&columns, &record) {
+            Ok(r) => r,
+            Err(error) => {
+                let recipe_id = columns
+                    .get("id")
+                    .and_then(|&i| record.get(i))
+                    .and_then(|id| Uuid::parse_str(id.trim()).ok());
+                response.errors.push(CsvRowError {
+                    line,
+                    recipe_id,
+                    error,
+                });
+                continue;
+            }
+        };
+
+        match apply_row(&mut conn, user.id, recipe_id, edits) {
+            Ok(RowOutcome::Updated) => response.updated += 1,
+            Ok(RowOutcome::Unchanged) => response.unchanged += 1,
+            Err(error) => response.errors.push(CsvRowError {
+                line,
+                recipe_id: Some(recipe_id),
+                error,
+            }),
+        }
+    }
+
+    tracing::info!(
+        "CSV import for user {}: {} updated, {} unchanged, {} errors",
+        user.id,
+        response.updated,
+        response.unchanged,
+        response.errors.len()
+    );
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::recipes::export_csv::join_list;
+
+    fn columns(header: &[&str]) -> ColumnIndex {
+        index_columns(&csv::StringRecord::from(header.to_vec())).unwrap()
+    }
+
+    #[test]
+    fn test_index_columns_requires_id() {
+        let err = index_columns(&csv::StringRecord::from(vec!["title"])).unwrap_err();
+        assert!(err.contains("'id'"));
+    }
+
+    #[test]
+    fn test_index_columns_rejects_unknown() {
+        let err = index_columns(&csv::StringRecord::from(vec!["id", "colour"])).unwrap_err();
+        assert!(err.contains("colour"));
+    }
+
+    #[test]
+    fn test_parse_row_only_present_columns() {
+        let cols = columns(&["id", "rating"]);
+        let id = Uuid::new_v4();
+        let record = csv::StringRecord::from(vec![id.to_string(), "4".to_string()]);
+        let (parsed_id, edits) = parse_row(&cols, &record).unwrap();
+        assert_eq!(parsed_id, id);
+        assert_eq!(edits.rating, Some(Some(4)));
+        assert_eq!(edits.title, None);
+        assert_eq!(edits.tags, None);
+    }
+
+    #[test]
+    fn test_parse_row_blank_cell_clears_field() {
+        let cols = columns(&["id", "difficulty", "rating"]);
+        let record =
+            csv::StringRecord::from(vec![Uuid::new_v4().to_string(), " ".into(), "".into()]);
+        let (_, edits) = parse_row(&cols, &record).unwrap();
+        assert_eq!(edits.difficulty, Some(None));
+        assert_eq!(edits.rating, Some(None));
+    }
+
+    #[test]
+    fn test_parse_row_rejects_bad_rating() {
+        let cols = columns(&["id", "rating"]);
+        for bad in ["0", "6", "four", "3.5"] {
+            let record = csv::StringRecord::from(vec![Uuid::new_v4().to_string(), bad.into()]);
+            assert!(
+                parse_row(&cols, &record).is_err(),
+                "accepted rating {}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_row_rejects_empty_title() {
+        let cols = columns(&["id", "title"]);
+        let record = csv::StringRecord::from(vec![Uuid::new_v4().to_string(), "".into()]);
+        assert_eq!(
+            parse_row(&cols, &record).unwrap_err(),
+            "Title cannot be empty"
+        );
+    }
+
+    #[test]
+    fn test_parse_row_rejects_bad_id() {
+        let cols = columns(&["id"]);
+        let record = csv::StringRecord::from(vec!["not-a-uuid"]);
+        assert!(parse_row(&cols, &record).is_err());
+    }
+
+    #[test]
+    fn test_parse_row_published_date() {
+        let cols = columns(&["id", "published_date"]);
+        let record = csv::StringRecord::from(vec![Uuid::new_v4().to_string(), "2024-03-05".into()]);
+        let (_, edits) = parse_row(&cols, &record).unwrap();
+        assert_eq!(
+            edits.published_date,
+            Some(NaiveDate::from_ymd_opt(2024, 3, 5))
+        );
+
+        let record = csv::StringRecord::from(vec![Uuid::new_v4().to_string(), "March 5".into()]);
+        assert!(parse_row(&cols, &record).is_err());
+    }
+
+    #[test]
+    fn test_parse_row_video_url() {
+        let cols = columns(&["id", "video_url"]);
+        let record = csv::StringRecord::from(vec![
+            Uuid::new_v4().to_string(),
+            "https://example.com/v.mp4".into(),
+        ]);
+        let (_, edits) = parse_row(&cols, &record).unwrap();
+        assert_eq!(
+            edits.video_url,
+            Some(Some("https://example.com/v.mp4".to_string()))
+        );
+
+        let record = csv::StringRecord::from(vec![
+            Uuid::new_v4().to_string(),
+            "javascript:alert(1)".into(),
+        ]);
+        assert!(parse_row(&cols, &record).is_err());
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            parse_list("Skillet; skillet ;;Skillet; whisk"),
+            vec!["Skillet", "skillet", "whisk"]
+        );
+        assert!(parse_list("").is_empty());
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        let tags = vec![
+            "Dinner; quick".to_string(),
+            "C:\\temp".to_string(),
+            "trailing\\".to_string(),
+            "vegan".to_string(),
+        ];
+        let cell = join_list(&tags);
+        assert_eq!(parse_tags(&cell), tags);
+        assert_eq!(parse_list(&cell), tags);
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(
+            parse_tags("dinner; Quick ;;quick; vegan"),
+            vec!["dinner", "Quick", "vegan"]
+        );
+        assert!(parse_tags("").is_empty());
+    }
+}
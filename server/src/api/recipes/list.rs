@@ -77,8 +77,9 @@ pub struct ListRecipesParams {
     /// - created:>2024-01-01: created after date
     /// - created:<2024-12-31: created before date
     /// - created:2024-01-01..2024-12-31: created in date range
+    /// - time:<45, time:>1h, time:30..60: total time in minutes (or "1h", "90m")
     ///
-    /// Example: "chicken tag:dinner tag:quick has:photos"
+    /// Example: "chicken tag:dinner tag:quick has:photos time:<45"
     pub q: Option<String>,
     /// Sort field (default: updated_at)
     #[serde(default)]
@@ -97,6 +98,8 @@ struct ParsedQuery {
     has_photos: Option<bool>,
     created_after: Option<NaiveDate>,
     created_before: Option<NaiveDate>,
+    min_total_minutes: Option<i32>,
+    max_total_minutes: Option<i32>,
 }
 
 fn parse_query(q: &str) -> ParsedQuery {
@@ -120,6 +123,8 @@ fn parse_query(q: &str) -> ParsedQuery {
             result.has_photos = Some(false);
         } else if let Some(date_expr) = token.strip_prefix("created:") {
             parse_date_filter(date_expr, &mut result);
+        } else if let Some(time_expr) = token.strip_prefix("time:") {
+            parse_time_filter(time_expr, &mut result);
         } else if !token.is_empty() {
             // Plain text search term
             result.text.push(token.to_string());
@@ -193,6 +198,25 @@ fn parse_date_filter(expr: &str, result: &mut ParsedQuery) {
     }
 }
 
+fn parse_time_filter(expr: &str, result: &mut ParsedQuery) {
+    let minutes = |s: &str| {
+        ramekin_core::duration_parser::parse_duration_minutes(s).and_then(|m| i32::try_from(m).ok())
+    };
+
+    // Handle range: 30..60
+    if let Some((min, max)) = expr.split_once("..") {
+        result.min_total_minutes = minutes(min);
+        result.max_total_minutes = minutes(max);
+    } else if let Some(max) = expr.strip_prefix('<') {
+        result.max_total_minutes = minutes(max);
+    } else if let Some(min) = expr.strip_prefix('>') {
+        result.min_total_minutes = minutes(min);
+    } else {
+        // A bare time means "ready within"
+        result.max_total_minutes = minutes(expr);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PaginationMetadata {
     /// Total number of items available
@@ -337,6 +361,14 @@ pub async fn list_recipes(
         }
     }
 
+    // Total time filters (recipes with no parsed total time never match)
+    if let Some(min) = parsed.min_total_minutes {
+        query = query.filter(recipe_versions::total_time_minutes.ge(min));
+    }
+    if let Some(max) = parsed.max_total_minutes {
+        query = query.filter(recipe_versions::total_time_minutes.le(max));
+    }
+
     // Add ordering (with recipes::id tiebreaker for deterministic pagination)
     let query = match (params.sort_by, params.sort_dir) {
         (SortBy::Random, _) => query.order(random()),
@@ -528,4 +560,24 @@ mod tests {
         assert_eq!(parsed.text, vec!["green beans"]);
         assert_eq!(parsed.tags, vec!["side"]);
     }
+
+    #[test]
+    fn test_parse_time_filters() {
+        let parsed = parse_query("soup time:<45");
+        assert_eq!(parsed.text, vec!["soup"]);
+        assert_eq!(parsed.max_total_minutes, Some(45));
+        assert!(parsed.min_total_minutes.is_none());
+
+        let parsed = parse_query("time:>1h");
+        assert_eq!(parsed.min_total_minutes, Some(60));
+        assert!(parsed.max_total_minutes.is_none());
+
+        let parsed = parse_query("time:30..90m");
+        assert_eq!(parsed.min_total_minutes, Some(30));
+        assert_eq!(parsed.max_total_minutes, Some(90));
+
+        let parsed = parse_query("time:soon");
+        assert!(parsed.min_total_minutes.is_none());
+        assert!(parsed.max_total_minutes.is_none());
+    }
 }
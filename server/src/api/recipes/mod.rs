@@ -1,10 +1,13 @@
 pub mod create;
 pub mod delete;
 pub mod export;
+pub mod export_csv;
 pub mod get;
+pub mod import_csv;
 pub mod list;
 pub mod rescrape;
 pub mod update;
+pub mod upstream_check;
 pub mod versions;
 
 use crate::AppState;
@@ -17,6 +20,8 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list::list_recipes).post(create::create_recipe))
         .route("/export", get(export::export_all_recipes))
+        .route("/export/csv", get(export_csv::export_recipes_csv))
+        .route("/import/csv", post(import_csv::import_recipes_csv))
         .route(
             "/{id}",
             get(get::get_recipe)
@@ -26,6 +31,14 @@ pub fn router() -> Router<AppState> {
         .route("/{id}/export", get(export::export_recipe))
         .route("/{id}/versions", get(versions::list_versions))
         .route("/{id}/rescrape", post(rescrape::rescrape))
+        .route(
+            "/{id}/upstream-check",
+            get(upstream_check::get_upstream_check).put(upstream_check::set_upstream_check),
+        )
+        .route(
+            "/{id}/upstream-check/run",
+            post(upstream_check::run_upstream_check),
+        )
 }
 
 #[derive(OpenApi)]
@@ -38,8 +51,13 @@ pub fn router() -> Router<AppState> {
         delete::delete_recipe,
         export::export_recipe,
         export::export_all_recipes,
+        export_csv::export_recipes_csv,
+        import_csv::import_recipes_csv,
         versions::list_versions,
         rescrape::rescrape,
+        upstream_check::get_upstream_check,
+        upstream_check::set_upstream_check,
+        upstream_check::run_upstream_check,
     ),
     components(schemas(
         create::CreateRecipeRequest,
@@ -50,9 +68,14 @@ pub fn router() -> Router<AppState> {
         list::Direction,
         get::RecipeResponse,
         update::UpdateRecipeRequest,
+        import_csv::ImportRecipesCsvRequest,
+        import_csv::ImportRecipesCsvResponse,
+        import_csv::CsvRowError,
         versions::VersionListResponse,
         versions::VersionSummary,
         rescrape::RescrapeResponse,
+        upstream_check::SetUpstreamCheckRequest,
+        upstream_check::UpstreamCheckResponse,
     ))
 )]
 pub struct ApiDoc;
@@ -150,8 +150,8 @@ pub async fn rescrape(
         }
     };
 
-    // Spawn background task
-    scraping::spawn_scrape_job(pool.clone(), job.id, &source_url, "rescrape");
+    // The job is queued; wake a worker to pick it up
+    scraping::wake_workers();
 
     (
         StatusCode::CREATED,
@@ -2,7 +2,9 @@ use crate::api::ErrorResponse;
 use crate::auth::AuthUser;
 use crate::db::DbPool;
 use crate::get_conn;
-use crate::models::{Ingredient, NewRecipeVersion, NewUserTag, RecipeVersionTag};
+use crate::models::{
+    Ingredient, NewRecipeVersion, NewUserTag, ParsedRecipeFields, RecipeVersionTag, SourceDetails,
+};
 use crate::raw_sql;
 use crate::schema::{recipe_version_tags, recipe_versions, recipes, user_tags};
 use axum::{
@@ -11,7 +13,9 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use chrono::NaiveDate;
 use diesel::prelude::*;
+use ramekin_core::extract::http_url;
 use serde::Deserialize;
 use serde_with::rust::double_option;
 use std::sync::Arc;
@@ -59,28 +63,42 @@ pub struct UpdateRecipeRequest {
     #[serde(default, deserialize_with = "double_option::deserialize")]
     #[schema(value_type = Option<String>)]
     pub notes: Option<Option<String>>,
+    #[serde(default, deserialize_with = "double_option::deserialize")]
+    #[schema(value_type = Option<String>)]
+    pub video_url: Option<Option<String>>,
+    pub equipment: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "double_option::deserialize")]
+    #[schema(value_type = Option<String>)]
+    pub author: Option<Option<String>>,
+    #[serde(default, deserialize_with = "double_option::deserialize")]
+    #[schema(value_type = Option<NaiveDate>)]
+    pub published_date: Option<Option<NaiveDate>>,
 }
 
 // Type alias for the combined recipe + version + tags query result
 #[allow(clippy::type_complexity)]
 type CurrentVersionRow = (
-    Uuid,              // recipes.id
-    String,            // recipe_versions.title
-    Option<String>,    // description
-    serde_json::Value, // ingredients (JSON)
-    String,            // instructions
-    Option<String>,    // source_url
-    Option<String>,    // source_name
-    Vec<Option<Uuid>>, // photo_ids
-    Option<String>,    // servings
-    Option<String>,    // prep_time
-    Option<String>,    // cook_time
-    Option<String>,    // total_time
-    Option<i32>,       // rating
-    Option<String>,    // difficulty
-    Option<String>,    // nutritional_info
-    Option<String>,    // notes
-    Vec<String>,       // tags (from correlated subquery)
+    Uuid,                // recipes.id
+    String,              // recipe_versions.title
+    Option<String>,      // description
+    serde_json::Value,   // ingredients (JSON)
+    String,              // instructions
+    Option<String>,      // source_url
+    Option<String>,      // source_name
+    Vec<Option<Uuid>>,   // photo_ids
+    Option<String>,      // servings
+    Option<String>,      // prep_time
+    Option<String>,      // cook_time
+    Option<String>,      // total_time
+    Option<i32>,         // rating
+    Option<String>,      // difficulty
+    Option<String>,      // nutritional_info
+    Option<String>,      // notes
+    Vec<String>,         // tags (from correlated subquery)
+    Option<String>,      // video_url
+    Vec<Option<String>>, // equipment
+    Option<String>,      // author
+    Option<NaiveDate>,   // published_date
 );
 
 #[utoipa::path(
@@ -131,6 +149,18 @@ pub async fn update_recipe(
         }
     }
 
+    if let Some(Some(ref video_url)) = request.video_url {
+        if http_url(video_url).is_none() {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Video URL must be an http or https URL".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    }
+
     let mut conn = get_conn!(pool);
 
     // Fetch recipe, current version, and tags in a single query
@@ -161,6 +191,10 @@ pub async fn update_recipe(
             recipe_versions::nutritional_info,
             recipe_versions::notes,
             raw_sql::tags_subquery(),
+            recipe_versions::video_url,
+            recipe_versions::equipment,
+            recipe_versions::author,
+            recipe_versions::published_date,
         ))
         .first(&mut conn)
     {
@@ -203,6 +237,10 @@ pub async fn update_recipe(
         cur_nutritional_info,
         cur_notes,
         cur_tags,
+        cur_video_url,
+        cur_equipment,
+        cur_author,
+        cur_published_date,
     ) = current;
 
     // Merge request with current version
@@ -239,6 +277,15 @@ pub async fn update_recipe(
     let new_difficulty = request.difficulty.unwrap_or(cur_difficulty);
     let new_nutritional_info = request.nutritional_info.unwrap_or(cur_nutritional_info);
     let new_notes = request.notes.unwrap_or(cur_notes);
+    let new_source_details = SourceDetails {
+        video_url: request.video_url.unwrap_or(cur_video_url),
+        equipment: request
+            .equipment
+            .map(|equipment| equipment.into_iter().map(Some).collect())
+            .unwrap_or(cur_equipment),
+        author: request.author.unwrap_or(cur_author),
+        published_date: request.published_date.unwrap_or(cur_published_date),
+    };
 
     // Create new version in a transaction
     let result: Result<(), diesel::result::Error> = conn.transaction(|conn| {
@@ -260,6 +307,13 @@ pub async fn update_recipe(
             nutritional_info: new_nutritional_info.as_deref(),
             notes: new_notes.as_deref(),
             version_source: "user",
+            parsed: ParsedRecipeFields::parse(
+                new_servings.as_deref(),
+                new_prep_time.as_deref(),
+                new_cook_time.as_deref(),
+                new_total_time.as_deref(),
+            ),
+            source_details: new_source_details,
         };
 
         let version_id: Uuid = diesel::insert_into(recipe_versions::table)
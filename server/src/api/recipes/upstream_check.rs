@@ -0,0 +1,267 @@
+use crate::api::upstream_updates::list::UpstreamUpdateResponse;
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::get_conn;
+use crate::http_cache;
+use crate::schema::{recipe_versions, recipes};
+use crate::scraping::{self, upstream};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SetUpstreamCheckRequest {
+    /// Whether to periodically check the recipe's source page for edits
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UpstreamCheckResponse {
+    /// Whether the source page is checked for edits
+    pub enabled: bool,
+    /// When the source page was last checked
+    pub checked_at: Option<DateTime<Utc>>,
+    /// Why the last check failed
+    pub last_error: Option<String>,
+    /// Upstream change waiting to be accepted or dismissed
+    pub pending_update: Option<UpstreamUpdateResponse>,
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(ErrorResponse {
+            error: message.to_string(),
+        }),
+    )
+        .into_response()
+}
+
+/// Look up the source URL of a recipe owned by the user.
+/// Returns Ok(None) if the recipe doesn't exist, Ok(Some(None)) if it has no source URL.
+fn recipe_source_url(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    recipe_id: Uuid,
+) -> QueryResult<Option<Option<String>>> {
+    recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::id.eq(recipe_id))
+        .filter(recipes::user_id.eq(user_id))
+        .filter(recipes::deleted_at.is_null())
+        .select(recipe_versions::source_url)
+        .first::<Option<String>>(conn)
+        .optional()
+        .map(|found| found.map(|url| url.filter(|u| !u.is_empty())))
+}
+
+fn check_response(pool: &DbPool, recipe_id: Uuid) -> Response {
+    let check = match upstream::get_check(pool, recipe_id) {
+        Ok(check) => check,
+        Err(e) => {
+            tracing::error!("Failed to get upstream check: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to get upstream check",
+            );
+        }
+    };
+    let pending_update = match upstream::get_pending_update(pool, recipe_id) {
+        Ok(update) => update.and_then(UpstreamUpdateResponse::from_update),
+        Err(e) => {
+            tracing::error!("Failed to get upstream update: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to get upstream check",
+            );
+        }
+    };
+
+    (
+        StatusCode::OK,
+        Json(UpstreamCheckResponse {
+            enabled: check.is_some(),
+            checked_at: check.as_ref().and_then(|c| c.checked_at),
+            last_error: check.and_then(|c| c.last_error),
+            pending_update,
+        }),
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/recipes/{id}/upstream-check",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "Recipe ID")
+    ),
+    responses(
+        (status = 200, description = "Upstream check settings and any pending update", body = UpstreamCheckResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Recipe not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_upstream_check(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(recipe_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let mut conn = get_conn!(pool);
+
+    match recipe_source_url(&mut conn, user.id, recipe_id) {
+        Ok(Some(_)) => {}
+        Ok(None) => return error(StatusCode::NOT_FOUND, "Recipe not found"),
+        Err(e) => {
+            tracing::error!("Failed to fetch recipe: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, "Failed to fetch recipe");
+        }
+    }
+
+    check_response(&pool, recipe_id)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/recipes/{id}/upstream-check",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "Recipe ID")
+    ),
+    request_body = SetUpstreamCheckRequest,
+    responses(
+        (status = 200, description = "Upstream check settings updated", body = UpstreamCheckResponse),
+        (status = 400, description = "Recipe has no source URL to check", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Recipe not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn set_upstream_check(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(recipe_id): Path<Uuid>,
+    Json(request): Json<SetUpstreamCheckRequest>,
+) -> impl IntoResponse {
+    let mut conn = get_conn!(pool);
+
+    match recipe_source_url(&mut conn, user.id, recipe_id) {
+        Ok(Some(Some(_))) => {}
+        Ok(Some(None)) if request.enabled => {
+            return error(StatusCode::BAD_REQUEST, "Recipe has no source URL to check");
+        }
+        Ok(Some(None)) => {}
+        Ok(None) => return error(StatusCode::NOT_FOUND, "Recipe not found"),
+        Err(e) => {
+            tracing::error!("Failed to fetch recipe: {}", e);
+            return error(StatusCode::INTERNAL_SERVER_ERROR, "Failed to fetch recipe");
+        }
+    }
+
+    if let Err(e) = upstream::set_enabled(&pool, user.id, recipe_id, request.enabled) {
+        tracing::error!("Failed to update upstream check: {}", e);
+        return error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to update upstream check",
+        );
+    }
+
+    check_response(&pool, recipe_id)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recipes/{id}/upstream-check/run",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "Recipe ID")
+    ),
+    responses(
+        (status = 200, description = "Source page checked; any change is returned as the pending update", body = UpstreamCheckResponse),
+        (status = 400, description = "Upstream checks are not enabled for the recipe", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Recipe not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn run_upstream_check(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(recipe_id): Path<Uuid>,
+) -> impl IntoResponse {
+    {
+        let mut conn = get_conn!(pool);
+        match recipe_source_url(&mut conn, user.id, recipe_id) {
+            Ok(Some(_)) => {}
+            Ok(None) => return error(StatusCode::NOT_FOUND, "Recipe not found"),
+            Err(e) => {
+                tracing::error!("Failed to fetch recipe: {}", e);
+                return error(StatusCode::INTERNAL_SERVER_ERROR, "Failed to fetch recipe");
+            }
+        }
+    }
+
+    let check = match upstream::get_check(&pool, recipe_id) {
+        Ok(Some(check)) => check,
+        Ok(None) => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "Upstream checks are not enabled for this recipe",
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to get upstream check: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to get upstream check",
+            );
+        }
+    };
+
+    let client = match http_cache::client_builder().build() {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::error!("Failed to create HTTP client: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to check upstream recipe",
+            );
+        }
+    };
+
+    // Fetch and extraction failures are recorded as last_error on the check
+    match upstream::check_recipe(&pool, &client, &check).await {
+        Ok(outcome) => tracing::debug!("Upstream check of recipe {}: {:?}", recipe_id, outcome),
+        Err(scraping::ScrapeError::Database(e)) => {
+            tracing::error!("Failed to record upstream check: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to check upstream recipe",
+            );
+        }
+        Err(e) => tracing::info!("Upstream check of recipe {} failed: {}", recipe_id, e),
+    }
+
+    check_response(&pool, recipe_id)
+}
@@ -0,0 +1,303 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping;
+use crate::scraping::batch::{ITEM_DUPLICATE, ITEM_INVALID, MAX_BATCH_URLS};
+use crate::scraping::ScrapeError;
+use axum::{
+    extract::{FromRequest, Multipart, Path, Request, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateScrapeBatchRequest {
+    /// URLs to scrape for recipe data
+    pub urls: Vec<String>,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct CreateScrapeBatchUpload {
+    /// Netscape bookmarks export (HTML) or plain text with one URL per line
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScrapeBatchItemResponse {
+    /// URL as submitted (normalized if it was valid)
+    pub url: String,
+    /// duplicate, invalid, or the scrape job's status (pending, scraping, parsing, completed, failed, cancelled)
+    pub status: String,
+    /// Scrape job created for this URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<Uuid>,
+    /// Recipe saved by the job, or the existing recipe for a duplicate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_id: Option<Uuid>,
+    /// Why the URL was skipped or its job failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScrapeBatchResponse {
+    /// The batch ID
+    pub id: Uuid,
+    /// When the batch was submitted
+    pub created_at: DateTime<Utc>,
+    /// Number of URLs submitted
+    pub total: usize,
+    /// Jobs still queued or running
+    pub pending: usize,
+    /// Jobs that saved a recipe
+    pub completed: usize,
+    /// Jobs that failed
+    pub failed: usize,
+    /// Jobs that were cancelled
+    pub cancelled: usize,
+    /// URLs skipped as duplicates (earlier in the batch or already in the library)
+    pub duplicate: usize,
+    /// URLs skipped as invalid or not allowed
+    pub invalid: usize,
+    /// One entry per submitted URL, in submission order
+    pub items: Vec<ScrapeBatchItemResponse>,
+}
+
+fn bad_request(error: String) -> Response {
+    (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+}
+
+/// Read the URL list from a JSON body or an uploaded file.
+async fn read_urls(request: Request) -> Result<Vec<String>, Response> {
+    let is_multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("multipart/form-data"));
+
+    if !is_multipart {
+        let Json(body) = Json::<CreateScrapeBatchRequest>::from_request(request, &())
+            .await
+            .map_err(IntoResponse::into_response)?;
+        return Ok(body
+            .urls
+            .into_iter()
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty())
+            .collect());
+    }
+
+    let mut multipart = Multipart::from_request(request, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    let field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        Ok(None) => return Err(bad_request("No file provided".to_string())),
+        Err(e) => {
+            tracing::warn!("Multipart read error: {}", e);
+            return Err((
+                e.status(),
+                Json(ErrorResponse {
+                    error: format!("Failed to read multipart data: {}", e.body_text()),
+                }),
+            )
+                .into_response());
+        }
+    };
+
+    let data = field.bytes().await.map_err(|e| {
+        tracing::warn!("Field read error: {}", e);
+        (
+            e.status(),
+            Json(ErrorResponse {
+                error: format!("Failed to read file data: {}", e.body_text()),
+            }),
+        )
+            .into_response()
+    })?;
+
+    Ok(scraping::batch::parse_url_list(&String::from_utf8_lossy(
+        &data,
+    )))
+}
+
+/// Summarize a batch owned by the user.
+fn batch_response(
+    pool: &DbPool,
+    user_id: Uuid,
+    batch_id: Uuid,
+) -> Result<ScrapeBatchResponse, ScrapeError> {
+    let (batch, rows) = scraping::batch::get_batch_items(pool, batch_id)?;
+    if batch.user_id != user_id {
+        return Err(ScrapeError::BatchNotFound);
+    }
+
+    let mut response = ScrapeBatchResponse {
+        id: batch.id,
+        created_at: batch.created_at,
+        total: rows.len(),
+        pending: 0,
+        completed: 0,
+        failed: 0,
+        cancelled: 0,
+        duplicate: 0,
+        invalid: 0,
+        items: Vec::with_capacity(rows.len()),
+    };
+
+    for (item, job) in rows {
+        let entry = match job {
+            Some(job) => ScrapeBatchItemResponse {
+                url: item.url,
+                status: job.status,
+                job_id: Some(job.id),
+                recipe_id: job.recipe_id,
+                error: job.error_message,
+            },
+            None => ScrapeBatchItemResponse {
+                url: item.url,
+                status: item.status,
+                job_id: None,
+                recipe_id: item.recipe_id,
+                error: item.error,
+            },
+        };
+
+        match entry.status.as_str() {
+            scraping::STATUS_COMPLETED => response.completed += 1,
+            scraping::STATUS_FAILED => response.failed += 1,
+            scraping::STATUS_CANCELLED => response.cancelled += 1,
+            ITEM_DUPLICATE => response.duplicate += 1,
+            ITEM_INVALID => response.invalid += 1,
+            _ => response.pending += 1,
+        }
+        response.items.push(entry);
+    }
+
+    Ok(response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scrape/batch",
+    tag = "scrape",
+    request_body(content(
+        (CreateScrapeBatchRequest = "application/json"),
+        (CreateScrapeBatchUpload = "multipart/form-data")
+    )),
+    responses(
+        (status = 201, description = "Batch created; valid, new URLs are queued as scrape jobs", body = ScrapeBatchResponse),
+        (status = 400, description = "No URLs, too many URLs, or unreadable upload", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_scrape_batch(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    request: Request,
+) -> impl IntoResponse {
+    let urls = match read_urls(request).await {
+        Ok(urls) => urls,
+        Err(response) => return response,
+    };
+
+    if urls.is_empty() {
+        return bad_request("No URLs provided".to_string());
+    }
+    if urls.len() > MAX_BATCH_URLS {
+        return bad_request(format!(
+            "Too many URLs: {} (maximum {})",
+            urls.len(),
+            MAX_BATCH_URLS
+        ));
+    }
+
+    let batch = match scraping::batch::create_batch(&pool, user.id, &urls) {
+        Ok(batch) => batch,
+        Err(e) => {
+            tracing::error!("Failed to create scrape batch: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to create scrape batch".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    scraping::wake_workers();
+
+    match batch_response(&pool, user.id, batch.id) {
+        Ok(response) => (StatusCode::CREATED, Json(response)).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load scrape batch: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to load scrape batch".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scrape/batch/{id}",
+    tag = "scrape",
+    params(
+        ("id" = Uuid, Path, description = "Scrape batch ID")
+    ),
+    responses(
+        (status = 200, description = "Batch progress with per-URL status", body = ScrapeBatchResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Batch not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_scrape_batch(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(batch_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let response = match batch_response(&pool, user.id, batch_id) {
+        Ok(r) => r,
+        Err(ScrapeError::BatchNotFound) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Scrape batch not found".to_string(),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to get scrape batch: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get scrape batch".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,106 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CancelScrapeResponse {
+    /// The scrape job ID
+    pub id: Uuid,
+    /// New job status (cancelled)
+    pub status: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scrape/{id}/cancel",
+    tag = "scrape",
+    params(
+        ("id" = Uuid, Path, description = "Scrape job ID")
+    ),
+    responses(
+        (status = 200, description = "Job cancelled", body = CancelScrapeResponse),
+        (status = 400, description = "Job already finished or its recipe is already saved", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Job not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn cancel_scrape(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(job_id): Path<Uuid>,
+) -> impl IntoResponse {
+    // Get job to check ownership
+    let job = match scraping::get_job(&pool, job_id) {
+        Ok(j) => j,
+        Err(scraping::ScrapeError::JobNotFound) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Scrape job not found".to_string(),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to get scrape job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get scrape job".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // Check ownership
+    if job.user_id != user.id {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Scrape job not found".to_string(),
+            }),
+        )
+            .into_response();
+    }
+
+    match scraping::cancel_job(&pool, job_id) {
+        Ok(()) => {}
+        Err(scraping::ScrapeError::InvalidState(msg)) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: msg })).into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to cancel scrape job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to cancel scrape job".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    }
+
+    (
+        StatusCode::OK,
+        Json(CancelScrapeResponse {
+            id: job_id,
+            status: scraping::STATUS_CANCELLED.to_string(),
+        }),
+    )
+        .into_response()
+}
@@ -14,6 +14,10 @@ pub struct CaptureRequest {
     pub html: String,
     /// The URL the HTML came from (used for source attribution)
     pub source_url: String,
+    /// Import every recipe on the page instead of asking which to import when
+    /// there's more than one
+    #[serde(default)]
+    pub import_all: bool,
 }
 
 #[utoipa::path(
@@ -47,20 +51,25 @@ pub async fn capture(
     }
 
     // Create job with pre-existing HTML
-    let job =
-        match scraping::create_job_with_html(&pool, user.id, &request.source_url, &request.html) {
-            Ok(j) => j,
-            Err(e) => {
-                tracing::error!("Failed to create capture job: {}", e);
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: "Failed to create capture job".to_string(),
-                    }),
-                )
-                    .into_response();
-            }
-        };
+    let job = match scraping::create_job_with_html(
+        &pool,
+        user.id,
+        &request.source_url,
+        &request.html,
+        request.import_all,
+    ) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!("Failed to create capture job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to create capture job".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
 
     tracing::info!(
         "Created capture job {} for URL {}",
@@ -68,8 +77,8 @@ pub async fn capture(
         request.source_url
     );
 
-    // Spawn background task with instrumentation
-    scraping::spawn_scrape_job(pool.clone(), job.id, &request.source_url, "capture");
+    // The job is queued; wake a worker to pick it up
+    scraping::wake_workers();
 
     (
         StatusCode::CREATED,
@@ -12,6 +12,10 @@ use uuid::Uuid;
 pub struct CreateScrapeRequest {
     /// URL to scrape for recipe data
     pub url: String,
+    /// Import every recipe on the page instead of asking which to import when
+    /// there's more than one
+    #[serde(default)]
+    pub import_all: bool,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -64,7 +68,7 @@ pub async fn create_scrape(
     }
 
     // Create job
-    let job = match scraping::create_job(&pool, user.id, &request.url) {
+    let job = match scraping::create_job(&pool, user.id, &request.url, request.import_all) {
         Ok(j) => j,
         Err(e) => {
             tracing::error!("Failed to create scrape job: {}", e);
@@ -78,8 +82,8 @@ pub async fn create_scrape(
         }
     };
 
-    // Spawn background task with instrumentation
-    scraping::spawn_scrape_job(pool.clone(), job.id, &request.url, "create");
+    // The job is queued; wake a worker to pick it up
+    scraping::wake_workers();
 
     (
         StatusCode::CREATED,
@@ -0,0 +1,208 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping;
+use crate::scraping::events::{PublishedEvent, ScrapeJobEvent};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    Json,
+};
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use uuid::Uuid;
+
+/// How often a job stream checks the database, for jobs run by another instance
+const DB_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+fn to_sse_event(event: &ScrapeJobEvent) -> Event {
+    Event::default()
+        .event(event.name())
+        .data(serde_json::to_string(event).unwrap_or_default())
+}
+
+struct JobStreamState {
+    pool: Arc<DbPool>,
+    job_id: Uuid,
+    rx: Receiver<PublishedEvent>,
+    pending: VecDeque<ScrapeJobEvent>,
+    db_check: tokio::time::Interval,
+    done: bool,
+}
+
+/// The job's final event, if the database says it has finished.
+fn finished_event(pool: &DbPool, job_id: Uuid) -> Option<ScrapeJobEvent> {
+    scraping::get_job(pool, job_id)
+        .ok()
+        .map(|job| ScrapeJobEvent::from_job(&job))
+        .filter(ScrapeJobEvent::is_final)
+}
+
+/// Events for one job, ending after its final event.
+fn job_event_stream(state: JobStreamState) -> impl Stream<Item = Result<Event, Infallible>> + Send {
+    stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+
+        let event = match state.pending.pop_front() {
+            Some(event) => event,
+            None => loop {
+                tokio::select! {
+                    received = state.rx.recv() => match received {
+                        Ok(published) if published.event.job_id() == state.job_id => {
+                            break published.event;
+                        }
+                        Ok(_) => {}
+                        Err(RecvError::Lagged(skipped)) => {
+                            tracing::debug!("Job event stream skipped {} events", skipped);
+                            if let Some(event) = finished_event(&state.pool, state.job_id) {
+                                break event;
+                            }
+                        }
+                        Err(RecvError::Closed) => return None,
+                    },
+                    _ = state.db_check.tick() => {
+                        if let Some(event) = finished_event(&state.pool, state.job_id) {
+                            break event;
+                        }
+                    }
+                }
+            },
+        };
+
+        state.done = event.is_final();
+        Some((Ok(to_sse_event(&event)), state))
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scrape/{id}/events",
+    tag = "scrape",
+    params(
+        ("id" = Uuid, Path, description = "Scrape job ID")
+    ),
+    responses(
+        (status = 200, description = "Server-sent events for the job, starting with its current status and ending after completed, failed or cancelled", content_type = "text/event-stream", body = ScrapeJobEvent),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Job not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_scrape_events(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(job_id): Path<Uuid>,
+) -> impl IntoResponse {
+    // Subscribe before reading the job so no event falls between the two
+    let rx = scraping::events::subscribe();
+
+    let job = match scraping::get_job(&pool, job_id) {
+        Ok(j) if j.user_id == user.id => j,
+        Ok(_) | Err(scraping::ScrapeError::JobNotFound) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Scrape job not found".to_string(),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to get scrape job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get scrape job".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let mut db_check = tokio::time::interval(DB_CHECK_INTERVAL);
+    db_check.reset();
+
+    let state = JobStreamState {
+        pool,
+        job_id,
+        rx,
+        pending: VecDeque::from([ScrapeJobEvent::from_job(&job)]),
+        db_check,
+        done: false,
+    };
+
+    Sse::new(job_event_stream(state))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scrape/events",
+    tag = "scrape",
+    responses(
+        (status = 200, description = "Server-sent events for all of the user's jobs, starting with the status of each unfinished job", content_type = "text/event-stream", body = ScrapeJobEvent),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_user_scrape_events(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+) -> impl IntoResponse {
+    let rx = scraping::events::subscribe();
+
+    let active_jobs = match scraping::get_active_jobs(&pool, user.id) {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            tracing::error!("Failed to list active scrape jobs: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to list scrape jobs".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let pending: VecDeque<ScrapeJobEvent> =
+        active_jobs.iter().map(ScrapeJobEvent::from_job).collect();
+    let user_id = user.id;
+
+    let events = stream::unfold((rx, pending), move |(mut rx, mut pending)| async move {
+        if let Some(event) = pending.pop_front() {
+            return Some((Ok::<_, Infallible>(to_sse_event(&event)), (rx, pending)));
+        }
+        loop {
+            match rx.recv().await {
+                Ok(published) if published.user_id == user_id => {
+                    return Some((Ok(to_sse_event(&published.event)), (rx, pending)));
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::debug!("User event stream skipped {} events", skipped);
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
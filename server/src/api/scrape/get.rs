@@ -8,7 +8,7 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -17,7 +17,7 @@ use uuid::Uuid;
 pub struct ScrapeJobResponse {
     /// The scrape job ID
     pub id: Uuid,
-    /// Current job status (pending, scraping, parsing, completed, failed)
+    /// Current job status (pending, scraping, parsing, awaiting_selection, completed, failed, cancelled)
     pub status: String,
     /// URL being scraped (optional for imports)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -35,6 +35,25 @@ pub struct ScrapeJobResponse {
     pub can_retry: bool,
     /// Number of retry attempts
     pub retry_count: i32,
+    /// Jobs created for the other recipes when a photo import or a page had more than one
+    pub split_job_ids: Vec<Uuid>,
+    /// Titles of the recipes found on the page when there was more than one. While
+    /// the job is awaiting_selection, pass indices into this list to /select.
+    pub recipe_candidates: Vec<String>,
+    /// How far the extracted recipe can be trusted, from 0.0 to 1.0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extraction_confidence: Option<f64>,
+    /// What looked wrong with the extracted recipe, so the user knows to review it
+    pub warnings: Vec<ScrapeWarning>,
+}
+
+/// Something suspicious about a scraped recipe
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScrapeWarning {
+    /// ingredients_look_like_instructions, concatenated_ingredients, placeholder_text,
+    /// promotional_instructions or missing_times
+    pub kind: String,
+    pub message: String,
 }
 
 #[utoipa::path(
@@ -94,6 +113,25 @@ pub async fn get_scrape(
 
     let can_retry = job.status == scraping::STATUS_FAILED;
 
+    let split_job_ids = match scraping::get_split_job_ids(&pool, job.id) {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!("Failed to load split jobs for {}: {}", job.id, e);
+            vec![]
+        }
+    };
+
+    let recipe_candidates = match scraping::get_recipe_candidates(&pool, job.id) {
+        Ok(titles) => titles,
+        Err(e) => {
+            tracing::warn!("Failed to load recipe candidates for {}: {}", job.id, e);
+            vec![]
+        }
+    };
+
+    let warnings: Vec<ScrapeWarning> =
+        serde_json::from_value(job.quality_warnings).unwrap_or_default();
+
     (
         StatusCode::OK,
         Json(ScrapeJobResponse {
@@ -105,6 +143,10 @@ pub async fn get_scrape(
             failed_at_step: job.failed_at_step,
             can_retry,
             retry_count: job.retry_count,
+            split_job_ids,
+            recipe_candidates,
+            extraction_confidence: job.extraction_confidence,
+            warnings,
         }),
     )
         .into_response()
@@ -1,7 +1,11 @@
+pub mod batch;
+pub mod cancel;
 pub mod capture;
 pub mod create;
+pub mod events;
 pub mod get;
 pub mod retry;
+pub mod select;
 
 use crate::AppState;
 use axum::extract::DefaultBodyLimit;
@@ -13,8 +17,17 @@ use utoipa::OpenApi;
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create::create_scrape))
+        .route(
+            "/batch",
+            post(batch::create_scrape_batch).layer(DefaultBodyLimit::max(5 * 1024 * 1024)), // 5MB limit for bookmark exports
+        )
+        .route("/batch/{id}", get(batch::get_scrape_batch))
+        .route("/events", get(events::get_user_scrape_events))
         .route("/{id}", get(get::get_scrape))
+        .route("/{id}/cancel", post(cancel::cancel_scrape))
+        .route("/{id}/events", get(events::get_scrape_events))
         .route("/{id}/retry", post(retry::retry_scrape))
+        .route("/{id}/select", post(select::select_scrape_recipes))
         .route(
             "/capture",
             post(capture::capture).layer(DefaultBodyLimit::max(5 * 1024 * 1024)), // 5MB limit for HTML
@@ -24,17 +37,32 @@ pub fn router() -> Router<AppState> {
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        batch::create_scrape_batch,
+        batch::get_scrape_batch,
+        cancel::cancel_scrape,
         capture::capture,
         create::create_scrape,
+        events::get_scrape_events,
+        events::get_user_scrape_events,
         get::get_scrape,
         retry::retry_scrape,
+        select::select_scrape_recipes,
     ),
     components(schemas(
+        batch::CreateScrapeBatchRequest,
+        batch::CreateScrapeBatchUpload,
+        batch::ScrapeBatchItemResponse,
+        batch::ScrapeBatchResponse,
+        cancel::CancelScrapeResponse,
         capture::CaptureRequest,
         create::CreateScrapeRequest,
         create::CreateScrapeResponse,
+        crate::scraping::events::ScrapeJobEvent,
         get::ScrapeJobResponse,
+        get::ScrapeWarning,
         retry::RetryScrapeResponse,
+        select::SelectScrapeRecipesRequest,
+        select::SelectScrapeRecipesResponse,
     ))
 )]
 pub struct ApiDoc;
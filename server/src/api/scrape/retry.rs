@@ -79,18 +79,15 @@ pub async fn retry_scrape(
     }
 
     // Retry only makes sense for jobs with a URL (scrape jobs, not imports)
-    let url = match &job.url {
-        Some(u) => u,
-        None => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "Cannot retry import jobs".to_string(),
-                }),
-            )
-                .into_response();
-        }
-    };
+    if job.url.is_none() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Cannot retry import jobs".to_string(),
+            }),
+        )
+            .into_response();
+    }
 
     // Retry job
     let new_status = match scraping::retry_job(&pool, job_id) {
@@ -110,8 +107,8 @@ pub async fn retry_scrape(
         }
     };
 
-    // Spawn background task with instrumentation
-    scraping::spawn_scrape_job(pool.clone(), job_id, url, "retry");
+    // retry_job queued the job again; wake a worker to pick it up
+    scraping::wake_workers();
 
     (
         StatusCode::OK,
@@ -0,0 +1,118 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SelectScrapeRecipesRequest {
+    /// Indices into the job's recipe_candidates. The first is imported by this
+    /// job; each of the others gets a job of its own (see split_job_ids).
+    pub recipe_indices: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SelectScrapeRecipesResponse {
+    /// The scrape job ID
+    pub id: Uuid,
+    /// New job status after selecting
+    pub status: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/scrape/{id}/select",
+    tag = "scrape",
+    params(
+        ("id" = Uuid, Path, description = "Scrape job ID")
+    ),
+    request_body = SelectScrapeRecipesRequest,
+    responses(
+        (status = 200, description = "Selection saved and job resumed", body = SelectScrapeRecipesResponse),
+        (status = 400, description = "Job isn't awaiting selection or the selection is invalid", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Job not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn select_scrape_recipes(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(job_id): Path<Uuid>,
+    Json(request): Json<SelectScrapeRecipesRequest>,
+) -> impl IntoResponse {
+    // Get job to check ownership
+    let job = match scraping::get_job(&pool, job_id) {
+        Ok(j) => j,
+        Err(scraping::ScrapeError::JobNotFound) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Scrape job not found".to_string(),
+                }),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to get scrape job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to get scrape job".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // Check ownership
+    if job.user_id != user.id {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Scrape job not found".to_string(),
+            }),
+        )
+            .into_response();
+    }
+
+    let job = match scraping::select_recipes(&pool, job_id, request.recipe_indices) {
+        Ok(j) => j,
+        Err(scraping::ScrapeError::InvalidState(msg)) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: msg })).into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to select recipes for scrape job: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to select recipes".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // select_recipes queued the job again; wake a worker to pick it up
+    scraping::wake_workers();
+
+    (
+        StatusCode::OK,
+        Json(SelectScrapeRecipesResponse {
+            id: job.id,
+            status: job.status,
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,83 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping::{self, upstream, ScrapeError};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AcceptUpstreamUpdateResponse {
+    /// The rescrape job saving the upstream recipe as a new version
+    pub job_id: Uuid,
+    /// Current job status
+    pub status: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/upstream-updates/{id}/accept",
+    tag = "upstream_updates",
+    params(
+        ("id" = Uuid, Path, description = "Upstream update ID")
+    ),
+    responses(
+        (status = 201, description = "Rescrape job created to save the upstream recipe as a new version", body = AcceptUpstreamUpdateResponse),
+        (status = 400, description = "Update was already accepted or dismissed", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Upstream update not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn accept_upstream_update(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(update_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let job = match upstream::accept_update(&pool, user.id, update_id) {
+        Ok(job) => job,
+        Err(ScrapeError::UpstreamUpdateNotFound) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "Upstream update not found".to_string(),
+                }),
+            )
+                .into_response();
+        }
+        Err(ScrapeError::InvalidState(msg)) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: msg })).into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to accept upstream update: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to accept upstream update".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    // The job is queued; wake a worker to pick it up
+    scraping::wake_workers();
+
+    (
+        StatusCode::CREATED,
+        Json(AcceptUpstreamUpdateResponse {
+            job_id: job.id,
+            status: job.status,
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,59 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::scraping::{upstream, ScrapeError};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/api/upstream-updates/{id}/dismiss",
+    tag = "upstream_updates",
+    params(
+        ("id" = Uuid, Path, description = "Upstream update ID")
+    ),
+    responses(
+        (status = 204, description = "Update dismissed; the same upstream content won't be offered again"),
+        (status = 400, description = "Update was already accepted or dismissed", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Upstream update not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn dismiss_upstream_update(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+    Path(update_id): Path<Uuid>,
+) -> impl IntoResponse {
+    match upstream::dismiss_update(&pool, user.id, update_id) {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(ScrapeError::UpstreamUpdateNotFound) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Upstream update not found".to_string(),
+            }),
+        )
+            .into_response(),
+        Err(ScrapeError::InvalidState(msg)) => {
+            (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: msg })).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to dismiss upstream update: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to dismiss upstream update".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::api::ErrorResponse;
+use crate::auth::AuthUser;
+use crate::db::DbPool;
+use crate::models::RecipeUpstreamUpdate;
+use crate::scraping::upstream;
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use chrono::{DateTime, Utc};
+use ramekin_core::ExtractRecipeOutput;
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The recipe as it now appears on its source page
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UpstreamRecipe {
+    pub title: String,
+    pub description: Option<String>,
+    /// Ingredient lines as written on the page
+    pub ingredients: Vec<String>,
+    pub instructions: String,
+    pub servings: Option<String>,
+    pub prep_time: Option<String>,
+    pub cook_time: Option<String>,
+    pub total_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UpstreamUpdateResponse {
+    /// The upstream update ID
+    pub id: Uuid,
+    /// Recipe the update applies to
+    pub recipe_id: Uuid,
+    /// Version the source page was compared against
+    pub base_version_id: Uuid,
+    /// Fields that differ from that version (title, description, ingredients,
+    /// instructions, servings, prep_time, cook_time, total_time)
+    pub changed_fields: Vec<String>,
+    /// When the change was found
+    pub detected_at: DateTime<Utc>,
+    pub upstream: UpstreamRecipe,
+}
+
+impl UpstreamUpdateResponse {
+    /// Build the response for a stored update, or None if its extraction can't be read.
+    pub fn from_update(update: RecipeUpstreamUpdate) -> Option<Self> {
+        let output: ExtractRecipeOutput = match serde_json::from_value(update.extract_output) {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::warn!("Unreadable upstream update {}: {}", update.id, e);
+                return None;
+            }
+        };
+        let raw = output.raw_recipe;
+
+        Some(Self {
+            id: update.id,
+            recipe_id: update.recipe_id,
+            base_version_id: update.base_version_id,
+            changed_fields: update.changed_fields,
+            detected_at: update.created_at,
+            upstream: UpstreamRecipe {
+                title: raw.title,
+                description: raw.description,
+                ingredients: raw
+                    .ingredients
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                instructions: raw.instructions,
+                servings: raw.servings,
+                prep_time: raw.prep_time,
+                cook_time: raw.cook_time,
+                total_time: raw.total_time,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ListUpstreamUpdatesResponse {
+    pub updates: Vec<UpstreamUpdateResponse>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/upstream-updates",
+    tag = "upstream_updates",
+    responses(
+        (status = 200, description = "Pending upstream updates, newest first", body = ListUpstreamUpdatesResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_upstream_updates(
+    AuthUser(user): AuthUser,
+    State(pool): State<Arc<DbPool>>,
+) -> impl IntoResponse {
+    let updates = match upstream::list_pending_updates(&pool, user.id) {
+        Ok(updates) => updates,
+        Err(e) => {
+            tracing::error!("Failed to list upstream updates: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to list upstream updates".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    (
+        StatusCode::OK,
+        Json(ListUpstreamUpdatesResponse {
+            updates: updates
+                .into_iter()
+                .filter_map(UpstreamUpdateResponse::from_update)
+                .collect(),
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,32 @@
+pub mod accept;
+pub mod dismiss;
+pub mod list;
+
+use crate::AppState;
+use axum::routing::{get, post};
+use axum::Router;
+use utoipa::OpenApi;
+
+/// Returns the router for /api/upstream-updates endpoints (mounted at /api/upstream-updates)
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list::list_upstream_updates))
+        .route("/{id}/accept", post(accept::accept_upstream_update))
+        .route("/{id}/dismiss", post(dismiss::dismiss_upstream_update))
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list::list_upstream_updates,
+        accept::accept_upstream_update,
+        dismiss::dismiss_upstream_update,
+    ),
+    components(schemas(
+        list::ListUpstreamUpdatesResponse,
+        list::UpstreamUpdateResponse,
+        list::UpstreamRecipe,
+        accept::AcceptUpstreamUpdateResponse,
+    ))
+)]
+pub struct ApiDoc;
@@ -0,0 +1,74 @@
+//! One-off data migrations that need Rust code, run in the background at startup.
+
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::db::DbPool;
+use crate::models::{ParsedRecipeFields, PARSED_FIELDS_VERSION};
+use crate::schema::recipe_versions;
+
+/// Rows re-parsed per transaction.
+const BATCH_SIZE: i64 = 500;
+
+type UnparsedRow = (
+    Uuid,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+/// Start filling recipe_versions' typed time and yield columns for rows parsed
+/// by an older PARSED_FIELDS_VERSION (or never). Safe to run on every instance
+/// at once: rows are claimed with SKIP LOCKED.
+pub fn start_parsed_fields_backfill(pool: Arc<DbPool>) {
+    tokio::task::spawn_blocking(move || match backfill_parsed_fields(&pool) {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Parsed times and yields for {} recipe version(s)", n),
+        Err(e) => tracing::warn!("Recipe time/yield backfill failed: {}", e),
+    });
+}
+
+fn backfill_parsed_fields(pool: &DbPool) -> Result<usize, String> {
+    let mut conn = pool.get().map_err(|e| e.to_string())?;
+    let mut total = 0;
+    loop {
+        let updated = conn
+            .transaction(|conn| {
+                let rows: Vec<UnparsedRow> = recipe_versions::table
+                    .filter(recipe_versions::parsed_fields_version.lt(PARSED_FIELDS_VERSION))
+                    .limit(BATCH_SIZE)
+                    .select((
+                        recipe_versions::id,
+                        recipe_versions::servings,
+                        recipe_versions::prep_time,
+                        recipe_versions::cook_time,
+                        recipe_versions::total_time,
+                    ))
+                    .for_update()
+                    .skip_locked()
+                    .load(conn)?;
+
+                for (id, servings, prep_time, cook_time, total_time) in &rows {
+                    let parsed = ParsedRecipeFields::parse(
+                        servings.as_deref(),
+                        prep_time.as_deref(),
+                        cook_time.as_deref(),
+                        total_time.as_deref(),
+                    );
+                    diesel::update(recipe_versions::table.find(id))
+                        .set(&parsed)
+                        .execute(conn)?;
+                }
+                Ok(rows.len())
+            })
+            .map_err(|e: diesel::result::Error| e.to_string())?;
+
+        if updated == 0 {
+            return Ok(total);
+        }
+        total += updated;
+    }
+}
@@ -0,0 +1,396 @@
+//! Shared HTTP cache for server fetches.
+//!
+//! By default fetched pages and images are cached in the `url_cache` table, so
+//! every server instance reuses what any of them fetched instead of each keeping
+//! its own disk cache. Server code gets clients from [`client_builder`].
+
+use crate::db::{DbConn, DbPool};
+use crate::models::{NewUrlCacheEntry, UrlCacheEntry};
+use crate::schema::url_cache;
+use crate::scraping::env_number;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use ramekin_core::http::{
+    CacheMetadata, CacheStats, CachedError, CachedResponse, CachingClient, CachingClientBuilder,
+    HttpCache,
+};
+use std::env;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// Expired and over-cap entries are pruned once every this many writes.
+const PRUNE_EVERY_PUTS: usize = 100;
+
+/// Rows deleted per statement when enforcing the total size cap.
+const PRUNE_CHUNK: usize = 500;
+
+/// Where server HTTP clients cache responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheBackend {
+    /// The url_cache table, shared by all instances
+    Postgres,
+    /// ramekin-core's per-machine disk cache (configured by RAMEKIN_HTTP_CACHE)
+    Disk,
+    /// No caching
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpCacheConfig {
+    pub backend: CacheBackend,
+    /// Entries older than this are refetched; None keeps them until evicted
+    pub ttl: Option<Duration>,
+    /// Responses larger than this aren't stored in the database
+    pub max_entry_bytes: Option<u64>,
+    /// Oldest entries are evicted once the stored responses exceed this
+    pub max_total_bytes: Option<u64>,
+}
+
+impl Default for HttpCacheConfig {
+    fn default() -> Self {
+        Self {
+            backend: CacheBackend::Postgres,
+            ttl: Some(Duration::from_secs(7 * 24 * 60 * 60)),
+            max_entry_bytes: Some(10 * 1024 * 1024),
+            max_total_bytes: Some(1024 * 1024 * 1024),
+        }
+    }
+}
+
+impl HttpCacheConfig {
+    /// Defaults overridden by HTTP_CACHE_BACKEND ("postgres", "disk" or "none"),
+    /// HTTP_CACHE_TTL_SECS, HTTP_CACHE_MAX_ENTRY_BYTES and HTTP_CACHE_MAX_TOTAL_BYTES
+    /// (0 disables the limit).
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(backend) = env::var("HTTP_CACHE_BACKEND") {
+            match backend.trim() {
+                "postgres" => config.backend = CacheBackend::Postgres,
+                "disk" => config.backend = CacheBackend::Disk,
+                "none" => config.backend = CacheBackend::None,
+                other => tracing::warn!("Ignoring invalid HTTP_CACHE_BACKEND={:?}", other),
+            }
+        }
+        if let Some(secs) = env_number("HTTP_CACHE_TTL_SECS") {
+            config.ttl = (secs > 0).then_some(Duration::from_secs(secs));
+        }
+        if let Some(bytes) = env_number("HTTP_CACHE_MAX_ENTRY_BYTES") {
+            config.max_entry_bytes = (bytes > 0).then_some(bytes);
+        }
+        if let Some(bytes) = env_number("HTTP_CACHE_MAX_TOTAL_BYTES") {
+            config.max_total_bytes = (bytes > 0).then_some(bytes);
+        }
+        config
+    }
+}
+
+enum SharedCache {
+    Postgres(Arc<PgHttpCache>),
+    Disk { ttl: Option<Duration> },
+    None,
+}
+
+static SHARED_CACHE: OnceLock<SharedCache> = OnceLock::new();
+
+/// Set up the cache used by [`client_builder`]. Call once at startup.
+pub fn init(pool: Arc<DbPool>, config: &HttpCacheConfig) {
+    let shared = match config.backend {
+        CacheBackend::Postgres => SharedCache::Postgres(Arc::new(PgHttpCache::new(pool, config))),
+        CacheBackend::Disk => SharedCache::Disk { ttl: config.ttl },
+        CacheBackend::None => SharedCache::None,
+    };
+    if SHARED_CACHE.set(shared).is_err() {
+        tracing::warn!("HTTP cache already initialized");
+    }
+    tracing::info!("HTTP cache backend: {:?}", config.backend);
+}
+
+/// Builder for an HTTP client that uses the server's cache. Before [`init`]
+/// runs this is ramekin-core's environment-configured default.
+pub fn client_builder() -> CachingClientBuilder {
+    let builder = CachingClient::builder();
+    match SHARED_CACHE.get() {
+        Some(SharedCache::Postgres(cache)) => builder.cache(cache.clone()),
+        Some(SharedCache::Disk { ttl }) => builder.cache_ttl(*ttl),
+        Some(SharedCache::None) => builder.cache_dir(None),
+        None => builder,
+    }
+}
+
+fn io_error(e: impl std::fmt::Display) -> io::Error {
+    io::Error::other(e.to_string())
+}
+
+/// [`HttpCache`] backed by the url_cache table.
+pub struct PgHttpCache {
+    pool: Arc<DbPool>,
+    ttl: Option<Duration>,
+    max_entry_bytes: Option<u64>,
+    max_total_bytes: Option<u64>,
+    puts: AtomicUsize,
+}
+
+impl PgHttpCache {
+    pub fn new(pool: Arc<DbPool>, config: &HttpCacheConfig) -> Self {
+        Self {
+            pool,
+            ttl: config.ttl,
+            max_entry_bytes: config.max_entry_bytes,
+            max_total_bytes: config.max_total_bytes,
+            puts: AtomicUsize::new(0),
+        }
+    }
+
+    fn conn(&self) -> io::Result<DbConn> {
+        self.pool.get().map_err(io_error)
+    }
+
+    /// Entries fetched before this have expired.
+    fn cutoff(&self) -> Option<DateTime<Utc>> {
+        let ttl = chrono::Duration::from_std(self.ttl?).ok()?;
+        Some(Utc::now() - ttl)
+    }
+
+    fn is_fresh(&self, fetched_at: DateTime<Utc>) -> bool {
+        self.cutoff().is_none_or(|cutoff| fetched_at >= cutoff)
+    }
+
+    fn find(&self, url: &str) -> Option<UrlCacheEntry> {
+        let result = self.conn().and_then(|mut conn| {
+            url_cache::table
+                .find(url)
+                .select(UrlCacheEntry::as_select())
+                .first(&mut conn)
+                .optional()
+                .map_err(io_error)
+        });
+        match result {
+            Ok(entry) => entry,
+            Err(e) => {
+                // A cache that can't be read behaves like a miss
+                tracing::warn!("Failed to read HTTP cache: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Count a write, pruning every PRUNE_EVERY_PUTS of them.
+    fn after_write(&self) {
+        if !(self.puts.fetch_add(1, Ordering::Relaxed) + 1).is_multiple_of(PRUNE_EVERY_PUTS) {
+            return;
+        }
+        match self.prune() {
+            Ok(0) => {}
+            Ok(n) => tracing::info!("Pruned {} HTTP cache entries", n),
+            Err(e) => tracing::warn!("Failed to prune HTTP cache: {}", e),
+        }
+    }
+
+    /// Delete expired entries, then the oldest responses until the rest fit
+    /// under the total size cap. Returns the number of entries removed.
+    pub fn prune(&self) -> io::Result<usize> {
+        let mut conn = self.conn()?;
+        let mut removed = 0;
+
+        if let Some(cutoff) = self.cutoff() {
+            removed += diesel::delete(
+                url_cache::table
+                    .filter(url_cache::fetched_at.lt(cutoff))
+                    .filter(
+                        url_cache::error_at
+                            .is_null()
+                            .or(url_cache::error_at.lt(cutoff)),
+                    ),
+            )
+            .execute(&mut conn)
+            .map_err(io_error)?;
+        }
+
+        if let Some(max_total) = self.max_total_bytes {
+            let sizes: Vec<(String, i64)> = url_cache::table
+                .filter(url_cache::size_bytes.gt(0))
+                .order(url_cache::fetched_at.desc())
+                .select((url_cache::url, url_cache::size_bytes))
+                .load(&mut conn)
+                .map_err(io_error)?;
+
+            let mut total: u64 = 0;
+            let evicted: Vec<String> = sizes
+                .into_iter()
+                .filter_map(|(url, size)| {
+                    total += size.max(0) as u64;
+                    (total > max_total).then_some(url)
+                })
+                .collect();
+
+            for chunk in evicted.chunks(PRUNE_CHUNK) {
+                removed += diesel::delete(url_cache::table.filter(url_cache::url.eq_any(chunk)))
+                    .execute(&mut conn)
+                    .map_err(io_error)?;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+impl HttpCache for PgHttpCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        let entry = self.find(url)?;
+        let data = entry.content?;
+        if !self.is_fresh(entry.fetched_at) {
+            return None;
+        }
+        Some(CachedResponse {
+            data,
+            metadata: CacheMetadata {
+                url: entry.url,
+                content_type: entry.content_type,
+                fetched_at: entry.fetched_at,
+                etag: entry.etag,
+                last_modified: entry.last_modified,
+            },
+        })
+    }
+
+    fn get_error(&self, url: &str) -> Option<CachedError> {
+        let entry = self.find(url)?;
+        let (error, error_at) = entry.error.zip(entry.error_at)?;
+        if !self.is_fresh(error_at) {
+            return None;
+        }
+        Some(CachedError {
+            error,
+            fetched_at: error_at,
+            url: Some(entry.url),
+        })
+    }
+
+    fn put(
+        &self,
+        url: &str,
+        data: &[u8],
+        content_type: Option<String>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    ) -> io::Result<()> {
+        if self
+            .max_entry_bytes
+            .is_some_and(|max| data.len() as u64 > max)
+        {
+            // Too big to share; drop any older copy so it isn't served instead
+            return self.remove(url);
+        }
+
+        let entry = NewUrlCacheEntry {
+            url,
+            content: Some(data),
+            content_type: content_type.as_deref(),
+            etag: etag.as_deref(),
+            last_modified: last_modified.as_deref(),
+            size_bytes: data.len() as i64,
+            fetched_at: Utc::now(),
+            error: None,
+            error_at: None,
+        };
+
+        let mut conn = self.conn()?;
+        diesel::insert_into(url_cache::table)
+            .values(&entry)
+            .on_conflict(url_cache::url)
+            .do_update()
+            .set((
+                url_cache::content.eq(entry.content),
+                url_cache::content_type.eq(entry.content_type),
+                url_cache::etag.eq(entry.etag),
+                url_cache::last_modified.eq(entry.last_modified),
+                url_cache::size_bytes.eq(entry.size_bytes),
+                url_cache::fetched_at.eq(entry.fetched_at),
+                url_cache::error.eq(None::<String>),
+                url_cache::error_at.eq(None::<DateTime<Utc>>),
+            ))
+            .execute(&mut conn)
+            .map_err(io_error)?;
+
+        self.after_write();
+        Ok(())
+    }
+
+    fn put_error(&self, url: &str, error: &str) -> io::Result<()> {
+        let now = Utc::now();
+        let entry = NewUrlCacheEntry {
+            url,
+            content: None,
+            content_type: None,
+            etag: None,
+            last_modified: None,
+            size_bytes: 0,
+            fetched_at: now,
+            error: Some(error),
+            error_at: Some(now),
+        };
+
+        // Keeps any cached response, as the disk cache does
+        let mut conn = self.conn()?;
+        diesel::insert_into(url_cache::table)
+            .values(&entry)
+            .on_conflict(url_cache::url)
+            .do_update()
+            .set((
+                url_cache::error.eq(entry.error),
+                url_cache::error_at.eq(entry.error_at),
+            ))
+            .execute(&mut conn)
+            .map_err(io_error)?;
+
+        self.after_write();
+        Ok(())
+    }
+
+    fn remove(&self, url: &str) -> io::Result<()> {
+        let mut conn = self.conn()?;
+        diesel::delete(url_cache::table.find(url))
+            .execute(&mut conn)
+            .map_err(io_error)?;
+        Ok(())
+    }
+
+    fn stats(&self) -> CacheStats {
+        let counts = self.conn().and_then(|mut conn| {
+            let cached_success = url_cache::table
+                .filter(url_cache::content.is_not_null())
+                .count()
+                .get_result::<i64>(&mut conn)
+                .map_err(io_error)?;
+            let cached_errors = url_cache::table
+                .filter(url_cache::content.is_null())
+                .filter(url_cache::error.is_not_null())
+                .count()
+                .get_result::<i64>(&mut conn)
+                .map_err(io_error)?;
+            Ok((cached_success, cached_errors))
+        });
+
+        match counts {
+            Ok((cached_success, cached_errors)) => CacheStats {
+                cached_success: cached_success as usize,
+                cached_errors: cached_errors as usize,
+                ..Default::default()
+            },
+            Err(e) => {
+                tracing::warn!("Failed to count HTTP cache entries: {}", e);
+                CacheStats::default()
+            }
+        }
+    }
+
+    fn clear(&self) -> io::Result<()> {
+        let mut conn = self.conn()?;
+        diesel::delete(url_cache::table)
+            .execute(&mut conn)
+            .map_err(io_error)?;
+        Ok(())
+    }
+}
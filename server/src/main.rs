@@ -1,6 +1,8 @@
 mod api;
 mod auth;
+mod backfill;
 mod db;
+mod http_cache;
 mod models;
 mod photos;
 mod raw_sql;
@@ -9,10 +11,10 @@ mod scraping;
 mod telemetry;
 mod types;
 
-use axum::extract::MatchedPath;
+use axum::extract::{DefaultBodyLimit, MatchedPath};
 use axum::http::Request;
 use axum::middleware;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::Router;
 use opentelemetry::trace::TracerProvider;
 use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
@@ -147,6 +149,21 @@ async fn main() {
 
     let pool: AppState = Arc::new(db::create_pool(&database_url));
 
+    // Cache shared by all HTTP fetches (by default in Postgres, across instances)
+    http_cache::init(pool.clone(), &http_cache::HttpCacheConfig::from_env());
+
+    // Background workers for the durable scrape job queue
+    scraping::start_workers(pool.clone(), scraping::WorkerConfig::from_env());
+
+    // Periodic checks of opted-in recipes for upstream edits
+    scraping::upstream::start_upstream_checks(
+        pool.clone(),
+        scraping::upstream::UpstreamCheckConfig::from_env(),
+    );
+
+    // Typed time/yield columns for versions saved before the parsers (or by an older one)
+    backfill::start_parsed_fields_backfill(pool.clone());
+
     // Public routes (no auth required)
     let public_router = api::public::router();
 
@@ -167,6 +184,9 @@ async fn main() {
         .nest("/api/tags", api::tags::router())
         .nest("/api/meal-plans", api::meal_plans::router())
         .nest("/api/shopping-list", api::shopping_list::router())
+        .nest("/api/account", api::account::router())
+        .nest("/api/upstream-updates", api::upstream_updates::router())
+        .nest("/api/admin", api::admin::router())
         .route("/api/enrich", post(api::enrich::enrich_recipe))
         .route(
             "/api/enrich/custom",
@@ -174,6 +194,15 @@ async fn main() {
         )
         .route("/api/import/recipe", post(api::import::import_recipe))
         .route("/api/import/photos", post(api::import::import_from_photos))
+        .route(
+            "/api/import/document",
+            post(api::import::import_from_document)
+                .layer(DefaultBodyLimit::max(api::import::MAX_DOCUMENT_SIZE)),
+        )
+        .route(
+            "/api/import/paprika",
+            get(api::import::get_paprika_sync_state).post(api::import::import_paprika_recipe),
+        )
         .layer(middleware::from_fn_with_state(
             pool.clone(),
             auth::require_auth,
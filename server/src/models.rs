@@ -1,5 +1,6 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
+use ramekin_core::extract::http_url;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
@@ -153,6 +154,17 @@ pub struct RecipeVersion {
     pub notes: Option<String>,
     pub version_source: String,
     pub created_at: DateTime<Utc>,
+    pub prep_time_minutes: Option<i32>,
+    pub cook_time_minutes: Option<i32>,
+    pub total_time_minutes: Option<i32>,
+    pub yield_quantity: Option<f64>,
+    pub yield_quantity_max: Option<f64>,
+    pub yield_unit: Option<String>,
+    pub parsed_fields_version: i32,
+    pub video_url: Option<String>,
+    pub equipment: Vec<Option<String>>,
+    pub author: Option<String>,
+    pub published_date: Option<NaiveDate>,
 }
 
 #[derive(Insertable)]
@@ -175,6 +187,107 @@ pub struct NewRecipeVersion<'a> {
     pub nutritional_info: Option<&'a str>,
     pub notes: Option<&'a str>,
     pub version_source: &'a str,
+    #[diesel(embed)]
+    pub parsed: ParsedRecipeFields,
+    #[diesel(embed)]
+    pub source_details: SourceDetails,
+}
+
+/// Bump when the duration or yield parsers change in a way that should be
+/// applied to existing rows; the backfill task re-parses anything older.
+pub const PARSED_FIELDS_VERSION: i32 = 1;
+
+// Typed copies of a version's servings and time text, kept alongside it
+#[derive(Insertable, AsChangeset, Debug, Clone, Default, PartialEq)]
+#[diesel(table_name = crate::schema::recipe_versions)]
+#[diesel(treat_none_as_null = true)]
+pub struct ParsedRecipeFields {
+    pub prep_time_minutes: Option<i32>,
+    pub cook_time_minutes: Option<i32>,
+    pub total_time_minutes: Option<i32>,
+    pub yield_quantity: Option<f64>,
+    pub yield_quantity_max: Option<f64>,
+    pub yield_unit: Option<String>,
+    pub parsed_fields_version: i32,
+}
+
+impl ParsedRecipeFields {
+    pub fn parse(
+        servings: Option<&str>,
+        prep_time: Option<&str>,
+        cook_time: Option<&str>,
+        total_time: Option<&str>,
+    ) -> Self {
+        let minutes = |text: Option<&str>| {
+            text.and_then(ramekin_core::duration_parser::parse_duration_minutes)
+                .and_then(|m| i32::try_from(m).ok())
+        };
+        let prep_time_minutes = minutes(prep_time);
+        let cook_time_minutes = minutes(cook_time);
+        // Plenty of recipes give prep and cook times but no total
+        let total_time_minutes =
+            minutes(total_time).or(match (prep_time_minutes, cook_time_minutes) {
+                (Some(prep), Some(cook)) => Some(prep + cook),
+                _ => None,
+            });
+        let recipe_yield = servings.and_then(ramekin_core::yield_parser::parse_yield);
+
+        Self {
+            prep_time_minutes,
+            cook_time_minutes,
+            total_time_minutes,
+            yield_quantity: recipe_yield.as_ref().map(|y| y.quantity),
+            yield_quantity_max: recipe_yield.as_ref().and_then(|y| y.quantity_max),
+            yield_unit: recipe_yield.and_then(|y| y.unit),
+            parsed_fields_version: PARSED_FIELDS_VERSION,
+        }
+    }
+}
+
+// Details from the page a recipe came from, beyond the recipe itself
+#[derive(Insertable, Debug, Clone, Default, PartialEq)]
+#[diesel(table_name = crate::schema::recipe_versions)]
+pub struct SourceDetails {
+    pub video_url: Option<String>,
+    pub equipment: Vec<Option<String>>,
+    pub author: Option<String>,
+    pub published_date: Option<NaiveDate>,
+}
+
+impl SourceDetails {
+    pub fn new(
+        video_url: Option<String>,
+        equipment: Vec<String>,
+        author: Option<String>,
+        published_date: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            video_url: video_url.as_deref().and_then(http_url),
+            equipment: equipment.into_iter().map(Some).collect(),
+            author,
+            published_date,
+        }
+    }
+
+    pub fn from_raw(raw: &ramekin_core::RawRecipe) -> Self {
+        Self::new(
+            raw.video_url.clone(),
+            raw.equipment.clone().unwrap_or_default(),
+            raw.author.clone(),
+            raw.published_date
+                .as_deref()
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()),
+        )
+    }
+
+    pub fn from_version(version: &RecipeVersion) -> Self {
+        Self {
+            video_url: version.video_url.clone(),
+            equipment: version.equipment.clone(),
+            author: version.author.clone(),
+            published_date: version.published_date,
+        }
+    }
 }
 
 // Scrape job for async URL scraping
@@ -194,6 +307,14 @@ pub struct ScrapeJob {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub current_step: Option<String>,
+    pub queued_at: Option<DateTime<Utc>>,
+    pub claimed_by: Option<String>,
+    pub heartbeat_at: Option<DateTime<Utc>>,
+    pub batch_id: Option<Uuid>,
+    pub saved_at: Option<DateTime<Utc>>,
+    pub extraction_confidence: Option<f64>,
+    /// Serialized `Vec<ramekin_core::QualityWarning>`
+    pub quality_warnings: serde_json::Value,
 }
 
 #[derive(Insertable)]
@@ -201,6 +322,107 @@ pub struct ScrapeJob {
 pub struct NewScrapeJob<'a> {
     pub user_id: Uuid,
     pub url: Option<&'a str>,
+    /// Set when the job can be picked up by a worker straight away
+    pub queued_at: Option<DateTime<Utc>>,
+}
+
+// Batch URL import: one row per submitted list
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::scrape_batches)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[allow(dead_code)]
+pub struct ScrapeBatch {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+// One URL of a batch import, with the child job it was queued as (if any)
+#[allow(dead_code)]
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::scrape_batch_items)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ScrapeBatchItem {
+    pub batch_id: Uuid,
+    pub position: i32,
+    pub url: String,
+    pub status: String,
+    pub scrape_job_id: Option<Uuid>,
+    pub recipe_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::scrape_batch_items)]
+pub struct NewScrapeBatchItem<'a> {
+    pub batch_id: Uuid,
+    pub position: i32,
+    pub url: &'a str,
+    pub status: &'a str,
+    pub scrape_job_id: Option<Uuid>,
+    pub recipe_id: Option<Uuid>,
+    pub error: Option<&'a str>,
+}
+
+// Opt-in upstream change checks for a recipe, with the last fetch's validators
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::recipe_upstream_checks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct RecipeUpstreamCheck {
+    pub recipe_id: Uuid,
+    pub user_id: Uuid,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Upstream change found by a check, pending until accepted or dismissed
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::recipe_upstream_updates)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[allow(dead_code)]
+pub struct RecipeUpstreamUpdate {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub user_id: Uuid,
+    pub base_version_id: Uuid,
+    pub extract_output: JsonValue,
+    pub changed_fields: Vec<String>,
+    pub status: String,
+    pub scrape_job_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+// Shared HTTP cache entry: the last good response and/or the last error for a URL
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::url_cache)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct UrlCacheEntry {
+    pub url: String,
+    pub content: Option<Vec<u8>>,
+    pub content_type: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+    pub error: Option<String>,
+    pub error_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::url_cache)]
+pub struct NewUrlCacheEntry<'a> {
+    pub url: &'a str,
+    pub content: Option<&'a [u8]>,
+    pub content_type: Option<&'a str>,
+    pub etag: Option<&'a str>,
+    pub last_modified: Option<&'a str>,
+    pub size_bytes: i64,
+    pub fetched_at: DateTime<Utc>,
+    pub error: Option<&'a str>,
+    pub error_at: Option<DateTime<Utc>>,
 }
 
 // Step output for pipeline step results (append-only log)
@@ -303,3 +525,109 @@ pub struct NewShoppingListItem<'a> {
     pub sort_order: i32,
     pub client_id: Option<Uuid>,
 }
+
+// Paprika sync state: which recipe a Paprika uid was imported into, and at what hash
+#[derive(Queryable, Selectable, Debug, Clone)]
+#[diesel(table_name = crate::schema::paprika_recipes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[allow(dead_code)]
+pub struct PaprikaRecipeLink {
+    pub user_id: Uuid,
+    pub uid: String,
+    pub hash: String,
+    pub recipe_id: Option<Uuid>,
+    pub scrape_job_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::paprika_recipes)]
+pub struct NewPaprikaRecipeLink<'a> {
+    pub user_id: Uuid,
+    pub uid: &'a str,
+    pub hash: &'a str,
+    pub recipe_id: Option<Uuid>,
+    pub scrape_job_id: Option<Uuid>,
+}
+
+// Full-row inserts used when restoring an account backup.
+// Ids are freshly generated by the restore; timestamps are carried over from the backup.
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::recipes)]
+pub struct RestoredRecipe {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::recipe_versions)]
+pub struct RestoredRecipeVersion<'a> {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub ingredients: serde_json::Value,
+    pub instructions: &'a str,
+    pub source_url: Option<&'a str>,
+    pub source_name: Option<&'a str>,
+    pub photo_ids: Vec<Option<Uuid>>,
+    pub servings: Option<&'a str>,
+    pub prep_time: Option<&'a str>,
+    pub cook_time: Option<&'a str>,
+    pub total_time: Option<&'a str>,
+    pub rating: Option<i32>,
+    pub difficulty: Option<&'a str>,
+    pub nutritional_info: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    pub version_source: &'a str,
+    pub created_at: DateTime<Utc>,
+    #[diesel(embed)]
+    pub parsed: ParsedRecipeFields,
+    #[diesel(embed)]
+    pub source_details: SourceDetails,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::photos)]
+pub struct RestoredPhoto<'a> {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub content_type: &'a str,
+    pub data: &'a [u8],
+    pub thumbnail: &'a [u8],
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::meal_plans)]
+pub struct RestoredMealPlan<'a> {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub recipe_id: Uuid,
+    pub meal_date: chrono::NaiveDate,
+    pub meal_type: &'a str,
+    pub notes: Option<&'a str>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::shopping_list_items)]
+pub struct RestoredShoppingListItem<'a> {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub item: &'a str,
+    pub amount: Option<&'a str>,
+    pub note: Option<&'a str>,
+    pub source_recipe_id: Option<Uuid>,
+    pub source_recipe_title: Option<&'a str>,
+    pub is_checked: bool,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
@@ -14,6 +14,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    paprika_recipes (user_id, uid) {
+        user_id -> Uuid,
+        uid -> Varchar,
+        hash -> Varchar,
+        recipe_id -> Nullable<Uuid>,
+        scrape_job_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     photo_thumbnails (id) {
         id -> Uuid,
@@ -36,6 +48,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    recipe_upstream_checks (recipe_id) {
+        recipe_id -> Uuid,
+        user_id -> Uuid,
+        etag -> Nullable<Text>,
+        last_modified -> Nullable<Text>,
+        checked_at -> Nullable<Timestamptz>,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    recipe_upstream_updates (id) {
+        id -> Uuid,
+        recipe_id -> Uuid,
+        user_id -> Uuid,
+        base_version_id -> Uuid,
+        extract_output -> Jsonb,
+        changed_fields -> Array<Text>,
+        status -> Varchar,
+        scrape_job_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        resolved_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     recipe_version_tags (recipe_version_id, tag_id) {
         recipe_version_id -> Uuid,
@@ -64,6 +103,17 @@ diesel::table! {
         notes -> Nullable<Text>,
         version_source -> Varchar,
         created_at -> Timestamptz,
+        prep_time_minutes -> Nullable<Int4>,
+        cook_time_minutes -> Nullable<Int4>,
+        total_time_minutes -> Nullable<Int4>,
+        yield_quantity -> Nullable<Float8>,
+        yield_quantity_max -> Nullable<Float8>,
+        yield_unit -> Nullable<Text>,
+        parsed_fields_version -> Int4,
+        video_url -> Nullable<Text>,
+        equipment -> Array<Nullable<Text>>,
+        author -> Nullable<Text>,
+        published_date -> Nullable<Date>,
     }
 }
 
@@ -77,6 +127,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    scrape_batch_items (batch_id, position) {
+        batch_id -> Uuid,
+        position -> Int4,
+        url -> Text,
+        status -> Varchar,
+        scrape_job_id -> Nullable<Uuid>,
+        recipe_id -> Nullable<Uuid>,
+        error -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    scrape_batches (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     scrape_jobs (id) {
         id -> Uuid,
@@ -90,6 +160,13 @@ diesel::table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         current_step -> Nullable<Varchar>,
+        queued_at -> Nullable<Timestamptz>,
+        claimed_by -> Nullable<Varchar>,
+        heartbeat_at -> Nullable<Timestamptz>,
+        batch_id -> Nullable<Uuid>,
+        saved_at -> Nullable<Timestamptz>,
+        extraction_confidence -> Nullable<Float8>,
+        quality_warnings -> Jsonb,
     }
 }
 
@@ -134,6 +211,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    url_cache (url) {
+        url -> Text,
+        content -> Nullable<Bytea>,
+        content_type -> Nullable<Text>,
+        etag -> Nullable<Text>,
+        last_modified -> Nullable<Text>,
+        size_bytes -> Int8,
+        fetched_at -> Timestamptz,
+        error -> Nullable<Text>,
+        error_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     user_tags (id) {
         id -> Uuid,
@@ -159,11 +250,25 @@ diesel::table! {
 
 diesel::joinable!(meal_plans -> recipes (recipe_id));
 diesel::joinable!(meal_plans -> users (user_id));
+diesel::joinable!(paprika_recipes -> recipes (recipe_id));
+diesel::joinable!(paprika_recipes -> scrape_jobs (scrape_job_id));
+diesel::joinable!(paprika_recipes -> users (user_id));
 diesel::joinable!(photo_thumbnails -> photos (photo_id));
 diesel::joinable!(photos -> users (user_id));
+diesel::joinable!(recipe_upstream_checks -> recipes (recipe_id));
+diesel::joinable!(recipe_upstream_checks -> users (user_id));
+diesel::joinable!(recipe_upstream_updates -> recipe_versions (base_version_id));
+diesel::joinable!(recipe_upstream_updates -> recipes (recipe_id));
+diesel::joinable!(recipe_upstream_updates -> scrape_jobs (scrape_job_id));
+diesel::joinable!(recipe_upstream_updates -> users (user_id));
 diesel::joinable!(recipe_version_tags -> recipe_versions (recipe_version_id));
 diesel::joinable!(recipe_version_tags -> user_tags (tag_id));
 diesel::joinable!(recipes -> users (user_id));
+diesel::joinable!(scrape_batch_items -> recipes (recipe_id));
+diesel::joinable!(scrape_batch_items -> scrape_batches (batch_id));
+diesel::joinable!(scrape_batch_items -> scrape_jobs (scrape_job_id));
+diesel::joinable!(scrape_batches -> users (user_id));
+diesel::joinable!(scrape_jobs -> scrape_batches (batch_id));
 diesel::joinable!(scrape_jobs -> users (user_id));
 diesel::joinable!(sessions -> users (user_id));
 diesel::joinable!(shopping_list_items -> recipes (source_recipe_id));
@@ -173,15 +278,21 @@ diesel::joinable!(user_tags -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     meal_plans,
+    paprika_recipes,
     photo_thumbnails,
     photos,
+    recipe_upstream_checks,
+    recipe_upstream_updates,
     recipe_version_tags,
     recipe_versions,
     recipes,
+    scrape_batch_items,
+    scrape_batches,
     scrape_jobs,
     sessions,
     shopping_list_items,
     step_outputs,
+    url_cache,
     user_tags,
     users,
 );
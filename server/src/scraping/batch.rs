@@ -0,0 +1,240 @@
+//! Batch URL imports.
+//!
+//! A batch records every submitted URL as an item. URLs that can be scraped are
+//! queued as child scrape jobs (with `batch_id` set, so their fetches are rate
+//! limited per host); duplicates and invalid URLs are recorded without a job so
+//! the batch status can report them alongside the jobs' outcomes.
+
+use super::{is_host_allowed, ScrapeError};
+use crate::db::DbPool;
+use crate::models::{NewScrapeBatchItem, ScrapeBatch, ScrapeBatchItem, ScrapeJob};
+use crate::schema::{recipe_versions, recipes, scrape_batch_items, scrape_batches, scrape_jobs};
+use chrono::Utc;
+use diesel::prelude::*;
+use scraper::{Html, Selector};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Maximum number of URLs accepted in one batch
+pub const MAX_BATCH_URLS: usize = 1000;
+
+/// Item queued as a child scrape job; its progress is the job's status
+pub const ITEM_QUEUED: &str = "queued";
+/// Item skipped because the URL was already in the batch or the user's library
+pub const ITEM_DUPLICATE: &str = "duplicate";
+/// Item skipped because the URL can't be scraped
+pub const ITEM_INVALID: &str = "invalid";
+
+/// Extract URLs from an uploaded list: a Netscape bookmarks export (or any
+/// HTML page of links), or plain text with one URL per line.
+pub fn parse_url_list(text: &str) -> Vec<String> {
+    if text.trim_start().starts_with('<') {
+        let document = Html::parse_document(text);
+        let selector = Selector::parse("a[href]").expect("Invalid selector");
+        document
+            .select(&selector)
+            .filter_map(|a| a.value().attr("href"))
+            .map(str::trim)
+            // Bookmark exports also link folders and bookmarklets (place:, javascript:)
+            .filter(|href| href.starts_with("http://") || href.starts_with("https://"))
+            .map(str::to_string)
+            .collect()
+    } else {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Canonical form used to spot duplicates: parsed, without the fragment.
+fn normalize_url(url: &str) -> Result<String, ScrapeError> {
+    let mut parsed =
+        reqwest::Url::parse(url).map_err(|e| ScrapeError::InvalidUrl(e.to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(ScrapeError::InvalidUrl(format!(
+            "scheme '{}' is not supported",
+            parsed.scheme()
+        )));
+    }
+    parsed.set_fragment(None);
+    is_host_allowed(parsed.as_str())?;
+    Ok(parsed.into())
+}
+
+/// Create a batch with one item per URL, queueing a child scrape job for each
+/// URL that isn't invalid or a duplicate.
+pub fn create_batch(
+    pool: &DbPool,
+    user_id: Uuid,
+    urls: &[String],
+) -> Result<ScrapeBatch, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let normalized: Vec<Result<String, ScrapeError>> =
+        urls.iter().map(|url| normalize_url(url)).collect();
+    let candidates: Vec<&str> = normalized
+        .iter()
+        .filter_map(|n| n.as_ref().ok().map(String::as_str))
+        .collect();
+
+    // Recipes already in the library, by source URL
+    let existing: HashMap<String, Uuid> = recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::user_id.eq(user_id))
+        .filter(recipes::deleted_at.is_null())
+        .filter(recipe_versions::source_url.eq_any(&candidates))
+        .select((recipe_versions::source_url.assume_not_null(), recipes::id))
+        .load::<(String, Uuid)>(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?
+        .into_iter()
+        .collect();
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        let batch: ScrapeBatch = diesel::insert_into(scrape_batches::table)
+            .values(scrape_batches::user_id.eq(user_id))
+            .get_result(conn)?;
+
+        // Position of the first occurrence of each URL in this batch
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+
+        for (position, (url, normalized)) in urls.iter().zip(&normalized).enumerate() {
+            let mut item = NewScrapeBatchItem {
+                batch_id: batch.id,
+                position: position as i32,
+                url,
+                status: ITEM_QUEUED,
+                scrape_job_id: None,
+                recipe_id: None,
+                error: None,
+            };
+            let duplicate_error;
+            let invalid_error;
+
+            match normalized {
+                Err(e) => {
+                    invalid_error = e.to_string();
+                    item.status = ITEM_INVALID;
+                    item.error = Some(&invalid_error);
+                }
+                Ok(normalized) => {
+                    item.url = normalized;
+                    if let Some(first) = seen.get(normalized.as_str()) {
+                        duplicate_error = format!("Same URL as item {} of this batch", first + 1);
+                        item.status = ITEM_DUPLICATE;
+                        item.error = Some(&duplicate_error);
+                    } else if let Some(&recipe_id) = existing.get(normalized) {
+                        seen.insert(normalized.as_str(), position);
+                        item.status = ITEM_DUPLICATE;
+                        item.recipe_id = Some(recipe_id);
+                        item.error = Some("Already in your recipes");
+                    } else {
+                        seen.insert(normalized.as_str(), position);
+                        let job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
+                            .values((
+                                scrape_jobs::user_id.eq(user_id),
+                                scrape_jobs::url.eq(normalized),
+                                scrape_jobs::batch_id.eq(Some(batch.id)),
+                                scrape_jobs::queued_at.eq(Some(Utc::now())),
+                            ))
+                            .get_result(conn)?;
+                        item.scrape_job_id = Some(job.id);
+                    }
+                }
+            }
+
+            diesel::insert_into(scrape_batch_items::table)
+                .values(&item)
+                .execute(conn)?;
+        }
+
+        Ok(batch)
+    })
+    .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// A batch item with its child job (if queued).
+pub type BatchItemRow = (ScrapeBatchItem, Option<ScrapeJob>);
+
+/// A batch's items in submission order, each with its child job (if queued).
+pub fn get_batch_items(
+    pool: &DbPool,
+    batch_id: Uuid,
+) -> Result<(ScrapeBatch, Vec<BatchItemRow>), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let batch: ScrapeBatch = scrape_batches::table
+        .find(batch_id)
+        .first(&mut conn)
+        .map_err(|e| match e {
+            diesel::NotFound => ScrapeError::BatchNotFound,
+            e => ScrapeError::Database(e.to_string()),
+        })?;
+
+    let items = scrape_batch_items::table
+        .left_join(scrape_jobs::table)
+        .filter(scrape_batch_items::batch_id.eq(batch_id))
+        .order(scrape_batch_items::position.asc())
+        .select((
+            ScrapeBatchItem::as_select(),
+            Option::<ScrapeJob>::as_select(),
+        ))
+        .load(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    Ok((batch, items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_text_list() {
+        let text = "https://example.com/a\n\n  # comment\nhttps://example.com/b  \r\n";
+        assert_eq!(
+            parse_url_list(text),
+            vec!["https://example.com/a", "https://example.com/b"]
+        );
+    }
+
+    #[test]
+    fn test_parse_netscape_bookmarks() {
+        let html = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><H3 ADD_DATE="1700000000">Recipes</H3>
+    <DL><p>
+        <DT><A HREF="https://example.com/soup" ADD_DATE="1700000000">Soup</A>
+        <DT><A HREF="place:sort=8&maxResults=10">Recent</A>
+        <DT><A HREF="http://example.com/bread">Bread</A>
+    </DL><p>
+</DL><p>
+"#;
+        assert_eq!(
+            parse_url_list(html),
+            vec!["https://example.com/soup", "http://example.com/bread"]
+        );
+    }
+
+    #[test]
+    fn test_normalize_url_drops_fragment_and_rejects_schemes() {
+        assert_eq!(
+            normalize_url("HTTPS://Example.com/soup#recipe").unwrap(),
+            "https://example.com/soup"
+        );
+        assert!(normalize_url("ftp://example.com/soup").is_err());
+        assert!(normalize_url("not a url").is_err());
+    }
+}
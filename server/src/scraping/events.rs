@@ -0,0 +1,124 @@
+//! In-process bus for scrape job progress events, streamed to clients over SSE.
+//!
+//! Events are only seen by subscribers in the process running the job; the SSE
+//! handlers fall back to the database for jobs finishing on another instance.
+
+use super::{STATUS_CANCELLED, STATUS_COMPLETED, STATUS_FAILED};
+use crate::models::ScrapeJob;
+use serde::Serialize;
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Events buffered per subscriber before it starts lagging
+const EVENT_BUFFER: usize = 1024;
+
+static EVENTS: LazyLock<broadcast::Sender<PublishedEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_BUFFER).0);
+
+/// Progress of a scrape job, sent as the data of a server-sent event whose
+/// event name matches `type`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScrapeJobEvent {
+    /// Current state of the job when the stream was opened
+    Status {
+        job_id: Uuid,
+        status: String,
+        current_step: Option<String>,
+    },
+    /// A step started running
+    StepStarted { job_id: Uuid, step: String },
+    /// A step finished
+    StepFinished {
+        job_id: Uuid,
+        step: String,
+        duration_ms: u64,
+        success: bool,
+        error: Option<String>,
+    },
+    /// The job saved its recipe
+    Completed { job_id: Uuid, recipe_id: Uuid },
+    /// The job failed
+    Failed {
+        job_id: Uuid,
+        failed_at_step: String,
+        error: String,
+    },
+    /// The job was cancelled
+    Cancelled { job_id: Uuid },
+}
+
+impl ScrapeJobEvent {
+    /// The event describing a job's current state: its final event once it has
+    /// finished, otherwise a status snapshot.
+    pub fn from_job(job: &ScrapeJob) -> Self {
+        match (job.status.as_str(), job.recipe_id) {
+            (STATUS_COMPLETED, Some(recipe_id)) => Self::Completed {
+                job_id: job.id,
+                recipe_id,
+            },
+            (STATUS_FAILED, _) => Self::Failed {
+                job_id: job.id,
+                failed_at_step: job.failed_at_step.clone().unwrap_or_default(),
+                error: job.error_message.clone().unwrap_or_default(),
+            },
+            (STATUS_CANCELLED, _) => Self::Cancelled { job_id: job.id },
+            _ => Self::Status {
+                job_id: job.id,
+                status: job.status.clone(),
+                current_step: job.current_step.clone(),
+            },
+        }
+    }
+
+    pub fn job_id(&self) -> Uuid {
+        match self {
+            Self::Status { job_id, .. }
+            | Self::StepStarted { job_id, .. }
+            | Self::StepFinished { job_id, .. }
+            | Self::Completed { job_id, .. }
+            | Self::Failed { job_id, .. }
+            | Self::Cancelled { job_id } => *job_id,
+        }
+    }
+
+    /// SSE event name (the serialized `type`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Status { .. } => "status",
+            Self::StepStarted { .. } => "step_started",
+            Self::StepFinished { .. } => "step_finished",
+            Self::Completed { .. } => "completed",
+            Self::Failed { .. } => "failed",
+            Self::Cancelled { .. } => "cancelled",
+        }
+    }
+
+    /// Whether this is the last event for the job
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed { .. } | Self::Failed { .. } | Self::Cancelled { .. }
+        )
+    }
+}
+
+/// An event with the user whose job it is, so streams can filter by user.
+#[derive(Debug, Clone)]
+pub struct PublishedEvent {
+    pub user_id: Uuid,
+    pub event: ScrapeJobEvent,
+}
+
+/// Publish an event to every subscriber in this process.
+pub fn publish(user_id: Uuid, event: ScrapeJobEvent) {
+    // Sending only fails when nobody is subscribed
+    let _ = EVENTS.send(PublishedEvent { user_id, event });
+}
+
+/// Subscribe to events for all jobs run by this process.
+pub fn subscribe() -> broadcast::Receiver<PublishedEvent> {
+    EVENTS.subscribe()
+}
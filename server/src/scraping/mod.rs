@@ -1,10 +1,14 @@
+pub mod batch;
+pub mod events;
 mod output_store;
+mod queue;
 pub mod steps;
+pub mod upstream;
 
 use crate::db::DbPool;
 use crate::models::{NewScrapeJob, NewStepOutput, ScrapeJob, StepOutput};
-use crate::schema::{photos, scrape_jobs, step_outputs, user_tags};
-use chrono::Utc;
+use crate::schema::{photos, recipe_versions, recipes, scrape_jobs, step_outputs, user_tags};
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use ramekin_core::ai::{AiClient, CachingAiClient};
 use ramekin_core::pipeline::steps::{
@@ -13,15 +17,21 @@ use ramekin_core::pipeline::steps::{
 };
 use ramekin_core::pipeline::{PipelineStep, StepContext, StepOutputStore, StepRegistry};
 use ramekin_core::{
-    ExtractRecipeOutput, ExtractionMethod, FetchHtmlOutput, FetchImagesOutput, RawRecipe, BUILD_ID,
+    assess_recipe, ExtractRecipeOutput, ExtractionMethod, ExtractionQuality, FetchHtmlOutput,
+    FetchImagesOutput, RawRecipe, BUILD_ID,
 };
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::sync::Arc;
 use thiserror::Error;
 use tracing::Instrument;
 use uuid::Uuid;
 
+use events::ScrapeJobEvent;
 use output_store::DbOutputStore;
+use queue::abort_running_job;
+pub(crate) use queue::env_number;
+pub use queue::{start_workers, wake_workers, WorkerConfig};
 use steps::{ApplyAutoTagsStep, FetchHtmlStep, FetchImagesStep, SaveRecipeStep};
 
 #[derive(Error, Debug)]
@@ -38,6 +48,15 @@ pub enum ScrapeError {
     #[error("Job not found")]
     JobNotFound,
 
+    #[error("Batch not found")]
+    BatchNotFound,
+
+    #[error("Upstream update not found")]
+    UpstreamUpdateNotFound,
+
+    #[error("Job was cancelled")]
+    Cancelled,
+
     #[error("Invalid job state: {0}")]
     InvalidState(String),
 
@@ -50,6 +69,9 @@ pub enum ScrapeError {
     #[error("Max retries exceeded")]
     MaxRetriesExceeded,
 
+    #[error("HTTP client error: {0}")]
+    HttpClient(String),
+
     #[error("AI configuration error: {0}")]
     AiConfig(#[from] ramekin_core::ai::AiError),
 }
@@ -60,6 +82,62 @@ pub const STATUS_SCRAPING: &str = "scraping";
 pub const STATUS_PARSING: &str = "parsing";
 pub const STATUS_COMPLETED: &str = "completed";
 pub const STATUS_FAILED: &str = "failed";
+pub const STATUS_CANCELLED: &str = "cancelled";
+/// The page had more than one recipe; waiting for the user to choose which to import
+pub const STATUS_AWAITING_SELECTION: &str = "awaiting_selection";
+
+/// Statuses a job never leaves (other than by retrying a failed job)
+const FINISHED_STATUSES: [&str; 3] = [STATUS_COMPLETED, STATUS_FAILED, STATUS_CANCELLED];
+
+/// Whether a job with this status has finished running.
+pub fn is_finished(status: &str) -> bool {
+    FINISHED_STATUSES.contains(&status)
+}
+
+/// Version source of a version saved by re-importing a changed Paprika recipe
+pub const PAPRIKA_VERSION_SOURCE: &str = "paprika";
+
+/// Step name for the vision extraction that runs before a photo import's pipeline
+const PHOTO_EXTRACT_STEP: &str = "photo_extract";
+
+/// Step name for the AI extraction that runs before a document import's pipeline
+const DOCUMENT_EXTRACT_STEP: &str = "document_extract";
+
+/// Step output holding a photo import's input, so a reclaimed job can rerun extraction
+const PHOTO_IMPORT_INPUT_STEP: &str = "photo_import_input";
+
+/// Step output holding a document import's text, so a reclaimed job can rerun extraction
+const DOCUMENT_IMPORT_INPUT_STEP: &str = "document_import_input";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PhotoImportInput {
+    photo_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DocumentImportInput {
+    text: String,
+}
+
+/// Step output holding which of a page's recipes to import
+const RECIPE_SELECTION_STEP: &str = "recipe_selection";
+
+/// Step output recording the jobs split off for the other selected recipes on a page
+const SPLIT_RECIPES_STEP: &str = "split_recipes";
+
+/// Output of the photo_extract and split_recipes steps: import jobs created for
+/// the other recipes when a batch of photos or a page contains more than one.
+#[derive(Debug, Serialize, Deserialize)]
+struct SplitJobsOutput {
+    split_job_ids: Vec<Uuid>,
+}
+
+/// Which of a page's recipes to import, by index into the extracted recipes.
+/// `None` imports all of them.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecipeSelection {
+    recipe_indices: Option<Vec<usize>>,
+}
 
 /// Maximum retries before hard fail
 const MAX_RETRIES: i32 = 5;
@@ -115,23 +193,28 @@ fn fetch_user_tags(pool: &DbPool, user_id: Uuid) -> Result<Vec<String>, ScrapeEr
 /// Build a step registry for server-side pipeline execution.
 ///
 /// This creates all step implementations with the necessary resources (DB pool, user ID).
-/// If `existing_recipe_id` is provided, SaveRecipeStep will update that recipe instead of
-/// creating a new one (for rescrape functionality).
-pub fn build_registry(
-    pool: Arc<DbPool>,
-    user_id: Uuid,
-    existing_recipe_id: Option<Uuid>,
-) -> Result<StepRegistry, ScrapeError> {
+/// If the job already has a `recipe_id`, SaveRecipeStep will update that recipe instead of
+/// creating a new one (for rescrape functionality). Jobs that are part of a batch import
+/// fetch their page, and any print or AMP version of it, through a per-host rate limiter.
+pub fn build_registry(pool: Arc<DbPool>, job: &ScrapeJob) -> Result<StepRegistry, ScrapeError> {
+    let user_id = job.user_id;
     let mut registry = StepRegistry::new();
-    registry.register(Box::new(FetchHtmlStep));
-    registry.register(Box::new(ExtractRecipeStep));
+    let batch = job.batch_id.is_some();
+    let fetch_step = if batch {
+        FetchHtmlStep::for_batch()
+    } else {
+        FetchHtmlStep::new()
+    };
+    registry.register(Box::new(fetch_step));
+    let page_client = steps::page_client(batch).map_err(ScrapeError::HttpClient)?;
+    registry.register(Box::new(ExtractRecipeStep::new(page_client)));
     registry.register(Box::new(FetchImagesStep::new(pool.clone(), user_id)));
     registry.register(Box::new(ParseIngredientsStep));
 
     // Use the appropriate SaveRecipeStep based on whether this is a rescrape
-    let save_step = match existing_recipe_id {
-        Some(recipe_id) => SaveRecipeStep::for_rescrape(pool.clone(), user_id, recipe_id),
-        None => SaveRecipeStep::new(pool.clone(), user_id),
+    let save_step = match job.recipe_id {
+        Some(recipe_id) => SaveRecipeStep::for_rescrape(pool.clone(), user_id, job.id, recipe_id),
+        None => SaveRecipeStep::new(pool.clone(), user_id, job.id),
     };
     registry.register(Box::new(save_step));
 
@@ -150,23 +233,49 @@ pub fn build_registry(
     Ok(registry)
 }
 
-/// Create a new scrape job.
-pub fn create_job(pool: &DbPool, user_id: Uuid, url: &str) -> Result<ScrapeJob, ScrapeError> {
+/// Create a new scrape job. With `import_all`, every recipe on the page is
+/// imported instead of asking which to import when there's more than one.
+pub fn create_job(
+    pool: &DbPool,
+    user_id: Uuid,
+    url: &str,
+    import_all: bool,
+) -> Result<ScrapeJob, ScrapeError> {
     let mut conn = pool
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
+    // An import_all job is queued once its selection is stored
     let new_job = NewScrapeJob {
         user_id,
         url: Some(url),
+        queued_at: (!import_all).then(Utc::now),
     };
 
-    diesel::insert_into(scrape_jobs::table)
+    let job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
         .values(&new_job)
+        .get_result(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    if !import_all {
+        return Ok(job);
+    }
+    select_all_recipes(pool, job.id)?;
+    diesel::update(scrape_jobs::table.find(job.id))
+        .set(scrape_jobs::queued_at.eq(Some(Utc::now())))
         .get_result::<ScrapeJob>(&mut conn)
         .map_err(|e| ScrapeError::Database(e.to_string()))
 }
 
+/// Store a selection importing every recipe the job finds.
+fn select_all_recipes(pool: &DbPool, job_id: Uuid) -> Result<(), ScrapeError> {
+    let selection = serde_json::to_value(RecipeSelection {
+        recipe_indices: None,
+    })
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    save_step_output(pool, job_id, RECIPE_SELECTION_STEP, selection)
+}
+
 /// Create a rescrape job for an existing recipe.
 /// This pre-populates recipe_id so save_recipe knows to update instead of create.
 pub fn create_rescrape_job(
@@ -184,6 +293,7 @@ pub fn create_rescrape_job(
             scrape_jobs::user_id.eq(user_id),
             scrape_jobs::url.eq(url),
             scrape_jobs::recipe_id.eq(Some(recipe_id)),
+            scrape_jobs::queued_at.eq(Some(Utc::now())),
         ))
         .get_result::<ScrapeJob>(&mut conn)
         .map_err(|e| ScrapeError::Database(e.to_string()))
@@ -197,15 +307,17 @@ pub fn create_job_with_html(
     user_id: Uuid,
     url: &str,
     html: &str,
+    import_all: bool,
 ) -> Result<ScrapeJob, ScrapeError> {
     let mut conn = pool
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    // Create the job
+    // Create the job (queued once the HTML is stored)
     let new_job = NewScrapeJob {
         user_id,
         url: Some(url),
+        queued_at: None,
     };
     let job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
         .values(&new_job)
@@ -219,12 +331,16 @@ pub fn create_job_with_html(
     let output_json =
         serde_json::to_value(&fetch_output).map_err(|e| ScrapeError::Database(e.to_string()))?;
     save_step_output(pool, job.id, FetchHtmlStep::NAME, output_json)?;
+    if import_all {
+        select_all_recipes(pool, job.id)?;
+    }
 
     // Update the job to start from parsing (skip fetch step)
     diesel::update(scrape_jobs::table.find(job.id))
         .set((
             scrape_jobs::status.eq(STATUS_PARSING),
             scrape_jobs::current_step.eq(Some(ExtractRecipeStep::NAME)),
+            scrape_jobs::queued_at.eq(Some(Utc::now())),
             scrape_jobs::updated_at.eq(Utc::now()),
         ))
         .execute(&mut conn)
@@ -243,30 +359,51 @@ pub fn create_import_job(
     raw_recipe: &RawRecipe,
     extraction_method: ExtractionMethod,
     photo_ids: Vec<Uuid>,
+    existing_recipe_id: Option<Uuid>,
 ) -> Result<ScrapeJob, ScrapeError> {
     let mut conn = pool
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    // Create the job (url is optional for imports)
-    let new_job = NewScrapeJob {
-        user_id,
-        url: source_url,
-    };
+    // Create the job (url is optional for imports).
+    // A pre-populated recipe_id makes save_recipe add a version instead of a new recipe.
     let job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
-        .values(&new_job)
+        .values((
+            scrape_jobs::user_id.eq(user_id),
+            scrape_jobs::url.eq(source_url),
+            scrape_jobs::recipe_id.eq(existing_recipe_id),
+        ))
         .get_result(&mut conn)
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
+    prepopulate_extracted_recipe(pool, job.id, raw_recipe, extraction_method, photo_ids)?;
+
+    // Return the updated job
+    get_job(pool, job.id)
+}
+
+/// Store extract_recipe and fetch_images outputs for a recipe that was extracted
+/// outside the pipeline (imports, photos, documents) and point the job at
+/// parse_ingredients so the pipeline picks up from there. This also queues the job.
+fn prepopulate_extracted_recipe(
+    pool: &DbPool,
+    job_id: Uuid,
+    raw_recipe: &RawRecipe,
+    extraction_method: ExtractionMethod,
+    photo_ids: Vec<Uuid>,
+) -> Result<(), ScrapeError> {
     // Store the extract_recipe step output
     let extract_output = ExtractRecipeOutput {
         raw_recipe: raw_recipe.clone(),
         method_used: extraction_method,
         all_attempts: vec![],
+        other_recipes: vec![],
+        alternate: None,
+        quality: None,
     };
     let extract_json =
         serde_json::to_value(&extract_output).map_err(|e| ScrapeError::Database(e.to_string()))?;
-    save_step_output(pool, job.id, ExtractRecipeStep::NAME, extract_json)?;
+    save_step_output(pool, job_id, ExtractRecipeStep::NAME, extract_json)?;
 
     // Store the fetch_images step output (photos already uploaded)
     let images_output = FetchImagesOutput {
@@ -275,94 +412,218 @@ pub fn create_import_job(
     };
     let images_json =
         serde_json::to_value(&images_output).map_err(|e| ScrapeError::Database(e.to_string()))?;
-    save_step_output(pool, job.id, FetchImagesStepMeta::NAME, images_json)?;
+    save_step_output(pool, job_id, FetchImagesStepMeta::NAME, images_json)?;
 
-    // Update the job to start from parse_ingredients (skip fetch_html, extract_recipe, fetch_images)
+    // Start from parse_ingredients (skip fetch_html, extract_recipe, fetch_images)
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    let updated = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne(STATUS_CANCELLED)),
+    )
+    .set((
+        scrape_jobs::status.eq(STATUS_PARSING),
+        scrape_jobs::current_step.eq(Some(ParseIngredientsStep::NAME)),
+        scrape_jobs::queued_at.eq(Some(Utc::now())),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .execute(&mut conn)
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    if updated == 0 {
+        return Err(ScrapeError::Cancelled);
+    }
+    Ok(())
+}
+
+/// Create a pending import job whose recipe is extracted in the background
+/// (photo and document imports). The extraction input is stored as a step
+/// output so a reclaimed job can run the extraction again.
+fn create_pending_import_job(
+    pool: &DbPool,
+    user_id: Uuid,
+    extract_step: &str,
+    input_step: &str,
+    input: serde_json::Value,
+) -> Result<ScrapeJob, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let new_job = NewScrapeJob {
+        user_id,
+        url: None,
+        queued_at: None,
+    };
+
+    let job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
+        .values(&new_job)
+        .get_result(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    save_step_output(pool, job.id, input_step, input)?;
+
+    // Queue the job at the extraction step now that its input is stored
     diesel::update(scrape_jobs::table.find(job.id))
         .set((
-            scrape_jobs::status.eq(STATUS_PARSING),
-            scrape_jobs::current_step.eq(Some(ParseIngredientsStep::NAME)),
+            scrape_jobs::current_step.eq(Some(extract_step)),
+            scrape_jobs::queued_at.eq(Some(Utc::now())),
             scrape_jobs::updated_at.eq(Utc::now()),
         ))
         .execute(&mut conn)
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    // Return the updated job
     get_job(pool, job.id)
 }
 
-/// Spawn an import job (same as scrape job, but no URL for tracing).
-pub fn spawn_import_job(pool: Arc<DbPool>, job_id: Uuid) {
-    let span = tracing::info_span!(
-        "import_job",
-        otel.name = "import_job",
-        job.id = %job_id,
-        job.operation = "import",
-        job.status = tracing::field::Empty,
-        job.error = tracing::field::Empty,
-    );
-
-    tokio::spawn(
-        async move {
-            run_scrape_job(pool, job_id).await;
-        }
-        .instrument(span),
-    );
+/// Create a queued photo import job that extracts recipes from the given photos.
+pub fn create_photo_import_job(
+    pool: &DbPool,
+    user_id: Uuid,
+    photo_ids: Vec<Uuid>,
+) -> Result<ScrapeJob, ScrapeError> {
+    let input = serde_json::to_value(PhotoImportInput { photo_ids })
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    create_pending_import_job(
+        pool,
+        user_id,
+        PHOTO_EXTRACT_STEP,
+        PHOTO_IMPORT_INPUT_STEP,
+        input,
+    )
 }
 
-/// Create a pending photo import job (no step pre-population yet).
-pub fn create_pending_photo_job(pool: &DbPool, user_id: Uuid) -> Result<ScrapeJob, ScrapeError> {
-    let mut conn = pool
-        .get()
+/// Create a queued document import job that extracts the recipe from document
+/// text with AI. Used when the plain-text heuristics couldn't find a recipe.
+pub fn create_document_import_job(
+    pool: &DbPool,
+    user_id: Uuid,
+    text: String,
+) -> Result<ScrapeJob, ScrapeError> {
+    let input = serde_json::to_value(DocumentImportInput { text })
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    create_pending_import_job(
+        pool,
+        user_id,
+        DOCUMENT_EXTRACT_STEP,
+        DOCUMENT_IMPORT_INPUT_STEP,
+        input,
+    )
+}
 
-    let new_job = NewScrapeJob { user_id, url: None };
+/// Build the OpenTelemetry span a worker runs a job under.
+fn job_span(job: &ScrapeJob) -> tracing::Span {
+    match (job.current_step.as_deref(), job.url.as_deref()) {
+        (Some(PHOTO_EXTRACT_STEP), _) => tracing::info_span!(
+            "photo_import_job",
+            otel.name = "photo_import_job",
+            job.id = %job.id,
+            job.operation = "photo_import",
+            job.status = tracing::field::Empty,
+            job.error = tracing::field::Empty,
+        ),
+        (Some(DOCUMENT_EXTRACT_STEP), _) => tracing::info_span!(
+            "document_import_job",
+            otel.name = "document_import_job",
+            job.id = %job.id,
+            job.operation = "document_import",
+            job.status = tracing::field::Empty,
+            job.error = tracing::field::Empty,
+        ),
+        (_, Some(url)) => {
+            let domain = reqwest::Url::parse(url)
+                .ok()
+                .and_then(|u| u.host_str().map(String::from))
+                .unwrap_or_else(|| "unknown".to_string());
+            let operation = if job.retry_count > 0 {
+                "retry"
+            } else if job.recipe_id.is_some() {
+                "rescrape"
+            } else {
+                "scrape"
+            };
+            tracing::info_span!(
+                "scrape_job",
+                otel.name = %format!("scrape_job {}", operation),
+                job.id = %job.id,
+                job.operation = %operation,
+                url.full = %url,
+                url.domain = %domain,
+                job.status = tracing::field::Empty,
+                job.error = tracing::field::Empty,
+            )
+        }
+        (_, None) => tracing::info_span!(
+            "import_job",
+            otel.name = "import_job",
+            job.id = %job.id,
+            job.operation = "import",
+            job.status = tracing::field::Empty,
+            job.error = tracing::field::Empty,
+        ),
+    }
+}
 
-    diesel::insert_into(scrape_jobs::table)
-        .values(&new_job)
-        .get_result::<ScrapeJob>(&mut conn)
-        .map_err(|e| ScrapeError::Database(e.to_string()))
+/// Run a claimed job from wherever it left off. Photo and document imports
+/// start with their extraction step; everything else is the step pipeline.
+async fn run_job(pool: Arc<DbPool>, job: ScrapeJob) {
+    match job.current_step.as_deref() {
+        Some(PHOTO_EXTRACT_STEP) => run_photo_import_job(pool, job.id, job.user_id).await,
+        Some(DOCUMENT_EXTRACT_STEP) => run_document_import_job(pool, job.id, job.user_id).await,
+        _ => run_scrape_job(pool, job.id).await,
+    }
 }
 
-/// Spawn a photo import job with proper OpenTelemetry context propagation.
-pub fn spawn_photo_import_job(
-    pool: Arc<DbPool>,
+/// Load the input stored for a pending import job by create_pending_import_job.
+fn load_import_input<T: serde::de::DeserializeOwned>(
+    pool: &DbPool,
     job_id: Uuid,
-    user_id: Uuid,
-    photo_ids: Vec<Uuid>,
-) {
-    let span = tracing::info_span!(
-        "photo_import_job",
-        otel.name = "photo_import_job",
-        job.id = %job_id,
-        job.operation = "photo_import",
-        job.status = tracing::field::Empty,
-        job.error = tracing::field::Empty,
-        photos.count = photo_ids.len(),
-    );
-
-    tokio::spawn(
-        async move {
-            run_photo_import_job(pool, job_id, user_id, photo_ids).await;
-        }
-        .instrument(span),
-    );
+    input_step: &str,
+) -> Result<T, ScrapeError> {
+    let output = get_latest_step_output(pool, job_id, input_step)?.ok_or_else(|| {
+        ScrapeError::InvalidState(format!("Missing {} output for import job", input_step))
+    })?;
+    serde_json::from_value(output.output).map_err(|e| ScrapeError::Database(e.to_string()))
 }
 
 /// Run a photo import job: extract recipe from photos, then run pipeline.
-async fn run_photo_import_job(
-    pool: Arc<DbPool>,
-    job_id: Uuid,
-    user_id: Uuid,
-    photo_ids: Vec<Uuid>,
-) {
+async fn run_photo_import_job(pool: Arc<DbPool>, job_id: Uuid, user_id: Uuid) {
     // Update status to "scraping" (extraction phase)
-    if let Err(e) = update_status_and_step(&pool, job_id, STATUS_SCRAPING, Some("photo_extract")) {
+    if let Err(e) = update_status_and_step(&pool, job_id, STATUS_SCRAPING, Some(PHOTO_EXTRACT_STEP))
+    {
         tracing::error!("Failed to update job status: {}", e);
         let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
         return;
     }
 
+    events::publish(
+        user_id,
+        ScrapeJobEvent::StepStarted {
+            job_id,
+            step: PHOTO_EXTRACT_STEP.to_string(),
+        },
+    );
+
+    let photo_ids =
+        match load_import_input::<PhotoImportInput>(&pool, job_id, PHOTO_IMPORT_INPUT_STEP) {
+            Ok(input) => input.photo_ids,
+            Err(e) => {
+                let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
+                return;
+            }
+        };
+
+    // A reclaimed job may already have split off the other recipes
+    let previous_split = match get_latest_step_output(&pool, job_id, PHOTO_EXTRACT_STEP) {
+        Ok(output) => output.and_then(|o| serde_json::from_value::<SplitJobsOutput>(o.output).ok()),
+        Err(e) => {
+            let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
+            return;
+        }
+    };
+
     // Step 1: Fetch photo bytes from database
     let images = match fetch_photo_images(&pool, user_id, &photo_ids) {
         Ok(imgs) => imgs,
@@ -384,7 +645,7 @@ async fn run_photo_import_job(
         };
 
     let extract_result =
-        match ramekin_core::ai::extract_recipe_from_photos(ai_client.as_ref(), images).await {
+        match ramekin_core::ai::extract_recipes_from_photos(ai_client.as_ref(), images).await {
             Ok(r) => r,
             Err(e) => {
                 tracing::error!("Photo extraction failed: {}", e);
@@ -394,70 +655,400 @@ async fn run_photo_import_job(
         };
 
     tracing::info!(
-        "Extracted recipe '{}' from photos (cached={})",
-        extract_result.raw_recipe.title,
+        "Extracted {} recipe(s) from {} photos (cached={})",
+        extract_result.recipes.len(),
+        photo_ids.len(),
         extract_result.cached
     );
 
-    // Step 3: Pre-populate step outputs and continue pipeline
-    // Store extract_recipe output
-    let extract_output = ExtractRecipeOutput {
-        raw_recipe: extract_result.raw_recipe.clone(),
-        method_used: ExtractionMethod::PhotoUpload,
-        all_attempts: vec![],
+    // Photos for each recipe, in page order
+    let recipe_photo_ids = |recipe: &ramekin_core::ai::ExtractedPhotoRecipe| -> Vec<Uuid> {
+        recipe
+            .photo_indices
+            .iter()
+            .filter_map(|&i| photo_ids.get(i).copied())
+            .collect()
     };
-    let extract_json = match serde_json::to_value(&extract_output) {
-        Ok(j) => j,
-        Err(e) => {
-            let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
-            return;
-        }
+
+    let mut recipes = extract_result.recipes.into_iter();
+    let Some(first_recipe) = recipes.next() else {
+        let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, "No recipe found in photos");
+        return;
     };
-    if let Err(e) = save_step_output(&pool, job_id, ExtractRecipeStep::NAME, extract_json) {
+
+    // Step 3: Every other recipe in the batch gets its own import job
+    if previous_split.is_some() {
+        tracing::info!("Job {} already split its other recipes, skipping", job_id);
+    }
+    let mut split_job_ids: Vec<Uuid> = Vec::new();
+    for recipe in recipes.filter(|_| previous_split.is_none()) {
+        match create_import_job(
+            &pool,
+            user_id,
+            None,
+            &recipe.raw_recipe,
+            ExtractionMethod::PhotoUpload,
+            recipe_photo_ids(&recipe),
+            None,
+        ) {
+            Ok(split_job) => {
+                tracing::info!(
+                    "Split recipe '{}' into import job {}",
+                    recipe.raw_recipe.title,
+                    split_job.id
+                );
+                split_job_ids.push(split_job.id);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to create import job for '{}': {}",
+                    recipe.raw_recipe.title,
+                    e
+                );
+            }
+        }
+    }
+
+    // Record the split so the original job can point clients at the others
+    if previous_split.is_none() {
+        let photo_extract_output = SplitJobsOutput { split_job_ids };
+        match serde_json::to_value(&photo_extract_output) {
+            Ok(json) => {
+                if let Err(e) = save_step_output(&pool, job_id, PHOTO_EXTRACT_STEP, json) {
+                    tracing::warn!("Failed to save photo_extract output: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to serialize photo_extract output: {}", e),
+        }
+        wake_workers();
+    }
+
+    // Step 4: The first recipe continues in this job
+    if let Err(e) = prepopulate_extracted_recipe(
+        &pool,
+        job_id,
+        &first_recipe.raw_recipe,
+        ExtractionMethod::PhotoUpload,
+        recipe_photo_ids(&first_recipe),
+    ) {
         let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
         return;
     }
 
-    // Store fetch_images output (photos already uploaded)
-    let images_output = FetchImagesOutput {
-        photo_ids: photo_ids.clone(),
-        failed_urls: vec![],
+    // Step 5: Run the rest of the pipeline
+    run_scrape_job(pool, job_id).await;
+}
+
+/// Jobs created for the other recipes found by a photo import or on a scraped page.
+pub fn get_split_job_ids(pool: &DbPool, job_id: Uuid) -> Result<Vec<Uuid>, ScrapeError> {
+    let output = match get_latest_step_output(pool, job_id, PHOTO_EXTRACT_STEP)? {
+        Some(output) => output,
+        None => match get_latest_step_output(pool, job_id, SPLIT_RECIPES_STEP)? {
+            Some(output) => output,
+            None => return Ok(vec![]),
+        },
     };
-    let images_json = match serde_json::to_value(&images_output) {
-        Ok(j) => j,
-        Err(e) => {
-            let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
-            return;
+    let split_output: SplitJobsOutput =
+        serde_json::from_value(output.output).map_err(|e| ScrapeError::Database(e.to_string()))?;
+    Ok(split_output.split_job_ids)
+}
+
+/// Titles of the recipes a job found on its page, when there was more than one
+/// to choose from. Indices into this list are what `select_recipes` takes.
+pub fn get_recipe_candidates(pool: &DbPool, job_id: Uuid) -> Result<Vec<String>, ScrapeError> {
+    let Some(output) = get_latest_step_output(pool, job_id, ExtractRecipeStep::NAME)? else {
+        return Ok(vec![]);
+    };
+    let Ok(extract_output) = serde_json::from_value::<ExtractRecipeOutput>(output.output) else {
+        // A failed extraction saves a null output
+        return Ok(vec![]);
+    };
+    if extract_output.other_recipes.is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(std::iter::once(extract_output.raw_recipe)
+        .chain(extract_output.other_recipes)
+        .map(|recipe| recipe.title)
+        .collect())
+}
+
+/// Choose which of the recipes found on a job's page to import, and queue the
+/// job again. The first chosen recipe continues in this job; each of the others
+/// gets a job of its own.
+pub fn select_recipes(
+    pool: &DbPool,
+    job_id: Uuid,
+    recipe_indices: Vec<usize>,
+) -> Result<ScrapeJob, ScrapeError> {
+    let job = get_job(pool, job_id)?;
+    if job.status != STATUS_AWAITING_SELECTION {
+        return Err(ScrapeError::InvalidState(format!(
+            "Cannot select recipes for job in status: {}",
+            job.status
+        )));
+    }
+
+    let candidates = get_recipe_candidates(pool, job_id)?;
+    let mut selected: Vec<usize> = Vec::new();
+    for index in recipe_indices {
+        if index >= candidates.len() {
+            return Err(ScrapeError::InvalidState(format!(
+                "Recipe index {} out of range (page has {} recipes)",
+                index,
+                candidates.len()
+            )));
         }
+        if !selected.contains(&index) {
+            selected.push(index);
+        }
+    }
+    if selected.is_empty() {
+        return Err(ScrapeError::InvalidState(
+            "Select at least one recipe".to_string(),
+        ));
+    }
+
+    let selection = serde_json::to_value(RecipeSelection {
+        recipe_indices: Some(selected),
+    })
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    save_step_output(pool, job_id, RECIPE_SELECTION_STEP, selection)?;
+
+    // Extraction runs again and picks up the selection
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.eq(STATUS_AWAITING_SELECTION)),
+    )
+    .set((
+        scrape_jobs::status.eq(STATUS_PARSING),
+        scrape_jobs::current_step.eq(Some(ExtractRecipeStep::NAME)),
+        scrape_jobs::queued_at.eq(Some(Utc::now())),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .get_result::<ScrapeJob>(&mut conn)
+    .optional()
+    .map_err(|e| ScrapeError::Database(e.to_string()))?
+    .ok_or_else(|| ScrapeError::InvalidState("Job is no longer awaiting selection".to_string()))
+}
+
+/// Narrow a successful extract_recipe output that found several recipes down to
+/// the one this job imports, splitting the other selected recipes into jobs of
+/// their own. Returns false when the user hasn't chosen yet.
+///
+/// Rescrapes keep the recipe they were for and batch imports keep the first,
+/// since nobody is there to choose.
+fn apply_recipe_selection(
+    pool: &DbPool,
+    job: &ScrapeJob,
+    output: &mut serde_json::Value,
+) -> Result<bool, ScrapeError> {
+    let mut extract_output: ExtractRecipeOutput =
+        serde_json::from_value(output.clone()).map_err(|e| ScrapeError::Database(e.to_string()))?;
+    if extract_output.other_recipes.is_empty() {
+        return Ok(true);
+    }
+    let mut recipes: Vec<RawRecipe> = std::iter::once(extract_output.raw_recipe.clone())
+        .chain(std::mem::take(&mut extract_output.other_recipes))
+        .collect();
+
+    let selected: Vec<usize> = if let Some(recipe_id) = job.recipe_id {
+        let title = get_recipe_title(pool, recipe_id)?;
+        let index = recipes
+            .iter()
+            .position(|r| Some(&r.title) == title.as_ref())
+            .unwrap_or(0);
+        vec![index]
+    } else if job.batch_id.is_some() {
+        vec![0]
+    } else {
+        let selection = get_latest_step_output(pool, job.id, RECIPE_SELECTION_STEP)?
+            .and_then(|o| serde_json::from_value::<RecipeSelection>(o.output).ok());
+        match selection {
+            Some(RecipeSelection {
+                recipe_indices: Some(indices),
+            }) => indices.into_iter().filter(|&i| i < recipes.len()).collect(),
+            Some(RecipeSelection {
+                recipe_indices: None,
+            }) => (0..recipes.len()).collect(),
+            None => vec![],
+        }
+    };
+    let Some((&first, others)) = selected.split_first() else {
+        return Ok(false);
+    };
+
+    // A reclaimed job may already have split off the other recipes
+    let previous_split = get_latest_step_output(pool, job.id, SPLIT_RECIPES_STEP)?;
+    if !others.is_empty() && previous_split.is_none() {
+        let mut split_job_ids: Vec<Uuid> = Vec::new();
+        for &index in others {
+            let recipe = &recipes[index];
+            match create_split_job(pool, job, recipe, extract_output.method_used) {
+                Ok(split_job) => {
+                    tracing::info!(
+                        "Split recipe '{}' into scrape job {}",
+                        recipe.title,
+                        split_job.id
+                    );
+                    split_job_ids.push(split_job.id);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to create scrape job for '{}': {}", recipe.title, e);
+                }
+            }
+        }
+        let split_json = serde_json::to_value(SplitJobsOutput { split_job_ids })
+            .map_err(|e| ScrapeError::Database(e.to_string()))?;
+        save_step_output(pool, job.id, SPLIT_RECIPES_STEP, split_json)?;
+        wake_workers();
+    }
+
+    extract_output.raw_recipe = recipes.swap_remove(first);
+    extract_output.quality = Some(assess_recipe(
+        &extract_output.raw_recipe,
+        extract_output.method_used,
+    ));
+    *output =
+        serde_json::to_value(&extract_output).map_err(|e| ScrapeError::Database(e.to_string()))?;
+    Ok(true)
+}
+
+/// Title of a recipe's current version.
+fn get_recipe_title(pool: &DbPool, recipe_id: Uuid) -> Result<Option<String>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::id.eq(recipe_id))
+        .select(recipe_versions::title)
+        .first::<String>(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Create a job for another recipe selected from a page, starting after
+/// extraction so it still fetches its own images.
+fn create_split_job(
+    pool: &DbPool,
+    job: &ScrapeJob,
+    raw_recipe: &RawRecipe,
+    method_used: ExtractionMethod,
+) -> Result<ScrapeJob, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let new_job = NewScrapeJob {
+        user_id: job.user_id,
+        url: job.url.as_deref(),
+        queued_at: None,
     };
-    if let Err(e) = save_step_output(&pool, job_id, FetchImagesStepMeta::NAME, images_json) {
+    let split_job: ScrapeJob = diesel::insert_into(scrape_jobs::table)
+        .values(&new_job)
+        .get_result(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let extract_output = ExtractRecipeOutput {
+        raw_recipe: raw_recipe.clone(),
+        method_used,
+        all_attempts: vec![],
+        other_recipes: vec![],
+        alternate: None,
+        quality: Some(assess_recipe(raw_recipe, method_used)),
+    };
+    let extract_json =
+        serde_json::to_value(&extract_output).map_err(|e| ScrapeError::Database(e.to_string()))?;
+    save_step_output(pool, split_job.id, ExtractRecipeStep::NAME, extract_json)?;
+    save_extraction_quality(pool, split_job.id, extract_output.quality.as_ref())?;
+
+    diesel::update(scrape_jobs::table.find(split_job.id))
+        .set((
+            scrape_jobs::status.eq(STATUS_PARSING),
+            scrape_jobs::current_step.eq(Some(FetchImagesStepMeta::NAME)),
+            scrape_jobs::queued_at.eq(Some(Utc::now())),
+            scrape_jobs::updated_at.eq(Utc::now()),
+        ))
+        .get_result(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Run a document import job: extract recipe from the text with AI, then run pipeline.
+async fn run_document_import_job(pool: Arc<DbPool>, job_id: Uuid, user_id: Uuid) {
+    // Update status to "scraping" (extraction phase)
+    if let Err(e) =
+        update_status_and_step(&pool, job_id, STATUS_SCRAPING, Some(DOCUMENT_EXTRACT_STEP))
+    {
+        tracing::error!("Failed to update job status: {}", e);
         let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
         return;
     }
 
-    // Update job to start from parse_ingredients
-    {
-        let mut conn = match pool.get() {
-            Ok(c) => c,
+    events::publish(
+        user_id,
+        ScrapeJobEvent::StepStarted {
+            job_id,
+            step: DOCUMENT_EXTRACT_STEP.to_string(),
+        },
+    );
+
+    let text =
+        match load_import_input::<DocumentImportInput>(&pool, job_id, DOCUMENT_IMPORT_INPUT_STEP) {
+            Ok(input) => input.text,
             Err(e) => {
                 let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
                 return;
             }
         };
-        if let Err(e) = diesel::update(scrape_jobs::table.find(job_id))
-            .set((
-                scrape_jobs::status.eq(STATUS_PARSING),
-                scrape_jobs::current_step.eq(Some(ParseIngredientsStep::NAME)),
-                scrape_jobs::updated_at.eq(Utc::now()),
-            ))
-            .execute(&mut conn)
-        {
+
+    let ai_client: Arc<dyn AiClient> = match CachingAiClient::from_env() {
+        Ok(c) => Arc::new(c),
+        Err(e) => {
+            let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
+            return;
+        }
+    };
+
+    let extract_result = match ramekin_core::ai::extract_recipe_from_document_text(
+        ai_client.as_ref(),
+        &text,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::error!("Document extraction failed: {}", e);
             let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
             return;
         }
+    };
+
+    tracing::info!(
+        "Extracted recipe '{}' from document (cached={})",
+        extract_result.raw_recipe.title,
+        extract_result.cached
+    );
+
+    if let Err(e) = prepopulate_extracted_recipe(
+        &pool,
+        job_id,
+        &extract_result.raw_recipe,
+        ExtractionMethod::DocumentAi,
+        vec![],
+    ) {
+        let _ = mark_failed(&pool, job_id, STATUS_SCRAPING, &e.to_string());
+        return;
     }
 
-    // Step 4: Run the rest of the pipeline
     run_scrape_job(pool, job_id).await;
 }
 
@@ -517,7 +1108,22 @@ pub fn get_job(pool: &DbPool, job_id: Uuid) -> Result<ScrapeJob, ScrapeError> {
         .ok_or(ScrapeError::JobNotFound)
 }
 
-/// Update job status and current_step.
+/// Get a user's unfinished scrape jobs, oldest first.
+pub fn get_active_jobs(pool: &DbPool, user_id: Uuid) -> Result<Vec<ScrapeJob>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    scrape_jobs::table
+        .filter(scrape_jobs::user_id.eq(user_id))
+        .filter(scrape_jobs::status.ne_all(FINISHED_STATUSES))
+        .order(scrape_jobs::created_at.asc())
+        .load::<ScrapeJob>(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Update job status and current_step, failing with `Cancelled` if the job was
+/// cancelled so the pipeline stops before its next step.
 fn update_status_and_step(
     pool: &DbPool,
     job_id: Uuid,
@@ -528,15 +1134,22 @@ fn update_status_and_step(
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    diesel::update(scrape_jobs::table.find(job_id))
-        .set((
-            scrape_jobs::status.eq(status),
-            scrape_jobs::current_step.eq(current_step),
-            scrape_jobs::updated_at.eq(Utc::now()),
-        ))
-        .execute(&mut conn)
-        .map_err(|e| ScrapeError::Database(e.to_string()))?;
-
+    let updated = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne(STATUS_CANCELLED)),
+    )
+    .set((
+        scrape_jobs::status.eq(status),
+        scrape_jobs::current_step.eq(current_step),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .execute(&mut conn)
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    if updated == 0 {
+        return Err(ScrapeError::Cancelled);
+    }
     Ok(())
 }
 
@@ -585,73 +1198,135 @@ fn get_latest_step_output(
         .map_err(|e| ScrapeError::Database(e.to_string()))
 }
 
-/// Mark job as failed.
+/// Mark job as failed and notify event subscribers. A cancelled job stays cancelled.
 fn mark_failed(pool: &DbPool, job_id: Uuid, step: &str, error: &str) -> Result<(), ScrapeError> {
     let mut conn = pool
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    diesel::update(scrape_jobs::table.find(job_id))
-        .set((
-            scrape_jobs::status.eq(STATUS_FAILED),
-            scrape_jobs::failed_at_step.eq(Some(step)),
-            scrape_jobs::error_message.eq(Some(error)),
-            scrape_jobs::updated_at.eq(Utc::now()),
-        ))
-        .execute(&mut conn)
-        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    let user_id: Option<Uuid> = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne(STATUS_CANCELLED)),
+    )
+    .set((
+        scrape_jobs::status.eq(STATUS_FAILED),
+        scrape_jobs::failed_at_step.eq(Some(step)),
+        scrape_jobs::error_message.eq(Some(error)),
+        scrape_jobs::queued_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::claimed_by.eq::<Option<String>>(None),
+        scrape_jobs::heartbeat_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .returning(scrape_jobs::user_id)
+    .get_result(&mut conn)
+    .optional()
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let Some(user_id) = user_id else {
+        return Ok(());
+    };
+
+    events::publish(
+        user_id,
+        ScrapeJobEvent::Failed {
+            job_id,
+            failed_at_step: step.to_string(),
+            error: error.to_string(),
+        },
+    );
 
     Ok(())
 }
 
-/// Mark job as completed with recipe ID.
+/// Mark job as completed with recipe ID and notify event subscribers.
 fn mark_completed(pool: &DbPool, job_id: Uuid, recipe_id: Uuid) -> Result<(), ScrapeError> {
     let mut conn = pool
         .get()
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    diesel::update(scrape_jobs::table.find(job_id))
+    let user_id: Uuid = diesel::update(scrape_jobs::table.find(job_id))
         .set((
             scrape_jobs::status.eq(STATUS_COMPLETED),
             scrape_jobs::recipe_id.eq(Some(recipe_id)),
             scrape_jobs::current_step.eq::<Option<String>>(None),
+            scrape_jobs::queued_at.eq::<Option<DateTime<Utc>>>(None),
+            scrape_jobs::claimed_by.eq::<Option<String>>(None),
+            scrape_jobs::heartbeat_at.eq::<Option<DateTime<Utc>>>(None),
             scrape_jobs::updated_at.eq(Utc::now()),
         ))
+        .returning(scrape_jobs::user_id)
+        .get_result(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    events::publish(user_id, ScrapeJobEvent::Completed { job_id, recipe_id });
+
+    Ok(())
+}
+
+/// Record how far a job's extracted recipe can be trusted and what looked
+/// wrong with it, so the user knows to review the recipe.
+fn save_extraction_quality(
+    pool: &DbPool,
+    job_id: Uuid,
+    quality: Option<&ExtractionQuality>,
+) -> Result<(), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let warnings = quality.map(|q| q.warnings.as_slice()).unwrap_or_default();
+    let warnings_json =
+        serde_json::to_value(warnings).map_err(|e| ScrapeError::Database(e.to_string()))?;
+    diesel::update(scrape_jobs::table.find(job_id))
+        .set((
+            scrape_jobs::extraction_confidence.eq(quality.map(|q| q.confidence)),
+            scrape_jobs::quality_warnings.eq(warnings_json),
+        ))
         .execute(&mut conn)
         .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
     Ok(())
 }
 
-/// Spawn a scrape job with proper OpenTelemetry context propagation.
-///
-/// This creates a span that:
-/// - Links to the current (HTTP request) span as parent
-/// - Contains job metadata (job_id, url, operation type)
-/// - Wraps the entire job execution
-pub fn spawn_scrape_job(pool: Arc<DbPool>, job_id: Uuid, url: &str, operation: &str) {
-    let domain = reqwest::Url::parse(url)
-        .ok()
-        .and_then(|u| u.host_str().map(String::from))
-        .unwrap_or_else(|| "unknown".to_string());
+/// Park a job until the user chooses which of its page's recipes to import.
+fn mark_awaiting_selection(pool: &DbPool, job_id: Uuid) -> Result<(), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
 
-    let span = tracing::info_span!(
-        "scrape_job",
-        otel.name = %format!("scrape_job {}", operation),
-        job.id = %job_id,
-        job.operation = %operation,
-        url.full = %url,
-        url.domain = %domain,
-        job.status = tracing::field::Empty,
-        job.error = tracing::field::Empty,
-    );
+    let user_id: Option<Uuid> = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne(STATUS_CANCELLED)),
+    )
+    .set((
+        scrape_jobs::status.eq(STATUS_AWAITING_SELECTION),
+        scrape_jobs::current_step.eq::<Option<String>>(None),
+        scrape_jobs::queued_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::claimed_by.eq::<Option<String>>(None),
+        scrape_jobs::heartbeat_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .returning(scrape_jobs::user_id)
+    .get_result(&mut conn)
+    .optional()
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let Some(user_id) = user_id else {
+        return Err(ScrapeError::Cancelled);
+    };
 
-    tokio::spawn(
-        async move {
-            run_scrape_job(pool, job_id).await;
-        }
-        .instrument(span),
+    events::publish(
+        user_id,
+        ScrapeJobEvent::Status {
+            job_id,
+            status: STATUS_AWAITING_SELECTION.to_string(),
+            current_step: None,
+        },
     );
+
+    Ok(())
 }
 
 /// Run the scrape job state machine.
@@ -664,6 +1339,10 @@ pub async fn run_scrape_job(pool: Arc<DbPool>, job_id: Uuid) {
         Ok(()) => {
             current_span.record("job.status", "completed");
         }
+        Err(ScrapeError::Cancelled) => {
+            current_span.record("job.status", "cancelled");
+            tracing::info!("Scrape job {} was cancelled", job_id);
+        }
         Err(e) => {
             current_span.record("job.status", "failed");
             current_span.record("job.error", tracing::field::display(e));
@@ -676,7 +1355,7 @@ async fn run_scrape_job_inner(pool: Arc<DbPool>, job_id: Uuid) -> Result<(), Scr
     let job = get_job(&pool, job_id)?;
 
     // Terminal states - nothing to do
-    if job.status == STATUS_COMPLETED || job.status == STATUS_FAILED {
+    if is_finished(&job.status) {
         return Ok(());
     }
 
@@ -690,8 +1369,8 @@ async fn run_scrape_job_inner(pool: Arc<DbPool>, job_id: Uuid) -> Result<(), Scr
     );
 
     // Build the step registry and output store
-    // If job.recipe_id is already set, this is a rescrape - pass it to build_registry
-    let registry = build_registry(pool.clone(), job.user_id, job.recipe_id)?;
+    // If job.recipe_id is already set, this is a rescrape - build_registry handles it
+    let registry = build_registry(pool.clone(), &job)?;
     let mut store = DbOutputStore::new(&pool, job_id);
 
     // URL for context (empty string for imports without a URL)
@@ -721,15 +1400,53 @@ async fn run_scrape_job_inner(pool: Arc<DbPool>, job_id: Uuid) -> Result<(), Scr
 
         // Update job status and current_step before executing
         update_status_and_step(&pool, job_id, step_status, Some(&step_name))?;
+        events::publish(
+            job.user_id,
+            ScrapeJobEvent::StepStarted {
+                job_id,
+                step: step_name.clone(),
+            },
+        );
 
         // Execute step with OpenTelemetry span
-        let result = execute_step_with_tracing(step, url, &store, &step_name).await;
+        let mut result = execute_step_with_tracing(step, url, &store, &step_name).await;
+
+        // A page with several recipes continues with the chosen one
+        let selected = if step_name == ExtractRecipeStep::NAME && result.success {
+            apply_recipe_selection(&pool, &job, &mut result.output)?
+        } else {
+            true
+        };
+
+        if step_name == ExtractRecipeStep::NAME && result.success && selected {
+            let quality = result
+                .output
+                .get("quality")
+                .and_then(|q| serde_json::from_value::<ExtractionQuality>(q.clone()).ok());
+            save_extraction_quality(&pool, job_id, quality.as_ref())?;
+        }
+
+        events::publish(
+            job.user_id,
+            ScrapeJobEvent::StepFinished {
+                job_id,
+                step: step_name.clone(),
+                duration_ms: result.duration_ms,
+                success: result.success,
+                error: result.error.clone(),
+            },
+        );
 
         // Save output (for both success and failure - useful for debugging)
         if let Err(e) = store.save_output(&step_name, &result.output) {
             tracing::warn!("Failed to save output for step {}: {}", step_name, e);
         }
 
+        if !selected {
+            tracing::info!("Job {} found several recipes, awaiting selection", job_id);
+            return mark_awaiting_selection(&pool, job_id);
+        }
+
         let meta = step.metadata();
         let should_continue = result.success || meta.continues_on_failure;
 
@@ -902,7 +1619,7 @@ async fn execute_step_with_tracing(
     result
 }
 
-/// Reset a failed job for retry.
+/// Reset a failed job for retry and queue it again.
 /// Returns the status to resume from.
 pub fn retry_job(pool: &DbPool, job_id: Uuid) -> Result<String, ScrapeError> {
     let job = get_job(pool, job_id)?;
@@ -963,6 +1680,7 @@ pub fn retry_job(pool: &DbPool, job_id: Uuid) -> Result<String, ScrapeError> {
             scrape_jobs::failed_at_step.eq::<Option<String>>(None),
             scrape_jobs::error_message.eq::<Option<String>>(None),
             scrape_jobs::retry_count.eq(job.retry_count + 1),
+            scrape_jobs::queued_at.eq(Some(Utc::now())),
             scrape_jobs::updated_at.eq(Utc::now()),
         ))
         .execute(&mut conn)
@@ -970,3 +1688,44 @@ pub fn retry_job(pool: &DbPool, job_id: Uuid) -> Result<String, ScrapeError> {
 
     Ok(resume_status.to_string())
 }
+
+/// Cancel a job that hasn't saved its recipe yet. A job running in this process
+/// is aborted straight away; workers elsewhere stop it at their next heartbeat.
+pub fn cancel_job(pool: &DbPool, job_id: Uuid) -> Result<(), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    // Waits for an in-progress save (which holds the row lock) and then sees saved_at
+    let user_id: Option<Uuid> = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne_all(FINISHED_STATUSES))
+            .filter(scrape_jobs::saved_at.is_null()),
+    )
+    .set((
+        scrape_jobs::status.eq(STATUS_CANCELLED),
+        scrape_jobs::queued_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::claimed_by.eq::<Option<String>>(None),
+        scrape_jobs::heartbeat_at.eq::<Option<DateTime<Utc>>>(None),
+        scrape_jobs::updated_at.eq(Utc::now()),
+    ))
+    .returning(scrape_jobs::user_id)
+    .get_result(&mut conn)
+    .optional()
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let Some(user_id) = user_id else {
+        let job = get_job(pool, job_id)?;
+        return Err(ScrapeError::InvalidState(if job.saved_at.is_some() {
+            "Cannot cancel job: its recipe has already been saved".to_string()
+        } else {
+            format!("Cannot cancel job in status: {}", job.status)
+        }));
+    };
+
+    abort_running_job(job_id);
+    events::publish(user_id, ScrapeJobEvent::Cancelled { job_id });
+
+    Ok(())
+}
@@ -0,0 +1,335 @@
+//! Durable worker pool for scrape jobs.
+//!
+//! Jobs are queued by setting `scrape_jobs.queued_at`. Workers claim them with
+//! `SELECT ... FOR UPDATE SKIP LOCKED`, so several server instances can share
+//! the queue, and refresh `heartbeat_at` while a job runs. A job whose worker
+//! stops heartbeating (crash, deploy) is claimed again and resumes from its
+//! `current_step`. A worker that finds it no longer holds the claim (the job was
+//! cancelled, or reclaimed after a stall) aborts the job's task.
+
+use super::{get_job, is_finished, job_span, mark_failed, run_job, ScrapeError};
+use super::{STATUS_AWAITING_SELECTION, STATUS_PENDING, STATUS_SCRAPING};
+use crate::db::DbPool;
+use crate::models::ScrapeJob;
+use crate::schema::scrape_jobs;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::{Notify, Semaphore};
+use tokio::task::AbortHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Wakes the local dispatcher when a job is queued, so it doesn't wait for the next poll.
+static JOB_QUEUED: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+/// Tasks of the jobs this process is running, so a cancel can abort them.
+static RUNNING_JOBS: LazyLock<Mutex<HashMap<Uuid, AbortHandle>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Worker pool settings.
+#[derive(Debug, Clone)]
+pub struct WorkerConfig {
+    /// Jobs this instance runs at once (0 disables the workers)
+    pub concurrency: usize,
+    /// How often to look for queued jobs when not woken locally
+    pub poll_interval: Duration,
+    /// How often a running job's heartbeat is refreshed
+    pub heartbeat_interval: Duration,
+    /// How old a heartbeat can get before the job is considered abandoned
+    pub stale_after: Duration,
+}
+
+impl Default for WorkerConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            poll_interval: Duration::from_secs(2),
+            heartbeat_interval: Duration::from_secs(10),
+            stale_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl WorkerConfig {
+    /// Read overrides from SCRAPE_WORKER_CONCURRENCY and SCRAPE_JOB_STALE_SECS.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(concurrency) = env_number("SCRAPE_WORKER_CONCURRENCY") {
+            config.concurrency = concurrency as usize;
+        }
+        if let Some(secs) = env_number("SCRAPE_JOB_STALE_SECS") {
+            config.stale_after = Duration::from_secs(secs);
+            // Heartbeat several times per stale window
+            config.heartbeat_interval = Duration::from_secs((secs / 6).max(1));
+        }
+        config
+    }
+}
+
+pub(crate) fn env_number(name: &str) -> Option<u64> {
+    let value = env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(n) => Some(n),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid {}={:?}", name, value);
+            None
+        }
+    }
+}
+
+/// Identifies this server process in `claimed_by`: the host (for logs) plus a
+/// random suffix, since several processes can share a HOSTNAME.
+fn worker_id() -> String {
+    let host = env::var("HOSTNAME")
+        .ok()
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "ramekin".to_string());
+    let suffix = Uuid::new_v4().simple().to_string();
+    format!("{}-{}", host, &suffix[..8])
+}
+
+/// Wake this instance's workers after queueing a job. Queued jobs are picked up
+/// by the next poll anyway; this just avoids the wait.
+pub fn wake_workers() {
+    JOB_QUEUED.notify_one();
+}
+
+/// Abort a job's task if this process is running it, dropping whatever HTTP or
+/// AI request it is waiting on.
+pub(super) fn abort_running_job(job_id: Uuid) {
+    let running = RUNNING_JOBS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(handle) = running.get(&job_id) {
+        tracing::info!("Aborting job {}", job_id);
+        handle.abort();
+    }
+}
+
+/// Start the background workers that run queued scrape jobs.
+pub fn start_workers(pool: Arc<DbPool>, config: WorkerConfig) {
+    if config.concurrency == 0 {
+        tracing::info!("Scrape workers disabled (SCRAPE_WORKER_CONCURRENCY=0)");
+        return;
+    }
+
+    let worker_id = worker_id();
+
+    match release_stale_claims(&pool, config.stale_after) {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("Released {} job(s) whose worker stopped heartbeating", n),
+        Err(e) => tracing::warn!("Failed to release stale claims: {}", e),
+    }
+
+    tracing::info!(
+        "Starting {} scrape worker(s) as {}",
+        config.concurrency,
+        worker_id
+    );
+    tokio::spawn(dispatch(pool, config, worker_id));
+}
+
+/// Release claims whose heartbeat has gone stale, left by processes that
+/// crashed or were stopped. Claiming would take these jobs over anyway; doing
+/// it at startup reports the interruption. Live workers keep their heartbeats
+/// fresh, so their claims are untouched whichever process they belong to.
+fn release_stale_claims(pool: &DbPool, stale_after: Duration) -> Result<usize, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    diesel::update(
+        scrape_jobs::table
+            .filter(scrape_jobs::claimed_by.is_not_null())
+            .filter(scrape_jobs::heartbeat_at.lt(stale_before(stale_after))),
+    )
+    .set((
+        scrape_jobs::claimed_by.eq::<Option<String>>(None),
+        scrape_jobs::heartbeat_at.eq::<Option<DateTime<Utc>>>(None),
+    ))
+    .execute(&mut conn)
+    .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Heartbeats older than this belong to workers that are gone.
+fn stale_before(stale_after: Duration) -> DateTime<Utc> {
+    Utc::now()
+        - chrono::Duration::from_std(stale_after).unwrap_or_else(|_| chrono::Duration::minutes(1))
+}
+
+/// Claim the oldest queued job that nobody is running (or whose worker went
+/// quiet). SKIP LOCKED keeps concurrent claimers off each other's rows.
+fn claim_next_job(
+    pool: &DbPool,
+    worker_id: &str,
+    stale_after: Duration,
+) -> Result<Option<ScrapeJob>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let stale_before = stale_before(stale_after);
+
+    conn.transaction(|conn| {
+        let candidate: Option<(Uuid, Option<String>)> = scrape_jobs::table
+            .filter(scrape_jobs::queued_at.is_not_null())
+            .filter(
+                scrape_jobs::claimed_by
+                    .is_null()
+                    .or(scrape_jobs::heartbeat_at.lt(stale_before)),
+            )
+            .order(scrape_jobs::queued_at.asc())
+            .select((scrape_jobs::id, scrape_jobs::claimed_by))
+            .for_update()
+            .skip_locked()
+            .first(conn)
+            .optional()?;
+
+        let Some((job_id, previous_worker)) = candidate else {
+            return Ok(None);
+        };
+
+        if let Some(previous_worker) = previous_worker {
+            tracing::warn!(
+                "Reclaiming job {} abandoned by worker {}",
+                job_id,
+                previous_worker
+            );
+        }
+
+        diesel::update(scrape_jobs::table.find(job_id))
+            .set((
+                scrape_jobs::claimed_by.eq(Some(worker_id)),
+                scrape_jobs::heartbeat_at.eq(Some(Utc::now())),
+            ))
+            .get_result::<ScrapeJob>(conn)
+            .map(Some)
+    })
+    .map_err(|e: diesel::result::Error| ScrapeError::Database(e.to_string()))
+}
+
+/// Refresh the heartbeat on a job this worker is running. Returns false if the
+/// worker no longer holds the claim.
+fn heartbeat(pool: &DbPool, job_id: Uuid, worker_id: &str) -> Result<bool, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let updated = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::claimed_by.eq(worker_id)),
+    )
+    .set(scrape_jobs::heartbeat_at.eq(Some(Utc::now())))
+    .execute(&mut conn)
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    Ok(updated > 0)
+}
+
+/// Claim jobs while there is spare capacity; otherwise wait to be woken or for
+/// the next poll.
+async fn dispatch(pool: Arc<DbPool>, config: WorkerConfig, worker_id: String) {
+    let slots = Arc::new(Semaphore::new(config.concurrency));
+
+    loop {
+        let permit = slots
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("worker semaphore is never closed");
+
+        match claim_next_job(&pool, &worker_id, config.stale_after) {
+            Ok(Some(job)) => {
+                let pool = pool.clone();
+                let worker_id = worker_id.clone();
+                let heartbeat_interval = config.heartbeat_interval;
+                tokio::spawn(async move {
+                    run_claimed_job(pool, job, &worker_id, heartbeat_interval).await;
+                    drop(permit);
+                });
+            }
+            Ok(None) => {
+                drop(permit);
+                wait_for_work(config.poll_interval).await;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to claim scrape job: {}", e);
+                drop(permit);
+                wait_for_work(config.poll_interval).await;
+            }
+        }
+    }
+}
+
+async fn wait_for_work(poll_interval: Duration) {
+    tokio::select! {
+        _ = JOB_QUEUED.notified() => {}
+        _ = tokio::time::sleep(poll_interval) => {}
+    }
+}
+
+/// Run a claimed job, heartbeating until it finishes. The job runs in its own
+/// task so a panic fails the job instead of leaving it claimed.
+async fn run_claimed_job(
+    pool: Arc<DbPool>,
+    job: ScrapeJob,
+    worker_id: &str,
+    heartbeat_interval: Duration,
+) {
+    let job_id = job.id;
+    let span = job_span(&job);
+    let mut handle = tokio::spawn(run_job(pool.clone(), job).instrument(span));
+    RUNNING_JOBS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(job_id, handle.abort_handle());
+
+    let mut ticker = tokio::time::interval(heartbeat_interval);
+    ticker.tick().await; // The first tick completes immediately
+
+    let result = loop {
+        tokio::select! {
+            result = &mut handle => break result,
+            _ = ticker.tick() => match heartbeat(&pool, job_id, worker_id) {
+                Ok(true) => {}
+                Ok(false) => {
+                    tracing::info!("Job {} is no longer claimed by this worker, aborting", job_id);
+                    handle.abort();
+                }
+                Err(e) => tracing::warn!("Failed to heartbeat job {}: {}", job_id, e),
+            },
+        }
+    };
+
+    RUNNING_JOBS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&job_id);
+
+    match &result {
+        // Cancelled, or reclaimed by another worker; either way it isn't ours to finish
+        Err(e) if e.is_cancelled() => return,
+        Err(e) => tracing::error!("Scrape job {} panicked: {}", job_id, e),
+        Ok(()) => {}
+    }
+
+    // A job that stopped without reaching a terminal state (or a pause for the
+    // user to choose recipes) would otherwise be claimed again straight away
+    match get_job(&pool, job_id) {
+        Ok(job) if !is_finished(&job.status) && job.status != STATUS_AWAITING_SELECTION => {
+            let step = if job.status == STATUS_PENDING {
+                STATUS_SCRAPING
+            } else {
+                job.status.as_str()
+            };
+            if let Err(e) = mark_failed(&pool, job_id, step, "Job stopped before finishing") {
+                tracing::error!("Failed to mark job {} as failed: {}", job_id, e);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Failed to check job {} after running: {}", job_id, e),
+    }
+}
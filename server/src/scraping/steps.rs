@@ -3,14 +3,15 @@
 //! These implement the `PipelineStep` trait with database operations
 //! for storing recipes, fetching images, etc.
 
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use diesel::prelude::*;
 use serde_json::json;
 use uuid::Uuid;
 
+use ramekin_core::http::{CachingClient, HttpClient, RobotsPolicy};
 use ramekin_core::pipeline::{
     steps::{FetchImagesStepMeta, SaveRecipeStepMeta},
     PipelineStep, StepContext, StepMetadata, StepResult,
@@ -19,20 +20,73 @@ use ramekin_core::{ExtractionMethod, FailedImageFetch, FetchImagesOutput, RawRec
 
 use crate::db::DbPool;
 use crate::models::{
-    Ingredient, NewPhoto, NewRecipe, NewRecipeVersion, NewUserTag, RecipeVersionTag,
+    Ingredient, NewPhoto, NewRecipe, NewRecipeVersion, NewUserTag, ParsedRecipeFields,
+    RecipeVersionTag, SourceDetails,
 };
 use crate::photos::processing::{process_image, MAX_FILE_SIZE};
-use crate::schema::{photos, recipe_version_tags, recipe_versions, recipes, user_tags};
+use crate::raw_sql;
+use crate::schema::{
+    photos, recipe_version_tags, recipe_versions, recipes, scrape_jobs, user_tags,
+};
 
-use super::is_host_allowed;
+use super::{is_host_allowed, PAPRIKA_VERSION_SOURCE, STATUS_CANCELLED};
+
+/// Minimum delay between fetches to the same host for batch import jobs.
+const BATCH_HOST_DELAY: Duration = Duration::from_secs(1);
+
+/// Shared by all batch jobs run by this process, so a batch of URLs from one
+/// site is fetched at a polite pace whichever workers pick the jobs up. Batches
+/// are bulk crawls, so they honor robots.txt and any longer Crawl-delay.
+static BATCH_CLIENT: LazyLock<Result<Arc<CachingClient>, String>> = LazyLock::new(|| {
+    crate::http_cache::client_builder()
+        .rate_limit_ms(BATCH_HOST_DELAY.as_millis() as u64)
+        .robots_policy(RobotsPolicy::Crawl)
+        .build()
+        .map(Arc::new)
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+});
+
+/// The client a job fetches its page (and the page's print or AMP version)
+/// with: the shared crawl client for batch imports, otherwise a client on the
+/// server's shared HTTP cache.
+pub fn page_client(batch: bool) -> Result<Arc<CachingClient>, String> {
+    if batch {
+        return BATCH_CLIENT.clone();
+    }
+    crate::http_cache::client_builder()
+        .build()
+        .map(Arc::new)
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
 
 /// Server implementation of FetchHtml step.
 ///
-/// Uses ramekin_core::fetch_html directly (no caching).
-pub struct FetchHtmlStep;
+/// Single imports are user-initiated fetches through a client on the server's
+/// shared HTTP cache. Batch imports go through the shared crawl client.
+/// Neither renders script-built pages in a headless browser: the browser's own
+/// network access bypasses the SSRF checks (see `ramekin_core::http::BrowserClient`).
+#[derive(Default)]
+pub struct FetchHtmlStep {
+    batch: bool,
+}
 
 impl FetchHtmlStep {
     pub const NAME: &'static str = "fetch_html";
+
+    pub fn new() -> Self {
+        Self { batch: false }
+    }
+
+    /// Create a FetchHtmlStep for a batch import job, rate limited per host
+    /// and subject to robots.txt.
+    pub fn for_batch() -> Self {
+        Self { batch: true }
+    }
+
+    async fn fetch(&self, url: &str) -> Result<String, String> {
+        let client = page_client(self.batch)?;
+        client.fetch_html(url).await.map_err(|e| e.to_string())
+    }
 }
 
 #[async_trait]
@@ -60,7 +114,7 @@ impl PipelineStep for FetchHtmlStep {
             };
         }
 
-        match ramekin_core::fetch_html(ctx.url).await {
+        match self.fetch(ctx.url).await {
             Ok(html) => StepResult {
                 step_name: Self::NAME.to_string(),
                 success: true,
@@ -73,7 +127,7 @@ impl PipelineStep for FetchHtmlStep {
                 step_name: Self::NAME.to_string(),
                 success: false,
                 output: serde_json::Value::Null,
-                error: Some(e.to_string()),
+                error: Some(e),
                 duration_ms: start.elapsed().as_millis() as u64,
                 next_step: None,
             },
@@ -176,9 +230,10 @@ impl FetchImagesStep {
         is_host_allowed(url).map_err(|e| e.to_string())?;
 
         // Fetch the image bytes
-        let data = ramekin_core::fetch_bytes(url)
-            .await
-            .map_err(|e| e.to_string())?;
+        let client = crate::http_cache::client_builder()
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        let data = client.fetch_bytes(url).await.map_err(|e| e.to_string())?;
 
         // Validate size
         if data.len() > MAX_FILE_SIZE {
@@ -220,27 +275,80 @@ impl FetchImagesStep {
 pub struct SaveRecipeStep {
     pool: Arc<DbPool>,
     user_id: Uuid,
+    job_id: Uuid,
     existing_recipe_id: Option<Uuid>,
 }
 
 impl SaveRecipeStep {
-    pub fn new(pool: Arc<DbPool>, user_id: Uuid) -> Self {
+    pub fn new(pool: Arc<DbPool>, user_id: Uuid, job_id: Uuid) -> Self {
         Self {
             pool,
             user_id,
+            job_id,
             existing_recipe_id: None,
         }
     }
 
-    pub fn for_rescrape(pool: Arc<DbPool>, user_id: Uuid, recipe_id: Uuid) -> Self {
+    pub fn for_rescrape(pool: Arc<DbPool>, user_id: Uuid, job_id: Uuid, recipe_id: Uuid) -> Self {
         Self {
             pool,
             user_id,
+            job_id,
             existing_recipe_id: Some(recipe_id),
         }
     }
 }
 
+/// Where a new version of an existing recipe gets its notes and tags from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotesAndTags {
+    /// The saved recipe's own notes and categories (a re-imported Paprika
+    /// recipe is the user's own copy, edited in Paprika)
+    FromRecipe,
+    /// The current version's, so a rescrape or accepted upstream update
+    /// doesn't lose what the user added
+    KeepCurrent,
+}
+
+/// Record that the job is saving its recipe, failing if it was cancelled or
+/// has already saved. Runs first in the save transaction and holds the job
+/// row's lock until commit, so a concurrent cancel either lands first (and the
+/// save rolls back) or sees `saved_at` and refuses, and two workers running the
+/// same job can't both save.
+fn mark_job_saved(conn: &mut PgConnection, job_id: Uuid) -> Result<(), diesel::result::Error> {
+    let updated = diesel::update(
+        scrape_jobs::table
+            .find(job_id)
+            .filter(scrape_jobs::status.ne(STATUS_CANCELLED))
+            .filter(scrape_jobs::saved_at.is_null()),
+    )
+    .set(scrape_jobs::saved_at.eq(Some(chrono::Utc::now())))
+    .execute(conn)?;
+
+    if updated == 0 {
+        return Err(diesel::result::Error::RollbackTransaction);
+    }
+    Ok(())
+}
+
+/// The recipe a job already saved, if it got that far. A job reclaimed after
+/// its worker stalled, or retried from save_recipe, can reach the step again
+/// after the save transaction committed.
+fn saved_recipe_id(conn: &mut PgConnection, job_id: Uuid) -> Result<Option<Uuid>, String> {
+    let (saved_at, recipe_id): (Option<chrono::DateTime<chrono::Utc>>, Option<Uuid>) =
+        scrape_jobs::table
+            .find(job_id)
+            .select((scrape_jobs::saved_at, scrape_jobs::recipe_id))
+            .first(conn)
+            .map_err(|e| e.to_string())?;
+
+    match (saved_at, recipe_id) {
+        (None, _) => Ok(None),
+        (Some(_), Some(recipe_id)) => Ok(Some(recipe_id)),
+        (Some(_), None) => Err("Recipe was already saved".to_string()),
+    }
+}
+
 #[async_trait]
 impl PipelineStep for SaveRecipeStep {
     fn metadata(&self) -> StepMetadata {
@@ -250,6 +358,41 @@ impl PipelineStep for SaveRecipeStep {
     async fn execute(&self, ctx: &StepContext<'_>) -> StepResult {
         let start = Instant::now();
 
+        // Saving again would create a second recipe (or version)
+        let saved = self
+            .pool
+            .get()
+            .map_err(|e| e.to_string())
+            .and_then(|mut conn| saved_recipe_id(&mut conn, self.job_id));
+        match saved {
+            Ok(None) => {}
+            Ok(Some(recipe_id)) => {
+                tracing::info!(
+                    "Job {} already saved recipe {}, not saving again",
+                    self.job_id,
+                    recipe_id
+                );
+                return StepResult {
+                    step_name: SaveRecipeStepMeta::NAME.to_string(),
+                    success: true,
+                    output: json!({ "recipe_id": recipe_id.to_string() }),
+                    error: None,
+                    duration_ms: start.elapsed().as_millis() as u64,
+                    next_step: Some("enrich_normalize_ingredients".to_string()),
+                };
+            }
+            Err(e) => {
+                return StepResult {
+                    step_name: SaveRecipeStepMeta::NAME.to_string(),
+                    success: false,
+                    output: serde_json::Value::Null,
+                    error: Some(e),
+                    duration_ms: start.elapsed().as_millis() as u64,
+                    next_step: None,
+                };
+            }
+        }
+
         // Get extract output
         let extract_output = match ctx.outputs.get_output("extract_recipe") {
             Some(o) => o,
@@ -290,8 +433,15 @@ impl PipelineStep for SaveRecipeStep {
 
         // Determine version_source based on extraction method
         let version_source = match extraction_method {
+            // Re-importing a changed Paprika recipe versions the recipe it was first imported into
+            Some(ExtractionMethod::Paprika) if self.existing_recipe_id.is_some() => {
+                PAPRIKA_VERSION_SOURCE
+            }
             Some(ExtractionMethod::Paprika) => "import",
             Some(ExtractionMethod::PhotoUpload) => "photo_import",
+            Some(ExtractionMethod::DocumentText | ExtractionMethod::DocumentAi) => {
+                "document_import"
+            }
             _ => match self.existing_recipe_id {
                 Some(_) => "rescrape",
                 None => "scrape",
@@ -329,6 +479,11 @@ impl PipelineStep for SaveRecipeStep {
                     .collect()
             });
 
+        let notes_and_tags = match extraction_method {
+            Some(ExtractionMethod::Paprika) => NotesAndTags::FromRecipe,
+            _ => NotesAndTags::KeepCurrent,
+        };
+
         // Create or update recipe in database
         let result = match self.existing_recipe_id {
             Some(recipe_id) => self.update_recipe(
@@ -337,6 +492,7 @@ impl PipelineStep for SaveRecipeStep {
                 &photo_ids,
                 &parsed_ingredients,
                 version_source,
+                notes_and_tags,
             ),
             None => {
                 self.create_recipe(&raw_recipe, &photo_ids, &parsed_ingredients, version_source)
@@ -382,6 +538,8 @@ impl SaveRecipeStep {
 
         // Use a transaction to create recipe + version atomically
         conn.transaction(|conn| {
+            mark_job_saved(conn, self.job_id)?;
+
             // 1. Create the recipe row
             let new_recipe = NewRecipe {
                 user_id: self.user_id,
@@ -411,6 +569,13 @@ impl SaveRecipeStep {
                 nutritional_info: raw.nutritional_info.as_deref(),
                 notes: raw.notes.as_deref(),
                 version_source,
+                parsed: ParsedRecipeFields::parse(
+                    raw.servings.as_deref(),
+                    raw.prep_time.as_deref(),
+                    raw.cook_time.as_deref(),
+                    raw.total_time.as_deref(),
+                ),
+                source_details: SourceDetails::from_raw(raw),
             };
 
             let version_id: Uuid = diesel::insert_into(recipe_versions::table)
@@ -423,33 +588,15 @@ impl SaveRecipeStep {
                 .set(recipes::current_version_id.eq(version_id))
                 .execute(conn)?;
 
+            // Record the recipe on the job with saved_at, so a rerun of this
+            // step finds it instead of creating another
+            diesel::update(scrape_jobs::table.find(self.job_id))
+                .set(scrape_jobs::recipe_id.eq(Some(recipe_id)))
+                .execute(conn)?;
+
             // 4. Handle categories as tags (from Paprika imports)
             if let Some(ref categories) = raw.categories {
-                for tag_name in categories {
-                    if tag_name.is_empty() {
-                        continue;
-                    }
-                    // Upsert the tag into user_tags
-                    let tag_id: Uuid = diesel::insert_into(user_tags::table)
-                        .values(NewUserTag {
-                            user_id: self.user_id,
-                            name: tag_name,
-                        })
-                        .on_conflict((user_tags::user_id, user_tags::name))
-                        .do_update()
-                        .set(user_tags::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)) // Revive soft-deleted tags
-                        .returning(user_tags::id)
-                        .get_result(conn)?;
-
-                    // Insert into junction table
-                    diesel::insert_into(recipe_version_tags::table)
-                        .values(RecipeVersionTag {
-                            recipe_version_id: version_id,
-                            tag_id,
-                        })
-                        .on_conflict_do_nothing()
-                        .execute(conn)?;
-                }
+                save_categories_as_tags(conn, self.user_id, version_id, categories)?;
             }
 
             Ok(recipe_id)
@@ -464,6 +611,7 @@ impl SaveRecipeStep {
         photo_ids: &[Uuid],
         parsed_ingredients: &[Ingredient],
         version_source: &str,
+        notes_and_tags: NotesAndTags,
     ) -> Result<Uuid, String> {
         let mut conn = self.pool.get().map_err(|e| e.to_string())?;
 
@@ -475,6 +623,24 @@ impl SaveRecipeStep {
 
         // Use a transaction to create new version and update recipe
         conn.transaction(|conn| {
+            mark_job_saved(conn, self.job_id)?;
+
+            let (notes, tags): (Option<String>, Vec<String>) = match notes_and_tags {
+                NotesAndTags::FromRecipe => (
+                    raw.notes.clone(),
+                    raw.categories.clone().unwrap_or_default(),
+                ),
+                NotesAndTags::KeepCurrent => recipes::table
+                    .inner_join(
+                        recipe_versions::table.on(recipe_versions::id
+                            .nullable()
+                            .eq(recipes::current_version_id)),
+                    )
+                    .filter(recipes::id.eq(recipe_id))
+                    .select((recipe_versions::notes, raw_sql::tags_subquery()))
+                    .first(conn)?,
+            };
+
             // Create a new version
             let new_version = NewRecipeVersion {
                 recipe_id,
@@ -492,8 +658,15 @@ impl SaveRecipeStep {
                 rating: raw.rating,
                 difficulty: raw.difficulty.as_deref(),
                 nutritional_info: raw.nutritional_info.as_deref(),
-                notes: None,
+                notes: notes.as_deref(),
                 version_source,
+                parsed: ParsedRecipeFields::parse(
+                    raw.servings.as_deref(),
+                    raw.prep_time.as_deref(),
+                    raw.cook_time.as_deref(),
+                    raw.total_time.as_deref(),
+                ),
+                source_details: SourceDetails::from_raw(raw),
             };
 
             let version_id: Uuid = diesel::insert_into(recipe_versions::table)
@@ -506,12 +679,49 @@ impl SaveRecipeStep {
                 .set(recipes::current_version_id.eq(version_id))
                 .execute(conn)?;
 
+            save_categories_as_tags(conn, self.user_id, version_id, &tags)?;
+
             Ok(recipe_id)
         })
         .map_err(|e: diesel::result::Error| e.to_string())
     }
 }
 
+/// Upsert category names as user tags and attach them to a recipe version.
+fn save_categories_as_tags(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    version_id: Uuid,
+    categories: &[String],
+) -> Result<(), diesel::result::Error> {
+    for tag_name in categories {
+        if tag_name.is_empty() {
+            continue;
+        }
+        // Upsert the tag into user_tags
+        let tag_id: Uuid = diesel::insert_into(user_tags::table)
+            .values(NewUserTag {
+                user_id,
+                name: tag_name,
+            })
+            .on_conflict((user_tags::user_id, user_tags::name))
+            .do_update()
+            .set(user_tags::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)) // Revive soft-deleted tags
+            .returning(user_tags::id)
+            .get_result(conn)?;
+
+        // Insert into junction table
+        diesel::insert_into(recipe_version_tags::table)
+            .values(RecipeVersionTag {
+                recipe_version_id: version_id,
+                tag_id,
+            })
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
 /// Server implementation of ApplyAutoTags step.
 ///
 /// Takes the suggested tags from enrich_auto_tag output and creates a new
@@ -657,6 +867,13 @@ impl ApplyAutoTagsStep {
                 nutritional_info: current_version.nutritional_info.as_deref(),
                 notes: current_version.notes.as_deref(),
                 version_source: "enrichment",
+                parsed: ParsedRecipeFields::parse(
+                    current_version.servings.as_deref(),
+                    current_version.prep_time.as_deref(),
+                    current_version.cook_time.as_deref(),
+                    current_version.total_time.as_deref(),
+                ),
+                source_details: SourceDetails::from_version(&current_version),
             };
 
             let new_version_id: Uuid = diesel::insert_into(recipe_versions::table)
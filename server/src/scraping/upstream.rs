@@ -0,0 +1,701 @@
+//! Opt-in checks for upstream edits to saved recipes.
+//!
+//! A background task periodically re-fetches the source_url of recipes with
+//! checks enabled, sending back the ETag/Last-Modified validators from the
+//! previous fetch so unchanged pages cost a 304. Pages that did change are
+//! re-extracted and compared with the recipe's current version; differences are
+//! recorded as a pending upstream update, which the user can accept (saving a
+//! new version through a rescrape job) or dismiss.
+
+use super::queue::env_number;
+use super::{create_import_job, is_host_allowed, ScrapeError};
+use crate::db::DbPool;
+use crate::models::{
+    Ingredient, RecipeUpstreamCheck, RecipeUpstreamUpdate, RecipeVersion, ScrapeJob,
+};
+use crate::schema::{recipe_upstream_checks, recipe_upstream_updates, recipe_versions, recipes};
+use chrono::Utc;
+use diesel::prelude::*;
+use ramekin_core::ingredient_parser::parse_sectioned_ingredients;
+use ramekin_core::{
+    extract_recipe_with_stats, recipe_language, CacheMetadata, CachingClient, ConditionalFetch,
+    ExtractRecipeOutput, FetchError, RawRecipe, RobotsPolicy,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Upstream update statuses
+pub const UPDATE_PENDING: &str = "pending";
+pub const UPDATE_ACCEPTED: &str = "accepted";
+pub const UPDATE_DISMISSED: &str = "dismissed";
+
+/// Background check settings.
+#[derive(Debug, Clone)]
+pub struct UpstreamCheckConfig {
+    /// How long after a check a recipe is due again (0 disables the checker)
+    pub interval: Duration,
+    /// How often to look for recipes that are due
+    pub poll_interval: Duration,
+    /// Recipes claimed per poll
+    pub batch_size: i64,
+}
+
+impl Default for UpstreamCheckConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(24 * 60 * 60),
+            poll_interval: Duration::from_secs(5 * 60),
+            batch_size: 20,
+        }
+    }
+}
+
+impl UpstreamCheckConfig {
+    /// Read overrides from UPSTREAM_CHECK_INTERVAL_SECS.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = env_number("UPSTREAM_CHECK_INTERVAL_SECS") {
+            config.interval = Duration::from_secs(secs);
+            // Don't sit on due recipes for longer than the interval itself
+            config.poll_interval = config.poll_interval.min(Duration::from_secs(secs.max(1)));
+        }
+        config
+    }
+}
+
+/// What a single check found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckOutcome {
+    /// The page is unchanged, or matches the recipe or an earlier update
+    Unchanged,
+    /// A pending update was created or refreshed
+    UpdateFound(Uuid),
+    /// The recipe was deleted or has nothing to check
+    Skipped,
+}
+
+/// Start the background task that checks recipes for upstream edits.
+pub fn start_upstream_checks(pool: Arc<DbPool>, config: UpstreamCheckConfig) {
+    if config.interval.is_zero() {
+        tracing::info!("Upstream checks disabled (UPSTREAM_CHECK_INTERVAL_SECS=0)");
+        return;
+    }
+
+    tracing::info!(
+        "Checking opted-in recipes for upstream edits every {}s",
+        config.interval.as_secs()
+    );
+    tokio::spawn(async move {
+        loop {
+            match run_due_checks(&pool, &config).await {
+                Ok(0) => {}
+                Ok(n) => tracing::info!("Checked {} recipe(s) for upstream edits", n),
+                Err(e) => tracing::warn!("Upstream check round failed: {}", e),
+            }
+            tokio::time::sleep(config.poll_interval).await;
+        }
+    });
+}
+
+async fn run_due_checks(pool: &DbPool, config: &UpstreamCheckConfig) -> Result<usize, ScrapeError> {
+    let due = claim_due_checks(pool, config)?;
+    if due.is_empty() {
+        return Ok(0);
+    }
+
+    // One client per round so its per-host rate limiter spaces out the fetches.
+    // Background checks are a crawl, so they honor robots.txt.
+    let client = crate::http_cache::client_builder()
+        .robots_policy(RobotsPolicy::Crawl)
+        .build()
+        .map_err(FetchError::from)?;
+    for check in &due {
+        if let Err(e) = check_recipe(pool, &client, check).await {
+            tracing::warn!("Upstream check of recipe {} failed: {}", check.recipe_id, e);
+        }
+    }
+    Ok(due.len())
+}
+
+/// Claim recipes whose last check is older than the interval by stamping
+/// checked_at. SKIP LOCKED keeps concurrent instances off each other's rows.
+fn claim_due_checks(
+    pool: &DbPool,
+    config: &UpstreamCheckConfig,
+) -> Result<Vec<RecipeUpstreamCheck>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let due_before = Utc::now()
+        - chrono::Duration::from_std(config.interval).unwrap_or_else(|_| chrono::Duration::days(1));
+
+    conn.transaction(|conn| {
+        let due: Vec<RecipeUpstreamCheck> = recipe_upstream_checks::table
+            .filter(
+                recipe_upstream_checks::checked_at
+                    .is_null()
+                    .or(recipe_upstream_checks::checked_at.lt(due_before)),
+            )
+            .order(recipe_upstream_checks::checked_at.asc().nulls_first())
+            .limit(config.batch_size)
+            .select(RecipeUpstreamCheck::as_select())
+            .for_update()
+            .skip_locked()
+            .load(conn)?;
+
+        let ids: Vec<Uuid> = due.iter().map(|c| c.recipe_id).collect();
+        diesel::update(
+            recipe_upstream_checks::table.filter(recipe_upstream_checks::recipe_id.eq_any(&ids)),
+        )
+        .set(recipe_upstream_checks::checked_at.eq(Some(Utc::now())))
+        .execute(conn)?;
+
+        Ok(due)
+    })
+    .map_err(|e: diesel::result::Error| ScrapeError::Database(e.to_string()))
+}
+
+/// Get the upstream check row for a recipe, if checks are enabled.
+pub fn get_check(
+    pool: &DbPool,
+    recipe_id: Uuid,
+) -> Result<Option<RecipeUpstreamCheck>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipe_upstream_checks::table
+        .find(recipe_id)
+        .select(RecipeUpstreamCheck::as_select())
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Enable or disable upstream checks for a recipe. Re-enabling keeps the stored
+/// validators. The caller is responsible for checking recipe ownership.
+pub fn set_enabled(
+    pool: &DbPool,
+    user_id: Uuid,
+    recipe_id: Uuid,
+    enabled: bool,
+) -> Result<(), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    if enabled {
+        diesel::insert_into(recipe_upstream_checks::table)
+            .values((
+                recipe_upstream_checks::recipe_id.eq(recipe_id),
+                recipe_upstream_checks::user_id.eq(user_id),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+    } else {
+        diesel::delete(recipe_upstream_checks::table.find(recipe_id)).execute(&mut conn)
+    }
+    .map(|_| ())
+    .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Re-fetch a recipe's source page and record a pending update if it changed.
+/// Any error is also stored on the check row so it can be shown to the user.
+pub async fn check_recipe(
+    pool: &DbPool,
+    client: &CachingClient,
+    check: &RecipeUpstreamCheck,
+) -> Result<CheckOutcome, ScrapeError> {
+    let result = fetch_and_compare(pool, client, check).await;
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    let error = result.as_ref().err().map(|e| e.to_string());
+    diesel::update(recipe_upstream_checks::table.find(check.recipe_id))
+        .set((
+            recipe_upstream_checks::checked_at.eq(Some(Utc::now())),
+            recipe_upstream_checks::last_error.eq(error),
+        ))
+        .execute(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    result
+}
+
+async fn fetch_and_compare(
+    pool: &DbPool,
+    client: &CachingClient,
+    check: &RecipeUpstreamCheck,
+) -> Result<CheckOutcome, ScrapeError> {
+    let Some(version) = current_version(pool, check.recipe_id)? else {
+        return Ok(CheckOutcome::Skipped);
+    };
+    let Some(url) = version.source_url.clone().filter(|u| !u.is_empty()) else {
+        return Ok(CheckOutcome::Skipped);
+    };
+    is_host_allowed(&url)?;
+
+    let previous = CacheMetadata {
+        url: url.clone(),
+        content_type: None,
+        fetched_at: check.checked_at.unwrap_or(check.created_at),
+        etag: check.etag.clone(),
+        last_modified: check.last_modified.clone(),
+    };
+
+    let (html, metadata) = match client.fetch_html_if_modified(&url, Some(&previous)).await? {
+        ConditionalFetch::NotModified => return Ok(CheckOutcome::Unchanged),
+        ConditionalFetch::Modified { html, metadata } => (html, metadata),
+    };
+
+    let mut output = extract_recipe_with_stats(&html, &url)?;
+    // Only now keep the validators: stored before a failed extraction, they'd
+    // turn every later check into a 304 and the failure would never be retried
+    save_validators(pool, check.recipe_id, metadata)?;
+    // On a page with several recipes, follow the one this recipe was saved from
+    if let Some(index) = output
+        .other_recipes
+        .iter()
+        .position(|r| r.title == version.title)
+    {
+        std::mem::swap(&mut output.raw_recipe, &mut output.other_recipes[index]);
+    }
+    let upstream = Snapshot::from_raw(&output.raw_recipe);
+
+    let changed = changed_fields(&Snapshot::from_version(&version), &upstream);
+    if changed.is_empty() {
+        return Ok(CheckOutcome::Unchanged);
+    }
+
+    // Don't offer the same upstream content twice, whether it was accepted,
+    // dismissed or is still pending
+    if let Some(latest) = latest_update(pool, check.recipe_id)? {
+        let seen = serde_json::from_value::<ExtractRecipeOutput>(latest.extract_output)
+            .is_ok_and(|seen| Snapshot::from_raw(&seen.raw_recipe) == upstream);
+        if seen {
+            return Ok(CheckOutcome::Unchanged);
+        }
+    }
+
+    let update_id = save_pending_update(pool, check, version.id, &output, &changed)?;
+    Ok(CheckOutcome::UpdateFound(update_id))
+}
+
+/// Store the ETag/Last-Modified sent back on the next check.
+fn save_validators(
+    pool: &DbPool,
+    recipe_id: Uuid,
+    metadata: CacheMetadata,
+) -> Result<(), ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    diesel::update(recipe_upstream_checks::table.find(recipe_id))
+        .set((
+            recipe_upstream_checks::etag.eq(metadata.etag),
+            recipe_upstream_checks::last_modified.eq(metadata.last_modified),
+        ))
+        .execute(&mut conn)
+        .map(|_| ())
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// The recipe's current version, or None if it was deleted.
+fn current_version(pool: &DbPool, recipe_id: Uuid) -> Result<Option<RecipeVersion>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipes::table
+        .inner_join(
+            recipe_versions::table.on(recipe_versions::id
+                .nullable()
+                .eq(recipes::current_version_id)),
+        )
+        .filter(recipes::id.eq(recipe_id))
+        .filter(recipes::deleted_at.is_null())
+        .select(RecipeVersion::as_select())
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+fn latest_update(
+    pool: &DbPool,
+    recipe_id: Uuid,
+) -> Result<Option<RecipeUpstreamUpdate>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipe_upstream_updates::table
+        .filter(recipe_upstream_updates::recipe_id.eq(recipe_id))
+        .order(recipe_upstream_updates::created_at.desc())
+        .select(RecipeUpstreamUpdate::as_select())
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Replace the recipe's pending update, or create one.
+fn save_pending_update(
+    pool: &DbPool,
+    check: &RecipeUpstreamCheck,
+    base_version_id: Uuid,
+    output: &ExtractRecipeOutput,
+    changed: &[String],
+) -> Result<Uuid, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let extract_output =
+        serde_json::to_value(output).map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    conn.transaction(|conn| {
+        let replaced: Option<Uuid> = diesel::update(
+            recipe_upstream_updates::table
+                .filter(recipe_upstream_updates::recipe_id.eq(check.recipe_id))
+                .filter(recipe_upstream_updates::status.eq(UPDATE_PENDING)),
+        )
+        .set((
+            recipe_upstream_updates::base_version_id.eq(base_version_id),
+            recipe_upstream_updates::extract_output.eq(extract_output.clone()),
+            recipe_upstream_updates::changed_fields.eq(changed),
+            recipe_upstream_updates::created_at.eq(Utc::now()),
+        ))
+        .returning(recipe_upstream_updates::id)
+        .get_result(conn)
+        .optional()?;
+
+        if let Some(id) = replaced {
+            return Ok(id);
+        }
+
+        diesel::insert_into(recipe_upstream_updates::table)
+            .values((
+                recipe_upstream_updates::recipe_id.eq(check.recipe_id),
+                recipe_upstream_updates::user_id.eq(check.user_id),
+                recipe_upstream_updates::base_version_id.eq(base_version_id),
+                recipe_upstream_updates::extract_output.eq(extract_output.clone()),
+                recipe_upstream_updates::changed_fields.eq(changed),
+            ))
+            .returning(recipe_upstream_updates::id)
+            .get_result(conn)
+    })
+    .map_err(|e: diesel::result::Error| ScrapeError::Database(e.to_string()))
+}
+
+/// Pending updates for the user's recipes, newest first.
+pub fn list_pending_updates(
+    pool: &DbPool,
+    user_id: Uuid,
+) -> Result<Vec<RecipeUpstreamUpdate>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipe_upstream_updates::table
+        .inner_join(recipes::table)
+        .filter(recipe_upstream_updates::user_id.eq(user_id))
+        .filter(recipe_upstream_updates::status.eq(UPDATE_PENDING))
+        .filter(recipes::deleted_at.is_null())
+        .order(recipe_upstream_updates::created_at.desc())
+        .select(RecipeUpstreamUpdate::as_select())
+        .load(&mut conn)
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Get the pending update for a recipe, if any.
+pub fn get_pending_update(
+    pool: &DbPool,
+    recipe_id: Uuid,
+) -> Result<Option<RecipeUpstreamUpdate>, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    recipe_upstream_updates::table
+        .filter(recipe_upstream_updates::recipe_id.eq(recipe_id))
+        .filter(recipe_upstream_updates::status.eq(UPDATE_PENDING))
+        .select(RecipeUpstreamUpdate::as_select())
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))
+}
+
+/// Move a pending update owned by the user to `status`. Distinguishes an
+/// update that doesn't exist from one that was already resolved.
+fn resolve_update(
+    pool: &DbPool,
+    user_id: Uuid,
+    update_id: Uuid,
+    status: &str,
+) -> Result<RecipeUpstreamUpdate, ScrapeError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    let resolved: Option<RecipeUpstreamUpdate> = diesel::update(
+        recipe_upstream_updates::table
+            .filter(recipe_upstream_updates::id.eq(update_id))
+            .filter(recipe_upstream_updates::user_id.eq(user_id))
+            .filter(recipe_upstream_updates::status.eq(UPDATE_PENDING)),
+    )
+    .set((
+        recipe_upstream_updates::status.eq(status),
+        recipe_upstream_updates::resolved_at.eq(Some(Utc::now())),
+    ))
+    .returning(RecipeUpstreamUpdate::as_returning())
+    .get_result(&mut conn)
+    .optional()
+    .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    if let Some(update) = resolved {
+        return Ok(update);
+    }
+
+    let current: Option<String> = recipe_upstream_updates::table
+        .filter(recipe_upstream_updates::id.eq(update_id))
+        .filter(recipe_upstream_updates::user_id.eq(user_id))
+        .select(recipe_upstream_updates::status)
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+
+    match current {
+        Some(current) => Err(ScrapeError::InvalidState(format!(
+            "Upstream update is already {}",
+            current
+        ))),
+        None => Err(ScrapeError::UpstreamUpdateNotFound),
+    }
+}
+
+/// Accept a pending update: queue a rescrape job that saves the upstream recipe
+/// as a new version, keeping the current version's photos.
+pub fn accept_update(
+    pool: &DbPool,
+    user_id: Uuid,
+    update_id: Uuid,
+) -> Result<ScrapeJob, ScrapeError> {
+    let update = resolve_update(pool, user_id, update_id, UPDATE_ACCEPTED)?;
+
+    let queued = queue_accepted_update(pool, user_id, &update);
+
+    let mut conn = pool
+        .get()
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    let target = recipe_upstream_updates::table.find(update.id);
+
+    match queued {
+        Ok(job) => {
+            diesel::update(target)
+                .set(recipe_upstream_updates::scrape_job_id.eq(Some(job.id)))
+                .execute(&mut conn)
+                .map_err(|e| ScrapeError::Database(e.to_string()))?;
+            Ok(job)
+        }
+        Err(e) => {
+            // Leave the update pending so it can be accepted again
+            diesel::update(target)
+                .set((
+                    recipe_upstream_updates::status.eq(UPDATE_PENDING),
+                    recipe_upstream_updates::resolved_at.eq(None::<chrono::DateTime<Utc>>),
+                ))
+                .execute(&mut conn)
+                .map_err(|e| ScrapeError::Database(e.to_string()))?;
+            Err(e)
+        }
+    }
+}
+
+fn queue_accepted_update(
+    pool: &DbPool,
+    user_id: Uuid,
+    update: &RecipeUpstreamUpdate,
+) -> Result<ScrapeJob, ScrapeError> {
+    let output: ExtractRecipeOutput = serde_json::from_value(update.extract_output.clone())
+        .map_err(|e| ScrapeError::Database(e.to_string()))?;
+    let photo_ids = current_version(pool, update.recipe_id)?
+        .map(|v| v.photo_ids.into_iter().flatten().collect())
+        .unwrap_or_default();
+
+    create_import_job(
+        pool,
+        user_id,
+        output.raw_recipe.source_url.as_deref(),
+        &output.raw_recipe,
+        output.method_used,
+        photo_ids,
+        Some(update.recipe_id),
+    )
+}
+
+/// Dismiss a pending update. The same upstream content won't be offered again.
+pub fn dismiss_update(pool: &DbPool, user_id: Uuid, update_id: Uuid) -> Result<(), ScrapeError> {
+    resolve_update(pool, user_id, update_id, UPDATE_DISMISSED).map(|_| ())
+}
+
+/// The recipe content a check compares, normalized so formatting-only
+/// differences (whitespace, blank lines) don't count as upstream changes.
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    title: String,
+    description: Option<String>,
+    /// (section, line) pairs
+    ingredients: Vec<(Option<String>, String)>,
+    instructions: String,
+    servings: Option<String>,
+    prep_time: Option<String>,
+    cook_time: Option<String>,
+    total_time: Option<String>,
+}
+
+impl Snapshot {
+    fn from_raw(raw: &RawRecipe) -> Self {
+        Self {
+            title: normalize(&raw.title),
+            description: normalize_opt(raw.description.as_deref()),
+            // Parse the lines the way the pipeline does, so headers and
+            // ignored lines line up with what the saved version holds
+            ingredients: parse_sectioned_ingredients(
+                &raw.ingredients,
+                &raw.ingredient_sections,
+                recipe_language(raw),
+            )
+            .iter()
+            .map(|i| ingredient_line(i.section.as_deref(), i.raw.as_deref(), &i.item))
+            .collect(),
+            instructions: normalize(&raw.instructions),
+            servings: normalize_opt(raw.servings.as_deref()),
+            prep_time: normalize_opt(raw.prep_time.as_deref()),
+            cook_time: normalize_opt(raw.cook_time.as_deref()),
+            total_time: normalize_opt(raw.total_time.as_deref()),
+        }
+    }
+
+    fn from_version(version: &RecipeVersion) -> Self {
+        // Compare the ingredient lines as they were scraped, not as parsed
+        let ingredients: Vec<Ingredient> =
+            serde_json::from_value(version.ingredients.clone()).unwrap_or_default();
+
+        Self {
+            title: normalize(&version.title),
+            description: normalize_opt(version.description.as_deref()),
+            ingredients: ingredients
+                .iter()
+                .map(|i| ingredient_line(i.section.as_deref(), i.raw.as_deref(), &i.item))
+                .collect(),
+            instructions: normalize(&version.instructions),
+            servings: normalize_opt(version.servings.as_deref()),
+            prep_time: normalize_opt(version.prep_time.as_deref()),
+            cook_time: normalize_opt(version.cook_time.as_deref()),
+            total_time: normalize_opt(version.total_time.as_deref()),
+        }
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn ingredient_line(
+    section: Option<&str>,
+    raw: Option<&str>,
+    item: &str,
+) -> (Option<String>, String) {
+    (normalize_opt(section), normalize(raw.unwrap_or(item)))
+}
+
+fn normalize_opt(s: Option<&str>) -> Option<String> {
+    s.map(normalize).filter(|s| !s.is_empty())
+}
+
+/// Names of the fields that differ, in recipe order.
+fn changed_fields(base: &Snapshot, upstream: &Snapshot) -> Vec<String> {
+    let fields = [
+        ("title", base.title == upstream.title),
+        ("description", base.description == upstream.description),
+        ("ingredients", base.ingredients == upstream.ingredients),
+        ("instructions", base.instructions == upstream.instructions),
+        ("servings", base.servings == upstream.servings),
+        ("prep_time", base.prep_time == upstream.prep_time),
+        ("cook_time", base.cook_time == upstream.cook_time),
+        ("total_time", base.total_time == upstream.total_time),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, same)| !same)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(ingredients: &str, instructions: &str) -> RawRecipe {
+        RawRecipe {
+            title: "Rice Pilaf".to_string(),
+            description: None,
+            ingredients: ingredients.to_string(),
+            instructions: instructions.to_string(),
+            image_urls: vec![],
+            source_url: Some("https://example.com/pilaf".to_string()),
+            source_name: None,
+            servings: Some("4".to_string()),
+            prep_time: None,
+            cook_time: None,
+            total_time: None,
+            rating: None,
+            difficulty: None,
+            nutritional_info: None,
+            notes: None,
+            categories: None,
+            video_url: None,
+            equipment: None,
+            author: None,
+            published_date: None,
+            language: None,
+            ingredient_sections: vec![],
+            instruction_sections: vec![],
+        }
+    }
+
+    #[test]
+    fn test_formatting_changes_ignored() {
+        let base = Snapshot::from_raw(&raw("1 cup rice\n2 cups stock", "Toast the rice."));
+        let upstream =
+            Snapshot::from_raw(&raw("  1 cup  rice\n\n2 cups stock\n", "Toast the\n rice."));
+        assert!(changed_fields(&base, &upstream).is_empty());
+        assert_eq!(base, upstream);
+    }
+
+    #[test]
+    fn test_section_change_detected() {
+        let base = Snapshot::from_raw(&raw("1 cup rice\n2 cups stock", "Toast the rice."));
+        let upstream = Snapshot::from_raw(&raw(
+            "For the pilaf:\n1 cup rice\n2 cups stock",
+            "Toast the rice.",
+        ));
+        assert_eq!(changed_fields(&base, &upstream), vec!["ingredients"]);
+    }
+
+    #[test]
+    fn test_changed_fields_reported_in_order() {
+        let base = Snapshot::from_raw(&raw("1 cup rice\n2 cups stock", "Toast the rice."));
+        let mut edited = raw("1 cup rice\n2 1/2 cups stock", "Toast the rice in butter.");
+        edited.servings = None;
+        let upstream = Snapshot::from_raw(&edited);
+        assert_eq!(
+            changed_fields(&base, &upstream),
+            vec!["ingredients", "instructions", "servings"]
+        );
+    }
+}
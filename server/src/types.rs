@@ -1,4 +1,5 @@
 use crate::models::Ingredient;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -33,4 +34,14 @@ pub struct RecipeContent {
     pub nutritional_info: Option<String>,
     #[serde(default)]
     pub notes: Option<String>,
+    /// Link to a video of the recipe
+    #[serde(default)]
+    pub video_url: Option<String>,
+    #[serde(default)]
+    pub equipment: Vec<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// When the source published the recipe
+    #[serde(default)]
+    pub published_date: Option<NaiveDate>,
 }
@@ -2,10 +2,21 @@
 """HTTP server for test fixtures with silent logging."""
 
 import sys
-from http.server import HTTPServer, SimpleHTTPRequestHandler
+import time
+from http.server import SimpleHTTPRequestHandler, ThreadingHTTPServer
+
+# /slow/<path> serves <path> after a delay, for tests that need a fetch in flight
+SLOW_PREFIX = "/slow/"
+SLOW_DELAY_SECS = 5
 
 
 class SilentHandler(SimpleHTTPRequestHandler):
+    def do_GET(self):
+        if self.path.startswith(SLOW_PREFIX):
+            time.sleep(SLOW_DELAY_SECS)
+            self.path = "/" + self.path[len(SLOW_PREFIX) :]
+        super().do_GET()
+
     def log_message(self, format, *args):
         pass
 
@@ -15,5 +26,5 @@ class SilentHandler(SimpleHTTPRequestHandler):
 
 if __name__ == "__main__":
     port = int(sys.argv[1]) if len(sys.argv) > 1 else 8000
-    server = HTTPServer(("", port), SilentHandler)
+    server = ThreadingHTTPServer(("", port), SilentHandler)
     server.serve_forever()
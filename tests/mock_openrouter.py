@@ -75,7 +75,12 @@ class MockOpenRouterHandler(BaseHTTPRequestHandler):
                     if isinstance(part, dict)
                 )
                 if has_images:
-                    return self._mock_photo_extract()
+                    image_count = sum(
+                        1
+                        for part in content
+                        if isinstance(part, dict) and part.get("type") == "image_url"
+                    )
+                    return self._mock_photo_extract(image_count)
 
         # Extract text from messages
         all_text = ""
@@ -93,6 +98,10 @@ class MockOpenRouterHandler(BaseHTTPRequestHandler):
         if "recipe modification assistant" in all_text:
             return self._mock_custom_enrich(all_text)
 
+        # Document text extraction (PDF / plain text the heuristics couldn't parse)
+        if "text of a document" in all_text:
+            return self._mock_document_extract()
+
         # Default: auto-tag response
         return '{"suggested_tags": ["test-auto-tag"]}'
 
@@ -119,20 +128,49 @@ class MockOpenRouterHandler(BaseHTTPRequestHandler):
                 }
             )
 
-    def _mock_photo_extract(self):
-        """Return a mock recipe extracted from photos."""
+    def _mock_photo_extract(self, image_count):
+        """Return mock recipes extracted from photos.
+
+        Three photos are treated as a cookbook spread holding two recipes, with
+        the first recipe starting on the third photo and continuing on the first.
+        """
+        recipe = {
+            "photos": list(range(1, image_count + 1)),
+            "title": "Photo Imported Recipe",
+            "description": "A recipe extracted from a photo",
+            "ingredients": "1 cup flour\n2 eggs\n1/2 cup sugar",
+            "instructions": (
+                "Mix all ingredients together.\n\nBake at 350F for 30 minutes."
+            ),
+            "servings": "4 servings",
+            "prep_time": "10 minutes",
+            "cook_time": "30 minutes",
+            "total_time": "40 minutes",
+            "notes": None,
+        }
+        if image_count != 3:
+            return json.dumps({"recipes": [recipe]})
+
+        second = {
+            "photos": [2],
+            "title": "Second Photo Recipe",
+            "ingredients": "2 cups milk\n1 tbsp honey",
+            "instructions": "Warm the milk and stir in the honey.",
+        }
+        return json.dumps({"recipes": [dict(recipe, photos=[3, 1]), second]})
+
+    def _mock_document_extract(self):
+        """Return a mock recipe extracted from document text."""
         return json.dumps(
             {
-                "title": "Photo Imported Recipe",
-                "description": "A recipe extracted from a photo",
-                "ingredients": "1 cup flour\n2 eggs\n1/2 cup sugar",
-                "instructions": (
-                    "Mix all ingredients together.\n\nBake at 350F for 30 minutes."
-                ),
-                "servings": "4 servings",
-                "prep_time": "10 minutes",
-                "cook_time": "30 minutes",
-                "total_time": "40 minutes",
+                "title": "Document Imported Recipe",
+                "description": None,
+                "ingredients": "2 cups rice\n4 cups water",
+                "instructions": "Rinse the rice.\n\nSimmer covered for 18 minutes.",
+                "servings": None,
+                "prep_time": None,
+                "cook_time": None,
+                "total_time": None,
                 "notes": None,
             }
         )
@@ -0,0 +1,138 @@
+"""Tests for native account backup and restore."""
+
+import json
+import zipfile
+from datetime import date
+from io import BytesIO
+
+import requests
+
+from conftest import make_ingredient
+from ramekin_client.api import MealPlansApi, PhotosApi, RecipesApi
+from ramekin_client.models import CreateMealPlanRequest, CreateRecipeRequest
+
+
+def _backup(server_url, token):
+    response = requests.get(
+        f"{server_url}/api/account/backup",
+        headers={"Authorization": f"Bearer {token}"},
+    )
+    assert response.status_code == 200
+    assert response.headers["content-type"] == "application/zip"
+    return response.content
+
+
+def _restore(server_url, token, archive):
+    return requests.post(
+        f"{server_url}/api/account/restore",
+        headers={"Authorization": f"Bearer {token}"},
+        files={"file": ("backup.zip", archive, "application/zip")},
+    )
+
+
+def test_backup_contains_all_user_data(authed_api_client, server_url, test_image):
+    """Test that the backup archive includes recipes, versions, tags, photos and meal plans."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+    photos_api = PhotosApi(client)
+
+    photo = photos_api.upload(file=("bread.png", test_image))
+    recipe = recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Backup Bread",
+            instructions="Bake it",
+            ingredients=[make_ingredient(item="flour", amount="500", unit="g")],
+            tags=["bread"],
+            photo_ids=[photo.id],
+        )
+    )
+    MealPlansApi(client).create_meal_plan(
+        CreateMealPlanRequest(
+            recipe_id=recipe.id, meal_date=date.today(), meal_type="dinner"
+        )
+    )
+
+    archive = _backup(server_url, client.configuration.access_token)
+
+    with zipfile.ZipFile(BytesIO(archive), "r") as zf:
+        data = json.loads(zf.read("backup.json"))
+        assert data["format_version"] == 1
+        assert [r["id"] for r in data["recipes"]] == [str(recipe.id)]
+        assert data["recipe_versions"][0]["title"] == "Backup Bread"
+        assert [t["name"] for t in data["user_tags"]] == ["bread"]
+        assert len(data["recipe_version_tags"]) == 1
+        assert len(data["meal_plans"]) == 1
+        assert [p["id"] for p in data["photos"]] == [str(photo.id)]
+        assert zf.read(f"photos/{photo.id}") == test_image
+
+
+def test_restore_into_another_account(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test restoring a backup into a different account remaps ids."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    original = recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Restored Soup",
+            instructions="Simmer",
+            ingredients=[make_ingredient(item="stock", amount="1", unit="l")],
+            tags=["soup", "dinner"],
+        )
+    )
+
+    archive = _backup(server_url, client.configuration.access_token)
+
+    client2, user_id2 = second_authed_api_client
+    response = _restore(server_url, client2.configuration.access_token, archive)
+    assert response.status_code == 201
+    counts = response.json()
+    assert counts["recipes"] == 1
+    assert counts["recipe_versions"] == 1
+    assert counts["tags"] == 2
+
+    recipes_api2 = RecipesApi(client2)
+    listed = recipes_api2.list_recipes()
+    assert len(listed.recipes) == 1
+    restored = recipes_api2.get_recipe(id=listed.recipes[0].id)
+    assert restored.id != original.id
+    assert restored.title == "Restored Soup"
+    assert restored.ingredients[0].item == "stock"
+    assert set(restored.tags) == {"soup", "dinner"}
+
+
+def test_restore_into_same_account_duplicates_recipes(authed_api_client, server_url):
+    """Test restoring into the source account does not collide with existing rows."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Twice", instructions="Again", ingredients=[], tags=["repeat"]
+        )
+    )
+
+    token = client.configuration.access_token
+    archive = _backup(server_url, token)
+    response = _restore(server_url, token, archive)
+    assert response.status_code == 201
+
+    listed = recipes_api.list_recipes()
+    assert [r.title for r in listed.recipes] == ["Twice", "Twice"]
+
+
+def test_restore_rejects_invalid_archive(authed_api_client, server_url):
+    """Test that a file that isn't a backup archive is rejected."""
+    client, user_id = authed_api_client
+
+    response = _restore(
+        server_url, client.configuration.access_token, b"definitely not a zip"
+    )
+    assert response.status_code == 400
+
+
+def test_backup_requires_auth(server_url):
+    """Test that backup requires authentication."""
+    response = requests.get(f"{server_url}/api/account/backup")
+    assert response.status_code == 401
@@ -198,3 +198,155 @@ class TestCaptureIsolation:
             recipes_api2.get_recipe(job.recipe_id)
 
         assert exc_info.value.status == 404
+
+
+ROUNDUP_HTML = """<html><head><script type="application/ld+json">
+{"@context": "https://schema.org", "@graph": [
+  {"@type": "Recipe", "name": "Roundup Minestrone",
+   "recipeIngredient": ["1 can cannellini beans", "4 cups stock"],
+   "recipeInstructions": "Simmer everything for 30 minutes."},
+  {"@type": "Recipe", "name": "Roundup Pho",
+   "recipeIngredient": ["1 lb brisket", "8 cups beef stock"],
+   "recipeInstructions": "Simmer the stock, then pour over noodles."}
+]}
+</script></head><body><h1>Weeknight Soups</h1></body></html>"""
+
+
+def get_job_json(server_url: str, token: str, job_id: str) -> dict:
+    """Fetch a job as JSON (the generated client doesn't have recipe_candidates)."""
+    response = requests.get(
+        f"{server_url}/api/scrape/{job_id}",
+        headers={"Authorization": f"Bearer {token}"},
+    )
+    response.raise_for_status()
+    return response.json()
+
+
+def wait_for_job_status(
+    server_url: str, token: str, job_id: str, status: str, timeout: float = 10.0
+) -> dict:
+    """Poll until the job reaches the given status."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = get_job_json(server_url, token, job_id)
+        if job["status"] == status:
+            return job
+        if job["status"] == "failed":
+            raise Exception(f"Job failed: {job.get('error')}")
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not reach {status} within {timeout}s")
+
+
+def select_recipes(
+    server_url: str, token: str, job_id: str, indices: list[int]
+) -> requests.Response:
+    return requests.post(
+        f"{server_url}/api/scrape/{job_id}/select",
+        json={"recipe_indices": indices},
+        headers={"Authorization": f"Bearer {token}"},
+    )
+
+
+class TestCaptureMultipleRecipes:
+    """Test pages with more than one recipe."""
+
+    def test_capture_waits_for_selection_then_imports_chosen(
+        self, authed_api_client, server_url
+    ):
+        """Test choosing recipes from a page creates one recipe per choice."""
+        client, user_id = authed_api_client
+        recipes_api = RecipesApi(client)
+        scrape_api = ScrapeApi(client)
+        token = client.configuration.access_token
+
+        result = capture_recipe(
+            server_url, token, ROUNDUP_HTML, "https://example.com/soups"
+        )
+        job = wait_for_job_status(
+            server_url, token, result["id"], "awaiting_selection"
+        )
+        assert job["recipe_candidates"] == ["Roundup Minestrone", "Roundup Pho"]
+        assert "recipe_id" not in job
+
+        response = select_recipes(server_url, token, result["id"], [1, 0])
+        assert response.status_code == 200
+
+        job = wait_for_job_completion(scrape_api, result["id"])
+        assert recipes_api.get_recipe(job.recipe_id).title == "Roundup Pho"
+        assert len(job.split_job_ids) == 1
+
+        split_job = wait_for_job_completion(scrape_api, job.split_job_ids[0])
+        split_recipe = recipes_api.get_recipe(split_job.recipe_id)
+        assert split_recipe.title == "Roundup Minestrone"
+        assert split_recipe.source_url == "https://example.com/soups"
+
+    def test_select_rejects_out_of_range_index(self, authed_api_client, server_url):
+        """Test that selecting a recipe the page doesn't have is a 400."""
+        client, user_id = authed_api_client
+        token = client.configuration.access_token
+
+        result = capture_recipe(
+            server_url, token, ROUNDUP_HTML, "https://example.com/soups"
+        )
+        wait_for_job_status(server_url, token, result["id"], "awaiting_selection")
+
+        assert select_recipes(server_url, token, result["id"], [2]).status_code == 400
+        assert select_recipes(server_url, token, result["id"], []).status_code == 400
+
+    def test_select_rejects_job_not_awaiting_selection(
+        self, authed_api_client, server_url
+    ):
+        """Test that a single-recipe job can't be sent a selection."""
+        client, user_id = authed_api_client
+        scrape_api = ScrapeApi(client)
+        token = client.configuration.access_token
+
+        html = load_fixture("seriouseats/rice_pilaf.html")
+        result = capture_recipe(
+            server_url, token, html, "https://www.seriouseats.com/rice-pilaf"
+        )
+        job = wait_for_job_completion(scrape_api, result["id"])
+        assert job.split_job_ids == []
+
+        response = select_recipes(server_url, token, result["id"], [0])
+        assert response.status_code == 400
+
+    def test_capture_import_all(self, authed_api_client, server_url):
+        """Test that import_all imports every recipe without asking."""
+        client, user_id = authed_api_client
+        recipes_api = RecipesApi(client)
+        scrape_api = ScrapeApi(client)
+        token = client.configuration.access_token
+
+        response = requests.post(
+            f"{server_url}/api/scrape/capture",
+            json={
+                "html": ROUNDUP_HTML,
+                "source_url": "https://example.com/soups",
+                "import_all": True,
+            },
+            headers={"Authorization": f"Bearer {token}"},
+        )
+        response.raise_for_status()
+
+        job = wait_for_job_completion(scrape_api, response.json()["id"])
+        assert recipes_api.get_recipe(job.recipe_id).title == "Roundup Minestrone"
+        split_job = wait_for_job_completion(scrape_api, job.split_job_ids[0])
+        split_recipe = recipes_api.get_recipe(split_job.recipe_id)
+        assert split_recipe.title == "Roundup Pho"
+
+    def test_cannot_select_for_other_users_job(
+        self, authed_api_client, second_authed_api_client, server_url
+    ):
+        """Test that another user's job is not found."""
+        client1, user1_id = authed_api_client
+        client2, user2_id = second_authed_api_client
+        token1 = client1.configuration.access_token
+        token2 = client2.configuration.access_token
+
+        result = capture_recipe(
+            server_url, token1, ROUNDUP_HTML, "https://example.com/soups"
+        )
+        wait_for_job_status(server_url, token1, result["id"], "awaiting_selection")
+
+        assert select_recipes(server_url, token2, result["id"], [0]).status_code == 404
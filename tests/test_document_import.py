@@ -0,0 +1,163 @@
+"""Tests for importing recipes from PDF and plain-text documents."""
+
+import time
+
+import requests
+
+from ramekin_client.api import RecipesApi, ScrapeApi
+
+
+TEXT_RECIPE = """Weeknight Dal
+
+A quick lentil curry.
+Serves 4
+
+Ingredients
+1 cup red lentils
+1 onion, diced
+2 tsp curry powder
+3 cups water
+
+Method
+Fry the onion until soft, then stir in the curry powder.
+
+Add the lentils and water and simmer for 20 minutes.
+"""
+
+
+def wait_for_job_completion(scrape_api, job_id, timeout=30.0):
+    """Poll job status until completion or timeout."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def _import_document(server_url, token, filename, data, content_type):
+    return requests.post(
+        f"{server_url}/api/import/document",
+        headers={"Authorization": f"Bearer {token}"},
+        files={"file": (filename, data, content_type)},
+    )
+
+
+def _minimal_pdf(lines):
+    """Build a single-page PDF with a text layer containing the given lines."""
+    shown = " ".join(
+        "({}) '".format(line.replace("(", "\\(").replace(")", "\\)")) for line in lines
+    )
+    text_ops = f"BT /F1 12 Tf 72 720 Td 14 TL {shown} ET"
+    objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>",
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] "
+        "/Contents 4 0 R /Resources << /Font << /F1 5 0 R >> >> >>",
+        f"<< /Length {len(text_ops)} >>\nstream\n{text_ops}\nendstream",
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+    ]
+    out = "%PDF-1.4\n"
+    offsets = []
+    for i, obj in enumerate(objects, start=1):
+        offsets.append(len(out))
+        out += f"{i} 0 obj\n{obj}\nendobj\n"
+    xref = len(out)
+    out += f"xref\n0 {len(objects) + 1}\n0000000000 65535 f \n"
+    out += "".join(f"{off:010d} 00000 n \n" for off in offsets)
+    out += f"trailer\n<< /Size {len(objects) + 1} /Root 1 0 R >>\n"
+    out += f"startxref\n{xref}\n%%EOF\n"
+    return out.encode("latin-1")
+
+
+def test_import_plain_text_uses_heuristics(authed_api_client, server_url):
+    """Test that a well-formatted text file is parsed without AI."""
+    client, user_id = authed_api_client
+
+    response = _import_document(
+        server_url,
+        client.configuration.access_token,
+        "dal.txt",
+        TEXT_RECIPE.encode(),
+        "text/plain",
+    )
+    assert response.status_code == 201
+    body = response.json()
+    assert body["ai_extraction"] is False
+
+    job = wait_for_job_completion(ScrapeApi(client), body["job_id"])
+    assert job.status == "completed"
+
+    recipe = RecipesApi(client).get_recipe(job.recipe_id)
+    assert recipe.title == "Weeknight Dal"
+    assert recipe.servings == "Serves 4"
+    assert [i.item for i in recipe.ingredients][0] == "red lentils"
+    assert len(recipe.ingredients) == 4
+    assert recipe.instructions.startswith("Fry the onion")
+    assert recipe.version_source == "document_import"
+
+
+def test_import_pdf_text_layer(authed_api_client, server_url):
+    """Test that the text layer of a PDF is extracted."""
+    client, user_id = authed_api_client
+    pdf = _minimal_pdf(TEXT_RECIPE.splitlines())
+
+    response = _import_document(
+        server_url, client.configuration.access_token, "dal.pdf", pdf, "application/pdf"
+    )
+    assert response.status_code == 201
+
+    job = wait_for_job_completion(ScrapeApi(client), response.json()["job_id"])
+    assert job.status == "completed"
+    assert RecipesApi(client).get_recipe(job.recipe_id).title == "Weeknight Dal"
+
+
+def test_import_unstructured_text_falls_back_to_ai(authed_api_client, server_url):
+    """Test that text the heuristics can't parse is sent to AI extraction."""
+    client, user_id = authed_api_client
+    text = (
+        "Hi! Mum's rice: rinse two cups of rice, then simmer it covered "
+        "with four cups of water for about 18 minutes. Enjoy!"
+    )
+
+    response = _import_document(
+        server_url, client.configuration.access_token, "rice.txt", text, "text/plain"
+    )
+    assert response.status_code == 201
+    body = response.json()
+    assert body["ai_extraction"] is True
+
+    job = wait_for_job_completion(ScrapeApi(client), body["job_id"])
+    assert job.status == "completed"
+    recipe = RecipesApi(client).get_recipe(job.recipe_id)
+    assert recipe.title == "Document Imported Recipe"
+    assert recipe.version_source == "document_import"
+
+
+def test_import_rejects_unsupported_type(authed_api_client, server_url):
+    """Test that non-PDF, non-text files are rejected."""
+    client, user_id = authed_api_client
+
+    response = _import_document(
+        server_url,
+        client.configuration.access_token,
+        "recipe.docx",
+        b"PK\x03\x04",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    )
+    assert response.status_code == 400
+
+
+def test_import_rejects_empty_document(authed_api_client, server_url):
+    """Test that a document without any text is rejected."""
+    client, user_id = authed_api_client
+
+    response = _import_document(
+        server_url,
+        client.configuration.access_token,
+        "blank.txt",
+        b"  \n",
+        "text/plain",
+    )
+    assert response.status_code == 400
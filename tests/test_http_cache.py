@@ -0,0 +1,90 @@
+"""Tests for the shared HTTP cache admin endpoints."""
+
+import os
+import time
+
+import requests
+
+from ramekin_client.api import ScrapeApi
+from ramekin_client.exceptions import ApiException
+from ramekin_client.models import CreateScrapeRequest, LoginRequest, SignupRequest
+
+
+FIXTURE_BASE_URL = os.environ.get("FIXTURE_BASE_URL")
+if not FIXTURE_BASE_URL:
+    raise ValueError("FIXTURE_BASE_URL environment variable required")
+
+# Listed in ADMIN_USERNAMES for the test server
+ADMIN_USERNAME = "test_admin"
+
+
+def _headers(token):
+    return {"Authorization": f"Bearer {token}"}
+
+
+def admin_token(auth_api):
+    """Sign up the admin user, or log in if an earlier run already did."""
+    try:
+        response = auth_api.signup(
+            SignupRequest(username=ADMIN_USERNAME, password="testpass123")
+        )
+    except ApiException:
+        response = auth_api.login(
+            LoginRequest(username=ADMIN_USERNAME, password="testpass123")
+        )
+    return response.token
+
+
+def wait_for_job(scrape_api, job_id, timeout=30.0):
+    """Poll until the job completes."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            assert job.status == "completed", job.error
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def test_http_cache_requires_admin(authed_api_client, server_url):
+    """Test that regular users can't read or purge the shared cache."""
+    client, user_id = authed_api_client
+    headers = _headers(client.configuration.access_token)
+
+    response = requests.get(
+        f"{server_url}/api/admin/http-cache", headers=headers, timeout=10
+    )
+    assert response.status_code == 403
+
+    response = requests.delete(
+        f"{server_url}/api/admin/http-cache", headers=headers, timeout=10
+    )
+    assert response.status_code == 403
+
+
+def test_admin_purges_cached_url(authed_api_client, auth_api, server_url):
+    """Test that a scraped page lands in the shared cache and can be purged."""
+    client, user_id = authed_api_client
+    scrape_api = ScrapeApi(client)
+    url = f"{FIXTURE_BASE_URL}/seriouseats/rice_pilaf.html"
+    wait_for_job(scrape_api, scrape_api.create_scrape(CreateScrapeRequest(url=url)).id)
+
+    headers = _headers(admin_token(auth_api))
+    response = requests.get(
+        f"{server_url}/api/admin/http-cache", headers=headers, timeout=10
+    )
+    assert response.status_code == 200, response.text
+    assert response.json()["cached_success"] >= 1
+
+    response = requests.delete(
+        f"{server_url}/api/admin/http-cache",
+        headers=headers,
+        params={"url": url},
+        timeout=10,
+    )
+    assert response.status_code == 204, response.text
+
+    # Scraping again refetches the page and caches it again
+    job = scrape_api.create_scrape(CreateScrapeRequest(url=url))
+    assert wait_for_job(scrape_api, job.id).recipe_id is not None
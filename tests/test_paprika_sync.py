@@ -0,0 +1,149 @@
+"""Tests for incremental Paprika import keyed by uid and hash."""
+
+import time
+
+import requests
+
+from ramekin_client.api import RecipesApi, ScrapeApi
+
+
+def wait_for_job_completion(scrape_api: ScrapeApi, job_id: str, timeout: float = 10.0):
+    """Poll until job reaches a terminal state (completed or failed)."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def _raw_recipe(title, ingredients="1 cup flour", categories=None):
+    raw = {
+        "title": title,
+        "ingredients": ingredients,
+        "instructions": "Mix and bake",
+    }
+    if categories is not None:
+        raw["categories"] = categories
+    return raw
+
+
+def _import(server_url, token, uid, hash, raw_recipe):
+    return requests.post(
+        f"{server_url}/api/import/paprika",
+        headers={"Authorization": f"Bearer {token}"},
+        json={"raw_recipe": raw_recipe, "photo_ids": [], "uid": uid, "hash": hash},
+    )
+
+
+def _sync_state(server_url, token):
+    response = requests.get(
+        f"{server_url}/api/import/paprika",
+        headers={"Authorization": f"Bearer {token}"},
+    )
+    assert response.status_code == 200
+    return {e["uid"]: e for e in response.json()["recipes"]}
+
+
+def test_reimport_skips_unchanged_and_versions_changed(authed_api_client, server_url):
+    """Test that re-importing adds new, skips unchanged and versions changed recipes."""
+    client, user_id = authed_api_client
+    token = client.configuration.access_token
+    scrape_api = ScrapeApi(client)
+    recipes_api = RecipesApi(client)
+
+    response = _import(server_url, token, "UID-1", "hash-a", _raw_recipe("Scones"))
+    assert response.status_code == 201
+    first = response.json()
+    assert first["outcome"] == "added"
+    job = wait_for_job_completion(scrape_api, first["job_id"])
+    assert job.status == "completed"
+    recipe_id = job.recipe_id
+
+    state = _sync_state(server_url, token)
+    assert state["UID-1"]["hash"] == "hash-a"
+    assert state["UID-1"]["recipe_id"] == str(recipe_id)
+
+    # Same hash: nothing happens
+    response = _import(server_url, token, "UID-1", "hash-a", _raw_recipe("Scones"))
+    assert response.status_code == 200
+    assert response.json() == {
+        "outcome": "unchanged",
+        "job_id": None,
+        "recipe_id": str(recipe_id),
+    }
+
+    # New hash: a new version on the same recipe
+    response = _import(
+        server_url,
+        token,
+        "UID-1",
+        "hash-b",
+        _raw_recipe("Cheese Scones", "1 cup flour\n50g cheddar", ["baking"]),
+    )
+    assert response.status_code == 201
+    updated = response.json()
+    assert updated["outcome"] == "updated"
+    assert updated["recipe_id"] == str(recipe_id)
+    job = wait_for_job_completion(scrape_api, updated["job_id"])
+    assert job.status == "completed"
+    assert str(job.recipe_id) == str(recipe_id)
+
+    recipe = recipes_api.get_recipe(id=recipe_id)
+    assert recipe.title == "Cheese Scones"
+    assert recipe.version_source == "paprika"
+    assert recipe.tags == ["baking"]
+    assert len(recipes_api.list_versions(id=recipe_id).versions) == 2
+    assert len(recipes_api.list_recipes().recipes) == 1
+
+    assert _sync_state(server_url, token)["UID-1"]["hash"] == "hash-b"
+
+
+def test_deleted_recipe_is_imported_again(authed_api_client, server_url):
+    """Test that a Paprika recipe whose Ramekin recipe was deleted is re-added."""
+    client, user_id = authed_api_client
+    token = client.configuration.access_token
+    scrape_api = ScrapeApi(client)
+
+    response = _import(server_url, token, "UID-2", "hash-a", _raw_recipe("Tart"))
+    job = wait_for_job_completion(scrape_api, response.json()["job_id"])
+    RecipesApi(client).delete_recipe(id=job.recipe_id)
+
+    assert "UID-2" not in _sync_state(server_url, token)
+
+    response = _import(server_url, token, "UID-2", "hash-a", _raw_recipe("Tart"))
+    assert response.status_code == 201
+    assert response.json()["outcome"] == "added"
+
+
+def test_sync_state_is_per_user(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test that one user's Paprika imports don't affect another's."""
+    client, user_id = authed_api_client
+    response = _import(
+        server_url,
+        client.configuration.access_token,
+        "UID-3",
+        "hash-a",
+        _raw_recipe("Shared Uid"),
+    )
+    wait_for_job_completion(ScrapeApi(client), response.json()["job_id"])
+
+    client2, user_id2 = second_authed_api_client
+    token2 = client2.configuration.access_token
+    assert _sync_state(server_url, token2) == {}
+
+    response = _import(server_url, token2, "UID-3", "hash-a", _raw_recipe("Shared Uid"))
+    assert response.status_code == 201
+    assert response.json()["outcome"] == "added"
+
+
+def test_import_requires_uid_and_hash(authed_api_client, server_url):
+    """Test that an empty uid is rejected."""
+    client, user_id = authed_api_client
+    response = _import(
+        server_url, client.configuration.access_token, "", "hash", _raw_recipe("X")
+    )
+    assert response.status_code == 400
@@ -3,6 +3,7 @@
 import time
 
 import pytest
+import requests
 
 from ramekin_client.api import ImportApi, PhotosApi, RecipesApi, ScrapeApi
 
@@ -52,6 +53,43 @@ class TestPhotoImport:
         assert len(recipe.instructions) > 0
         assert photo_id in recipe.photo_ids
 
+    def test_import_splits_recipes_and_orders_pages(
+        self, authed_api_client, server_url, test_image
+    ):
+        """Test that a batch holding two recipes is split into two imports."""
+        client, user_id = authed_api_client
+        photos_api = PhotosApi(client)
+        import_api = ImportApi(client)
+        scrape_api = ScrapeApi(client)
+        recipes_api = RecipesApi(client)
+
+        photo_ids = [
+            photos_api.upload(file=(f"page{i}.png", test_image)).id for i in range(3)
+        ]
+
+        # The mock AI puts photos 3 and 1 in the first recipe and photo 2 in a second
+        response = import_api.import_from_photos(
+            import_from_photos_request={"photo_ids": photo_ids}
+        )
+        job = wait_for_job_completion(scrape_api, response.job_id)
+        assert job.status == "completed"
+
+        first = recipes_api.get_recipe(job.recipe_id)
+        assert first.title == "Photo Imported Recipe"
+        assert first.photo_ids == [photo_ids[2], photo_ids[0]]
+
+        raw_job = requests.get(
+            f"{server_url}/api/scrape/{response.job_id}",
+            headers={"Authorization": f"Bearer {client.configuration.access_token}"},
+        ).json()
+        assert len(raw_job["split_job_ids"]) == 1
+
+        split_job = wait_for_job_completion(scrape_api, raw_job["split_job_ids"][0])
+        assert split_job.status == "completed"
+        second = recipes_api.get_recipe(split_job.recipe_id)
+        assert second.title == "Second Photo Recipe"
+        assert second.photo_ids == [photo_ids[1]]
+
     def test_import_with_empty_photo_list_fails(self, authed_api_client):
         """Test that photo import requires at least one photo."""
         client, user_id = authed_api_client
@@ -1,3 +1,5 @@
+import datetime
+
 import pytest
 
 from conftest import make_ingredient
@@ -894,6 +896,43 @@ def test_combined_search_and_filters(authed_api_client):
     assert response.recipes[0].title == "Chicken Dinner"
 
 
+def test_filter_by_total_time(authed_api_client):
+    """Test filtering by parsed total time, falling back to prep + cook."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Quick Salad",
+            instructions="Toss it",
+            ingredients=[],
+            total_time="15 mins",
+        )
+    )
+    recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Braise",
+            instructions="Braise it",
+            ingredients=[],
+            prep_time="20 minutes",
+            cook_time="2 hrs",
+        )
+    )
+    recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Untimed Stew",
+            instructions="Stew it",
+            ingredients=[],
+        )
+    )
+
+    response = recipes_api.list_recipes(q="time:<30")
+    assert [r.title for r in response.recipes] == ["Quick Salad"]
+
+    response = recipes_api.list_recipes(q="time:>1h")
+    assert [r.title for r in response.recipes] == ["Braise"]
+
+
 def test_quoted_search_phrase(authed_api_client):
     """Test searching with quoted phrases."""
     client, user_id = authed_api_client
@@ -1372,6 +1411,14 @@ def test_create_recipe_with_paprika_fields(authed_api_client):
     assert recipe.nutritional_info == "200 calories per serving"
     assert recipe.notes == "Chef's tip: use fresh herbs."
 
+    # Times and yield are also parsed into numbers
+    assert recipe.prep_time_minutes == 15
+    assert recipe.cook_time_minutes == 30
+    assert recipe.total_time_minutes == 45
+    assert recipe.yield_quantity == 4
+    assert recipe.yield_quantity_max is None
+    assert recipe.yield_unit == "servings"
+
 
 def test_update_recipe_paprika_fields(authed_api_client):
     """Test updating Paprika-compatible fields on a recipe."""
@@ -1404,6 +1451,43 @@ def test_update_recipe_paprika_fields(authed_api_client):
     assert recipe.notes == "Updated notes"
 
 
+def test_recipe_source_details(authed_api_client):
+    """Test video, equipment, author and publish date through create and update."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    response = recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Sourdough",
+            instructions="Bake it.",
+            ingredients=[],
+            video_url="https://www.youtube.com/embed/abc123",
+            equipment=["Dutch oven", "Bench scraper"],
+            author="Ann Baker",
+            published_date=datetime.date(2024, 3, 5),
+        )
+    )
+
+    recipe = recipes_api.get_recipe(str(response.id))
+    assert recipe.video_url == "https://www.youtube.com/embed/abc123"
+    assert recipe.equipment == ["Dutch oven", "Bench scraper"]
+    assert recipe.author == "Ann Baker"
+    assert recipe.published_date == datetime.date(2024, 3, 5)
+
+    # Editing another field keeps them; they can be changed like any other field
+    recipes_api.update_recipe(str(response.id), UpdateRecipeRequest(title="Rye"))
+    recipe = recipes_api.get_recipe(str(response.id))
+    assert recipe.author == "Ann Baker"
+    assert recipe.equipment == ["Dutch oven", "Bench scraper"]
+
+    recipes_api.update_recipe(
+        str(response.id), UpdateRecipeRequest(equipment=["Loaf pan"])
+    )
+    recipe = recipes_api.get_recipe(str(response.id))
+    assert recipe.equipment == ["Loaf pan"]
+    assert recipe.video_url == "https://www.youtube.com/embed/abc123"
+
+
 def test_recipe_paprika_fields_optional(authed_api_client):
     """Test that Paprika fields are optional and default to None."""
     client, user_id = authed_api_client
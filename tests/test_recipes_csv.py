@@ -0,0 +1,168 @@
+"""Tests for CSV bulk export and import of recipe metadata."""
+
+import csv
+import io
+
+import requests
+
+from conftest import make_ingredient
+from ramekin_client.api import RecipesApi
+from ramekin_client.models import CreateRecipeRequest
+
+
+def _export_csv(server_url, token):
+    response = requests.get(
+        f"{server_url}/api/recipes/export/csv",
+        headers={"Authorization": f"Bearer {token}"},
+    )
+    assert response.status_code == 200
+    assert response.headers["content-type"].startswith("text/csv")
+    return list(csv.DictReader(io.StringIO(response.text)))
+
+
+def _import_csv(server_url, token, rows, fieldnames):
+    buf = io.StringIO()
+    writer = csv.DictWriter(buf, fieldnames=fieldnames)
+    writer.writeheader()
+    writer.writerows(rows)
+    return requests.post(
+        f"{server_url}/api/recipes/import/csv",
+        headers={"Authorization": f"Bearer {token}"},
+        files={"file": ("recipes.csv", buf.getvalue(), "text/csv")},
+    )
+
+
+def test_export_csv_one_row_per_recipe(authed_api_client, server_url):
+    """Test that the CSV export has one row per recipe with tags joined."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    recipe = recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Pancakes",
+            instructions="Flip",
+            ingredients=[make_ingredient(item="flour")],
+            tags=["quick", "breakfast"],
+            source_name="Test Kitchen",
+            rating=4,
+            difficulty="Easy",
+            prep_time="10 mins",
+        )
+    )
+    recipes_api.create_recipe(
+        CreateRecipeRequest(title="Waffles", instructions="Press", ingredients=[])
+    )
+
+    rows = _export_csv(server_url, client.configuration.access_token)
+
+    assert [r["title"] for r in rows] == ["Pancakes", "Waffles"]
+    pancakes = rows[0]
+    assert pancakes["id"] == str(recipe.id)
+    assert pancakes["tags"] == "breakfast; quick"
+    assert pancakes["source_name"] == "Test Kitchen"
+    assert pancakes["rating"] == "4"
+    assert pancakes["difficulty"] == "Easy"
+    assert pancakes["prep_time"] == "10 mins"
+    assert rows[1]["rating"] == ""
+
+
+def test_import_csv_creates_bulk_edit_versions(authed_api_client, server_url):
+    """Test that edited rows become new versions and unchanged rows are skipped."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    edited = recipes_api.create_recipe(
+        CreateRecipeRequest(
+            title="Soup", instructions="Simmer", ingredients=[], tags=["dinner"]
+        )
+    )
+    untouched = recipes_api.create_recipe(
+        CreateRecipeRequest(title="Salad", instructions="Toss", ingredients=[])
+    )
+
+    token = client.configuration.access_token
+    rows = _export_csv(server_url, token)
+    fieldnames = list(rows[0].keys())
+    for row in rows:
+        if row["id"] == str(edited.id):
+            row["rating"] = "5"
+            row["difficulty"] = "Hard"
+            row["tags"] = "dinner; winter"
+
+    response = _import_csv(server_url, token, rows, fieldnames)
+    assert response.status_code == 200
+    result = response.json()
+    assert result == {"updated": 1, "unchanged": 1, "errors": []}
+
+    recipe = recipes_api.get_recipe(id=edited.id)
+    assert recipe.rating == 5
+    assert recipe.difficulty == "Hard"
+    assert set(recipe.tags) == {"dinner", "winter"}
+    assert recipe.version_source == "bulk_edit"
+    # Fields not in the CSV are carried over from the previous version
+    assert recipe.instructions == "Simmer"
+
+    assert recipes_api.get_recipe(id=untouched.id).version_source == "user"
+
+
+def test_import_csv_reports_row_errors(authed_api_client, server_url):
+    """Test that invalid rows are reported without blocking valid rows."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+
+    recipe = recipes_api.create_recipe(
+        CreateRecipeRequest(title="Bread", instructions="Bake", ingredients=[])
+    )
+
+    rows = [
+        {"id": str(recipe.id), "rating": "3"},
+        {"id": str(recipe.id), "rating": "eleven"},
+        {"id": "not-a-uuid", "rating": "2"},
+        {"id": "00000000-0000-0000-0000-000000000000", "rating": "2"},
+    ]
+    response = _import_csv(
+        server_url, client.configuration.access_token, rows, ["id", "rating"]
+    )
+    assert response.status_code == 200
+    result = response.json()
+    assert result["updated"] == 1
+    assert [e["line"] for e in result["errors"]] == [3, 4, 5]
+    assert "Rating" in result["errors"][0]["error"]
+    assert result["errors"][1]["recipe_id"] is None
+    assert result["errors"][2]["error"] == "Recipe not found"
+
+    assert recipes_api.get_recipe(id=recipe.id).rating == 3
+
+
+def test_import_csv_rejects_unknown_column(authed_api_client, server_url):
+    """Test that a header with an unknown column is rejected up front."""
+    client, user_id = authed_api_client
+
+    response = _import_csv(
+        server_url,
+        client.configuration.access_token,
+        [{"id": "00000000-0000-0000-0000-000000000000", "colour": "red"}],
+        ["id", "colour"],
+    )
+    assert response.status_code == 400
+
+
+def test_import_csv_cannot_edit_other_users_recipes(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test that rows referencing another user's recipe are rejected."""
+    client, user_id = authed_api_client
+    recipe = RecipesApi(client).create_recipe(
+        CreateRecipeRequest(title="Mine", instructions="Private", ingredients=[])
+    )
+
+    client2, user_id2 = second_authed_api_client
+    response = _import_csv(
+        server_url,
+        client2.configuration.access_token,
+        [{"id": str(recipe.id), "title": "Stolen"}],
+        ["id", "title"],
+    )
+    assert response.status_code == 200
+    assert response.json()["errors"][0]["error"] == "Recipe not found"
+    assert RecipesApi(client).get_recipe(id=recipe.id).title == "Mine"
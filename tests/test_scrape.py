@@ -54,6 +54,22 @@ class TestScrapeSuccess:
         assert len(recipe.ingredients) > 0
         assert len(recipe.instructions) > 0
 
+    def test_scrape_records_extraction_quality(self, authed_api_client):
+        """Test that a scraped job reports how far its recipe can be trusted."""
+        client, user_id = authed_api_client
+        scrape_api = ScrapeApi(client)
+
+        url = f"{FIXTURE_BASE_URL}/seriouseats/rice_pilaf.html"
+        response = scrape_api.create_scrape(CreateScrapeRequest(url=url))
+        job = wait_for_job_completion(scrape_api, response.id)
+
+        assert job.status == "completed"
+        assert job.extraction_confidence is not None
+        assert 0.0 < job.extraction_confidence <= 1.0
+        for warning in job.warnings:
+            assert warning.kind
+            assert warning.message
+
     def test_scrape_parses_ingredient_measurements(self, authed_api_client):
         """Test that scraping parses ingredient measurements correctly.
 
@@ -0,0 +1,189 @@
+"""Tests for batch URL import."""
+
+import os
+import time
+
+import requests
+
+from ramekin_client.api import RecipesApi, ScrapeApi
+from ramekin_client.models import CreateScrapeRequest
+
+
+FIXTURE_BASE_URL = os.environ.get("FIXTURE_BASE_URL")
+if not FIXTURE_BASE_URL:
+    raise ValueError("FIXTURE_BASE_URL environment variable required")
+
+
+def _headers(client):
+    return {"Authorization": f"Bearer {client.configuration.access_token}"}
+
+
+def _get_batch(server_url, client, batch_id):
+    response = requests.get(
+        f"{server_url}/api/scrape/batch/{batch_id}",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 200, response.text
+    return response.json()
+
+
+def wait_for_batch(server_url, client, batch_id, timeout=60.0):
+    """Poll the batch until none of its jobs are pending."""
+    start = time.time()
+    while time.time() - start < timeout:
+        batch = _get_batch(server_url, client, batch_id)
+        if batch["pending"] == 0:
+            return batch
+        time.sleep(0.2)
+    raise TimeoutError(f"Batch {batch_id} did not finish within {timeout}s")
+
+
+def test_batch_reports_completed_failed_duplicate_and_invalid(
+    authed_api_client, server_url
+):
+    """Test that a JSON URL list is summarized with per-URL outcomes."""
+    client, user_id = authed_api_client
+    pilaf = f"{FIXTURE_BASE_URL}/seriouseats/rice_pilaf.html"
+    biscuits = f"{FIXTURE_BASE_URL}/seriouseats/cream_biscuits.html"
+    no_recipe = f"{FIXTURE_BASE_URL}/no_jsonld.html"
+
+    response = requests.post(
+        f"{server_url}/api/scrape/batch",
+        headers=_headers(client),
+        json={
+            "urls": [pilaf, f"{pilaf}#recipe", "not a url", no_recipe, biscuits, " "]
+        },
+        timeout=10,
+    )
+    assert response.status_code == 201, response.text
+    created = response.json()
+    assert created["total"] == 5
+    assert created["duplicate"] == 1
+    assert created["invalid"] == 1
+
+    batch = wait_for_batch(server_url, client, created["id"])
+    assert (
+        batch["completed"],
+        batch["failed"],
+        batch["duplicate"],
+        batch["invalid"],
+    ) == (2, 1, 1, 1)
+
+    items = batch["items"]
+    assert [item["url"] for item in items] == [
+        pilaf,
+        pilaf,
+        "not a url",
+        no_recipe,
+        biscuits,
+    ]
+    assert [item["status"] for item in items] == [
+        "completed",
+        "duplicate",
+        "invalid",
+        "failed",
+        "completed",
+    ]
+    assert "item 1" in items[1]["error"]
+    assert "job_id" not in items[1]
+    assert items[2]["error"].startswith("Invalid URL")
+    assert items[3]["error"]
+
+    recipe = RecipesApi(client).get_recipe(items[0]["recipe_id"])
+    assert recipe.source_url == pilaf
+
+
+def test_bookmarks_upload_skips_recipes_already_imported(
+    authed_api_client, server_url
+):
+    """Test that an uploaded bookmarks file marks library recipes as duplicates."""
+    client, user_id = authed_api_client
+    scrape_api = ScrapeApi(client)
+    pilaf = f"{FIXTURE_BASE_URL}/seriouseats/rice_pilaf.html"
+    kale = f"{FIXTURE_BASE_URL}/smittenkitchen/baked_kale_chips.html"
+
+    job = scrape_api.create_scrape(CreateScrapeRequest(url=pilaf))
+    start = time.time()
+    while scrape_api.get_scrape(job.id).status != "completed":
+        assert time.time() - start < 30, "initial scrape did not complete"
+        time.sleep(0.1)
+    existing_recipe_id = scrape_api.get_scrape(job.id).recipe_id
+
+    bookmarks = f"""<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<DL><p>
+    <DT><H3>Recipes</H3>
+    <DL><p>
+        <DT><A HREF="{pilaf}">Rice Pilaf</A>
+        <DT><A HREF="place:sort=8&maxResults=10">Recently Bookmarked</A>
+        <DT><A HREF="{kale}">Kale Chips</A>
+    </DL><p>
+</DL><p>
+"""
+    response = requests.post(
+        f"{server_url}/api/scrape/batch",
+        headers=_headers(client),
+        files={"file": ("bookmarks.html", bookmarks, "text/html")},
+        timeout=10,
+    )
+    assert response.status_code == 201, response.text
+
+    batch = wait_for_batch(server_url, client, response.json()["id"])
+    assert batch["total"] == 2
+    duplicate, imported = batch["items"]
+    assert duplicate["status"] == "duplicate"
+    assert duplicate["recipe_id"] == str(existing_recipe_id)
+    assert imported["url"] == kale
+    assert imported["status"] == "completed"
+
+
+def test_plain_text_upload(authed_api_client, server_url):
+    """Test that a plain text list is read one URL per line."""
+    client, user_id = authed_api_client
+    apple_cake = f"{FIXTURE_BASE_URL}/smittenkitchen/moms_apple_cake.html"
+
+    response = requests.post(
+        f"{server_url}/api/scrape/batch",
+        headers=_headers(client),
+        files={"file": ("urls.txt", f"# my list\n\n{apple_cake}\n", "text/plain")},
+        timeout=10,
+    )
+    assert response.status_code == 201, response.text
+    batch = response.json()
+    assert [item["url"] for item in batch["items"]] == [apple_cake]
+    assert batch["items"][0]["job_id"]
+
+
+def test_empty_batch_rejected(authed_api_client, server_url):
+    """Test that a batch without URLs is rejected."""
+    client, user_id = authed_api_client
+    response = requests.post(
+        f"{server_url}/api/scrape/batch",
+        headers=_headers(client),
+        json={"urls": []},
+        timeout=10,
+    )
+    assert response.status_code == 400
+
+
+def test_batch_is_per_user(authed_api_client, second_authed_api_client, server_url):
+    """Test that another user's batch is not found."""
+    client, user_id = authed_api_client
+    response = requests.post(
+        f"{server_url}/api/scrape/batch",
+        headers=_headers(client),
+        json={"urls": ["not a url"]},
+        timeout=10,
+    )
+    assert response.status_code == 201
+    batch_id = response.json()["id"]
+
+    client2, user_id2 = second_authed_api_client
+    response = requests.get(
+        f"{server_url}/api/scrape/batch/{batch_id}",
+        headers=_headers(client2),
+        timeout=10,
+    )
+    assert response.status_code == 404
@@ -0,0 +1,112 @@
+"""Tests for cancelling scrape jobs."""
+
+import json
+import os
+import time
+import uuid
+
+import requests
+
+from ramekin_client.api import RecipesApi, ScrapeApi
+from ramekin_client.models import CreateScrapeRequest
+
+
+FIXTURE_BASE_URL = os.environ.get("FIXTURE_BASE_URL")
+if not FIXTURE_BASE_URL:
+    raise ValueError("FIXTURE_BASE_URL environment variable required")
+
+# Matches SLOW_DELAY_SECS in fixture_server.py
+SLOW_DELAY_SECS = 5
+
+
+def _cancel(server_url, client, job_id):
+    return requests.post(
+        f"{server_url}/api/scrape/{job_id}/cancel",
+        headers={"Authorization": f"Bearer {client.configuration.access_token}"},
+        timeout=10,
+    )
+
+
+def wait_for_status(scrape_api, job_id, statuses, timeout=10.0):
+    """Poll until the job reaches one of the given statuses."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in statuses:
+            return job
+        time.sleep(0.05)
+    raise TimeoutError(f"Job {job_id} did not reach {statuses} within {timeout}s")
+
+
+def test_cancel_during_fetch_creates_no_recipe(authed_api_client, server_url):
+    """Test that cancelling a job mid-fetch stops it without saving a recipe."""
+    client, user_id = authed_api_client
+    scrape_api = ScrapeApi(client)
+
+    # Unique query string so a cached response can't make the fetch fast
+    url = f"{FIXTURE_BASE_URL}/slow/seriouseats/rice_pilaf.html?run={uuid.uuid4()}"
+    job_id = scrape_api.create_scrape(CreateScrapeRequest(url=url)).id
+    wait_for_status(scrape_api, job_id, ("scraping",))
+
+    response = _cancel(server_url, client, job_id)
+    assert response.status_code == 200, response.text
+    assert response.json() == {"id": str(job_id), "status": "cancelled"}
+
+    # Give the slow fixture time to respond, in case the fetch wasn't aborted
+    time.sleep(SLOW_DELAY_SECS + 1)
+
+    job = scrape_api.get_scrape(job_id)
+    assert job.status == "cancelled"
+    assert job.recipe_id is None
+    assert job.can_retry is False
+    assert RecipesApi(client).list_recipes().recipes == []
+
+    with requests.get(
+        f"{server_url}/api/scrape/{job_id}/events",
+        headers={"Authorization": f"Bearer {client.configuration.access_token}"},
+        stream=True,
+        timeout=10,
+    ) as stream:
+        lines = [
+            line
+            for line in stream.iter_lines(decode_unicode=True)
+            if line and not line.startswith(":")
+        ]
+    assert lines[0].startswith("event:")
+    assert lines[0][len("event:") :].strip() == "cancelled"
+    assert json.loads(lines[1][len("data:") :]) == {
+        "type": "cancelled",
+        "job_id": str(job_id),
+    }
+    assert len(lines) == 2
+
+
+def test_cancel_finished_job_rejected(authed_api_client, server_url):
+    """Test that a completed job can't be cancelled."""
+    client, user_id = authed_api_client
+    scrape_api = ScrapeApi(client)
+
+    url = f"{FIXTURE_BASE_URL}/seriouseats/rice_pilaf.html"
+    job_id = scrape_api.create_scrape(CreateScrapeRequest(url=url)).id
+    job = wait_for_status(scrape_api, job_id, ("completed", "failed"), timeout=30)
+    assert job.status == "completed"
+
+    response = _cancel(server_url, client, job_id)
+    assert response.status_code == 400
+    assert scrape_api.get_scrape(job_id).status == "completed"
+
+
+def test_cancel_other_users_job_not_found(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test that another user's job can't be cancelled."""
+    client, user_id = authed_api_client
+    url = f"{FIXTURE_BASE_URL}/slow/seriouseats/rice_pilaf.html?run={uuid.uuid4()}"
+    job_id = ScrapeApi(client).create_scrape(CreateScrapeRequest(url=url)).id
+
+    client2, user_id2 = second_authed_api_client
+    response = _cancel(server_url, client2, job_id)
+    assert response.status_code == 404
+
+    # Clean up so the slow fetch doesn't outlive the test
+    assert _cancel(server_url, client, job_id).status_code == 200
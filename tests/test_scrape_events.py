@@ -0,0 +1,130 @@
+"""Tests for scrape job progress server-sent events."""
+
+import json
+import time
+
+import requests
+
+from ramekin_client.api import ImportApi, ScrapeApi
+
+
+def wait_for_job_completion(scrape_api, job_id, timeout=30.0):
+    """Poll job status until completion or timeout."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def _open_stream(server_url, token, path):
+    return requests.get(
+        f"{server_url}{path}",
+        headers={"Authorization": f"Bearer {token}"},
+        stream=True,
+        timeout=30,
+    )
+
+
+def _events(response):
+    """Yield (event name, parsed data) pairs from an SSE response."""
+    name, data = None, []
+    for line in response.iter_lines(decode_unicode=True):
+        if line.startswith(":"):
+            continue
+        if not line:
+            if data:
+                yield name, json.loads("\n".join(data))
+            name, data = None, []
+        elif line.startswith("event:"):
+            name = line[len("event:") :].strip()
+        elif line.startswith("data:"):
+            data.append(line[len("data:") :].strip())
+
+
+def _import_recipe(client, title):
+    return ImportApi(client).import_recipe(
+        import_recipe_request={
+            "raw_recipe": {
+                "title": title,
+                "ingredients": "1 cup flour",
+                "instructions": "Mix and bake",
+                "image_urls": [],
+            },
+            "photo_ids": [],
+            "extraction_method": "json_ld",
+        }
+    )
+
+
+def test_user_stream_reports_steps_and_completion(authed_api_client, server_url):
+    """Test that the user-wide stream reports each step and the saved recipe."""
+    client, user_id = authed_api_client
+    token = client.configuration.access_token
+
+    with _open_stream(server_url, token, "/api/scrape/events") as response:
+        assert response.status_code == 200
+        assert response.headers["content-type"].startswith("text/event-stream")
+
+        job_id = str(_import_recipe(client, "Streamed Recipe").job_id)
+
+        seen = []
+        for name, data in _events(response):
+            if data["job_id"] != job_id:
+                continue
+            assert data["type"] == name
+            seen.append(data)
+            if name in ("completed", "failed"):
+                break
+
+    started = [e["step"] for e in seen if e["type"] == "step_started"]
+    finished = [e for e in seen if e["type"] == "step_finished"]
+    assert "parse_ingredients" in started
+    assert "save_recipe" in started
+    assert [e["step"] for e in finished] == started
+    assert all(e["duration_ms"] >= 0 for e in finished)
+
+    job = ScrapeApi(client).get_scrape(job_id)
+    assert seen[-1] == {
+        "type": "completed",
+        "job_id": job_id,
+        "recipe_id": str(job.recipe_id),
+    }
+
+
+def test_job_stream_for_finished_job_sends_final_event(authed_api_client, server_url):
+    """Test that a finished job's stream sends its final event and ends."""
+    client, user_id = authed_api_client
+    job_id = str(_import_recipe(client, "Finished Recipe").job_id)
+    job = wait_for_job_completion(ScrapeApi(client), job_id)
+
+    with _open_stream(
+        server_url, client.configuration.access_token, f"/api/scrape/{job_id}/events"
+    ) as response:
+        assert response.status_code == 200
+        events = list(_events(response))
+
+    assert events == [
+        (
+            "completed",
+            {"type": "completed", "job_id": job_id, "recipe_id": str(job.recipe_id)},
+        )
+    ]
+
+
+def test_job_stream_is_per_user(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test that another user's job stream is not found."""
+    client, user_id = authed_api_client
+    job_id = _import_recipe(client, "Private Recipe").job_id
+
+    client2, user_id2 = second_authed_api_client
+    response = requests.get(
+        f"{server_url}/api/scrape/{job_id}/events",
+        headers={"Authorization": f"Bearer {client2.configuration.access_token}"},
+        timeout=10,
+    )
+    assert response.status_code == 404
@@ -0,0 +1,43 @@
+"""Tests for the durable scrape job queue."""
+
+import time
+
+from ramekin_client.api import ImportApi, ScrapeApi
+
+
+def wait_for_job_completion(scrape_api, job_id, timeout=30.0):
+    """Poll job status until completion or timeout."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def test_queued_jobs_beyond_worker_concurrency_all_complete(authed_api_client):
+    """Test that more jobs than the workers run at once all get processed."""
+    client, user_id = authed_api_client
+    import_api = ImportApi(client)
+    scrape_api = ScrapeApi(client)
+
+    job_ids = []
+    for i in range(20):
+        response = import_api.import_recipe(
+            import_recipe_request={
+                "raw_recipe": {
+                    "title": f"Queued Recipe {i}",
+                    "ingredients": "1 cup flour",
+                    "instructions": "Mix and bake",
+                    "image_urls": [],
+                },
+                "photo_ids": [],
+                "extraction_method": "json_ld",
+            }
+        )
+        job_ids.append(response.job_id)
+
+    jobs = [wait_for_job_completion(scrape_api, job_id) for job_id in job_ids]
+    assert [job.status for job in jobs] == ["completed"] * len(job_ids)
+    assert len({job.recipe_id for job in jobs}) == len(job_ids)
@@ -0,0 +1,234 @@
+"""Tests for upstream change checks on scraped recipes."""
+
+import os
+import time
+
+import requests
+
+from ramekin_client.api import RecipesApi, ScrapeApi
+from ramekin_client.models import CreateScrapeRequest, UpdateRecipeRequest
+
+
+FIXTURE_BASE_URL = os.environ.get("FIXTURE_BASE_URL")
+if not FIXTURE_BASE_URL:
+    raise ValueError("FIXTURE_BASE_URL environment variable required")
+
+
+def _headers(client):
+    return {"Authorization": f"Bearer {client.configuration.access_token}"}
+
+
+def wait_for_job(scrape_api, job_id, timeout=30.0):
+    """Poll until the job completes."""
+    start = time.time()
+    while time.time() - start < timeout:
+        job = scrape_api.get_scrape(job_id)
+        if job.status in ("completed", "failed"):
+            assert job.status == "completed", job.error
+            return job
+        time.sleep(0.1)
+    raise TimeoutError(f"Job {job_id} did not complete within {timeout}s")
+
+
+def scrape_recipe(client, path="seriouseats/rice_pilaf.html"):
+    scrape_api = ScrapeApi(client)
+    url = f"{FIXTURE_BASE_URL}/{path}"
+    job = scrape_api.create_scrape(CreateScrapeRequest(url=url))
+    return wait_for_job(scrape_api, job.id).recipe_id
+
+
+def set_check(server_url, client, recipe_id, enabled):
+    return requests.put(
+        f"{server_url}/api/recipes/{recipe_id}/upstream-check",
+        headers=_headers(client),
+        json={"enabled": enabled},
+        timeout=10,
+    )
+
+
+def run_check(server_url, client, recipe_id):
+    response = requests.post(
+        f"{server_url}/api/recipes/{recipe_id}/upstream-check/run",
+        headers=_headers(client),
+        timeout=30,
+    )
+    assert response.status_code == 200, response.text
+    return response.json()
+
+
+def list_updates(server_url, client):
+    response = requests.get(
+        f"{server_url}/api/upstream-updates", headers=_headers(client), timeout=10
+    )
+    assert response.status_code == 200, response.text
+    return response.json()["updates"]
+
+
+def test_unchanged_recipe_has_no_update(authed_api_client, server_url):
+    """Test that checking a recipe that matches its source finds nothing."""
+    client, user_id = authed_api_client
+    recipe_id = scrape_recipe(client)
+
+    response = set_check(server_url, client, recipe_id, True)
+    assert response.status_code == 200, response.text
+    assert response.json()["enabled"] is True
+    assert response.json()["checked_at"] is None
+
+    check = run_check(server_url, client, recipe_id)
+    assert check["checked_at"] is not None
+    assert check["last_error"] is None
+    assert check["pending_update"] is None
+
+    # The second check sends the page's validators back and gets a 304
+    check = run_check(server_url, client, recipe_id)
+    assert check["last_error"] is None
+    assert check["pending_update"] is None
+    assert list_updates(server_url, client) == []
+
+
+def test_accept_upstream_update_creates_version(authed_api_client, server_url):
+    """Test that an accepted update saves the source's recipe as a new version."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+    recipe_id = scrape_recipe(client)
+    upstream_title = recipes_api.get_recipe(recipe_id).title
+
+    # A local edit makes the current version differ from the source page
+    recipes_api.update_recipe(
+        id=recipe_id,
+        update_recipe_request=UpdateRecipeRequest(title="My Pilaf"),
+    )
+    edited_version_id = recipes_api.get_recipe(recipe_id).version_id
+
+    assert set_check(server_url, client, recipe_id, True).status_code == 200
+    update = run_check(server_url, client, recipe_id)["pending_update"]
+    assert update is not None
+    assert update["changed_fields"] == ["title"]
+    assert update["base_version_id"] == str(edited_version_id)
+    assert update["upstream"]["title"] == upstream_title
+    assert update["upstream"]["ingredients"]
+
+    assert [u["id"] for u in list_updates(server_url, client)] == [update["id"]]
+
+    response = requests.post(
+        f"{server_url}/api/upstream-updates/{update['id']}/accept",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 201, response.text
+    job = wait_for_job(ScrapeApi(client), response.json()["job_id"])
+    assert job.recipe_id == recipe_id
+
+    recipe = recipes_api.get_recipe(recipe_id)
+    assert recipe.title == upstream_title
+    assert recipe.version_source == "rescrape"
+    assert list_updates(server_url, client) == []
+
+    # Already accepted
+    response = requests.post(
+        f"{server_url}/api/upstream-updates/{update['id']}/accept",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 400
+
+
+def test_accept_upstream_update_keeps_notes_and_tags(authed_api_client, server_url):
+    """Test that accepting an update keeps the notes and tags the user added."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+    recipe_id = scrape_recipe(client)
+
+    recipes_api.update_recipe(
+        id=recipe_id,
+        update_recipe_request=UpdateRecipeRequest(
+            title="My Pilaf",
+            notes="Use chicken stock",
+            tags=["weeknight", "sides"],
+        ),
+    )
+
+    assert set_check(server_url, client, recipe_id, True).status_code == 200
+    update = run_check(server_url, client, recipe_id)["pending_update"]
+    assert update is not None
+
+    response = requests.post(
+        f"{server_url}/api/upstream-updates/{update['id']}/accept",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 201, response.text
+    wait_for_job(ScrapeApi(client), response.json()["job_id"])
+
+    recipe = recipes_api.get_recipe(recipe_id)
+    assert recipe.title != "My Pilaf"
+    assert recipe.notes == "Use chicken stock"
+    assert {"weeknight", "sides"} <= set(recipe.tags)
+
+
+def test_dismissed_update_not_offered_again(authed_api_client, server_url):
+    """Test that a dismissed update stays dismissed on later checks."""
+    client, user_id = authed_api_client
+    recipes_api = RecipesApi(client)
+    recipe_id = scrape_recipe(client, "smittenkitchen/baked_kale_chips.html")
+    recipes_api.update_recipe(
+        id=recipe_id,
+        update_recipe_request=UpdateRecipeRequest(instructions="Bake until crisp."),
+    )
+
+    assert set_check(server_url, client, recipe_id, True).status_code == 200
+    update = run_check(server_url, client, recipe_id)["pending_update"]
+    assert update["changed_fields"] == ["instructions"]
+
+    response = requests.post(
+        f"{server_url}/api/upstream-updates/{update['id']}/dismiss",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 204
+
+    assert run_check(server_url, client, recipe_id)["pending_update"] is None
+    assert list_updates(server_url, client) == []
+    assert recipes_api.get_recipe(recipe_id).instructions == "Bake until crisp."
+
+
+def test_run_requires_opt_in(authed_api_client, server_url):
+    """Test that checks only run for recipes that opted in."""
+    client, user_id = authed_api_client
+    recipe_id = scrape_recipe(client)
+
+    response = requests.post(
+        f"{server_url}/api/recipes/{recipe_id}/upstream-check/run",
+        headers=_headers(client),
+        timeout=10,
+    )
+    assert response.status_code == 400
+
+    assert set_check(server_url, client, recipe_id, True).status_code == 200
+    response = set_check(server_url, client, recipe_id, False)
+    assert response.status_code == 200
+    assert response.json()["enabled"] is False
+
+
+def test_upstream_updates_are_per_user(
+    authed_api_client, second_authed_api_client, server_url
+):
+    """Test that another user can't see or resolve a user's updates."""
+    client, user_id = authed_api_client
+    recipe_id = scrape_recipe(client)
+    RecipesApi(client).update_recipe(
+        id=recipe_id,
+        update_recipe_request=UpdateRecipeRequest(title="My Pilaf"),
+    )
+    assert set_check(server_url, client, recipe_id, True).status_code == 200
+    update = run_check(server_url, client, recipe_id)["pending_update"]
+
+    client2, user_id2 = second_authed_api_client
+    assert list_updates(server_url, client2) == []
+    assert set_check(server_url, client2, recipe_id, True).status_code == 404
+    response = requests.post(
+        f"{server_url}/api/upstream-updates/{update['id']}/dismiss",
+        headers=_headers(client2),
+        timeout=10,
+    )
+    assert response.status_code == 404